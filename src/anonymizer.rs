@@ -0,0 +1,811 @@
+// SPDX-FileCopyrightText: 2025 RustInFinance
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use calamine::{open_workbook, DataType, Reader, Xlsx};
+
+use crate::pdfparser;
+
+/// Rewrites financial documents (PDF statements, Revolut CSV, G&L XLSX) so that they can be
+/// shared: personal identifiers (given names, addresses, account numbers) are replaced and
+/// amounts are scaled by the same factor. Anonymized documents are still parsed the same way
+/// as original ones
+pub struct Anonymizer {
+    scale: f64,
+    // Texts given by user e.g. name and address (case insensitive)
+    identifiers: Vec<regex::Regex>,
+    // Account numbers found in documents and their replacements, so that the same number
+    // is replaced the same way in all documents
+    replacements: HashMap<String, String>,
+    // Previous word was label of account number e.g. "ACCT:", "Account Number:"
+    after_account_label: bool,
+}
+
+// Text of anonymized PDF page: operator (TJ/Tj), position and text
+type PageText = Vec<(String, (f32, f32), String)>;
+
+// Columns of G&L document holding quantity of shares (not amounts)
+const QUANTITY_COLUMNS: [&str; 2] = ["Qty.", "Liczba"];
+// Headers of columns of PDF statements holding quantity of shares
+const PDF_QUANTITY_COLUMNS: [&str; 2] = ["Quantity", "QUANTITY"];
+
+impl Anonymizer {
+    pub fn new(scale: f64, identifiers: &[String]) -> Result<Anonymizer, String> {
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(format!(
+                "Error: scale of amounts has to be positive: {scale}"
+            ));
+        }
+        Ok(Anonymizer {
+            scale,
+            identifiers: identifiers
+                .iter()
+                .map(|i| i.trim())
+                .filter(|i| !i.is_empty())
+                .map(|i| {
+                    regex::Regex::new(&format!("(?i){}", regex::escape(i)))
+                        .map_err(|e| format!("Error: invalid identifier: {i}: {e}"))
+                })
+                .collect::<Result<Vec<regex::Regex>, String>>()?,
+            replacements: HashMap::new(),
+            after_account_label: false,
+        })
+    }
+
+    /// Scale (between 0.5 and 1.5) not known to reader of anonymized documents. Keys of
+    /// hasher are random (taken from entropy source of operating system)
+    pub fn random_scale() -> f64 {
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u8(0);
+        0.5 + (hasher.finish() % 1_000_000) as f64 / 1_000_000.0
+    }
+
+    // Replace identifiers given by user and account numbers. Amounts are scaled when
+    // requested
+    fn anonymize_text(&mut self, text: &str, scale_amounts: bool) -> String {
+        let mut text = text.to_owned();
+        for (i, identifier) in self.identifiers.iter().enumerate() {
+            text = identifier
+                .replace_all(&text, format!("ANONYMOUS {}", i + 1).as_str())
+                .into_owned();
+        }
+        // Label may be in other piece of text than account number, so it is remembered
+        // between calls
+        let mut words: Vec<String> = vec![];
+        let original: Vec<&str> = text.split(' ').collect();
+        for (i, word) in original.iter().enumerate() {
+            let labeled = self.after_account_label;
+            let core = word
+                .trim_matches(|c: char| !c.is_ascii_alphanumeric())
+                .to_uppercase();
+            self.after_account_label = match core.as_str() {
+                "ACCOUNT" | "ACCT" => true,
+                "NUMBER" | "NO" | "NR" | "" => labeled,
+                _ => false,
+            };
+            if scale_amounts {
+                let currency_follows = original.get(i + 1).is_some_and(|next| {
+                    ["PLN", "USD", "EUR"].contains(&next.trim_end_matches([',', ')']))
+                });
+                if let Some(amount) = scale_amount(word, self.scale, currency_follows) {
+                    words.push(amount);
+                    continue;
+                }
+            }
+            words.push(self.replace_account_number(word, labeled));
+        }
+        words.join(" ")
+    }
+
+    fn replace_account_number(&mut self, word: &str, labeled: bool) -> String {
+        let core = word.trim_matches(|c: char| !c.is_ascii_alphanumeric());
+        if !is_account_number(core, labeled) {
+            return word.to_owned();
+        }
+        let count = self.replacements.len() + 1;
+        let replacement = self
+            .replacements
+            .entry(core.to_owned())
+            .or_insert_with(|| {
+                // Digits are replaced with number of account (right aligned)
+                let digits = core.chars().filter(|c| c.is_ascii_digit()).count();
+                let mut number = format!("{count:0digits$}")
+                    .chars()
+                    .rev()
+                    .collect::<Vec<_>>();
+                core.chars()
+                    .rev()
+                    .map(|c| match c.is_ascii_digit() {
+                        true => number.remove(0),
+                        false => c,
+                    })
+                    .collect::<Vec<char>>()
+                    .into_iter()
+                    .rev()
+                    .collect()
+            })
+            .clone();
+        log::info!("Anonymizer: replaced account number: {core} -> {replacement}");
+        word.replacen(core, &replacement, 1)
+    }
+
+    /// Anonymize document and store it in output directory under the same name. Type of
+    /// document is recognized the same way as for computing taxes (by extension of file)
+    pub fn anonymize_document(&mut self, input: &str, output_dir: &str) -> Result<String, String> {
+        let name = Path::new(input)
+            .file_name()
+            .ok_or(format!("Error: invalid name of document: {input}"))?;
+        let output: PathBuf = Path::new(output_dir).join(name);
+        if std::fs::canonicalize(&output).ok() == std::fs::canonicalize(input).ok() {
+            return Err(format!(
+                "Error: anonymized document would overwrite original one: {input}"
+            ));
+        }
+        std::fs::create_dir_all(output_dir)
+            .map_err(|e| format!("Error: unable to create directory {output_dir}: {e}"))?;
+        if input.contains(".pdf") {
+            self.anonymize_pdf(input, &output)?;
+        } else if input.contains(".xlsx") {
+            self.anonymize_xlsx(input, &output)?;
+        } else if input.contains(".csv") || input.contains(".tsv") {
+            self.anonymize_csv(input, &output)?;
+        } else {
+            return Err(format!("Error: Unable to open a file: {input}"));
+        }
+        Ok(output.display().to_string())
+    }
+
+    /// PDF document is written again from its text only (with the same text operators and
+    /// positions), so nothing else of original document (images, metadata) is kept
+    pub fn anonymize_pdf(&mut self, input: &str, output: &Path) -> Result<(), String> {
+        let pages: Vec<PageText> = pdfparser::extract_text(input)?
+            .into_iter()
+            .map(|page| {
+                let quantities = in_quantity_column(&page);
+                page.into_iter()
+                    .zip(quantities)
+                    .map(|((operator, position, text), quantity)| {
+                        // Text that can not be decoded is ignored by parsers anyway. Quantity
+                        // of shares is not scaled, but amounts in its column (e.g. totals) are
+                        let text = text.map(|t| {
+                            let currency = t.contains(['$', '€'])
+                                || ["PLN", "USD", "EUR"].iter().any(|c| t.contains(c));
+                            self.anonymize_text(&t, !quantity || currency)
+                        });
+                        (operator, position, text.unwrap_or_default())
+                    })
+                    .collect()
+            })
+            .collect();
+        std::fs::write(output, create_pdf(&pages))
+            .map_err(|e| format!("Error: unable to write {}: {e}", output.display()))
+    }
+
+    pub fn anonymize_csv(&mut self, input: &str, output: &Path) -> Result<(), String> {
+        let delimiter = match input.ends_with(".tsv") {
+            true => b'\t',
+            false => b',',
+        };
+        let mut rdr = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(delimiter)
+            .from_path(input)
+            .map_err(|e| format!("Error: opening CSV {input}: {e}"))?;
+        let mut wtr = csv::WriterBuilder::new()
+            .flexible(true)
+            .delimiter(delimiter)
+            .from_path(output)
+            .map_err(|e| format!("Error: creating {}: {e}", output.display()))?;
+        for record in rdr.records() {
+            let record = record.map_err(|e| format!("Error: reading CSV {input}: {e}"))?;
+            let fields: Vec<String> = record
+                .iter()
+                .map(|field| self.anonymize_text(field, true))
+                .collect();
+            wtr.write_record(&fields)
+                .map_err(|e| format!("Error: writing {}: {e}", output.display()))?;
+        }
+        wtr.flush()
+            .map_err(|e| format!("Error: writing {}: {e}", output.display()))
+    }
+
+    /// All worksheets are written again (values only). Amounts are scaled apart from
+    /// columns of quantity
+    pub fn anonymize_xlsx(&mut self, input: &str, output: &Path) -> Result<(), String> {
+        let mut excel: Xlsx<_> =
+            open_workbook(input).map_err(|_| format!("Error opening XLSX file: {input}"))?;
+        let mut sheets: Vec<(String, Vec<Vec<Option<String>>>)> = vec![];
+        for name in excel.sheet_names().to_owned() {
+            let range = match excel.worksheet_range(&name) {
+                Some(Ok(r)) => r,
+                _ => return Err(format!("Error: unable to read worksheet {name} of {input}")),
+            };
+            let mut rows = range.rows();
+            let header: Vec<DataType> = rows.next().map(|r| r.to_vec()).unwrap_or_default();
+            let quantities: Vec<bool> = header
+                .iter()
+                .map(|c| {
+                    c.get_string()
+                        .is_some_and(|h| QUANTITY_COLUMNS.contains(&h.trim()))
+                })
+                .collect();
+            let mut cells = vec![header
+                .iter()
+                .map(|c| self.anonymize_cell(c, false))
+                .collect()];
+            for row in rows {
+                cells.push(
+                    row.iter()
+                        .enumerate()
+                        .map(|(i, c)| {
+                            self.anonymize_cell(c, !quantities.get(i).copied().unwrap_or(false))
+                        })
+                        .collect(),
+                );
+            }
+            sheets.push((name, cells));
+        }
+        write_xlsx(&sheets, output)
+    }
+
+    // Cell of worksheet as XML element content (None when cell is empty)
+    fn anonymize_cell(&mut self, cell: &DataType, scale_amounts: bool) -> Option<String> {
+        let number = |value: f64| format!("<v>{value}</v>");
+        match cell {
+            DataType::Empty => None,
+            DataType::String(s) => Some(format!(
+                "<is><t>{}</t></is>",
+                escape_xml(&self.anonymize_text(s, scale_amounts))
+            )),
+            DataType::Float(v) if scale_amounts => Some(number(v * self.scale)),
+            DataType::Int(v) if scale_amounts => Some(number(*v as f64 * self.scale)),
+            DataType::Float(v) | DataType::DateTime(v) | DataType::Duration(v) => Some(number(*v)),
+            DataType::Int(v) => Some(number(*v as f64)),
+            DataType::Bool(v) => Some(format!("<v>{}</v>", *v as u8)),
+            other => Some(format!(
+                "<is><t>{}</t></is>",
+                escape_xml(&other.to_string())
+            )),
+        }
+    }
+}
+
+// Masked or dashed account numbers and IBANs e.g. XXXX-1234, 123-456789-012,
+// PL61109010140000071219812874. Other numbers (e.g. quantities of shares) are account
+// numbers only when labeled as such e.g. "Account Number: 12345678". Dates and ISINs of
+// securities (e.g. US2058871029) are not account numbers
+fn is_account_number(word: &str, labeled: bool) -> bool {
+    static MASKED: OnceLock<regex::Regex> = OnceLock::new();
+    static IBAN: OnceLock<regex::Regex> = OnceLock::new();
+    static NUMBER: OnceLock<regex::Regex> = OnceLock::new();
+    static DATE: OnceLock<regex::Regex> = OnceLock::new();
+    let masked = MASKED.get_or_init(|| {
+        regex::Regex::new(r"^[A-Z]{0,4}-?(X+-?\d{4,}|\d+(-\d+)+)$")
+            .expect("Unable to create regular expression to capture account number")
+    });
+    let iban = IBAN.get_or_init(|| {
+        regex::Regex::new(r"^[A-Z]{2}\d{2}[A-Z0-9]{11,30}$")
+            .expect("Unable to create regular expression to capture IBAN")
+    });
+    let number = NUMBER.get_or_init(|| {
+        regex::Regex::new(r"^[\dX-]*\d{4,}[\dX-]*$")
+            .expect("Unable to create regular expression to capture account number")
+    });
+    let date = DATE.get_or_init(|| {
+        regex::Regex::new(r"^(\d{4}-\d{2}-\d{2}|\d{1,2}-\d{1,2}-\d{2,4})$")
+            .expect("Unable to create regular expression to capture date")
+    });
+    let digits = word.chars().filter(|c| c.is_ascii_digit()).count();
+    ((masked.is_match(word) && digits >= 4)
+        || iban.is_match(word)
+        || (labeled && number.is_match(word)))
+        && !date.is_match(word)
+}
+
+/// Scale amount keeping its format e.g. "$1,234.50", "(12.34)", "+2,500 PLN", "-€5.10",
+/// "10,50", "+US$17," (amounts listed in one field). Numbers without currency, decimal part of two digits or thousands separators
+/// (e.g. quantities, exchange rates, years) are not amounts. Currency may also be given
+/// in the following word e.g. "617 PLN"
+fn scale_amount(word: &str, scale: f64, currency_follows: bool) -> Option<String> {
+    static AMOUNT: OnceLock<regex::Regex> = OnceLock::new();
+    static GROUPED: OnceLock<regex::Regex> = OnceLock::new();
+    let re = AMOUNT.get_or_init(|| {
+        regex::Regex::new(
+        r"^(?P<prefix>[-+(]*(?:US\$|\$|€)?[-+]?)(?P<number>\d{1,3}(?:,\d{3})+(?:\.\d+)?|\d+(?:[.,]\d+)?)(?P<suffix>(?:€|\$|PLN|USD|EUR)?\)?,?)$",
+    )
+    .expect("Unable to create regular expression to capture amount")
+    });
+    let captures = re.captures(word)?;
+    let (prefix, number, suffix) = (
+        &captures["prefix"],
+        &captures["number"],
+        &captures["suffix"],
+    );
+    let grouped = GROUPED
+        .get_or_init(|| {
+            regex::Regex::new(r"^\d{1,3}(,\d{3})+(\.\d+)?$")
+                .expect("Unable to create regular expression to capture amount")
+        })
+        .is_match(number);
+    let decimal_comma = !grouped && number.contains(',');
+    let decimals = match decimal_comma {
+        true => number.split_once(','),
+        false => number.split_once('.'),
+    }
+    .map(|(_, d)| d.len())
+    .unwrap_or(0);
+    let currency = [prefix, suffix].iter().any(|s| {
+        s.contains(['$', '€']) || s.contains("PLN") || s.contains("USD") || s.contains("EUR")
+    });
+    if !currency && !currency_follows && !grouped && decimals != 2 {
+        return None;
+    }
+    let value: f64 = match decimal_comma {
+        true => number.replace(',', "."),
+        false => number.replace(',', ""),
+    }
+    .parse()
+    .ok()?;
+    let mut scaled = format!("{:.*}", decimals, value * scale);
+    if grouped {
+        let (integer, fraction) = scaled.split_once('.').unwrap_or((&scaled, ""));
+        let mut with_separators = integer
+            .chars()
+            .rev()
+            .collect::<Vec<char>>()
+            .chunks(3)
+            .map(|c| c.iter().rev().collect::<String>())
+            .rev()
+            .collect::<Vec<String>>()
+            .join(",");
+        if !fraction.is_empty() {
+            with_separators = format!("{with_separators}.{fraction}");
+        }
+        scaled = with_separators;
+    } else if decimal_comma {
+        scaled = scaled.replace('.', ",");
+    }
+    Some(format!("{prefix}{scaled}{suffix}"))
+}
+
+/// Which texts of PDF page are in column of quantity of shares. Column of text is the one
+/// whose header (in the nearest row of headers above text) is the closest horizontally
+fn in_quantity_column(page: &pdfparser::PageText) -> Vec<bool> {
+    let same_row = |a: f32, b: f32| (a - b).abs() < 1.0;
+    let is_quantity = |text: &Option<String>| {
+        text.as_deref()
+            .is_some_and(|t| PDF_QUANTITY_COLUMNS.contains(&t.trim()))
+    };
+    let header_rows: Vec<f32> = page
+        .iter()
+        .filter(|(_, _, text)| is_quantity(text))
+        .map(|(_, (_, y), _)| *y)
+        .collect();
+    page.iter()
+        .map(|(_, (x, y), _)| {
+            let Some(header_y) = header_rows
+                .iter()
+                .filter(|h| **h > *y && !same_row(**h, *y))
+                .min_by(|a, b| a.total_cmp(b))
+            else {
+                return false;
+            };
+            page.iter()
+                .filter(|(_, (_, h), _)| same_row(*h, *header_y))
+                .min_by(|(_, (a, _), _), (_, (b, _), _)| (a - x).abs().total_cmp(&(b - x).abs()))
+                .is_some_and(|(_, _, text)| is_quantity(text))
+        })
+        .collect()
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn create_pdf(pages: &[PageText]) -> Vec<u8> {
+    let escape = |text: &str| {
+        text.replace('\\', "\\\\")
+            .replace('(', "\\(")
+            .replace(')', "\\)")
+    };
+    let mut objects: Vec<Vec<u8>> = vec![
+        b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            (0..pages.len())
+                .map(|i| format!("{} 0 R", 4 + 2 * i))
+                .collect::<Vec<String>>()
+                .join(" "),
+            pages.len()
+        )
+        .into_bytes(),
+        b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_vec(),
+    ];
+    for (i, page) in pages.iter().enumerate() {
+        let mut content = String::from("BT\n/F1 9 Tf\n");
+        for (operator, (x, y), text) in page {
+            content.push_str(&format!("1 0 0 1 {x} {y} Tm\n"));
+            match operator.as_str() {
+                "TJ" => content.push_str(&format!("[({})] TJ\n", escape(text))),
+                _ => content.push_str(&format!("({}) Tj\n", escape(text))),
+            }
+        }
+        content.push_str("ET");
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                5 + 2 * i
+            )
+            .into_bytes(),
+        );
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend(content.into_bytes());
+        stream.extend(b"\nendstream");
+        objects.push(stream);
+    }
+
+    let mut data = b"%PDF-1.4\n".to_vec();
+    let mut offsets = vec![];
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(data.len());
+        data.extend(format!("{} 0 obj\n", i + 1).into_bytes());
+        data.extend(object);
+        data.extend(b"\nendobj\n");
+    }
+    let xref = data.len();
+    data.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).into_bytes());
+    offsets
+        .iter()
+        .for_each(|o| data.extend(format!("{o:010} 00000 n \n").into_bytes()));
+    data.extend(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .into_bytes(),
+    );
+    data
+}
+
+// Name of cell e.g. A1, AB12 (row and column indexed from 0)
+fn cell_name(row: usize, column: usize) -> String {
+    let mut name = String::new();
+    let mut column = column + 1;
+    while column > 0 {
+        name.insert(0, (b'A' + ((column - 1) % 26) as u8) as char);
+        column = (column - 1) / 26;
+    }
+    format!("{name}{}", row + 1)
+}
+
+// Minimal XLSX workbook (values only) of worksheets: name and rows of cells
+fn write_xlsx(sheets: &[(String, Vec<Vec<Option<String>>>)], output: &Path) -> Result<(), String> {
+    let file = std::fs::File::create(output)
+        .map_err(|e| format!("Error: creating {}: {e}", output.display()))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::FileOptions::default();
+    let mut files: Vec<(String, String)> = vec![
+        (
+            "[Content_Types].xml".to_owned(),
+            format!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>{}</Types>"#,
+                (1..=sheets.len())
+                    .map(|i| format!(r#"<Override PartName="/xl/worksheets/sheet{i}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#))
+                    .collect::<String>()
+            ),
+        ),
+        (
+            "_rels/.rels".to_owned(),
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#.to_owned(),
+        ),
+        (
+            "xl/workbook.xml".to_owned(),
+            format!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>{}</sheets></workbook>"#,
+                sheets
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (name, _))| format!(
+                        r#"<sheet name="{}" sheetId="{}" r:id="rId{}"/>"#,
+                        escape_xml(name),
+                        i + 1,
+                        i + 1
+                    ))
+                    .collect::<String>()
+            ),
+        ),
+        (
+            "xl/_rels/workbook.xml.rels".to_owned(),
+            format!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{}</Relationships>"#,
+                (1..=sheets.len())
+                    .map(|i| format!(r#"<Relationship Id="rId{i}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{i}.xml"/>"#))
+                    .collect::<String>()
+            ),
+        ),
+    ];
+    for (i, (_, rows)) in sheets.iter().enumerate() {
+        let rows = rows
+            .iter()
+            .enumerate()
+            .map(|(r, cells)| {
+                format!(
+                    r#"<row r="{}">{}</row>"#,
+                    r + 1,
+                    cells
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(c, cell)| cell.as_ref().map(|v| (c, v)))
+                        .map(|(c, value)| {
+                            let inline = match value.starts_with("<is>") {
+                                true => r#" t="inlineStr""#,
+                                false => "",
+                            };
+                            format!(r#"<c r="{}"{inline}>{value}</c>"#, cell_name(r, c))
+                        })
+                        .collect::<String>()
+                )
+            })
+            .collect::<String>();
+        files.push((
+            format!("xl/worksheets/sheet{}.xml", i + 1),
+            format!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>{rows}</sheetData></worksheet>"#
+            ),
+        ));
+    }
+    for (name, content) in files {
+        zip.start_file(name.as_str(), options)
+            .map_err(|e| format!("Error: writing {}: {e}", output.display()))?;
+        zip.write_all(content.as_bytes())
+            .map_err(|e| format!("Error: writing {}: {e}", output.display()))?;
+    }
+    zip.finish()
+        .map_err(|e| format!("Error: writing {}: {e}", output.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!(
+                "etradeTaxReturnHelper_test_{}/{name}",
+                std::process::id()
+            ))
+            .display()
+            .to_string()
+    }
+
+    #[test]
+    fn test_scale_amount() {
+        let scale = |word: &str| scale_amount(word, 2.0, false);
+        assert_eq!(scale("$1,234.50"), Some("$2,469.00".to_owned()));
+        assert_eq!(scale("(12.34)"), Some("(24.68)".to_owned()));
+        assert_eq!(scale("-$0.51"), Some("-$1.02".to_owned()));
+        assert_eq!(scale("+2,500"), Some("+5,000".to_owned()));
+        assert_eq!(scale("$286"), Some("$572".to_owned()));
+        assert_eq!(scale("+US$10,961.04"), Some("+US$21,922.08".to_owned()));
+        assert_eq!(scale("10,50"), Some("21,00".to_owned()));
+        assert_eq!(scale("-€5.10"), Some("-€10.20".to_owned()));
+        assert_eq!(scale("+US$17,"), Some("+US$34,".to_owned()));
+        // Quantities, exchange rates, years and dates are not amounts
+        assert_eq!(scale("-5"), None);
+        assert_eq!(scale("23.52941176"), None);
+        assert_eq!(scale("0.2324"), None);
+        assert_eq!(scale("2023"), None);
+        assert_eq!(scale("03/01/22"), None);
+        assert_eq!(scale("6,"), None);
+        // Currency in the following word
+        assert_eq!(scale_amount("617", 2.0, true), Some("1234".to_owned()));
+    }
+
+    #[test]
+    fn test_anonymize_text() -> Result<(), String> {
+        let mut anonymizer = Anonymizer::new(2.0, &["John Doe".to_owned()])?;
+        assert_eq!(
+            anonymizer.anonymize_text("JOHN DOE ACCT: 123-456789 Balance: 1,000.00", true),
+            "ANONYMOUS 1 ACCT: 000-000001 Balance: 2,000.00"
+        );
+        // The same account numbers are replaced the same way
+        assert_eq!(
+            anonymizer.anonymize_text("PL61109010140000071219812874, 123-456789", false),
+            "PL00000000000000000000000002, 000-000001"
+        );
+        assert_eq!(
+            anonymizer.anonymize_text("2023-09-09T05:35:43Z 12/31/2023 Q1 2026 US2058871029", true),
+            "2023-09-09T05:35:43Z 12/31/2023 Q1 2026 US2058871029"
+        );
+        // Quantities are not account numbers unless labeled as such
+        assert_eq!(
+            anonymizer.anonymize_text("Sold 1500 12000 Account Number:", false),
+            "Sold 1500 12000 Account Number:"
+        );
+        assert_eq!(
+            anonymizer.anonymize_text("12345678 1500", false),
+            "00000003 1500"
+        );
+        assert!(Anonymizer::new(0.0, &[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_anonymize_pdf() -> Result<(), String> {
+        // Amounts of original document are doubled, quantity is not changed and account
+        // number is replaced
+        let mut anonymizer = Anonymizer::new(2.0, &[])?;
+        let output = anonymizer.anonymize_document(
+            "data/brokerage_statement_example.pdf",
+            &test_dir("anonymized_pdf"),
+        )?;
+        assert_eq!(
+            pdfparser::parse_document(&output),
+            Ok(pdfparser::PdfDocument::Statement(
+                crate::Account::new("E*TRADE", Some("XXXX-0001")),
+                (
+                    vec![],
+                    vec![
+                        (
+                            "03/01/22".to_owned(),
+                            1396.5,
+                            209.48,
+                            Some("INTC".to_owned())
+                        ),
+                        ("03/15/22".to_owned(), 48.0, 7.2, Some("DLB".to_owned())),
+                    ],
+                    vec![(
+                        "03/02/22".to_owned(),
+                        "03/04/22".to_owned(),
+                        1.0,
+                        87.38,
+                        87.34,
+                        Some("INTC".to_owned())
+                    )],
+                    vec![],
+                    vec![],
+                )
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_anonymize_pdf_quantity_column() -> Result<(), String> {
+        let text = |x: f32, y: f32, t: &str| ("Tj".to_owned(), (x, y), t.to_owned());
+        let input_dir = test_dir("quantities_pdf");
+        std::fs::create_dir_all(&input_dir).map_err(|e| e.to_string())?;
+        let input = format!("{input_dir}/quantities.pdf");
+        std::fs::write(
+            &input,
+            create_pdf(&[vec![
+                text(40.0, 760.0, "Security Description"),
+                text(110.0, 760.0, "Quantity"),
+                text(180.0, 760.0, "Share Price"),
+                text(250.0, 760.0, "Current Yield %"),
+                text(40.0, 746.0, "INTEL CORP (INTC)"),
+                text(115.0, 746.0, "10.00"),
+                text(180.0, 746.0, "$50.25"),
+                text(250.0, 746.0, "1.00"),
+                text(40.0, 732.0, "TOTAL STOCKS"),
+                text(110.0, 732.0, "$502.50"),
+            ]]),
+        )
+        .map_err(|e| e.to_string())?;
+        let mut anonymizer = Anonymizer::new(2.0, &[])?;
+        let output = anonymizer.anonymize_document(&input, &test_dir("anonymized_quantities"))?;
+        let texts: Vec<Option<String>> = pdfparser::extract_text(&output)?
+            .concat()
+            .into_iter()
+            .map(|(_, _, t)| t)
+            .collect();
+        assert_eq!(
+            texts[4..],
+            [
+                Some("INTEL CORP (INTC)".to_owned()),
+                Some("10.00".to_owned()),
+                Some("$100.50".to_owned()),
+                Some("2.00".to_owned()),
+                Some("TOTAL STOCKS".to_owned()),
+                Some("$1005.00".to_owned()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_scale() {
+        let scales: Vec<f64> = (0..10).map(|_| Anonymizer::random_scale()).collect();
+        assert!(scales.iter().all(|s| (0.5..1.5).contains(s)));
+        assert!(scales.iter().any(|s| *s != scales[0]));
+    }
+
+    #[test]
+    fn test_anonymize_xlsx() -> Result<(), String> {
+        let mut anonymizer = Anonymizer::new(2.0, &[])?;
+        let output = anonymizer
+            .anonymize_document("data/G&L_Expanded.xlsx", &test_dir("anonymized_xlsx"))?;
+        let original = crate::xlsxparser::parse_gains_and_losses("data/G&L_Expanded.xlsx")?;
+        let anonymized = crate::xlsxparser::parse_gains_and_losses(&output)?;
+        assert_eq!(original.len(), anonymized.len());
+        original.iter().zip(anonymized.iter()).for_each(
+            |(
+                (acquired, sold, cost, basis, proceeds, lot),
+                (a_acquired, a_sold, a_cost, a_basis, a_proceeds, a_lot),
+            )| {
+                assert_eq!((acquired, sold), (a_acquired, a_sold));
+                assert_eq!(
+                    (cost * 2.0, basis * 2.0, proceeds * 2.0),
+                    (*a_cost, *a_basis, *a_proceeds)
+                );
+                assert_eq!(lot.quantity, a_lot.quantity);
+            },
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_anonymize_csv() -> Result<(), String> {
+        let mut anonymizer = Anonymizer::new(2.0, &[])?;
+        for document in [
+            "revolut_data/revolut_div.csv",
+            "revolut_data/consolidated-statement-v2-eng.csv",
+            // Quantity of sold bonds: 3100
+            "revolut_data/consolidated-statement_2024.csv",
+        ] {
+            let output = anonymizer.anonymize_document(document, &test_dir("anonymized_csv"))?;
+            let original = crate::csvparser::parse_revolut_transactions(document)?;
+            let anonymized = crate::csvparser::parse_revolut_transactions(&output)?;
+            assert_eq!(
+                original
+                    .dividend_transactions
+                    .iter()
+                    .map(|(date, gross, tax, company)| (
+                        date.clone(),
+                        gross.derive(gross.value() * 2.0),
+                        tax.derive(tax.value() * 2.0),
+                        company.clone()
+                    ))
+                    .collect::<Vec<_>>(),
+                anonymized.dividend_transactions
+            );
+            assert_eq!(
+                original
+                    .sold_transactions
+                    .iter()
+                    .map(|(acquired, sold, cost, income, company, lot)| (
+                        acquired.clone(),
+                        sold.clone(),
+                        cost.derive(cost.value() * 2.0),
+                        income.derive(income.value() * 2.0),
+                        company.clone(),
+                        lot.quantity
+                    ))
+                    .collect::<Vec<_>>(),
+                anonymized
+                    .sold_transactions
+                    .iter()
+                    .map(|(acquired, sold, cost, income, company, lot)| (
+                        acquired.clone(),
+                        sold.clone(),
+                        *cost,
+                        *income,
+                        company.clone(),
+                        lot.quantity
+                    ))
+                    .collect::<Vec<_>>()
+            );
+        }
+        Ok(())
+    }
+}
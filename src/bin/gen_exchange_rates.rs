@@ -1,19 +1,26 @@
 // SPDX-FileCopyrightText: 2024-2025 RustInFinance
 // SPDX-License-Identifier: BSD-3-Clause
 
+use chrono::{Datelike, NaiveDate};
 use clap::{Arg, Command};
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fs;
 
+// NBP API does not serve more than 93 days in a single query
+const MAX_DAYS_PER_QUERY: i64 = 93;
+
 #[derive(Deserialize)]
+#[allow(dead_code)]
 struct Kurs {
     no: String,
-    effectiveDate: String,
+    #[serde(rename = "effectiveDate")]
+    effective_date: String,
     mid: f64,
 }
 
 #[derive(Deserialize)]
+#[allow(dead_code)]
 struct Tabela {
     table: String,
     currency: String,
@@ -21,101 +28,324 @@ struct Tabela {
     rates: Vec<Kurs>,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
-pub enum Exchange {
-    EUR(String),
-    PLN(String),
-    USD(String),
+// Key: (currency, date). Sorted so generated file is stable
+type Kursy = BTreeMap<(String, String), f64>;
+
+fn add_table(kursy_map: &mut Kursy, table: Tabela) -> Result<(), String> {
+    let code = table.code.to_uppercase();
+    match code.as_str() {
+        "USD" | "EUR" | "PLN" => {
+            for kurs in table.rates {
+                kursy_map.insert((code.clone(), kurs.effective_date), kurs.mid);
+            }
+            Ok(())
+        }
+        _ => Err(format!("Unsupported currency: {}", table.code)),
+    }
+}
+
+// Read existing exchange rates data file (currency,date,rate)
+fn read_data_file(path: &str, kursy_map: &mut Kursy) -> Result<(), String> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .comment(Some(b'#'))
+        .trim(csv::Trim::All)
+        .from_path(path)
+        .map_err(|e| format!("Unable to open {path}: {e}"))?;
+    for record in rdr.records() {
+        let record = record.map_err(|e| format!("Unable to read {path}: {e}"))?;
+        match (
+            record.get(0),
+            record.get(1),
+            record.get(2).and_then(|r| r.parse::<f64>().ok()),
+        ) {
+            (Some(code), Some(data), Some(kurs)) => {
+                kursy_map.insert((code.to_uppercase(), data.to_owned()), kurs);
+            }
+            _ => return Err(format!("Ill formed entry in {path}: {:?}", record)),
+        }
+    }
+    Ok(())
+}
+
+// Split [from, to] into consecutive ranges not longer than max_days
+fn split_into_ranges(from: NaiveDate, to: NaiveDate, max_days: i64) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut ranges = vec![];
+    let mut start = from;
+    while start <= to {
+        let end = std::cmp::min(start + chrono::Duration::days(max_days - 1), to);
+        ranges.push((start, end));
+        start = end + chrono::Duration::days(1);
+    }
+    ranges
+}
+
+fn fetch_table(
+    client: &reqwest::blocking::Client,
+    base_url: &str,
+    currency: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Option<Tabela>, String> {
+    let url = format!(
+        "{}/{}/{}/{}/?format=json",
+        base_url.trim_end_matches('/'),
+        currency.to_lowercase(),
+        from.format("%Y-%m-%d"),
+        to.format("%Y-%m-%d")
+    );
+    eprintln!("Fetching: {url}");
+    let response = etradeTaxReturnHelper::send_with_retry(
+        || client.get(&url),
+        &etradeTaxReturnHelper::RetryPolicy::default(),
+    )
+    .map_err(|e| format!("Getting exchange rates from NBP ({url}) failed: {e}"))?;
+    // NBP responds with 404 when there is no rate published in a given range
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        return Err(format!(
+            "Getting exchange rates from NBP ({url}) failed: {}",
+            response.status()
+        ));
+    }
+    response
+        .json::<Tabela>()
+        .map(Some)
+        .map_err(|e| format!("Unable to parse response from {url}: {e}"))
+}
+
+fn is_working_day(date: &NaiveDate) -> Result<bool, String> {
+    match date.weekday() {
+        chrono::Weekday::Sat | chrono::Weekday::Sun => Ok(false),
+        _ => holidays::contains(holidays::Country::PL, *date)
+            .map(|holiday| !holiday)
+            .map_err(|_| format!("Error checking if given date: {date} is holiday")),
+    }
+}
+
+// Working days in [from, to] for which there is no exchange rate of given currency
+fn find_gaps(
+    kursy_map: &Kursy,
+    currency: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<NaiveDate>, String> {
+    let mut gaps = vec![];
+    let mut date = from;
+    while date <= to {
+        if is_working_day(&date)?
+            && !kursy_map.contains_key(&(currency.to_owned(), date.format("%Y-%m-%d").to_string()))
+        {
+            gaps.push(date);
+        }
+        date += chrono::Duration::days(1);
+    }
+    Ok(gaps)
+}
+
+fn to_csv(kursy_map: &Kursy) -> String {
+    let mut output_content = String::new();
+    output_content.push_str("currency,date,rate\n");
+    for ((code, data), kurs) in kursy_map {
+        output_content.push_str(&format!("{code},{data},{kurs}\n"));
+    }
+    output_content
 }
 
 fn main() {
     let matches = Command::new("etradeTaxHelper")
-        .version("1.1")
+        .version("1.2")
         .arg_required_else_help(true)
-        .about("Consumes NBP exchange rates and produces rust source code with it")
+        .about("Downloads (or consumes) NBP exchange rates (table A) and produces exchange rates data file (CSV) with it")
         .arg(
             Arg::new("input")
                 .long("input")
                 .value_name("FILE")
-                .help("Sets the input files")
+                .help("NBP JSON files with exchange rates to be used")
                 .num_args(1..)
-                .action(clap::ArgAction::Append)
-                .required(true),
+                .action(clap::ArgAction::Append),
+        )
+        .arg(
+            Arg::new("currency")
+                .long("currency")
+                .value_name("CURRENCY")
+                .help("Currencies to download from NBP e.g. --currency usd eur")
+                .num_args(1..)
+                .action(clap::ArgAction::Append),
+        )
+        .arg(
+            Arg::new("from-year")
+                .long("from-year")
+                .value_name("YEAR")
+                .help("First year to download")
+                .value_parser(clap::value_parser!(i32))
+                .requires("currency"),
+        )
+        .arg(
+            Arg::new("to-year")
+                .long("to-year")
+                .value_name("YEAR")
+                .help("Last year to download (default: current year)")
+                .value_parser(clap::value_parser!(i32))
+                .requires("from-year"),
+        )
+        .arg(
+            Arg::new("base-url")
+                .long("base-url")
+                .value_name("URL")
+                .help("NBP exchange rates API URL e.g. local mirror (default: $NBP_API_URL or https://api.nbp.pl/api/exchangerates/rates/a/)"),
+        )
+        .arg(
+            Arg::new("merge")
+                .long("merge")
+                .value_name("FILE")
+                .help("Existing exchange rates data file to be merged with downloaded rates"),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .value_name("FILE")
+                .help("Output data file (default: standard output)"),
         )
         .get_matches();
 
-    let file_paths = matches
-        .get_many::<String>("input")
-        .unwrap()
-        .cloned()
-        .collect::<Vec<_>>();
-    let mut kursy_map: HashMap<Exchange, f64> = HashMap::new();
-
-    for file in file_paths {
-        let file_content =
-            fs::read_to_string(&file).expect(&format!("Unable to read a file: {file}"));
-
-        // Deserializacja JSON do wektora struktur Kurs
-        let table: Tabela =
-            serde_json::from_str(&file_content).expect("Unable to parse {file} to JSON format");
-
-        // Tworzenie HashMapy
-        let kursy = table.rates;
-        match table.code.as_str() {
-            "USD" => {
-                for kurs in kursy {
-                    kursy_map.insert(Exchange::USD(kurs.effectiveDate), kurs.mid);
-                }
-            }
-            "EUR" => {
-                for kurs in kursy {
-                    kursy_map.insert(Exchange::EUR(kurs.effectiveDate), kurs.mid);
-                }
-            }
-            "PLN" => {
-                for kurs in kursy {
-                    kursy_map.insert(Exchange::PLN(kurs.effectiveDate), kurs.mid);
-                }
-            }
-            _ => {
-                panic!("Unsupported currency: {}", table.code);
-            }
+    let mut kursy_map: Kursy = BTreeMap::new();
+
+    if let Some(path) = matches.get_one::<String>("merge") {
+        read_data_file(path, &mut kursy_map).unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    if let Some(file_paths) = matches.get_many::<String>("input") {
+        for file in file_paths {
+            let file_content = fs::read_to_string(file)
+                .unwrap_or_else(|_| panic!("Unable to read a file: {file}"));
+
+            // Deserializacja JSON do wektora struktur Kurs
+            let table: Tabela = serde_json::from_str(&file_content)
+                .unwrap_or_else(|_| panic!("Unable to parse {file} to JSON format"));
+            add_table(&mut kursy_map, table).unwrap_or_else(|e| panic!("{e}"));
         }
     }
 
-    // Generowanie pliku .rs z hashmapą
-    let mut output_content = String::new();
-    output_content.push_str("use std::collections::HashMap;\n\n");
-    output_content.push_str("use etradeTaxReturnHelper::Exchange;\n\n");
-    output_content.push_str("#[allow(clippy::approx_constant)]\n");
-
-    output_content.push_str("pub fn get_exchange_rates() -> HashMap<Exchange, f64> {\n");
-    output_content.push_str("   let mut exchange_rates = HashMap::new();\n");
-
-    for (exchange, kurs) in &kursy_map {
-        match exchange {
-            Exchange::USD(data) => {
-                output_content.push_str(&format!(
-                    "  exchange_rates.insert(Exchange::USD(\"{}\".to_string()), {}f64);\n",
-                    data, kurs
-                ));
-            }
-            Exchange::EUR(data) => {
-                output_content.push_str(&format!(
-                    "  exchange_rates.insert(Exchange::EUR(\"{}\".to_string()), {}f64);\n",
-                    data, kurs
-                ));
+    if let Some(currencies) = matches.get_many::<String>("currency") {
+        let today = chrono::Local::now().date_naive();
+        let from_year = *matches
+            .get_one::<i32>("from-year")
+            .expect("Missing --from-year");
+        let to_year = matches
+            .get_one::<i32>("to-year")
+            .copied()
+            .unwrap_or(today.year());
+        let from = NaiveDate::from_ymd_opt(from_year, 1, 1).expect("Invalid --from-year");
+        let to = std::cmp::min(
+            NaiveDate::from_ymd_opt(to_year, 12, 31).expect("Invalid --to-year"),
+            today,
+        );
+        let base_url = matches
+            .get_one::<String>("base-url")
+            .cloned()
+            .unwrap_or_else(etradeTaxReturnHelper::nbp_api_url);
+
+        holidays::Builder::new()
+            .countries(&[holidays::Country::PL])
+            .years(from_year..to_year + 1)
+            .init()
+            .expect("Holiday module initialization failed");
+
+        let client = etradeTaxReturnHelper::create_http_client().unwrap_or_else(|e| panic!("{e}"));
+
+        for currency in currencies {
+            for (range_from, range_to) in split_into_ranges(from, to, MAX_DAYS_PER_QUERY) {
+                if let Some(table) = fetch_table(&client, &base_url, currency, range_from, range_to)
+                    .unwrap_or_else(|e| panic!("{e}"))
+                {
+                    add_table(&mut kursy_map, table).unwrap_or_else(|e| panic!("{e}"));
+                }
             }
-            Exchange::PLN(data) => {
-                output_content.push_str(&format!(
-                    "  exchange_rates.insert(Exchange::PLN(\"{}\".to_string()), {}f64);\n",
-                    data, kurs
-                ));
+            let gaps = find_gaps(&kursy_map, &currency.to_uppercase(), from, to)
+                .unwrap_or_else(|e| panic!("{e}"));
+            for gap in gaps {
+                eprintln!(
+                    "Warning: missing {} exchange rate for working day: {}",
+                    currency.to_uppercase(),
+                    gap.format("%Y-%m-%d")
+                );
             }
         }
     }
 
-    output_content.push_str("   exchange_rates\n");
-    output_content.push_str("}\n");
-    println!("{output_content}");
+    // Generowanie pliku CSV z kursami (format pliku kursow walut)
+    let output_content = to_csv(&kursy_map);
+    match matches.get_one::<String>("output") {
+        Some(path) => fs::write(path, output_content)
+            .unwrap_or_else(|e| panic!("Unable to write {path}: {e}")),
+        None => print!("{output_content}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_into_ranges() {
+        let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        let ranges = split_into_ranges(from, to, MAX_DAYS_PER_QUERY);
+        assert_eq!(ranges.len(), 4);
+        assert_eq!(
+            ranges[0],
+            (from, NaiveDate::from_ymd_opt(2024, 4, 2).unwrap())
+        );
+        assert_eq!(ranges[3].1, to);
+        ranges.windows(2).for_each(|w| {
+            assert_eq!(w[0].1 + chrono::Duration::days(1), w[1].0);
+            assert!((w[0].1 - w[0].0).num_days() < MAX_DAYS_PER_QUERY);
+        });
+        assert_eq!(
+            split_into_ranges(from, from, MAX_DAYS_PER_QUERY),
+            vec![(from, from)]
+        );
+    }
+
+    #[test]
+    fn test_merge_and_find_gaps() -> Result<(), String> {
+        holidays::Builder::new()
+            .countries(&[holidays::Country::PL])
+            .years(2021..2022)
+            .init()
+            .map_err(|_| "Holiday module initialization failed")?;
+
+        let mut kursy_map: Kursy = BTreeMap::new();
+        read_data_file("data/exchange_rates.csv", &mut kursy_map)?;
+        assert_eq!(kursy_map.len(), 3);
+
+        let table: Tabela = serde_json::from_str(
+            r#"{"table":"A","currency":"dolar amerykański","code":"USD",
+                "rates":[{"no":"039/A/NBP/2021","effectiveDate":"2021-02-26","mid":3.7247},
+                         {"no":"040/A/NBP/2021","effectiveDate":"2021-03-02","mid":3.7432}]}"#,
+        )
+        .map_err(|e| e.to_string())?;
+        add_table(&mut kursy_map, table)?;
+        assert_eq!(
+            kursy_map.get(&("USD".to_owned(), "2021-02-26".to_owned())),
+            Some(&3.7247)
+        );
+
+        // 2021-03-01 (Monday) is missing, weekend is not a gap
+        let gaps = find_gaps(
+            &kursy_map,
+            "USD",
+            NaiveDate::from_ymd_opt(2021, 2, 26).unwrap(),
+            NaiveDate::from_ymd_opt(2021, 3, 2).unwrap(),
+        )?;
+        assert_eq!(gaps, vec![NaiveDate::from_ymd_opt(2021, 3, 1).unwrap()]);
+
+        let csv = to_csv(&kursy_map);
+        assert!(csv.starts_with("currency,date,rate\nEUR,2000-03-01,4.2246\n"));
+        assert!(csv.contains("USD,2021-02-26,3.7247\n"));
+        Ok(())
+    }
 }
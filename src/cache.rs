@@ -0,0 +1,367 @@
+// SPDX-FileCopyrightText: 2025 RustInFinance
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use crate::rates::currency_code;
+use crate::Exchange;
+
+const CACHE_DIR_NAME: &str = "etradeTaxReturnHelper";
+const CACHE_FILE_NAME: &str = "exchange_rates.csv";
+const CACHE_HEADER: &str = "currency,date,rate,source,table";
+
+/// Exchange rate fetched from the network and stored in a local cache
+#[derive(Debug, PartialEq, Clone)]
+pub struct CachedExchangeRate {
+    pub currency: String,
+    pub date: String,
+    pub rate: f32,
+    // Where rate comes from e.g. NBP, ECB
+    pub source: String,
+    // Number of table (publication) the rate comes from e.g. 039/A/NBP/2021
+    pub table: String,
+}
+
+// Key: (source, currency, date)
+type CacheMap = HashMap<(String, String, String), CachedExchangeRate>;
+
+static EXCHANGE_RATES_CACHE: OnceLock<Mutex<CacheMap>> = OnceLock::new();
+// Directory of cache chosen instead of the default one (e.g. by tests)
+static CACHE_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Use given directory for exchange rates cache instead of the default one.
+/// Directory can be set only once (setting the same directory again is allowed)
+pub fn set_cache_dir(dir: &Path) -> Result<(), String> {
+    let current = CACHE_DIR.get_or_init(|| dir.to_path_buf());
+    if current != dir {
+        return Err(format!(
+            "Error: exchange rates cache directory is already set to: {}",
+            current.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Directory of exchange rates cache. Unless set with `set_cache_dir` it follows
+/// XDG base directory specification: $XDG_CACHE_HOME/etradeTaxReturnHelper or
+/// $HOME/.cache/etradeTaxReturnHelper (%LOCALAPPDATA%\etradeTaxReturnHelper on Windows)
+pub fn get_cache_dir() -> Option<PathBuf> {
+    match CACHE_DIR.get() {
+        Some(dir) => Some(dir.clone()),
+        None => default_cache_dir(),
+    }
+}
+
+// Tests never touch cache of the user
+#[cfg(test)]
+fn default_cache_dir() -> Option<PathBuf> {
+    Some(
+        std::env::temp_dir()
+            .join(format!("etradeTaxReturnHelper_test_{}", std::process::id()))
+            .join(CACHE_DIR_NAME),
+    )
+}
+
+#[cfg(not(test))]
+fn default_cache_dir() -> Option<PathBuf> {
+    xdg_cache_dir()
+}
+
+fn xdg_cache_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".cache")))
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))?;
+    Some(base.join(CACHE_DIR_NAME))
+}
+
+fn get_cache_file() -> Option<PathBuf> {
+    get_cache_dir().map(|d| d.join(CACHE_FILE_NAME))
+}
+
+fn read_cache_file(path: &Path) -> Result<Vec<CachedExchangeRate>, String> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_path(path)
+        .map_err(|e| {
+            format!(
+                "Error: opening exchange rates cache {}: {e}",
+                path.display()
+            )
+        })?;
+    let mut rates: Vec<CachedExchangeRate> = vec![];
+    for record in rdr.records() {
+        let record = record.map_err(|e| format!("Error: reading exchange rates cache: {e}"))?;
+        match (
+            record.get(0),
+            record.get(1),
+            record.get(2).and_then(|r| r.parse::<f32>().ok()),
+            record.get(3),
+            record.get(4),
+        ) {
+            (Some(currency), Some(date), Some(rate), Some(source), Some(table)) => {
+                rates.push(CachedExchangeRate {
+                    currency: currency.to_owned(),
+                    date: date.to_owned(),
+                    rate,
+                    source: source.to_owned(),
+                    table: table.to_owned(),
+                })
+            }
+            _ => log::warn!(
+                "Skipping ill formed exchange rates cache entry: {:?}",
+                record
+            ),
+        }
+    }
+    Ok(rates)
+}
+
+fn write_cache_file(path: &Path, rates: &[CachedExchangeRate]) -> Result<(), String> {
+    let mut wtr = csv::Writer::from_path(path)
+        .map_err(|e| format!("Error: creating file {}: {e}", path.display()))?;
+    wtr.write_record(CACHE_HEADER.split(','))
+        .map_err(|e| format!("Error: writing file {}: {e}", path.display()))?;
+    rates.iter().try_for_each(|r| {
+        wtr.write_record([
+            r.currency.as_str(),
+            r.date.as_str(),
+            &r.rate.to_string(),
+            r.source.as_str(),
+            r.table.as_str(),
+        ])
+        .map_err(|e| format!("Error: writing file {}: {e}", path.display()))
+    })?;
+    wtr.flush()
+        .map_err(|e| format!("Error: writing file {}: {e}", path.display()))
+}
+
+fn append_to_cache_file(path: &Path, rate: &CachedExchangeRate) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Error: creating cache directory {}: {e}", dir.display()))?;
+    }
+    let new_file = !path.exists();
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| {
+            format!(
+                "Error: opening exchange rates cache {}: {e}",
+                path.display()
+            )
+        })?;
+    let write_error = |e: csv::Error| {
+        format!(
+            "Error: writing exchange rates cache {}: {e}",
+            path.display()
+        )
+    };
+    let mut wtr = csv::WriterBuilder::new().from_writer(file);
+    if new_file {
+        wtr.write_record(CACHE_HEADER.split(','))
+            .map_err(write_error)?;
+    }
+    wtr.write_record([
+        rate.currency.as_str(),
+        rate.date.as_str(),
+        &rate.rate.to_string(),
+        rate.source.as_str(),
+        rate.table.as_str(),
+    ])
+    .map_err(write_error)?;
+    wtr.flush().map_err(|e| {
+        format!(
+            "Error: writing exchange rates cache {}: {e}",
+            path.display()
+        )
+    })
+}
+
+fn exchange_rates_cache() -> &'static Mutex<CacheMap> {
+    EXCHANGE_RATES_CACHE.get_or_init(|| {
+        let rates = match get_cache_file().map(|f| read_cache_file(&f)) {
+            Some(Ok(rates)) => rates,
+            Some(Err(e)) => {
+                log::warn!("Exchange rates cache could not be read: {e}");
+                vec![]
+            }
+            None => vec![],
+        };
+        log::info!("Loaded {} exchange rates from local cache", rates.len());
+        Mutex::new(
+            rates
+                .into_iter()
+                .map(|r| ((r.source.clone(), r.currency.clone(), r.date.clone()), r))
+                .collect(),
+        )
+    })
+}
+
+/// Exchange rate (from given source, for given currency and rate date) stored in local cache
+pub fn get_cached_exchange_rate(source: &str, exchange: &Exchange) -> Option<f32> {
+    let date = match exchange {
+        Exchange::USD(date) | Exchange::EUR(date) | Exchange::PLN(date) => date,
+    };
+    let key = (
+        source.to_owned(),
+        currency_code(exchange).to_owned(),
+        date.clone(),
+    );
+    exchange_rates_cache()
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(&key).map(|r| r.rate))
+}
+
+/// Store exchange rate fetched from the network in local cache. Failure to store is not fatal
+pub fn store_exchange_rate(source: &str, exchange: &Exchange, rate: f32, table: &str) {
+    let date = match exchange {
+        Exchange::USD(date) | Exchange::EUR(date) | Exchange::PLN(date) => date,
+    };
+    let entry = CachedExchangeRate {
+        currency: currency_code(exchange).to_owned(),
+        date: date.clone(),
+        rate,
+        source: source.to_owned(),
+        table: table.to_owned(),
+    };
+    if let Ok(mut cache) = exchange_rates_cache().lock() {
+        let key = (
+            entry.source.clone(),
+            entry.currency.clone(),
+            entry.date.clone(),
+        );
+        if cache.contains_key(&key) {
+            return;
+        }
+        cache.insert(key, entry.clone());
+    }
+    match get_cache_file() {
+        Some(file) => {
+            if let Err(e) = append_to_cache_file(&file, &entry) {
+                log::warn!("Exchange rate could not be stored in cache: {e}");
+            }
+        }
+        None => log::warn!("Unable to determine cache directory"),
+    }
+}
+
+/// All exchange rates stored in local cache (sorted by source, currency and date)
+pub fn list_cached_exchange_rates() -> Result<Vec<CachedExchangeRate>, String> {
+    let file = get_cache_file().ok_or("Error: unable to determine cache directory")?;
+    let mut rates = read_cache_file(&file)?;
+    rates.sort_by(|a, b| (&a.source, &a.currency, &a.date).cmp(&(&b.source, &b.currency, &b.date)));
+    Ok(rates)
+}
+
+// Currency that exchange rates of given source are expressed in
+fn source_target_currency(source: &str) -> Option<&str> {
+    match source {
+        "NBP" => Some("PLN"),
+        "ECB" => Some("EUR"),
+        _ => source.strip_prefix("EXCHANGE-RATES.ORG-"),
+    }
+}
+
+/// Export exchange rates from local cache to CSV file. Exported file can be used
+/// as exchange rates file (e.g. in offline mode). Exchange rates file does not
+/// carry source of rates so only rates to `target_currency` (currency of residency)
+/// are exported
+pub fn export_cached_exchange_rates(path: &str, target_currency: &str) -> Result<usize, String> {
+    let rates: Vec<CachedExchangeRate> = list_cached_exchange_rates()?
+        .into_iter()
+        .filter(|r| source_target_currency(&r.source) == Some(target_currency))
+        .collect();
+    write_cache_file(Path::new(path), &rates)?;
+    Ok(rates.len())
+}
+
+/// Remove all exchange rates from local cache
+pub fn purge_exchange_rates_cache() -> Result<(), String> {
+    let file = get_cache_file().ok_or("Error: unable to determine cache directory")?;
+    if file.exists() {
+        std::fs::remove_file(&file)
+            .map_err(|e| format!("Error: removing {}: {e}", file.display()))?;
+    }
+    if let Ok(mut cache) = exchange_rates_cache().lock() {
+        cache.clear();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_target_currency() {
+        assert_eq!(source_target_currency("NBP"), Some("PLN"));
+        assert_eq!(source_target_currency("ECB"), Some("EUR"));
+        assert_eq!(
+            source_target_currency("EXCHANGE-RATES.ORG-EUR"),
+            Some("EUR")
+        );
+        assert_eq!(source_target_currency("UNKNOWN"), None);
+    }
+
+    #[test]
+    fn test_exchange_rates_cache_file() -> Result<(), String> {
+        let path = std::env::temp_dir().join(format!(
+            "etradeTaxReturnHelper_test_cache_file_{}/{}",
+            std::process::id(),
+            CACHE_FILE_NAME
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(read_cache_file(&path)?, vec![]);
+
+        let usd = CachedExchangeRate {
+            currency: "USD".to_owned(),
+            date: "2021-02-26".to_owned(),
+            rate: 3.7247,
+            source: "NBP".to_owned(),
+            table: "039/A/NBP/2021".to_owned(),
+        };
+        let eur = CachedExchangeRate {
+            currency: "USD".to_owned(),
+            date: "2023-02-20".to_owned(),
+            rate: 0.9368559,
+            source: "ECB".to_owned(),
+            table: "N/A".to_owned(),
+        };
+        append_to_cache_file(&path, &usd)?;
+        append_to_cache_file(&path, &eur)?;
+        assert_eq!(read_cache_file(&path)?, vec![usd.clone(), eur.clone()]);
+
+        // Exported file is having the same format
+        write_cache_file(&path, std::slice::from_ref(&eur))?;
+        assert_eq!(read_cache_file(&path)?, vec![eur]);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_cache_dir() -> Result<(), String> {
+        let dir = xdg_cache_dir();
+        if let Some(dir) = dir {
+            assert!(dir.ends_with(CACHE_DIR_NAME));
+        }
+
+        // Tests use cache in temporary directory
+        let dir = get_cache_dir().ok_or("Error: no cache directory")?;
+        assert!(dir.starts_with(std::env::temp_dir()));
+        set_cache_dir(&dir)?;
+        assert!(set_cache_dir(Path::new("/no/such/dir")).is_err());
+        assert_eq!(get_cache_dir(), Some(dir));
+        Ok(())
+    }
+}
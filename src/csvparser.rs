@@ -9,6 +9,7 @@ use polars::prelude::*;
 
 const DELIMITER: u8 = b';';
 const DELIMITER_AS_STR: &str = ";";
+const BROKER: &str = "Revolut";
 
 #[derive(PartialEq)]
 enum ParsingState {
@@ -44,6 +45,7 @@ struct InvestmentTransactions {
     pub costs: Vec<crate::Currency>,
     pub gross: Vec<crate::Currency>,
     pub symbols: Vec<Option<String>>,
+    pub quantities: Vec<Option<f32>>,
 }
 #[derive(Default)]
 struct TransactionAccumulator {
@@ -64,8 +66,11 @@ pub struct RevolutTransactions {
         crate::Currency,
         crate::Currency,
         Option<String>,
+        crate::LotDetails,
     )>,
     pub crypto_transactions: Vec<(String, String, crate::Currency, crate::Currency)>,
+    // Statements do not report account number
+    pub account: crate::Account,
 }
 
 fn extract_cash_with_currency(cashline: &str, currency: &str) -> Result<crate::Currency, String> {
@@ -328,6 +333,7 @@ fn extract_sold_transactions(df: &DataFrame) -> Result<DataFrame, &'static str>
             "Date acquired",
             "Date sold",
             "Symbol",
+            "Quantity",
             "Cost basis",
             "Gross proceeds",
             "Currency",
@@ -377,12 +383,20 @@ fn extract_sold_transactions(df: &DataFrame) -> Result<DataFrame, &'static str>
             .copied()
             .unwrap_or("Fees");
 
-        df.select([date_col, symbol_col, value_col, other_taxes_col, fees_col])
+        df.select([
+            date_col,
+            symbol_col,
+            "Units sold",
+            value_col,
+            other_taxes_col,
+            fees_col,
+        ])
     } else {
         df.select([
             "Date acquired",
             "Date sold",
             "Symbol",
+            "Qty",
             "Cost basis base currency",
             "Gross proceeds base currency",
             "Fees  base currency",
@@ -763,6 +777,21 @@ fn parse_sold_incomes(
         .map(|v| v.into_iter().unzip())
 }
 
+fn parse_quantities(df: &DataFrame, col: &str) -> Result<Vec<Option<f32>>, String> {
+    let quantities = df
+        .column(col)
+        .map_err(|_| format!("Error: Unable to select Quantity column '{}'", col))?
+        .cast(&DataType::Float64)
+        .map_err(|e| format!("Error: Unable to convert column '{}' to f64: {e}", col))?;
+    let quantities = quantities
+        .f64()
+        .map_err(|e| format!("Error: Unable to convert column '{}' to f64: {e}", col))?;
+    Ok(quantities
+        .into_iter()
+        .map(|q| q.map(|q| q as f32))
+        .collect())
+}
+
 fn parse_incomes(df: &DataFrame, col: &str) -> Result<Vec<crate::Currency>, String> {
     let moneyin = df
         .column(col)
@@ -883,6 +912,9 @@ fn process_tax_consolidated_data_v2(
             ta.stock
                 .symbols
                 .extend(parse_symbols(&filtred_df, "Description, symbol and ISIN")?);
+            ta.stock
+                .quantities
+                .extend(parse_quantities(&filtred_df, "Units sold")?);
             let (lcosts, lsells) = parse_sold_incomes(&filtred_df, "Value (of Sale, of Purchase)")?;
             ta.stock.gross.extend(lsells);
             let fees = parse_incomes(&filtred_df, "Fees")?;
@@ -980,6 +1012,9 @@ fn process_tax_consolidated_data(
             ta.stock
                 .symbols
                 .extend(parse_symbols(&filtred_df, "Symbol")?);
+            ta.stock
+                .quantities
+                .extend(parse_quantities(&filtred_df, "Qty")?);
             let lcosts = parse_incomes(&filtred_df, "Cost basis base currency")?;
             ta.stock
                 .gross
@@ -1123,7 +1158,7 @@ fn process_tax_consolidated_statement_v2(
 /// Parse revolut CSV documents (savings account, trading, crypto)
 /// returns: (
 /// dividend transactions in a form: date, gross income, tax taken, company name (if available)
-/// sold transactions in a form date acquired, date sold, cost basis, gross income
+/// sold transactions in a form date acquired, date sold, cost basis, gross income, company name, lot details (quantity)
 /// crypto transactions in a form date acquired, date sold, cost basis, gross income
 /// )
 pub fn parse_revolut_transactions(csvtoparse: &str) -> Result<RevolutTransactions, String> {
@@ -1135,6 +1170,7 @@ pub fn parse_revolut_transactions(csvtoparse: &str) -> Result<RevolutTransaction
         crate::Currency,
         crate::Currency,
         Option<String>,
+        crate::LotDetails,
     )> = vec![];
     let mut crypto_transactions: Vec<(String, String, crate::Currency, crate::Currency)> = vec![];
 
@@ -1255,6 +1291,7 @@ pub fn parse_revolut_transactions(csvtoparse: &str) -> Result<RevolutTransaction
         ta.stock.costs = parse_income_with_currency(&filtred_df, "Cost basis", "Currency")?;
         ta.stock.gross = parse_income_with_currency(&filtred_df, "Gross proceeds", "Currency")?;
         ta.stock.symbols = parse_symbols(&filtred_df, "Symbol")?;
+        ta.stock.quantities = parse_quantities(&filtred_df, "Quantity")?;
 
         log::info!("Content of second to be DataFrame: {others}");
 
@@ -1347,29 +1384,31 @@ pub fn parse_revolut_transactions(csvtoparse: &str) -> Result<RevolutTransaction
     if ta.stock.acquired_dates.len() != ta.stock.gross.len()
         || ta.stock.acquired_dates.len() != ta.stock.sold_dates.len()
         || ta.stock.acquired_dates.len() != ta.stock.symbols.len()
+        || ta.stock.acquired_dates.len() != ta.stock.quantities.len()
     {
         return Err(format!(
-            "ERROR: Different number of sold acquired_dates({}), sold_dates({}), gross({}), cost({}) or symbols({})",
+            "ERROR: Different number of sold acquired_dates({}), sold_dates({}), gross({}), cost({}), symbols({}) or quantities({})",
             ta.stock.acquired_dates.len(),
             ta.stock.sold_dates.len(),
             ta.stock.gross.len(),
             ta.stock.costs.len(),
-            ta.stock.symbols.len()
+            ta.stock.symbols.len(),
+            ta.stock.quantities.len()
         ));
     }
 
     let iter = std::iter::zip(
         ta.stock.acquired_dates,
         std::iter::zip(
-            ta.stock.symbols,
+            std::iter::zip(ta.stock.symbols, ta.stock.quantities),
             std::iter::zip(
                 ta.stock.sold_dates,
                 std::iter::zip(ta.stock.costs, ta.stock.gross),
             ),
         ),
     );
-    iter.for_each(|(acq_d, (s, (sol_d, (c, g))))| {
-        sold_transactions.push((acq_d, sol_d, c, g, s));
+    iter.for_each(|(acq_d, ((s, q), (sol_d, (c, g))))| {
+        sold_transactions.push((acq_d, sol_d, c, g, s, crate::LotDetails::market_buy(q)));
     });
     // Crypto transactions
     log::info!("Crypto Acquire Dates: {:?}", ta.crypto.acquired_dates);
@@ -1416,6 +1455,7 @@ pub fn parse_revolut_transactions(csvtoparse: &str) -> Result<RevolutTransaction
         dividend_transactions,
         sold_transactions,
         crypto_transactions,
+        account: crate::Account::new(BROKER, None),
     })
 }
 
@@ -1683,6 +1723,7 @@ mod tests {
     #[test]
     fn test_parse_revolut_transactions_consolidated_crypto() -> Result<(), String> {
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![],
             sold_transactions: vec![],
             crypto_transactions: vec![
@@ -1849,6 +1890,7 @@ mod tests {
     #[test]
     fn test_parse_revolut_transactions_consolidated_eur() -> Result<(), String> {
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![
                 // EUR interests
                 (
@@ -1883,6 +1925,7 @@ mod tests {
     #[test]
     fn test_parse_revolut_transactions_consolidated() -> Result<(), String> {
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![
                 // EUR interests
                 (
@@ -1944,6 +1987,7 @@ mod tests {
                     crate::Currency::PLN(13037.94 + 65.94),
                     crate::Currency::PLN(13348.22),
                     Some("EU000A3K4DJ5".to_string()),
+                    crate::LotDetails::market_buy(Some(3100.0)),
                 ),
                 (
                     "09/09/24".to_owned(),
@@ -1951,6 +1995,7 @@ mod tests {
                     crate::Currency::PLN(16097.86 + 81.41),
                     crate::Currency::PLN(16477.91),
                     Some("XS1218821756".to_string()),
+                    crate::LotDetails::market_buy(Some(3.8)),
                 ),
                 (
                     "11/20/23".to_owned(),
@@ -1958,6 +2003,7 @@ mod tests {
                     crate::Currency::PLN(19863.25 + 0.66),
                     crate::Currency::PLN(22865.17),
                     Some("XOM".to_string()),
+                    crate::LotDetails::market_buy(Some(48.501167)),
                 ),
                 (
                     "06/11/24".to_owned(),
@@ -1965,6 +2011,7 @@ mod tests {
                     crate::Currency::PLN(525.08 + 0.0),
                     crate::Currency::PLN(624.00),
                     Some("TFC".to_string()),
+                    crate::LotDetails::market_buy(Some(3.6399436)),
                 ),
                 (
                     "10/23/23".to_owned(),
@@ -1972,6 +2019,7 @@ mod tests {
                     crate::Currency::PLN(835.88 + 0.03),
                     crate::Currency::PLN(1046.20),
                     Some("AMCR".to_string()),
+                    crate::LotDetails::market_buy(Some(23.529411)),
                 ),
                 (
                     "08/22/24".to_owned(),
@@ -1979,6 +2027,7 @@ mod tests {
                     crate::Currency::PLN(25135.50 + 128.17),
                     crate::Currency::PLN(26130.41),
                     Some("US13607LNF66".to_string()),
+                    crate::LotDetails::market_buy(Some(6.4)),
                 ),
             ],
             crypto_transactions: vec![],
@@ -1996,6 +2045,7 @@ mod tests {
         // Parser returns values in original currencies (USD, EUR) - NOT in PLN.
         // Conversion to PLN happens outside the parser in the main application logic.
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![
                 // EUR interests
                 (
@@ -2147,6 +2197,7 @@ mod tests {
                     crate::Currency::USD(20000.13),
                     crate::Currency::USD(10961.04),
                     Some("ConAgra Foods CAG (US2058871029)".to_string()),
+                    crate::LotDetails::market_buy(Some(644.71405)),
                 ),
                 // Sale: Jan 16, 2026, Purchase: Feb 26, 2025
                 // CSV: +US$328.85, -US$500, no fee
@@ -2156,6 +2207,7 @@ mod tests {
                     crate::Currency::USD(500.00),
                     crate::Currency::USD(328.85),
                     Some("ConAgra Foods CAG (US2058871029)".to_string()),
+                    crate::LotDetails::market_buy(Some(19.34236)),
                 ),
                 // Sale: Jan 16, 2026, Purchase: Apr 9, 2025
                 // CSV: +US$668.10, -US$981.99, Fee: US$0.01 (0.03 PLN)
@@ -2166,6 +2218,7 @@ mod tests {
                     crate::Currency::USD(982.00),
                     crate::Currency::USD(668.10),
                     Some("ConAgra Foods CAG (US2058871029)".to_string()),
+                    crate::LotDetails::market_buy(Some(39.297207)),
                 ),
                 // Dentsply - Sale: Mar 2, 2026, Purchase: Feb 26, 2025
                 // CSV: +US$2,298.25, -US$3,000, Fee: US$0.03 (0.10 PLN)
@@ -2176,6 +2229,7 @@ mod tests {
                     crate::Currency::USD(3000.03),
                     crate::Currency::USD(2298.25),
                     Some("Dentsply XRAY (US24906P1093)".to_string()),
+                    crate::LotDetails::market_buy(Some(159.37938)),
                 ),
                 // IBM - Sale: Mar 4, 2026, Purchase: Feb 24, 2026
                 // CSV: +US$747.61, -US$698.24, Fee: US$1.74 (6.23 PLN) + US$0.01 (0.03 PLN)
@@ -2186,6 +2240,7 @@ mod tests {
                     crate::Currency::USD(699.99),
                     crate::Currency::USD(747.61),
                     Some("IBM IBM (US4592001014)".to_string()),
+                    crate::LotDetails::market_buy(Some(2.9873786)),
                 ),
             ],
             crypto_transactions: vec![],
@@ -2205,6 +2260,7 @@ mod tests {
         // "Sprzedane jednostki", "Data (Sprzedaży, Zakupu)"
         // Returns values in original currencies (USD, EUR) - NOT in PLN
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![
                 // EUR interests - Polish description "Oprocentowanie brutto"
                 (
@@ -2298,6 +2354,7 @@ mod tests {
                     crate::Currency::USD(20000.13),
                     crate::Currency::USD(10961.04),
                     Some("ConAgra Foods CAG (US2058871029)".to_string()),
+                    crate::LotDetails::market_buy(Some(644.71405)),
                 ),
                 (
                     "02/26/25".to_owned(),
@@ -2305,6 +2362,7 @@ mod tests {
                     crate::Currency::USD(500.00),
                     crate::Currency::USD(328.85),
                     Some("ConAgra Foods CAG (US2058871029)".to_string()),
+                    crate::LotDetails::market_buy(Some(19.34236)),
                 ),
                 (
                     "04/09/25".to_owned(),
@@ -2312,6 +2370,7 @@ mod tests {
                     crate::Currency::USD(982.00),
                     crate::Currency::USD(668.10),
                     Some("ConAgra Foods CAG (US2058871029)".to_string()),
+                    crate::LotDetails::market_buy(Some(39.297207)),
                 ),
             ],
             crypto_transactions: vec![],
@@ -2326,6 +2385,7 @@ mod tests {
     #[test]
     fn test_parse_revolut_investment_gain_and_losses_dividends() -> Result<(), String> {
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![
                 (
                     "06/04/24".to_owned(),
@@ -2367,6 +2427,7 @@ mod tests {
     #[test]
     fn test_parse_revolut_investment_with_commas_gain_and_losses_dividends() -> Result<(), String> {
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![
                 (
                     "06/04/24".to_owned(),
@@ -2438,6 +2499,7 @@ mod tests {
     #[test]
     fn test_parse_revolut_investment_gain_and_losses_sells_and_dividends() -> Result<(), String> {
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![
                 (
                     "03/04/24".to_owned(),
@@ -2542,6 +2604,7 @@ mod tests {
                 crate::Currency::USD(5000.0),
                 crate::Currency::USD(5804.62),
                 Some("XOM".to_string()),
+                crate::LotDetails::market_buy(Some(48.501167)),
             )],
             crypto_transactions: vec![],
         });
@@ -2559,6 +2622,7 @@ mod tests {
     #[test]
     fn test_parse_revolut_transactions_english_statement_pln() -> Result<(), String> {
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![
                 (
                     "12/12/23".to_owned(),
@@ -2689,6 +2753,7 @@ mod tests {
     #[test]
     fn test_parse_revolut_investment_transactions_usd() -> Result<(), String> {
         let expected_result = Ok(RevolutTransactions {
+            account: crate::Account::new("Revolut", None),
             dividend_transactions: vec![
                 (
                     "11/02/23".to_owned(),
@@ -1,58 +1,63 @@
 // SPDX-FileCopyrightText: 2022-2025 RustInFinance
 // SPDX-License-Identifier: BSD-3-Clause
 
-use regex::Regex;
+pub struct DE {
+    // Sources of exchange rates given by user (e.g. corporate rates), asked before ECB ones
+    custom_providers: Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>>,
+}
 
-pub struct DE {}
+impl DE {
+    pub(crate) fn with_exchange_rate_providers(
+        providers: Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>>,
+    ) -> Self {
+        DE {
+            custom_providers: providers,
+        }
+    }
+}
+
+impl Default for DE {
+    fn default() -> Self {
+        Self::with_exchange_rate_providers(vec![])
+    }
+}
 
 impl etradeTaxReturnHelper::Residency for DE {
-    fn get_exchange_rates(
+    fn currency(&self) -> &'static str {
+        "EUR"
+    }
+
+    // ECB reference rate of business day preceding settlement (ECB does not publish
+    // rates on weekends, after TARGET holidays rate of the latest preceding day is used)
+    fn rate_date_rule(&self) -> etradeTaxReturnHelper::RateDateRule {
+        etradeTaxReturnHelper::RateDateRule {
+            sale_date: etradeTaxReturnHelper::SaleDate::Settlement,
+            rate_date: etradeTaxReturnHelper::previous_business_day,
+        }
+    }
+
+    // Discount is a non-cash benefit (geldwerter Vorteil) taxed as employment income, so
+    // shares are acquired at their market value
+    fn espp_policy(&self) -> etradeTaxReturnHelper::EsppPolicy {
+        etradeTaxReturnHelper::EsppPolicy {
+            discount_is_income: true,
+            cost_basis: etradeTaxReturnHelper::EsppCostBasis::MarketValue,
+        }
+    }
+
+    // Income is taxed in the year it was received (Zuflussprinzip, § 11 EStG)
+    fn tax_year_rule(&self) -> etradeTaxReturnHelper::TaxYearRule {
+        etradeTaxReturnHelper::TaxYearRule::CashReceived
+    }
+
+    fn custom_exchange_rate_providers(
         &self,
-        dates: &mut std::collections::HashMap<
-            etradeTaxReturnHelper::Exchange,
-            Option<(String, f32)>,
-        >,
-    ) -> Result<(), String> {
-        self.get_currency_exchange_rates(dates, "EUR")
+    ) -> &[Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>] {
+        &self.custom_providers
     }
 
-    fn parse_exchange_rates(&self, body: &str) -> Result<(f32, String), String> {
-        // to find examplery "1 US Dollar = 0.82831 Euros on 2/26/2021</td>"
-        let pattern = "1 USD</span> =";
-        let start_offset = body
-            .find(pattern)
-            .ok_or(&format!("Error finding pattern: {}", pattern))?;
-        let pattern_slice = &body[start_offset..start_offset + 100]; // 100 characters should be enough
-                                                                     // Extract exchange rate (fp32 value)
-        log::info!("Exchange rate slice:  {}", pattern_slice);
-        let re = Regex::new(r"[0-9]+[.][0-9]+").unwrap();
-
-        let exchange_rate: f32 = match re.find(pattern_slice) {
-            Some(hit) => hit.as_str().parse::<f32>().unwrap(),
-            None => panic!(),
-        };
-
-        // Parse date
-        let pattern = "USD to EUR on ";
-        let start_date_offset = body
-            .find(pattern)
-            .ok_or(&format!("Error finding pattern: {}", pattern))?;
-        // ..USD to EUR on 2023-2-20....
-        let date_pattern_slice = &body[start_date_offset + pattern.chars().count()..];
-
-        let re = Regex::new(r"[0-9]+[-][0-9]+-[0-9]+").unwrap();
-        let date_string: &str = match re.find(date_pattern_slice) {
-            Some(hit) => hit.as_str(),
-            None => panic!(),
-        };
-
-        let exchange_rate_date =
-            chrono::NaiveDate::parse_from_str(date_string, "%Y-%m-%d").unwrap();
-
-        Ok((
-            exchange_rate,
-            format!("{}", exchange_rate_date.format("%Y-%m-%d")),
-        ))
+    fn exchange_rate_providers(&self) -> Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>> {
+        self.exchange_rate_providers_with_ecb_url(&etradeTaxReturnHelper::ecb_api_url())
     }
 
     fn present_result(
@@ -74,6 +79,25 @@ impl etradeTaxReturnHelper::Residency for DE {
     }
 }
 
+impl DE {
+    pub(crate) fn exchange_rate_providers_with_ecb_url(
+        &self,
+        ecb_url: &str,
+    ) -> Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>> {
+        use etradeTaxReturnHelper::Residency;
+        let rate_date = self.rate_date_rule().rate_date;
+        vec![
+            Box::new(etradeTaxReturnHelper::FileRateProvider::new(rate_date)),
+            Box::new(etradeTaxReturnHelper::CacheRateProvider::new(
+                "ECB", rate_date,
+            )),
+            Box::new(etradeTaxReturnHelper::EcbRateProvider::with_base_url(
+                ecb_url, rate_date,
+            )),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,7 +105,7 @@ mod tests {
 
     #[test]
     fn test_present_result_de() -> Result<(), String> {
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(DE {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(DE::default());
 
         let gross_div = 100.0f32;
         let tax_div = 15.0f32;
@@ -107,6 +131,8 @@ mod tests {
 
     #[test]
     fn test_get_exchange_rates_eur() -> Result<(), String> {
+        crate::tests::use_test_cache_dir();
+        let (_server, ecb_url) = etradeTaxReturnHelper::fake_http_server::start_fake_ecb_server();
         let mut dates: std::collections::HashMap<
             etradeTaxReturnHelper::Exchange,
             Option<(String, f32)>,
@@ -116,8 +142,11 @@ mod tests {
             None,
         );
 
-        let rd: DE = DE {};
-        rd.get_currency_exchange_rates(&mut dates,"EUR").map_err(|x| "Error: unable to get exchange rates.  Please check your internet connection or proxy settings\n\nDetails:".to_string()+x.as_str())?;
+        let rd: DE = DE::default();
+        etradeTaxReturnHelper::fill_exchange_rates(
+            &rd.exchange_rate_providers_with_ecb_url(&ecb_url),
+            &mut dates,
+        )?;
 
         let mut expected_result: std::collections::HashMap<
             etradeTaxReturnHelper::Exchange,
@@ -132,4 +161,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_exchange_rates_custom_provider() -> Result<(), String> {
+        crate::tests::use_test_cache_dir();
+        let mut dates: std::collections::HashMap<
+            etradeTaxReturnHelper::Exchange,
+            Option<(String, f32)>,
+        > = std::collections::HashMap::new();
+        dates.insert(
+            etradeTaxReturnHelper::Exchange::USD("07/14/23".to_owned()),
+            None,
+        );
+
+        // Custom provider is asked before ECB
+        let rd = DE::with_exchange_rate_providers(vec![Box::new(
+            etradeTaxReturnHelper::FixedRateProvider::new("2023-07-13", 0.9),
+        )]);
+        rd.get_exchange_rates(&mut dates)?;
+
+        assert_eq!(
+            dates[&etradeTaxReturnHelper::Exchange::USD("07/14/23".to_owned())],
+            Some(("2023-07-13".to_owned(), 0.9))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_date_rule_de() -> Result<(), String> {
+        let rule = DE::default().rate_date_rule();
+        assert_eq!(rule.sale_date, etradeTaxReturnHelper::SaleDate::Settlement);
+        // Rate of Friday for transaction settled on Monday
+        let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!((rule.rate_date)(&d("2023-02-20"))?, d("2023-02-17"));
+        Ok(())
+    }
 }
@@ -4,15 +4,18 @@
 use chrono;
 use roxmltree;
 
+/// USD to EUR exchange rate of the latest day in given period ECB published rates for
+/// (there are none on weekends and TARGET holidays). Returned are date and rate
 pub fn get_eur_to_usd_exchange_rate(
+    url: &str,
     start_date: chrono::NaiveDate,
     end_date: chrono::NaiveDate,
-) -> Result<f32, String> {
+) -> Result<(chrono::NaiveDate, f32), String> {
     let query = [
         ("startPeriod", start_date.format("%Y-%m-%d").to_string()),
         ("endPeriod", end_date.format("%Y-%m-%d").to_string()),
     ];
-    let response: String = get_blocking_exchange_rate(ECB_URL, &query)
+    let response: String = get_blocking_exchange_rate(url, &query)
         .map_err(|e| format!("Failed to get exchange rate: {e}"))?;
     let ecb_response = EcbResponse::from_xml_string(&response)
         .map_err(|e| format!("Failed to parse ECB response: {e}"))?;
@@ -28,11 +31,18 @@ pub fn get_eur_to_usd_exchange_rate(
             ecb_response.currency_denom
         ));
     }
+    let date = chrono::NaiveDate::parse_from_str(&ecb_response.date, "%Y-%m-%d")
+        .map_err(|e| format!("Failed to parse exchange rate date: {}", e))?;
+    if date < start_date || date > end_date {
+        return Err(format!(
+            "Exchange rate of {date} is out of requested period: {start_date} - {end_date}"
+        ));
+    }
     let usd_to_eur = ecb_response
         .rate
         .parse::<f32>()
         .map_err(|e| format!("Failed to parse exchange rate: {}", e))?;
-    invert_exchange_rate(usd_to_eur)
+    Ok((date, invert_exchange_rate(usd_to_eur)?))
 }
 
 fn invert_exchange_rate(rate: f32) -> Result<f32, String> {
@@ -42,21 +52,19 @@ fn invert_exchange_rate(rate: f32) -> Result<f32, String> {
     Ok(1.0 / rate)
 }
 
-const ECB_URL: &str = "https://data-api.ecb.europa.eu/service/data/EXR/D.USD.EUR.SP00.A";
+pub(crate) const ECB_URL: &str = "https://data-api.ecb.europa.eu/service/data/EXR/D.USD.EUR.SP00.A";
 
 fn get_blocking_exchange_rate<T>(url: &str, query: &T) -> Result<String, String>
 where
     T: serde::Serialize + ?Sized,
 {
-    let client = reqwest::blocking::Client::builder()
-        .build()
-        .map_err(|e| format!("Failed to build client: {}", e))?;
+    let client = crate::create_http_client()?;
 
-    let response = client
-        .get(url)
-        .query(query)
-        .send()
-        .map_err(|e| format!("Failed to send request: {}", e))?;
+    let response = crate::send_with_retry(
+        || client.get(url).query(query),
+        &crate::RetryPolicy::default(),
+    )
+    .map_err(|e| format!("Failed to send request: {}", e))?;
 
     let status = response.status();
     if !status.is_success() {
@@ -137,14 +145,21 @@ impl EcbResponse {
                         Some("UNIT") => unit = node.attribute("value"),
                         _ => {}
                     },
+                    // Response for a period has observation of each day, the latest one is taken
                     "Obs" => {
+                        let mut obs_date: Option<&str> = None;
+                        let mut obs_rate: Option<&str> = None;
                         for child in node.children() {
                             match child.tag_name().name() {
-                                "ObsDimension" => date = child.attribute("value"),
-                                "ObsValue" => rate = child.attribute("value"),
+                                "ObsDimension" => obs_date = child.attribute("value"),
+                                "ObsValue" => obs_rate = child.attribute("value"),
                                 _ => {}
                             }
                         }
+                        if obs_date.is_some() && obs_date >= date {
+                            date = obs_date;
+                            rate = obs_rate;
+                        }
                     }
                     _ => {}
                 }
@@ -197,6 +212,7 @@ mod tests {
     }
 
     #[test]
+    #[ignore]
     fn test_ecb_content_type_from_url() {
         let query = [("startPeriod", "2023-07-13"), ("endPeriod", "2023-07-13")];
 
@@ -215,20 +231,26 @@ mod tests {
 
     #[test]
     fn test_ecb_get_blocking_exchange_rate_from_url() {
+        let (server, url) = crate::fake_http_server::start_fake_ecb_server();
         let query = [("startPeriod", "2023-07-13"), ("endPeriod", "2023-07-13")];
         let response: String =
-            get_blocking_exchange_rate(ECB_URL, &query).expect("Failed to get exchange rate");
+            get_blocking_exchange_rate(&url, &query).expect("Failed to get exchange rate");
         assert!(response.len() > 0);
+        assert_eq!(
+            server.requests(),
+            vec!["/service/data/EXR/D.USD.EUR.SP00.A?startPeriod=2023-07-13&endPeriod=2023-07-13"]
+        );
     }
 
     #[test]
     fn test_ecb_parse_exchange_rate_from_url() {
+        let (_server, url) = crate::fake_http_server::start_fake_ecb_server();
         // thursday
         {
             let date = "2023-07-13";
             let query = [("startPeriod", date), ("endPeriod", date)];
             let response: String =
-                get_blocking_exchange_rate(ECB_URL, &query).expect("Failed to get exchange rate");
+                get_blocking_exchange_rate(&url, &query).expect("Failed to get exchange rate");
             let ecb_response = EcbResponse::from_xml_string(&response).unwrap();
 
             assert_eq!(ecb_response.freq, "D");
@@ -242,25 +264,44 @@ mod tests {
             assert_eq!(ecb_response.date, "2023-07-13");
             assert_eq!(ecb_response.rate, "1.1182");
         }
+    }
+
+    #[test]
+    fn test_ecb_exchange_rate_not_published() {
+        // ECB responds with no data for days rates were not published (e.g. weekend) and
+        // with 404 when there is no data at all (e.g. future dates)
+        let server = crate::fake_http_server::FakeHttpServer::start(vec![
+            crate::fake_http_server::FakeResponse {
+                path_prefix: "/service/data/EXR/D.USD.EUR.SP00.A".to_owned(),
+                status: 200,
+                content_type: "application/vnd.sdmx.genericdata+xml;version=2.1".to_owned(),
+                times: Some(1),
+                ..Default::default()
+            },
+            crate::fake_http_server::FakeResponse {
+                path_prefix: "/service/data/EXR/D.USD.EUR.SP00.A".to_owned(),
+                status: 404,
+                body: "No results found.".to_owned(),
+                ..Default::default()
+            },
+        ]);
+        let url = format!("{}/service/data/EXR/D.USD.EUR.SP00.A", server.url);
         // sunday fails
-        {
-            let date = "2024-09-28";
-            let query = [("startPeriod", date), ("endPeriod", date)];
-            let response =
-                get_blocking_exchange_rate(ECB_URL, &query).expect("Failed to get exchange rate");
-            let ecb_response = EcbResponse::from_xml_string(&response);
-            assert_eq!(ecb_response.is_err(), true);
-        }
+        let sunday = chrono::NaiveDate::from_ymd_opt(2024, 9, 29).unwrap();
+        let err = get_eur_to_usd_exchange_rate(&url, sunday, sunday).unwrap_err();
+        assert!(
+            err.starts_with("Failed to parse ECB response: Error parsing XML"),
+            "{err}"
+        );
         // future fails
-        {
-            let date = chrono::Local::now() + chrono::Duration::days(2);
-            let date_str = date.format("%Y-%m-%d").to_string();
-            let query = [("startPeriod", &date_str), ("endPeriod", &date_str)];
-            let response =
-                get_blocking_exchange_rate(ECB_URL, &query).expect("Failed to get exchange rate");
-            let ecb_response = EcbResponse::from_xml_string(&response);
-            assert_eq!(ecb_response.is_err(), true);
-        }
+        let future = chrono::Local::now().date_naive() + chrono::Duration::days(2);
+        let err = get_eur_to_usd_exchange_rate(&url, future, future).unwrap_err();
+        assert!(
+            err.starts_with("Failed to get exchange rate: Request failed with status 404"),
+            "{err}"
+        );
+        assert!(err.ends_with("No results found."), "{err}");
+        assert_eq!(server.requests().len(), 2);
     }
 
     #[test]
@@ -274,6 +315,7 @@ mod tests {
     }
 
     #[test]
+    #[ignore]
     fn test_ecb_url_content_type() {
         let query = [("startPeriod", "2023-07-13"), ("endPeriod", "2023-07-13")];
 
@@ -292,10 +334,44 @@ mod tests {
 
     #[test]
     fn test_ecb_url_get_exchange_rate() {
+        let (_server, url) = crate::fake_http_server::start_fake_ecb_server();
         let query = [("startPeriod", "2023-07-13"), ("endPeriod", "2023-07-13")];
         let response: String =
-            get_blocking_exchange_rate(ECB_URL, &query).expect("Failed to get exchange rate");
+            get_blocking_exchange_rate(&url, &query).expect("Failed to get exchange rate");
         println!("{}", response);
         assert!(response.len() > 0);
     }
+
+    #[test]
+    fn test_ecb_get_exchange_rate_from_fake_server() {
+        let server = crate::fake_http_server::FakeHttpServer::start(vec![
+            crate::fake_http_server::FakeResponse {
+                path_prefix: "/service/data/EXR/D.USD.EUR.SP00.A".to_owned(),
+                status: 200,
+                content_type: "application/vnd.sdmx.genericdata+xml;version=2.1".to_owned(),
+                body: include_str!("../data/ecb_example_response.xml").to_owned(),
+                ..Default::default()
+            },
+        ]);
+        let date = chrono::NaiveDate::from_ymd_opt(2023, 7, 13).unwrap();
+        let rate = get_eur_to_usd_exchange_rate(
+            &format!("{}/service/data/EXR/D.USD.EUR.SP00.A", server.url),
+            date,
+            date,
+        )
+        .expect("Failed to get exchange rate");
+        assert_eq!(rate, (date, 1.0 / 1.1182));
+        assert_eq!(
+            server.requests(),
+            vec!["/service/data/EXR/D.USD.EUR.SP00.A?startPeriod=2023-07-13&endPeriod=2023-07-13"]
+        );
+
+        // Unknown resource
+        assert!(get_eur_to_usd_exchange_rate(
+            &format!("{}/service/data/EXR/D.GBP.EUR.SP00.A", server.url),
+            date,
+            date
+        )
+        .is_err());
+    }
 }
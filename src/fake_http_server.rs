@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: 2025 RustInFinance
+// SPDX-License-Identifier: BSD-3-Clause
+
+// Minimal HTTP server serving recorded responses, to be used in tests instead of
+// real NBP/ECB services
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+pub struct FakeResponse {
+    // Requests with path starting with this prefix get this response
+    pub path_prefix: String,
+    pub status: u16,
+    pub content_type: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    // How many times response is served (None: always). Next matching response
+    // is used afterwards
+    pub times: Option<usize>,
+}
+
+impl Default for FakeResponse {
+    fn default() -> Self {
+        FakeResponse {
+            path_prefix: "/".to_owned(),
+            status: 200,
+            content_type: "text/plain".to_owned(),
+            headers: vec![],
+            body: String::new(),
+            times: None,
+        }
+    }
+}
+
+pub struct FakeHttpServer {
+    pub url: String,
+    // Paths (with query) of received requests
+    pub requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl FakeHttpServer {
+    // Start serving on a random local port. Requests not matching any response get 404
+    pub fn start(responses: Vec<FakeResponse>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Unable to bind fake server");
+        let url = format!(
+            "http://{}",
+            listener.local_addr().expect("Unable to get local address")
+        );
+        let requests = Arc::new(Mutex::new(vec![]));
+        let received = requests.clone();
+        let mut responses: Vec<(FakeResponse, usize)> =
+            responses.into_iter().map(|r| (r, 0)).collect();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let mut reader = BufReader::new(&stream);
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).is_err() {
+                    continue;
+                }
+                // Skip headers
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok() && line.trim_end() != "" {
+                    line.clear();
+                }
+                let target = request_line
+                    .split_whitespace()
+                    .nth(1)
+                    .unwrap_or("/")
+                    .to_owned();
+                received.lock().unwrap().push(target.clone());
+                let path = target.split('?').next().unwrap_or("/");
+                let response = responses.iter_mut().find(|(r, served)| {
+                    path.starts_with(&r.path_prefix) && r.times.is_none_or(|t| *served < t)
+                });
+                let not_found = FakeResponse {
+                    status: 404,
+                    body: "404 NotFound".to_owned(),
+                    ..Default::default()
+                };
+                let r = match response {
+                    Some((r, served)) => {
+                        *served += 1;
+                        &*r
+                    }
+                    None => &not_found,
+                };
+                let headers: String = r
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}\r\n"))
+                    .collect();
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {} FAKE\r\nContent-Type: {}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    r.status,
+                    r.content_type,
+                    r.body.len(),
+                    r.body
+                );
+            }
+        });
+        FakeHttpServer { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+// ECB service serving exemplary response (USD/EUR rate of 2023-07-13). Returned are server
+// and URL of USD/EUR exchange rates
+pub fn start_fake_ecb_server() -> (FakeHttpServer, String) {
+    let server = FakeHttpServer::start(vec![FakeResponse {
+        path_prefix: "/service/data/EXR/D.USD.EUR.SP00.A".to_owned(),
+        status: 200,
+        content_type: "application/vnd.sdmx.genericdata+xml;version=2.1".to_owned(),
+        body: include_str!("../data/ecb_example_response.xml").to_owned(),
+        ..Default::default()
+    }]);
+    let url = format!("{}/service/data/EXR/D.USD.EUR.SP00.A", server.url);
+    (server, url)
+}
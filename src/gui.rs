@@ -118,7 +118,7 @@ fn create_execute_documents(
         buffer.set_text("");
         tbuffer.set_text("");
         nbuffer.set_text("Running...");
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(PL::default());
         let etradeTaxReturnHelper::TaxCalculationResult {
             gross_income: gross_div,
             tax: tax_div,
@@ -129,7 +129,10 @@ fn create_execute_documents(
             revolut_dividends_transactions: revolut_transactions,
             sold_transactions,
             revolut_sold_transactions,
-        } = match run_taxation(&rd, file_names,false, false) {
+            vest_transactions,
+            espp_transactions,
+            ..
+        } = match run_taxation(&rd, file_names,false, false, false) {
             Ok(res) => {
                 nbuffer.set_text("Finished.\n\n (Double check if generated tax data (Summary) makes sense and then copy it to your tax form)");
                 res
@@ -160,6 +163,12 @@ fn create_execute_documents(
         revolut_sold_transactions
             .iter()
             .for_each(|x| transactions_strings.push(x.format_to_print("REVOLUT ")));
+        vest_transactions
+            .iter()
+            .for_each(|x| transactions_strings.push(x.format_to_print("VEST").expect_and_log("Error: Formatting VEST transaction failed")));
+        espp_transactions
+            .iter()
+            .for_each(|x| transactions_strings.push(x.format_to_print("ESPP DISCOUNT").expect_and_log("Error: Formatting ESPP transaction failed")));
         tbuffer.set_text(&transactions_strings.join("\n"));
     });
 }
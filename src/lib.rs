@@ -1,21 +1,53 @@
 // SPDX-FileCopyrightText: 2022-2025 RustInFinance
 // SPDX-License-Identifier: BSD-3-Clause
 
+mod anonymizer;
+mod cache;
 mod csvparser;
 mod ecb;
+#[cfg(any(test, feature = "test-util"))]
+pub mod fake_http_server;
+mod http;
 mod logging;
 mod pdfparser;
+mod providers;
+mod rates;
 mod transactions;
 mod xlsxparser;
 
-type ReqwestClient = reqwest::blocking::Client;
-
+pub use anonymizer::Anonymizer;
+pub use cache::{
+    export_cached_exchange_rates, get_cache_dir, get_cached_exchange_rate,
+    list_cached_exchange_rates, purge_exchange_rates_cache, set_cache_dir, store_exchange_rate,
+    CachedExchangeRate,
+};
+pub use http::{
+    create_http_client, create_http_client_with, send_with_retry, NetworkConfig, RetryPolicy,
+    EXTRA_CA_BUNDLE_VAR, HTTP_CONNECT_TIMEOUT, HTTP_TIMEOUT,
+};
 pub use logging::ResultExt;
+pub use pdfparser::dump_document;
+pub use providers::{
+    fill_exchange_rates, previous_business_day, previous_day, same_day, CacheRateProvider,
+    EcbRateProvider, ExchangeRateProvider, ExchangeRates, ExchangeRatesOrgProvider,
+    FileRateProvider, FixedRateProvider, RateDatePolicy, RateDateRule, SaleDate,
+};
+pub use rates::{
+    currency_code, ecb_api_url, exchange_rates_org_url, exchange_with_date,
+    format_missing_exchange_rates, get_nbp_exchange_rate, get_user_exchange_rate,
+    is_nbp_publication_day, is_offline_mode, load_exchange_rates_file, nbp_api_url,
+    read_exchange_rates_file, set_nbp_exchange_rates_file, set_offline_mode,
+};
 use transactions::{
-    create_detailed_div_transactions, create_detailed_interests_transactions,
+    apply_statement_accounts, apply_tax_withholding_adjustments, apply_trade_confirmations,
+    attribute_interests_to_tax_year, create_detailed_div_transactions,
+    create_detailed_espp_transactions, create_detailed_interests_transactions,
     create_detailed_revolut_sold_transactions, create_detailed_revolut_transactions,
-    create_detailed_sold_transactions, create_per_company_report, reconstruct_sold_transactions,
-    verify_dividends_transactions, verify_interests_transactions, verify_transactions,
+    create_detailed_sold_transactions, create_detailed_vest_transactions,
+    create_per_account_report, create_per_company_report, reconcile_form_1042s, reconcile_holdings,
+    reconstruct_sold_transactions, reconstruct_sold_transactions_from_1099b,
+    split_prior_year_interests, verify_dividends_transactions, verify_interests_transactions,
+    verify_trade_confirmations, verify_transactions, StatementSoldTransaction,
 };
 
 #[derive(Debug, PartialEq, PartialOrd, Copy, Clone)]
@@ -58,6 +90,50 @@ pub enum Exchange {
     USD(String),
 }
 
+/// Account of broker that reported transaction. Only last four digits of account number
+/// are kept e.g. "XXX-XXXXX9-012"
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct Account {
+    pub broker: String,
+    pub number: Option<String>,
+}
+
+impl Account {
+    pub fn new(broker: &str, number: Option<&str>) -> Account {
+        Account {
+            broker: broker.to_owned(),
+            number: number.map(mask_account_number),
+        }
+    }
+}
+
+impl std::fmt::Display for Account {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.number {
+            Some(number) => write!(f, "{} {number}", self.broker),
+            None => write!(f, "{}", self.broker),
+        }
+    }
+}
+
+/// Replace all but last four digits of account number with 'X'
+fn mask_account_number(number: &str) -> String {
+    let digits = number.chars().filter(|c| c.is_ascii_digit()).count();
+    let mut to_mask = digits.saturating_sub(4);
+    number
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_digit() && to_mask > 0 {
+                to_mask -= 1;
+                'X'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
 #[derive(Debug, PartialEq, PartialOrd)]
 pub struct Transaction {
     pub transaction_date: String,
@@ -66,6 +142,8 @@ pub struct Transaction {
     pub exchange_rate_date: String,
     pub exchange_rate: f32,
     pub company: Option<String>,
+    // None when not reported for any account e.g. income from RSU release
+    pub account: Option<Account>,
 }
 
 impl Transaction {
@@ -100,6 +178,387 @@ impl Transaction {
     }
 }
 
+/// Type of plan (origin) of sold shares
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub enum PlanType {
+    RS,
+    ESPP,
+    MarketBuy,
+    Other(String),
+}
+
+impl std::fmt::Display for PlanType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanType::RS => write!(f, "RS"),
+            PlanType::ESPP => write!(f, "ESPP"),
+            PlanType::MarketBuy => write!(f, "MARKET BUY"),
+            PlanType::Other(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// Details of sold lot as reported by broker (G&L document, Revolut P&L statement).
+/// Not all documents provide all of them, hence optional values
+#[derive(Debug, PartialEq, PartialOrd, Clone, Default)]
+pub struct LotDetails {
+    pub quantity: Option<f32>,
+    pub plan_type: Option<PlanType>,
+    pub grant_date: Option<String>,
+    pub order_type: Option<String>,
+    pub cost_basis_per_share: Option<f32>,
+    pub proceeds_per_share: Option<f32>,
+    // Account sold shares were held in
+    pub account: Option<Account>,
+}
+
+impl LotDetails {
+    /// Lot of shares bought on the market (e.g. Revolut) where only quantity is known
+    pub fn market_buy(quantity: Option<f32>) -> LotDetails {
+        LotDetails {
+            quantity,
+            plan_type: Some(PlanType::MarketBuy),
+            ..Default::default()
+        }
+    }
+
+    pub fn format_to_print(&self) -> String {
+        fn or_na<T: std::fmt::Display>(val: &Option<T>) -> String {
+            val.as_ref()
+                .map(|x| x.to_string())
+                .unwrap_or("N/A".to_owned())
+        }
+        format!(
+            "quantity: {}, plan_type: {}, grant_date: {}, order_type: {}, cost_basis_per_share: {}, proceeds_per_share: {}, account: {}",
+            or_na(&self.quantity),
+            or_na(&self.plan_type),
+            or_na(&self.grant_date),
+            or_na(&self.order_type),
+            or_na(&self.cost_basis_per_share),
+            or_na(&self.proceeds_per_share),
+            or_na(&self.account),
+        )
+    }
+}
+
+/// Shares released (vested) from RSU award as reported in release confirmation
+#[derive(Debug, PartialEq, PartialOrd, Clone, Default)]
+pub struct VestEvent {
+    // Release (vest) date in format of account statements: %m/%d/%y
+    pub vest_date: String,
+    pub company: Option<String>,
+    pub symbol: Option<String>,
+    pub award_number: Option<String>,
+    pub shares_released: f32,
+    // Shares withheld (sold) to cover taxes due on release
+    pub shares_withheld: f32,
+    // Fair market value of single share at release
+    pub fmv: f32,
+    // Account shares were released to
+    pub account: Option<Account>,
+}
+
+impl VestEvent {
+    /// Income from release (value of all released shares)
+    pub fn income(&self) -> f32 {
+        self.shares_released * self.fmv
+    }
+
+    /// Value of shares withheld for taxes
+    pub fn tax_withheld(&self) -> f32 {
+        self.shares_withheld * self.fmv
+    }
+
+    /// Shares deposited to account after release
+    pub fn shares_issued(&self) -> f32 {
+        self.shares_released - self.shares_withheld
+    }
+
+    /// Whether shares of vest are shares of given company (name or symbol)
+    pub fn is_of_company(&self, company: &Option<String>) -> bool {
+        match company {
+            Some(c) => self.company.as_ref() == Some(c) || self.symbol.as_ref() == Some(c),
+            None => true,
+        }
+    }
+
+    pub fn format_to_print(&self) -> String {
+        format!(
+            "VEST date: {}, company: {}, award: {}, shares released: {}, shares withheld: {}, fmv: ${}, income: ${:.2}, account: {}",
+            chrono::NaiveDate::parse_from_str(&self.vest_date, "%m/%d/%y")
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or(self.vest_date.clone()),
+            self.company.as_deref().unwrap_or("N/A"),
+            self.award_number.as_deref().unwrap_or("N/A"),
+            self.shares_released,
+            self.shares_withheld,
+            self.fmv,
+            self.income(),
+            self.account
+                .as_ref()
+                .map(|a| a.to_string())
+                .unwrap_or("N/A".to_owned()),
+        )
+    }
+}
+
+/// Shares bought within Employee Stock Purchase Plan as reported in purchase confirmation
+#[derive(Debug, PartialEq, PartialOrd, Clone, Default)]
+pub struct EsppPurchase {
+    // Dates in format of account statements: %m/%d/%y
+    pub offering_date: Option<String>,
+    pub purchase_date: String,
+    pub company: Option<String>,
+    pub symbol: Option<String>,
+    pub shares_purchased: f32,
+    // Fair market value of single share at the beginning of offering period
+    pub offering_fmv: Option<f32>,
+    // Fair market value of single share at purchase
+    pub purchase_fmv: f32,
+    // Price actually paid for single share (discounted)
+    pub purchase_price: f32,
+    // Account purchased shares were deposited to
+    pub account: Option<Account>,
+}
+
+impl EsppPurchase {
+    /// Discount of purchase: market value of shares above price paid for them
+    pub fn discount(&self) -> f32 {
+        (self.purchase_fmv - self.purchase_price) * self.shares_purchased
+    }
+
+    pub fn cost_basis_per_share(&self, policy: EsppPolicy) -> f32 {
+        match policy.cost_basis {
+            EsppCostBasis::PurchasePrice => self.purchase_price,
+            EsppCostBasis::MarketValue => self.purchase_fmv,
+        }
+    }
+
+    pub fn is_of_company(&self, company: &Option<String>) -> bool {
+        match company {
+            Some(c) => self.company.as_ref() == Some(c) || self.symbol.as_ref() == Some(c),
+            None => true,
+        }
+    }
+
+    pub fn format_to_print(&self) -> String {
+        let format_date = |date: &str| {
+            chrono::NaiveDate::parse_from_str(date, "%m/%d/%y")
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or(date.to_owned())
+        };
+        format!(
+            "ESPP PURCHASE date: {}, offering_date: {}, company: {}, shares purchased: {}, offering fmv: {}, purchase fmv: ${}, purchase price: ${}, discount: ${:.2}, account: {}",
+            format_date(&self.purchase_date),
+            self.offering_date
+                .as_deref()
+                .map(format_date)
+                .unwrap_or("N/A".to_owned()),
+            self.company.as_deref().unwrap_or("N/A"),
+            self.shares_purchased,
+            self.offering_fmv
+                .map(|fmv| format!("${fmv}"))
+                .unwrap_or("N/A".to_owned()),
+            self.purchase_fmv,
+            self.purchase_price,
+            self.discount(),
+            self.account
+                .as_ref()
+                .map(|a| a.to_string())
+                .unwrap_or("N/A".to_owned()),
+        )
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+pub enum TradeAction {
+    Buy,
+    Sell,
+}
+
+/// Trade as reported in trade confirmation
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct TradeConfirmation {
+    // Dates in format of account statements: %m/%d/%y
+    pub trade_date: String,
+    pub settlement_date: String,
+    pub company: Option<String>,
+    pub symbol: Option<String>,
+    pub action: TradeAction,
+    pub quantity: f32,
+    pub price: f32,
+    // Value of shares traded (quantity * price)
+    pub principal: f32,
+    pub commission: f32,
+    // All the other fees of trade (transaction fee, supplemental fee etc.)
+    pub fees: f32,
+    // Principal less commission and fees (sell) or principal with them (buy)
+    pub net_amount: f32,
+}
+
+impl TradeConfirmation {
+    pub fn is_of_company(&self, company: &Option<String>) -> bool {
+        match company {
+            Some(c) => self.company.as_ref() == Some(c) || self.symbol.as_ref() == Some(c),
+            None => true,
+        }
+    }
+
+    pub fn format_to_print(&self) -> String {
+        format!(
+            "TRADE CONFIRMATION {:?} trade_date: {}, settlement_date: {}, company: {}, quantity: {}, price: ${}, principal: ${}, commission: ${}, fees: ${}, net amount: ${}",
+            self.action,
+            chrono::NaiveDate::parse_from_str(&self.trade_date, "%m/%d/%y")
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or(self.trade_date.clone()),
+            chrono::NaiveDate::parse_from_str(&self.settlement_date, "%m/%d/%y")
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or(self.settlement_date.clone()),
+            self.company.as_deref().or(self.symbol.as_deref()).unwrap_or("N/A"),
+            self.quantity,
+            self.price,
+            self.principal,
+            self.commission,
+            self.fees,
+            self.net_amount,
+        )
+    }
+}
+
+/// Income of a single income code reported in IRS Form 1042-S
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct Form1042S {
+    // e.g. 01 (interest), 06 (dividends)
+    pub income_code: String,
+    pub gross_income: f32,
+    pub tax_withheld: f32,
+}
+
+/// Dividends of a tax year reported in IRS Form 1099-DIV
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct Form1099Div {
+    // Last two digits of tax year e.g. 23
+    pub tax_year: String,
+    pub ordinary_dividends: f32,
+    // Part of ordinary dividends
+    pub qualified_dividends: f32,
+    pub federal_tax_withheld: f32,
+    pub foreign_tax_paid: f32,
+}
+
+impl Form1099Div {
+    pub fn format_to_print(&self) -> String {
+        format!(
+            "FORM 1099-DIV tax year: 20{}, ordinary dividends: ${}, qualified dividends: ${}, federal tax withheld: ${}, foreign tax paid: ${}",
+            self.tax_year,
+            self.ordinary_dividends,
+            self.qualified_dividends,
+            self.federal_tax_withheld,
+            self.foreign_tax_paid,
+        )
+    }
+}
+
+/// Sold lot reported in IRS Form 1099-B
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct Form1099BLot {
+    // Dates in format of account statements: %m/%d/%y
+    pub date_sold: String,
+    // None when reported as "VARIOUS"
+    pub date_acquired: Option<String>,
+    pub company: Option<String>,
+    pub symbol: Option<String>,
+    pub quantity: f32,
+    pub proceeds: f32,
+    pub cost_basis: f32,
+}
+
+/// Correction of tax withheld from dividend ("TAX WITHHOLDING ADJ" of account statement)
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct TaxWithholdingAdjustment {
+    // Date of adjustment in format of account statements: %m/%d/%y
+    pub date: String,
+    pub company: Option<String>,
+    // Pay date of adjusted dividend (if comment of adjustment refers to it)
+    pub pay_date: Option<String>,
+    // Tax returned (positive) or additionally withheld (negative)
+    pub amount: f32,
+}
+
+/// Security held at the end of account statement period
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct Holding {
+    // End of statement period in format of account statements: %m/%d/%y
+    pub date: String,
+    pub company: String,
+    pub symbol: String,
+    pub quantity: f32,
+    // Share price at the end of statement period
+    pub price: f32,
+    pub market_value: f32,
+    // Account of statement the security is held on
+    pub account: Option<Account>,
+}
+
+impl Holding {
+    pub fn is_of_company(&self, company: &Option<String>) -> bool {
+        match company {
+            Some(c) => self.company == *c || self.symbol == *c,
+            None => true,
+        }
+    }
+
+    /// Whether holding is a position at the end of tax year
+    pub fn is_year_end(&self) -> bool {
+        self.date.starts_with("12/31/")
+    }
+
+    pub fn format_to_print(&self) -> String {
+        format!(
+            "HOLDING date: {}, company: {} ({}), quantity: {}, price: ${}, market value: ${}, account: {}",
+            chrono::NaiveDate::parse_from_str(&self.date, "%m/%d/%y")
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or(self.date.clone()),
+            self.company,
+            self.symbol,
+            self.quantity,
+            self.price,
+            self.market_value,
+            self.account
+                .as_ref()
+                .map(|a| a.to_string())
+                .unwrap_or("N/A".to_owned()),
+        )
+    }
+}
+
+/// Cost of ESPP shares deductible when they are sold
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EsppCostBasis {
+    // Price actually paid for shares ("Acquisition Cost" of G&L)
+    PurchasePrice,
+    // Market value at purchase, discount included ("Adjusted Cost Basis" of G&L)
+    MarketValue,
+}
+
+/// How ESPP discount is taxed in residency
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EsppPolicy {
+    // Whether discount is taxed at purchase as employment income
+    pub discount_is_income: bool,
+    pub cost_basis: EsppCostBasis,
+}
+
+/// Tax year of income paid in one year but reportable (e.g. in 1099) for the prior one
+/// (account statements mark it as "Transaction Reportable for the Prior Year.")
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TaxYearRule {
+    // Income belongs to the year it was received in
+    CashReceived,
+    // Income belongs to the year it is reportable for
+    ReportableYear,
+}
+
 // 1. settlement date
 // 2. date of purchase
 // 3. net income
@@ -116,6 +575,9 @@ pub struct SoldTransaction {
     pub exchange_rate_acquisition_date: String,
     pub exchange_rate_acquisition: f32,
     pub company: Option<String>,
+    pub lot: LotDetails,
+    // Number of days between acquisition and trade (sell) date
+    pub holding_days: i64,
     // TODO
     //pub country : Option<String>,
 }
@@ -123,17 +585,21 @@ pub struct SoldTransaction {
 impl SoldTransaction {
     pub fn format_to_print(&self, prefix: &str) -> String {
         format!(
-                "{prefix} SOLD TRANSACTION trade_date: {}, settlement_date: {}, acquisition_date: {}, net_income: ${},  cost_basis: {}, exchange_rate_settlement: {} , exchange_rate_settlement_date: {}, exchange_rate_acquisition: {} , exchange_rate_acquisition_date: {}",
+                "{prefix} SOLD TRANSACTION trade_date: {}, settlement_date: {}, acquisition_date: {}, net_income: ${},  cost_basis: {}, exchange_rate_settlement: {} , exchange_rate_settlement_date: {}, exchange_rate_acquisition: {} , exchange_rate_acquisition_date: {}, holding_days: {}, {}",
                 chrono::NaiveDate::parse_from_str(&self.trade_date, "%m/%d/%y").unwrap().format("%Y-%m-%d"), 
                 chrono::NaiveDate::parse_from_str(&self.settlement_date, "%m/%d/%y").unwrap().format("%Y-%m-%d"), 
                 chrono::NaiveDate::parse_from_str(&self.acquisition_date, "%m/%d/%y").unwrap().format("%Y-%m-%d"), 
                 &self.income_us, &self.cost_basis, &self.exchange_rate_settlement, &self.exchange_rate_settlement_date, &self.exchange_rate_acquisition, &self.exchange_rate_acquisition_date,
+                &self.holding_days, self.lot.format_to_print(),
             )
             .to_owned()
     }
 }
 
 pub trait Residency {
+    // Currency taxes are computed in e.g. "PLN"
+    fn currency(&self) -> &'static str;
+
     fn present_result(
         &self,
         gross_div: f32,
@@ -141,115 +607,47 @@ pub trait Residency {
         gross_sold: f32,
         cost_sold: f32,
     ) -> (Vec<String>, Option<String>);
-    fn get_exchange_rates(
-        &self,
-        dates: &mut std::collections::HashMap<Exchange, Option<(String, f32)>>,
-    ) -> Result<(), String>;
-
-    // Default parser (not to be used)
-    fn parse_exchange_rates(&self, _body: &str) -> Result<(f32, String), String> {
-        panic!("This method should not be used. Implement your own if needed!");
-    }
 
-    fn get_currency_exchange_rates(
+    // Classification of holding period of sold shares (e.g. short/long term)
+    // for residencies where taxation depends on it. Default: no such rules
+    fn holding_period_term(
         &self,
-        dates: &mut std::collections::HashMap<Exchange, Option<(String, f32)>>,
-        to: &str,
-    ) -> Result<(), String> {
-        if to == "EUR" {
-            self.get_currency_exchange_rates_ecb(dates, to)
-        } else {
-            self.get_currency_exchange_rates_legacy(dates, to)
-        }
+        _acquisition_date: &chrono::NaiveDate,
+        _trade_date: &chrono::NaiveDate,
+    ) -> Option<&'static str> {
+        None
     }
 
-    fn get_currency_exchange_rates_ecb(
-        &self,
-        dates: &mut std::collections::HashMap<Exchange, Option<(String, f32)>>,
-        _to: &str,
-    ) -> Result<(), String> {
-        dates.iter_mut().try_for_each(|(exchange, val)| {
-            let (_from, date) = match exchange {
-                Exchange::USD(date) => ("usd", date),
-                Exchange::EUR(date) => ("eur", date),
-                Exchange::PLN(date) => ("pln", date),
-            };
-
-            let converted_date = chrono::NaiveDate::parse_from_str(&date, "%m/%d/%y")
-                .map_err(|x| format!("Unable to convert date {x}"))?;
-
-            let day_before = converted_date
-                .checked_sub_signed(chrono::Duration::days(1))
-                .ok_or("Error traversing date")?;
+    // Which date of transaction and which day's exchange rate are used for conversion
+    fn rate_date_rule(&self) -> RateDateRule;
 
-            let day_before_str = day_before.format("%Y-%m-%d").to_string();
+    // Taxation of ESPP discount and cost basis of ESPP shares
+    fn espp_policy(&self) -> EsppPolicy;
 
-            let exchange_rate = ecb::get_eur_to_usd_exchange_rate(day_before, day_before)
-                .map_err(|x| format!("Error getting exchange rate from ECB: {x}"))?;
+    // Tax year of income received in one year, but reportable for the prior one
+    fn tax_year_rule(&self) -> TaxYearRule;
 
-            *val = Some((day_before_str, exchange_rate));
-            Ok::<(), String>(())
-        })?;
+    // Sources of exchange rates (asked in order) proper for taxation in residency
+    fn exchange_rate_providers(&self) -> Vec<Box<dyn ExchangeRateProvider>>;
 
-        Ok(())
+    // Sources of exchange rates given by user of residency (e.g. corporate rates). They
+    // are asked before providers of residency. Default: none
+    fn custom_exchange_rate_providers(&self) -> &[Box<dyn ExchangeRateProvider>] {
+        &[]
     }
 
-    fn get_currency_exchange_rates_legacy(
+    fn get_exchange_rates(
         &self,
         dates: &mut std::collections::HashMap<Exchange, Option<(String, f32)>>,
-        to: &str,
     ) -> Result<(), String> {
-        let client = create_client();
-
-        // Example URL: https://www.exchange-rates.org/Rate/USD/EUR/2-27-2021
-
-        let base_exchange_rate_url = "https://www.exchange-rates.org/Rate/";
-
-        dates.iter_mut().try_for_each(|(exchange, val)| {
-            let (from, date) = match exchange {
-                Exchange::USD(date) => ("usd", date),
-                Exchange::EUR(date) => ("eur", date),
-                Exchange::PLN(date) => ("pln", date),
-            };
-
-            let mut converted_date = chrono::NaiveDate::parse_from_str(&date, "%m/%d/%y")
-                .map_err(|x| format!("Unable to convert date {x}"))?;
-
-            converted_date = converted_date
-                .checked_sub_signed(chrono::Duration::days(1))
-                .ok_or("Error traversing date")?;
-
-            let fms =
-                format!("{}/{}/{}", from, to, converted_date.format("%m-%d-%Y")) + "/?format=json";
-            let exchange_rate_url: String = base_exchange_rate_url.to_string() + fms.as_str();
-
-            let body = client.get(&(exchange_rate_url)).send();
-            let actual_body = body.map_err(|_| {
-                format!(
-                    "Getting Exchange Rate from Exchange-Rates.org ({}) failed",
-                    exchange_rate_url
-                )
-            })?;
-            if actual_body.status().is_success() {
-                log::info!("RESPONSE {:#?}", actual_body);
-
-                let exchange_rates_response = actual_body
-                    .text()
-                    .map_err(|_| "Error converting response to Text")?;
-                log::info!("body of exchange_rate = {:#?}", &exchange_rates_response);
-                // parsing text response
-                if let Ok((exchange_rate, exchange_rate_date)) =
-                    self.parse_exchange_rates(&exchange_rates_response)
-                {
-                    *val = Some((exchange_rate_date, exchange_rate));
-                }
-                Ok(())
-            } else {
-                return Err("Error getting exchange rate".to_string());
-            }
-        })?;
-
-        Ok(())
+        let providers = self.exchange_rate_providers();
+        providers::fill_exchange_rates_in_order(
+            self.custom_exchange_rate_providers()
+                .iter()
+                .chain(providers.iter())
+                .map(|p| p.as_ref()),
+            dates,
+        )
     }
 }
 
@@ -263,28 +661,16 @@ pub struct TaxCalculationResult {
     pub revolut_dividends_transactions: Vec<Transaction>,
     pub sold_transactions: Vec<SoldTransaction>,
     pub revolut_sold_transactions: Vec<SoldTransaction>,
-}
-
-fn create_client() -> reqwest::blocking::Client {
-    // proxies are taken from env vars: http_proxy and https_proxy
-    let http_proxy = std::env::var("http_proxy");
-    let https_proxy = std::env::var("https_proxy");
-
-    // If there is proxy then pick first URL
-    let base_client = ReqwestClient::builder();
-    let client = match &http_proxy {
-        Ok(proxy) => base_client
-            .proxy(reqwest::Proxy::http(proxy).expect_and_log("Error setting HTTP proxy")),
-        Err(_) => base_client,
-    };
-    let client = match &https_proxy {
-        Ok(proxy) => {
-            client.proxy(reqwest::Proxy::https(proxy).expect_and_log("Error setting HTTP proxy"))
-        }
-        Err(_) => client,
-    };
-    let client = client.build().expect_and_log("Could not create client");
-    client
+    // Income from RSU releases (employment income) in target currency
+    pub vest_income: f32,
+    pub vest_transactions: Vec<Transaction>,
+    // ESPP discount taxed as employment income (depending on residency) in target currency
+    pub espp_discount_income: f32,
+    pub espp_transactions: Vec<Transaction>,
+    // Totals of Form 1099-DIV (in USD) not included in dividends: federal income tax
+    // withheld and qualified part of ordinary dividends
+    pub federal_tax_withheld: f32,
+    pub qualified_dividends: f32,
 }
 
 fn compute_div_taxation(transactions: &Vec<Transaction>) -> (f32, f32) {
@@ -317,6 +703,15 @@ fn compute_sold_taxation(transactions: &Vec<SoldTransaction>) -> (f32, f32) {
 
 pub fn format_sold_transactions_to_string() {}
 
+// Accounts are collected along with parsed transactions, so they are in the same order as
+// detailed transactions
+fn assign_accounts(transactions: &mut [Transaction], accounts: Vec<Option<Account>>) {
+    transactions
+        .iter_mut()
+        .zip(accounts)
+        .for_each(|(transaction, account)| transaction.account = account);
+}
+
 use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::path::Path;
@@ -373,14 +768,21 @@ pub fn run_taxation(
     rd: &Box<dyn Residency>,
     names: Vec<String>,
     per_company: bool,
+    per_account: bool,
     multiyear: bool,
 ) -> Result<TaxCalculationResult, String> {
     validate_file_names(&names)?;
 
-    let mut parsed_interests_transactions: Vec<(String, f32, f32)> = vec![];
+    // Interests are attributed to tax year as statements are parsed
+    let tax_year_rule = rd.tax_year_rule();
+    log::info!("Tax year rule: {:?}", tax_year_rule);
+    // Interests: transaction date, gross_us, tax_us, account
+    let mut parsed_interests_transactions: Vec<(String, f32, f32, Option<Account>)> = vec![];
+    let mut parsed_prior_year_interests: Vec<(String, f32, f32, Option<Account>)> = vec![];
     let mut parsed_div_transactions: Vec<(String, f32, f32, Option<String>)> = vec![];
-    let mut parsed_sold_transactions: Vec<(String, String, f32, f32, f32, Option<String>)> = vec![];
-    let mut parsed_gain_and_losses: Vec<(String, String, f32, f32, f32)> = vec![];
+    // Sold shares of statements along with account holding them
+    let mut parsed_statement_sold_transactions: Vec<StatementSoldTransaction> = vec![];
+    let mut parsed_gain_and_losses: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![];
     let mut parsed_revolut_dividends_transactions: Vec<(
         String,
         Currency,
@@ -393,25 +795,106 @@ pub fn run_taxation(
         Currency,
         Currency,
         Option<String>,
+        LotDetails,
     )> = vec![];
+    let mut parsed_vest_events: Vec<VestEvent> = vec![];
+    let mut parsed_espp_purchases: Vec<EsppPurchase> = vec![];
+    let mut parsed_trade_confirmations: Vec<TradeConfirmation> = vec![];
+    let mut parsed_forms_1042s: Vec<Form1042S> = vec![];
+    let mut parsed_forms_1099_div: Vec<(Account, Form1099Div)> = vec![];
+    let mut parsed_1099b_lots: Vec<Form1099BLot> = vec![];
+    let mut parsed_holdings: Vec<Holding> = vec![];
+    let mut parsed_tax_adjustments: Vec<TaxWithholdingAdjustment> = vec![];
+    // Accounts of parsed transactions (in the same order as transactions)
+    let mut parsed_div_accounts: Vec<Option<Account>> = vec![];
+    let mut parsed_1099b_accounts: Vec<Account> = vec![];
+    let mut parsed_revolut_dividends_accounts: Vec<Option<Account>> = vec![];
+    // Documents and accounts they were issued for
+    let mut parsed_documents: Vec<(String, Option<Account>)> = vec![];
 
     // 1. Parse PDF,XLSX and CSV documents to get list of transactions
     names.iter().try_for_each(|x| {
         // If name contains .pdf then parse as pdf
         // if name contains .xlsx then parse as spreadsheet
         if x.contains(".pdf") {
-            let (mut int_t, mut div_t, mut sold_t, _) = pdfparser::parse_statement(x)?;
-            parsed_interests_transactions.append(&mut int_t);
-            parsed_div_transactions.append(&mut div_t);
-            parsed_sold_transactions.append(&mut sold_t);
+            let document = pdfparser::parse_document(x)?;
+            let account = match &document {
+                pdfparser::PdfDocument::Statement(account, _)
+                | pdfparser::PdfDocument::ReleaseConfirmation(account, _)
+                | pdfparser::PdfDocument::PurchaseConfirmation(account, _)
+                | pdfparser::PdfDocument::TradeConfirmation(account, _)
+                | pdfparser::PdfDocument::Form1042S(account, _)
+                | pdfparser::PdfDocument::Form1099(account, _, _) => account.clone(),
+            };
+            parsed_documents.push((x.clone(), Some(account)));
+            match document {
+                pdfparser::PdfDocument::Statement(
+                    account,
+                    (int_t, mut div_t, sold_t, mut holdings, mut tax_adjustments),
+                ) => {
+                    let (mut received_interests, mut prior_year_interests) =
+                        attribute_interests_to_tax_year(int_t, tax_year_rule, &account);
+                    parsed_interests_transactions.append(&mut received_interests);
+                    parsed_prior_year_interests.append(&mut prior_year_interests);
+                    parsed_div_accounts.extend(div_t.iter().map(|_| Some(account.clone())));
+                    parsed_div_transactions.append(&mut div_t);
+                    parsed_statement_sold_transactions
+                        .extend(sold_t.into_iter().map(|sold| (account.clone(), sold)));
+                    holdings
+                        .iter_mut()
+                        .for_each(|holding| holding.account = Some(account.clone()));
+                    parsed_holdings.append(&mut holdings);
+                    parsed_tax_adjustments.append(&mut tax_adjustments);
+                }
+                pdfparser::PdfDocument::ReleaseConfirmation(account, mut vests) => {
+                    vests
+                        .iter_mut()
+                        .for_each(|vest| vest.account = Some(account.clone()));
+                    parsed_vest_events.append(&mut vests);
+                }
+                pdfparser::PdfDocument::PurchaseConfirmation(account, mut purchases) => {
+                    purchases
+                        .iter_mut()
+                        .for_each(|purchase| purchase.account = Some(account.clone()));
+                    parsed_espp_purchases.append(&mut purchases);
+                }
+                pdfparser::PdfDocument::TradeConfirmation(_, mut trades) => {
+                    parsed_trade_confirmations.append(&mut trades);
+                }
+                pdfparser::PdfDocument::Form1042S(_, mut forms) => {
+                    parsed_forms_1042s.append(&mut forms);
+                }
+                // 1099-DIV reports yearly totals, which can be converted only by
+                // residencies taxing in USD (others convert each payment on its own)
+                pdfparser::PdfDocument::Form1099(_, _, _) if rd.currency() != "USD" => {
+                    return Err(format!("\n\nERROR: Form 1099 ({x}) is supported only for US residency. Please provide account statements and Gain&Losses document instead\n\n"));
+                }
+                pdfparser::PdfDocument::Form1099(account, dividends, mut lots) => {
+                    parsed_forms_1099_div.extend(dividends.map(|d| (account.clone(), d)));
+                    parsed_1099b_accounts.extend(lots.iter().map(|_| account.clone()));
+                    parsed_1099b_lots.append(&mut lots);
+                }
+            }
         } else if x.contains(".xlsx") {
-            parsed_gain_and_losses.append(&mut xlsxparser::parse_gains_and_losses(x)?);
+            let mut gains_and_losses = xlsxparser::parse_gains_and_losses(x)?;
+            let account = gains_and_losses
+                .first()
+                .and_then(|(_, _, _, _, _, lot)| lot.account.clone());
+            parsed_documents.push((x.clone(), account));
+            parsed_gain_and_losses.append(&mut gains_and_losses);
         } else if x.contains(".csv") {
             let csvparser::RevolutTransactions {
                 mut dividend_transactions,
                 mut sold_transactions,
+                account,
                 ..
             } = csvparser::parse_revolut_transactions(x)?;
+            parsed_revolut_dividends_accounts
+                .extend(dividend_transactions.iter().map(|_| Some(account.clone())));
+            sold_transactions
+                .iter_mut()
+                .for_each(|(_, _, _, _, _, lot)| lot.account = Some(account.clone()));
+            parsed_documents.push((x.clone(), Some(account)));
             parsed_revolut_dividends_transactions.append(&mut dividend_transactions);
             parsed_revolut_sold_transactions.append(&mut sold_transactions);
         } else {
@@ -419,6 +902,79 @@ pub fn run_taxation(
         }
         Ok::<(), String>(())
     })?;
+    let parsed_sold_transactions: Vec<(String, String, f32, f32, f32, Option<String>)> =
+        parsed_statement_sold_transactions
+            .iter()
+            .map(|(_, sold)| sold.clone())
+            .collect();
+    // Consolidated 1099 is an alternative to account statements and G&L, so transactions
+    // must not be reported twice
+    if !parsed_forms_1099_div.is_empty() && !parsed_div_transactions.is_empty() {
+        return Err("\n\nERROR: Dividends are reported by both Form 1099-DIV and account statements. Please provide only one of them\n\n".to_string());
+    }
+    if !parsed_1099b_lots.is_empty()
+        && (!parsed_sold_transactions.is_empty() || !parsed_gain_and_losses.is_empty())
+    {
+        return Err("\n\nERROR: Sold shares are reported by both Form 1099-B and account statements or Gain&Losses. Please provide only one of them\n\n".to_string());
+    }
+    // Dividends of 1099-DIV are yearly totals, so they are dated on the last day of tax year
+    let federal_tax_withheld: f32 = parsed_forms_1099_div
+        .iter()
+        .map(|(_, form)| form.federal_tax_withheld)
+        .sum();
+    let qualified_dividends: f32 = parsed_forms_1099_div
+        .iter()
+        .map(|(_, form)| form.qualified_dividends)
+        .sum();
+    parsed_forms_1099_div.iter().for_each(|(account, form)| {
+        let msg = form.format_to_print();
+        println!("{}", msg);
+        log::info!("{}", msg);
+        parsed_div_transactions.push((
+            format!("12/31/{}", form.tax_year),
+            form.ordinary_dividends,
+            form.foreign_tax_paid,
+            None,
+        ));
+        parsed_div_accounts.push(Some(account.clone()));
+    });
+    // Adjustments may refer to dividends of earlier statements, so they are applied once all
+    // documents are parsed
+    let (adjusted, unmatched) =
+        apply_tax_withholding_adjustments(&mut parsed_div_transactions, &parsed_tax_adjustments);
+    adjusted.iter().for_each(|msg| {
+        println!("{msg}");
+        log::info!("{msg}");
+    });
+    unmatched.iter().for_each(|discrepancy| {
+        println!("WARNING: {discrepancy}");
+        log::warn!("{discrepancy}");
+    });
+    // Years of interests are verified once they are attributed to tax year, as statement of
+    // January may report interests of the prior year. Prior years are computed as well in
+    // multi-year mode
+    let (prior_year_interests, earlier_year_interests) = match multiyear {
+        true => (parsed_prior_year_interests, vec![]),
+        false => {
+            split_prior_year_interests(&parsed_interests_transactions, parsed_prior_year_interests)
+        }
+    };
+    prior_year_interests
+        .iter()
+        .for_each(|(reportable_date, gross_us, tax_us, _)| {
+            let msg = format!("TAX YEAR interests (gross: ${gross_us}, tax: ${tax_us}) paid in January are reportable for the prior year and attributed to {reportable_date}");
+            println!("{msg}");
+            log::info!("{msg}");
+        });
+    earlier_year_interests
+        .iter()
+        .for_each(|(reportable_date, gross_us, tax_us, _)| {
+            let msg = format!("TAX YEAR interests (gross: ${gross_us}, tax: ${tax_us}) paid in January are reportable for the prior year ({reportable_date}) and they are not taxed with income of this year");
+            println!("{msg}");
+            log::info!("{msg}");
+        });
+    parsed_interests_transactions.extend(prior_year_interests);
+
     // 2. Verify Transactions (if they all come from same year unless multiyear is enabled)
     if multiyear == false {
         verify_interests_transactions(&parsed_interests_transactions)?;
@@ -433,9 +989,78 @@ pub fn run_taxation(
         log::info!("Multi-year mode enabled, skipping verification of transaction years");
     }
 
+    if !parsed_forms_1042s.is_empty() {
+        let (report, discrepancies) = reconcile_form_1042s(
+            &parsed_forms_1042s,
+            &parsed_interests_transactions,
+            &parsed_div_transactions,
+        );
+        report.iter().for_each(|line| {
+            println!("{line}");
+            log::info!("{line}");
+        });
+        discrepancies.iter().for_each(|discrepancy| {
+            println!("WARNING: {discrepancy}");
+            log::warn!("{discrepancy}");
+        });
+    }
+
+    // Year-end positions (e.g. for reporting of foreign assets)
+    parsed_holdings
+        .iter()
+        .filter(|holding| holding.is_year_end())
+        .for_each(|holding| {
+            let msg = holding.format_to_print();
+            println!("{}", msg);
+            log::info!("{}", msg);
+        });
+    reconcile_holdings(
+        &parsed_holdings,
+        &parsed_statement_sold_transactions,
+        &parsed_gain_and_losses,
+    )
+    .iter()
+    .for_each(|discrepancy| {
+        println!("WARNING: {discrepancy}");
+        log::warn!("{discrepancy}");
+    });
+
     // 3. Verify and create full sold transactions info needed for TAX purposes
-    let detailed_sold_transactions =
-        reconstruct_sold_transactions(&parsed_sold_transactions, &parsed_gain_and_losses)?;
+    parsed_trade_confirmations.iter().for_each(|trade| {
+        let msg = trade.format_to_print();
+        println!("{}", msg);
+        log::info!("{}", msg);
+    });
+    verify_trade_confirmations(
+        &parsed_trade_confirmations,
+        &parsed_sold_transactions,
+        &parsed_gain_and_losses,
+    )
+    .iter()
+    .for_each(|discrepancy| {
+        println!("WARNING: {discrepancy}");
+        log::warn!("{discrepancy}");
+    });
+    let espp_policy = rd.espp_policy();
+    log::info!("ESPP policy: {:?}", espp_policy);
+    let mut detailed_sold_transactions = reconstruct_sold_transactions(
+        &parsed_sold_transactions,
+        &parsed_gain_and_losses,
+        &parsed_vest_events,
+        &parsed_espp_purchases,
+        espp_policy,
+    )?;
+    apply_trade_confirmations(&mut detailed_sold_transactions, &parsed_trade_confirmations);
+    apply_statement_accounts(
+        &mut detailed_sold_transactions,
+        &parsed_statement_sold_transactions,
+    );
+    let mut sold_1099b = reconstruct_sold_transactions_from_1099b(&parsed_1099b_lots)?;
+    sold_1099b
+        .iter_mut()
+        .zip(parsed_1099b_accounts)
+        .for_each(|((_, _, _, _, _, _, lot), account)| lot.account = Some(account));
+    detailed_sold_transactions.append(&mut sold_1099b);
 
     // 4. Get Exchange rates
     // Gather all trade , settlement and transaction dates into hash map to be passed to
@@ -445,7 +1070,7 @@ pub fn run_taxation(
         std::collections::HashMap::new();
     parsed_interests_transactions
         .iter()
-        .for_each(|(trade_date, _, _)| {
+        .for_each(|(trade_date, _, _, _)| {
             let ex = Exchange::USD(trade_date.clone());
             if dates.contains_key(&ex) == false {
                 dates.insert(ex, None);
@@ -459,13 +1084,25 @@ pub fn run_taxation(
                 dates.insert(ex, None);
             }
         });
+    parsed_vest_events.iter().for_each(|vest| {
+        dates
+            .entry(Exchange::USD(vest.vest_date.clone()))
+            .or_insert(None);
+    });
+    parsed_espp_purchases.iter().for_each(|purchase| {
+        dates
+            .entry(Exchange::USD(purchase.purchase_date.clone()))
+            .or_insert(None);
+    });
+    let rate_date_rule = rd.rate_date_rule();
+    log::info!("Exchange rates rule: {:?}", rate_date_rule);
     detailed_sold_transactions.iter().for_each(
-        |(trade_date, settlement_date, acquisition_date, _, _, _)| {
-            let ex = Exchange::USD(trade_date.clone());
-            if dates.contains_key(&ex) == false {
-                dates.insert(ex, None);
-            }
-            let ex = Exchange::USD(settlement_date.clone());
+        |(trade_date, settlement_date, acquisition_date, _, _, _, _)| {
+            let ex = Exchange::USD(
+                rate_date_rule
+                    .proceeds_date(trade_date, settlement_date)
+                    .to_owned(),
+            );
             if dates.contains_key(&ex) == false {
                 dates.insert(ex, None);
             }
@@ -484,7 +1121,7 @@ pub fn run_taxation(
             }
         });
     parsed_revolut_sold_transactions.iter().for_each(
-        |(acquired_date, sold_date, cost, gross, _)| {
+        |(acquired_date, sold_date, cost, gross, _, _)| {
             let ex = cost.derive_exchange(acquired_date.clone());
             if dates.contains_key(&ex) == false {
                 dates.insert(ex, None);
@@ -496,16 +1133,59 @@ pub fn run_taxation(
         },
     );
 
-    rd.get_exchange_rates(&mut dates).map_err(|x| "Error: unable to get exchange rates.  Please check your internet connection or proxy settings\n\nDetails:".to_string()+x.as_str())?;
+    if is_offline_mode() {
+        rd.get_exchange_rates(&mut dates).map_err(|x| {
+            "Error: unable to get exchange rates in offline mode.\n\nDetails:".to_string()
+                + x.as_str()
+        })?;
+    } else {
+        rd.get_exchange_rates(&mut dates).map_err(|x| "Error: unable to get exchange rates.  Please check your internet connection or proxy settings\n\nDetails:".to_string()+x.as_str())?;
+    }
 
     // Make a detailed_div_transactions
     let interests = create_detailed_interests_transactions(parsed_interests_transactions, &dates)?;
-    let transactions = create_detailed_div_transactions(parsed_div_transactions, &dates)?;
-    let sold_transactions = create_detailed_sold_transactions(detailed_sold_transactions, &dates)?;
-    let revolut_dividends_transactions =
+    let mut transactions = create_detailed_div_transactions(parsed_div_transactions, &dates)?;
+    assign_accounts(&mut transactions, parsed_div_accounts);
+    let sold_transactions =
+        create_detailed_sold_transactions(detailed_sold_transactions, &dates, rate_date_rule)?;
+    let mut revolut_dividends_transactions =
         create_detailed_revolut_transactions(parsed_revolut_dividends_transactions, &dates)?;
+    assign_accounts(
+        &mut revolut_dividends_transactions,
+        parsed_revolut_dividends_accounts,
+    );
     let revolut_sold_transactions =
         create_detailed_revolut_sold_transactions(parsed_revolut_sold_transactions, &dates)?;
+    let vest_transactions = create_detailed_vest_transactions(&parsed_vest_events, &dates)?;
+    let espp_transactions =
+        create_detailed_espp_transactions(&parsed_espp_purchases, espp_policy, &dates)?;
+
+    // Residencies where taxation depends on holding period get sold lots classified
+    sold_transactions
+        .iter()
+        .chain(revolut_sold_transactions.iter())
+        .for_each(|t| {
+            let parse = |date: &str| chrono::NaiveDate::parse_from_str(date, "%m/%d/%y").ok();
+            let term = parse(&t.acquisition_date)
+                .zip(parse(&t.trade_date))
+                .and_then(|(acquisition_date, trade_date)| {
+                    rd.holding_period_term(&acquisition_date, &trade_date)
+                });
+            if let Some(term) = term {
+                let msg = format!(
+                    "HOLDING PERIOD acquisition_date: {}, trade_date: {}, holding_days: {}, quantity: {}, term: {term}",
+                    t.acquisition_date,
+                    t.trade_date,
+                    t.holding_days,
+                    t.lot
+                        .quantity
+                        .map(|q| q.to_string())
+                        .unwrap_or("N/A".to_owned())
+                );
+                println!("{}", msg);
+                log::info!("{}", msg);
+            }
+        });
 
     if per_company {
         let per_company_report = create_per_company_report(
@@ -519,11 +1199,36 @@ pub fn run_taxation(
         println!("{}", per_company_report);
     }
 
+    if per_account {
+        parsed_documents.iter().for_each(|(document, account)| {
+            let msg = format!(
+                "DOCUMENT {document} ACCOUNT {}",
+                account
+                    .as_ref()
+                    .map(|a| a.to_string())
+                    .unwrap_or("N/A".to_owned())
+            );
+            println!("{}", msg);
+            log::info!("{}", msg);
+        });
+        let per_account_report = create_per_account_report(
+            &interests,
+            &transactions,
+            &sold_transactions,
+            &revolut_dividends_transactions,
+            &revolut_sold_transactions,
+        )?;
+
+        println!("{}", per_account_report);
+    }
+
     let (gross_interests, _) = compute_div_taxation(&interests);
     let (gross_div, tax_div) = compute_div_taxation(&transactions);
     let (gross_sold, cost_sold) = compute_sold_taxation(&sold_transactions);
     let (gross_revolut, tax_revolut) = compute_div_taxation(&revolut_dividends_transactions);
     let (gross_revolut_sold, cost_revolut_sold) = compute_sold_taxation(&revolut_sold_transactions);
+    let (vest_income, _) = compute_div_taxation(&vest_transactions);
+    let (espp_discount_income, _) = compute_div_taxation(&espp_transactions);
     Ok(TaxCalculationResult {
         gross_income: gross_interests + gross_div + gross_revolut,
         tax: tax_div + tax_revolut,
@@ -534,6 +1239,12 @@ pub fn run_taxation(
         revolut_dividends_transactions: revolut_dividends_transactions,
         sold_transactions: sold_transactions,
         revolut_sold_transactions: revolut_sold_transactions,
+        vest_income,
+        vest_transactions,
+        espp_discount_income,
+        espp_transactions,
+        federal_tax_withheld,
+        qualified_dividends,
     })
 }
 
@@ -601,6 +1312,20 @@ mod tests {
         assert_eq!(err, format!("File has no extension: {}", fpath));
     }
 
+    #[test]
+    fn test_account() {
+        let account = Account::new("Morgan Stanley", Some(" 123-456789-012"));
+        assert_eq!(account.number, Some("XXX-XXXXX9-012".to_owned()));
+        assert_eq!(account.to_string(), "Morgan Stanley XXX-XXXXX9-012");
+        // Already masked and short numbers are kept
+        assert_eq!(
+            Account::new("E*TRADE", Some("XXXX-1234")).to_string(),
+            "E*TRADE XXXX-1234"
+        );
+        assert_eq!(mask_account_number("123"), "123");
+        assert_eq!(Account::new("Revolut", None).to_string(), "Revolut");
+    }
+
     #[test]
     fn test_simple_div_taxation() -> Result<(), String> {
         // Init Transactions
@@ -611,6 +1336,7 @@ mod tests {
             exchange_rate_date: "N/A".to_string(),
             exchange_rate: 4.0,
             company: Some("INTEL CORP".to_owned()),
+            account: None,
         }];
         assert_eq!(compute_div_taxation(&transactions), (400.0, 100.0));
         Ok(())
@@ -627,6 +1353,7 @@ mod tests {
                 exchange_rate_date: "N/A".to_string(),
                 exchange_rate: 4.0,
                 company: Some("INTEL CORP".to_owned()),
+                account: None,
             },
             Transaction {
                 transaction_date: "N/A".to_string(),
@@ -635,6 +1362,7 @@ mod tests {
                 exchange_rate_date: "N/A".to_string(),
                 exchange_rate: 3.5,
                 company: Some("INTEL CORP".to_owned()),
+                account: None,
             },
         ];
         assert_eq!(
@@ -653,6 +1381,7 @@ mod tests {
                 exchange_rate_date: "N/A".to_string(),
                 exchange_rate: 1.0,
                 company: None,
+                account: None,
             },
             Transaction {
                 transaction_date: "04/11/21".to_string(),
@@ -661,6 +1390,7 @@ mod tests {
                 exchange_rate_date: "N/A".to_string(),
                 exchange_rate: 1.0,
                 company: None,
+                account: None,
             },
         ];
         assert_eq!(
@@ -680,6 +1410,7 @@ mod tests {
                 exchange_rate_date: "02/28/21".to_string(),
                 exchange_rate: 2.0,
                 company: None,
+                account: None,
             },
             Transaction {
                 transaction_date: "04/11/21".to_string(),
@@ -688,6 +1419,7 @@ mod tests {
                 exchange_rate_date: "04/10/21".to_string(),
                 exchange_rate: 3.0,
                 company: None,
+                account: None,
             },
         ];
         assert_eq!(
@@ -711,6 +1443,8 @@ mod tests {
             exchange_rate_acquisition_date: "N/A".to_string(),
             exchange_rate_acquisition: 6.0,
             company: Some("TFC".to_owned()),
+            lot: LotDetails::default(),
+            holding_days: 0,
         }];
         assert_eq!(
             compute_sold_taxation(&transactions),
@@ -734,6 +1468,8 @@ mod tests {
                 exchange_rate_acquisition_date: "N/A".to_string(),
                 exchange_rate_acquisition: 6.0,
                 company: Some("PXD".to_owned()),
+                lot: LotDetails::default(),
+                holding_days: 0,
             },
             SoldTransaction {
                 trade_date: "N/A".to_string(),
@@ -746,6 +1482,8 @@ mod tests {
                 exchange_rate_acquisition_date: "N/A".to_string(),
                 exchange_rate_acquisition: 3.0,
                 company: Some("TFC".to_owned()),
+                lot: LotDetails::default(),
+                holding_days: 0,
             },
         ];
         assert_eq!(
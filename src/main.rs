@@ -6,7 +6,6 @@ use std::env;
 
 mod de;
 mod logging;
-mod nbp;
 mod pl;
 mod us;
 
@@ -44,7 +43,7 @@ fn create_cmd_line_pattern(myapp: Command) -> Command {
             Arg::new("financial documents")
                 .help("Account statement PDFs  and Gain & Losses xlsx documents\n\nAccount statements can be downloaded from:\n\thttps://edoc.etrade.com/e/t/onlinedocs/docsearch?doc_type=stmt\n\nGain&Losses documents can be downloaded from:\n\thttps://us.etrade.com/etx/sp/stockplan#/myAccount/gainsLosses\n")
                 .num_args(1..)
-                .required(true),
+                .required_unless_present_any(["cache-list", "cache-export", "cache-purge"]),
         )
         .arg(
             Arg::new("per-company")
@@ -52,12 +51,124 @@ fn create_cmd_line_pattern(myapp: Command) -> Command {
                 .help("Enable per-company mode")
                 .action(clap::ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new("per-account")
+                .long("per-account")
+                .help("Enable per-account mode (totals of each broker account and documents they come from)")
+                .action(clap::ArgAction::SetTrue)
+        )
         .arg(
             Arg::new("multiyear")
                 .long("multiyear")
                 .help("Allow processing documents across more than year")
                 .action(clap::ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new("offline")
+                .long("offline")
+                .help("Do not use network. Exchange rates are taken only from bundled data and exchange rates files")
+                .action(clap::ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("exchange-rates")
+                .long("exchange-rates")
+                .help("CSV file with exchange rates (columns: currency,date,rate) to be used before querying network")
+                .value_name("FILE")
+                .action(clap::ArgAction::Append)
+        )
+        .arg(
+            Arg::new("nbp-rates")
+                .long("nbp-rates")
+                .help("CSV file with NBP exchange rates (output of gen_exchange_rates) to be used instead of bundled ones")
+                .value_name("FILE")
+        )
+        .arg(
+            Arg::new("dump")
+                .long("dump")
+                .help("Print text of PDF documents (with positions), decisions of parser and result of parsing instead of computing taxes")
+                .action(clap::ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("mask")
+                .long("mask")
+                .help("Mask amounts and account numbers in dump of PDF documents, so it can be shared")
+                .requires("dump")
+                .action(clap::ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("anonymize")
+                .long("anonymize")
+                .help("Write anonymized copies of documents (personal identifiers replaced, amounts scaled) to given directory instead of computing taxes")
+                .value_name("DIR")
+        )
+        .arg(
+            Arg::new("identifier")
+                .long("identifier")
+                .help("Text to be replaced in anonymized documents e.g. name or address (may be given many times)")
+                .value_name("TEXT")
+                .requires("anonymize")
+                .action(clap::ArgAction::Append)
+        )
+        .arg(
+            Arg::new("scale")
+                .long("scale")
+                .help("Factor by which amounts of anonymized documents are scaled (random one by default)")
+                .value_name("FACTOR")
+                .requires("anonymize")
+                .value_parser(clap::value_parser!(f64))
+        )
+        .arg(
+            Arg::new("cache-list")
+                .long("cache-list")
+                .help("Print exchange rates stored in local cache")
+                .action(clap::ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("cache-export")
+                .long("cache-export")
+                .help("Export exchange rates stored in local cache to CSV file (usable with --exchange-rates). Only rates to currency of selected residency are exported")
+                .value_name("FILE")
+        )
+        .arg(
+            Arg::new("cache-purge")
+                .long("cache-purge")
+                .help("Remove all exchange rates from local cache")
+                .action(clap::ArgAction::SetTrue)
+        )
+}
+
+// Returns true if any of exchange rates cache operations was requested
+fn process_cache_commands(
+    matches: &clap::ArgMatches,
+    rd: &dyn etradeTaxReturnHelper::Residency,
+) -> Result<bool, String> {
+    let mut processed = false;
+    if matches.get_flag("cache-list") {
+        let cache_dir = etradeTaxReturnHelper::get_cache_dir()
+            .map(|d| d.display().to_string())
+            .unwrap_or("N/A".to_owned());
+        println!("Exchange rates cache: {cache_dir}");
+        etradeTaxReturnHelper::list_cached_exchange_rates()?
+            .iter()
+            .for_each(|r| {
+                println!(
+                    "{} {} {} {} (table: {})",
+                    r.source, r.currency, r.date, r.rate, r.table
+                )
+            });
+        processed = true;
+    }
+    if let Some(file) = matches.get_one::<String>("cache-export") {
+        let count = etradeTaxReturnHelper::export_cached_exchange_rates(file, rd.currency())?;
+        println!("Exported {count} exchange rates to: {file}");
+        processed = true;
+    }
+    if matches.get_flag("cache-purge") {
+        etradeTaxReturnHelper::purge_exchange_rates_cache()?;
+        println!("Exchange rates cache purged");
+        processed = true;
+    }
+    Ok(processed)
 }
 
 fn configure_dataframes_format() {
@@ -92,31 +203,87 @@ fn main() {
         .get_one::<String>("residency")
         .expect_and_log("error getting residency value");
     let rd: Box<dyn etradeTaxReturnHelper::Residency> = match residency.as_str() {
-        "de" => Box::new(de::DE {}),
-        "pl" => Box::new(pl::PL {}),
-        "us" => Box::new(us::US {}),
+        "de" => Box::new(de::DE::default()),
+        "pl" => Box::new(pl::PL::default()),
+        "us" => Box::new(us::US::default()),
         _ => panic!(
             "{}",
             &format!("Error: unimplemented residency: {}", residency)
         ),
     };
 
+    match process_cache_commands(&matches, rd.as_ref()) {
+        Ok(true) => return,
+        Ok(false) => (),
+        Err(msg) => panic!("\nError: Unable to process exchange rates cache. \n\nDetails: {msg}"),
+    }
+
+    etradeTaxReturnHelper::set_offline_mode(matches.get_flag("offline"));
+    if let Some(nbp_rates_file) = matches.get_one::<String>("nbp-rates") {
+        etradeTaxReturnHelper::set_nbp_exchange_rates_file(nbp_rates_file).expect_and_log(
+            &format!("Error: unable to use NBP exchange rates file: {nbp_rates_file}"),
+        );
+    }
+    if let Some(rates_files) = matches.get_many::<String>("exchange-rates") {
+        rates_files.for_each(|f| {
+            etradeTaxReturnHelper::load_exchange_rates_file(f)
+                .expect_and_log(&format!("Error: unable to load exchange rates file: {f}"));
+        });
+    }
+
     let pdfnames = matches
         .get_many::<String>("financial documents")
         .expect_and_log("error getting brokarage statements pdfs names.\n\nBrokerege statements can be downloaded from:\n\nhttps://edoc.etrade.com/e/t/onlinedocs/docsearch?doc_type=stmt\n\n");
 
     let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
 
+    if matches.get_flag("dump") {
+        pdfnames.iter().for_each(|pdfname| {
+            etradeTaxReturnHelper::dump_document(pdfname, matches.get_flag("mask"))
+                .expect_and_log(&format!("Error: unable to dump document: {pdfname}"))
+                .iter()
+                .for_each(|line| println!("{line}"));
+        });
+        return;
+    }
+
+    if let Some(output_dir) = matches.get_one::<String>("anonymize") {
+        let identifiers: Vec<String> = matches
+            .get_many::<String>("identifier")
+            .map(|i| i.cloned().collect())
+            .unwrap_or_default();
+        let scale = matches
+            .get_one::<f64>("scale")
+            .copied()
+            .unwrap_or(etradeTaxReturnHelper::Anonymizer::random_scale());
+        let mut anonymizer = etradeTaxReturnHelper::Anonymizer::new(scale, &identifiers)
+            .expect_and_log("Error: unable to anonymize documents");
+        pdfnames.iter().for_each(|name| {
+            let output = anonymizer
+                .anonymize_document(name, output_dir)
+                .expect_and_log(&format!("Error: unable to anonymize document: {name}"));
+            println!("Anonymized {name} -> {output}");
+        });
+        return;
+    }
+
     let TaxCalculationResult {
         gross_income: gross_div,
         tax: tax_div,
         gross_sold,
         cost_sold,
+        vest_income,
+        vest_transactions,
+        espp_discount_income,
+        espp_transactions,
+        federal_tax_withheld,
+        qualified_dividends,
         ..
     } = match run_taxation(
         &rd,
         pdfnames,
         matches.get_flag("per-company"),
+        matches.get_flag("per-account"),
         matches.get_flag("multiyear"),
     ) {
         Ok(res) => res,
@@ -125,6 +292,18 @@ fn main() {
 
     let (presentation, warning) = rd.present_result(gross_div, tax_div, gross_sold, cost_sold);
     presentation.iter().for_each(|x| println!("{x}"));
+    if !vest_transactions.is_empty() {
+        println!("(RSU releases) Employment income: {vest_income:.2}");
+    }
+    if !espp_transactions.is_empty() {
+        println!("(ESPP discount) Employment income: {espp_discount_income:.2}");
+    }
+    if qualified_dividends > 0.0 {
+        println!("(1099-DIV) Qualified dividends: {qualified_dividends:.2}");
+    }
+    if federal_tax_withheld > 0.0 {
+        println!("(1099-DIV) Federal income tax withheld: {federal_tax_withheld:.2}");
+    }
 
     if let Some(warn_msg) = warning {
         println!("\n\nWARNING: {warn_msg}");
@@ -136,9 +315,18 @@ mod tests {
     use super::*;
     use clap::Command;
 
+    // Tests must not use nor modify exchange rates cache of the user
+    pub(crate) fn use_test_cache_dir() {
+        let dir =
+            std::env::temp_dir().join(format!("etradeTaxReturnHelper_test_{}", std::process::id()));
+        etradeTaxReturnHelper::set_cache_dir(&dir).expect("Unable to set test cache directory");
+    }
+
     #[test]
     fn test_exchange_rate_de() -> Result<(), String> {
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(de::DE {});
+        use_test_cache_dir();
+        let (_server, ecb_url) = etradeTaxReturnHelper::fake_http_server::start_fake_ecb_server();
+        let rd = de::DE::default();
 
         let mut dates: std::collections::HashMap<
             etradeTaxReturnHelper::Exchange,
@@ -146,27 +334,31 @@ mod tests {
         > = std::collections::HashMap::new();
 
         dates.insert(
-            etradeTaxReturnHelper::Exchange::USD("02/21/23".to_owned()),
+            etradeTaxReturnHelper::Exchange::USD("07/14/23".to_owned()),
             None,
         );
 
-        rd.get_exchange_rates(&mut dates)?;
+        etradeTaxReturnHelper::fill_exchange_rates(
+            &rd.exchange_rate_providers_with_ecb_url(&ecb_url),
+            &mut dates,
+        )?;
 
         let (exchange_rate_date, exchange_rate) = dates
-            [&etradeTaxReturnHelper::Exchange::USD("02/21/23".to_owned())]
+            [&etradeTaxReturnHelper::Exchange::USD("07/14/23".to_owned())]
             .clone()
             .unwrap();
 
         assert_eq!(
             (exchange_rate_date, exchange_rate),
-            ("2023-02-20".to_owned(), 0.9368559)
+            ("2023-07-13".to_owned(), 0.89429444)
         );
         Ok(())
     }
 
     #[test]
     fn test_exchange_rate_pl() -> Result<(), String> {
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL {});
+        use_test_cache_dir();
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL::default());
 
         let mut dates: std::collections::HashMap<
             etradeTaxReturnHelper::Exchange,
@@ -194,7 +386,8 @@ mod tests {
 
     #[test]
     fn test_exchange_rate_us() -> Result<(), String> {
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(us::US {});
+        use_test_cache_dir();
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(us::US::default());
 
         let mut dates: std::collections::HashMap<
             etradeTaxReturnHelper::Exchange,
@@ -272,6 +465,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cmdline_per_account() -> Result<(), clap::Error> {
+        // Init Transactions
+        let myapp = Command::new("E-trade tax helper");
+        let matches =
+            create_cmd_line_pattern(myapp).get_matches_from(vec!["mytest", "data/example.pdf"]);
+        let per_account = matches.get_flag("per-account");
+        match per_account {
+            false => (),
+            true => {
+                return Err(clap::error::Error::<clap::error::DefaultFormatter>::new(
+                    clap::error::ErrorKind::InvalidValue,
+                ))
+            }
+        };
+        let myapp = Command::new("E-trade tax helper");
+        let matches = create_cmd_line_pattern(myapp).get_matches_from(vec![
+            "mytest",
+            "--per-account",
+            "data/example.pdf",
+        ]);
+        let per_account = matches.get_flag("per-account");
+        match per_account {
+            true => (),
+            false => {
+                return Err(clap::error::Error::<clap::error::DefaultFormatter>::new(
+                    clap::error::ErrorKind::InvalidValue,
+                ))
+            }
+        };
+        Ok(())
+    }
+
     #[test]
     fn test_cmdline_multiyear() -> Result<(), clap::Error> {
         // Init Transactions
@@ -305,6 +531,111 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cmdline_offline() -> Result<(), clap::Error> {
+        let myapp = Command::new("E-trade tax helper");
+        let matches = create_cmd_line_pattern(myapp).try_get_matches_from(vec![
+            "mytest",
+            "--offline",
+            "--exchange-rates",
+            "data/exchange_rates.csv",
+            "--nbp-rates",
+            "src/nbp_exchange_rates.csv",
+            "data/example.pdf",
+        ])?;
+        assert!(matches.get_flag("offline"));
+        assert_eq!(
+            matches.get_one::<String>("nbp-rates"),
+            Some(&"src/nbp_exchange_rates.csv".to_owned())
+        );
+        let rates_files: Vec<&String> = matches
+            .get_many::<String>("exchange-rates")
+            .expect("exchange rates files missing")
+            .collect();
+        assert_eq!(rates_files, vec!["data/exchange_rates.csv"]);
+        let pdfs: Vec<&String> = matches
+            .get_many::<String>("financial documents")
+            .expect("financial documents missing")
+            .collect();
+        assert_eq!(pdfs, vec!["data/example.pdf"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cmdline_dump() -> Result<(), clap::Error> {
+        let myapp = Command::new("E-trade tax helper");
+        let matches = create_cmd_line_pattern(myapp).try_get_matches_from(vec![
+            "mytest",
+            "--dump",
+            "--mask",
+            "data/example.pdf",
+        ])?;
+        assert!(matches.get_flag("dump"));
+        assert!(matches.get_flag("mask"));
+        // Masking is an option of dump only
+        let myapp = Command::new("E-trade tax helper");
+        assert!(create_cmd_line_pattern(myapp)
+            .try_get_matches_from(vec!["mytest", "--mask", "data/example.pdf"])
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cmdline_anonymize() -> Result<(), clap::Error> {
+        let myapp = Command::new("E-trade tax helper");
+        let matches = create_cmd_line_pattern(myapp).try_get_matches_from(vec![
+            "mytest",
+            "--anonymize",
+            "anonymized",
+            "--identifier",
+            "John Doe",
+            "--identifier",
+            "Main Street 1",
+            "--scale",
+            "0.75",
+            "data/example.pdf",
+        ])?;
+        assert_eq!(
+            matches.get_one::<String>("anonymize"),
+            Some(&"anonymized".to_owned())
+        );
+        let identifiers: Vec<&String> = matches
+            .get_many::<String>("identifier")
+            .expect("identifiers missing")
+            .collect();
+        assert_eq!(identifiers, vec!["John Doe", "Main Street 1"]);
+        assert_eq!(matches.get_one::<f64>("scale"), Some(&0.75));
+        // Options of anonymizer are not accepted without it
+        let myapp = Command::new("E-trade tax helper");
+        assert!(create_cmd_line_pattern(myapp)
+            .try_get_matches_from(vec!["mytest", "--scale", "2", "data/example.pdf"])
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cmdline_cache() -> Result<(), clap::Error> {
+        let myapp = Command::new("E-trade tax helper");
+        let matches = create_cmd_line_pattern(myapp).try_get_matches_from(vec![
+            "mytest",
+            "--cache-list",
+            "--cache-export",
+            "rates.csv",
+        ])?;
+        assert!(matches.get_flag("cache-list"));
+        assert!(!matches.get_flag("cache-purge"));
+        assert_eq!(
+            matches.get_one::<String>("cache-export"),
+            Some(&"rates.csv".to_owned())
+        );
+        // Documents are required unless cache is to be processed
+        let myapp = Command::new("E-trade tax helper");
+        assert!(create_cmd_line_pattern(myapp)
+            .try_get_matches_from(vec!["mytest", "--offline"])
+            .is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_cmdline_pl() -> Result<(), clap::Error> {
         // Init Transactions
@@ -360,11 +691,12 @@ mod tests {
 
     #[test]
     fn test_unrecognized_file_taxation() -> Result<(), clap::Error> {
+        use_test_cache_dir();
         // Get all brokerage with dividends only
 
         let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
 
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL::default());
         // Check printed values or returned values?
         let matches = create_cmd_line_pattern(myapp)
             .get_matches_from(vec!["mytest", "unrecognized_file.txt"]);
@@ -374,17 +706,152 @@ mod tests {
             .expect_and_log("error getting financial documents names");
         let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
 
-        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false) {
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
             Ok(_) => panic!("Expected an error from run_taxation, but got Ok"),
             Err(_) => Ok(()), // Expected error, test passes
         }
     }
 
+    #[test]
+    fn test_form_1099_taxation_pl() -> Result<(), clap::Error> {
+        use_test_cache_dir();
+        // Yearly totals of 1099-DIV can not be converted with exchange rates of each payment
+        let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL::default());
+
+        let matches = create_cmd_line_pattern(myapp)
+            .get_matches_from(vec!["mytest", "data/form_1099_example.pdf"]);
+
+        let pdfnames = matches
+            .get_many::<String>("financial documents")
+            .expect_and_log("error getting financial documents names");
+        let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
+
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
+            Ok(_) => panic!("Expected an error from run_taxation, but got Ok"),
+            Err(msg) => assert!(msg.contains("supported only for US residency")),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_form_1099_taxation_us() -> Result<(), clap::Error> {
+        use_test_cache_dir();
+        let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(us::US::default());
+
+        let matches = create_cmd_line_pattern(myapp)
+            .get_matches_from(vec!["mytest", "data/form_1099_example.pdf"]);
+
+        let pdfnames = matches
+            .get_many::<String>("financial documents")
+            .expect_and_log("error getting financial documents names");
+        let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
+
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
+            Ok(TaxCalculationResult {
+                gross_income: gross_div,
+                tax: tax_div,
+                gross_sold,
+                cost_sold,
+                federal_tax_withheld,
+                qualified_dividends,
+                ..
+            }) => {
+                assert_eq!(
+                    (gross_div, tax_div, gross_sold, cost_sold),
+                    (345.6, 12.4, 1130.0, 860.0),
+                );
+                assert_eq!((qualified_dividends, federal_tax_withheld), (300.0, 0.0));
+                Ok(())
+            }
+            Err(x) => panic!("Error in taxation process: {x}"),
+        }
+    }
+
+    #[test]
+    fn test_interests_reportable_for_prior_year_us() -> Result<(), clap::Error> {
+        // January statement with interests reportable for the prior year and interests of
+        // this year. Only the latter are taxed
+        use_test_cache_dir();
+        let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(us::US::default());
+
+        let matches = create_cmd_line_pattern(myapp)
+            .get_matches_from(vec!["mytest", "data/account_statement_january_example.pdf"]);
+
+        let pdfnames = matches
+            .get_many::<String>("financial documents")
+            .expect_and_log("error getting financial documents names");
+        let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
+
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
+            Ok(TaxCalculationResult {
+                gross_income: gross_div,
+                tax: tax_div,
+                interests,
+                ..
+            }) => {
+                assert_eq!((gross_div, tax_div), (1.05, 0.0));
+                assert_eq!(interests.len(), 1);
+                Ok(())
+            }
+            Err(x) => panic!("Error in taxation process: {x}"),
+        }
+    }
+
+    #[test]
+    fn test_interests_reportable_for_tax_year_us() -> Result<(), clap::Error> {
+        // Statements of December and January with interests reportable for the prior year.
+        // Both are taxed in year of December statement
+        use_test_cache_dir();
+        let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(us::US::default());
+
+        let matches = create_cmd_line_pattern(myapp).get_matches_from(vec![
+            "mytest",
+            "data/account_statement_december_example.pdf",
+            "data/account_statement_january_prior_year_example.pdf",
+        ]);
+
+        let pdfnames = matches
+            .get_many::<String>("financial documents")
+            .expect_and_log("error getting financial documents names");
+        let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
+
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
+            Ok(TaxCalculationResult {
+                gross_income: gross_div,
+                interests,
+                ..
+            }) => {
+                assert_eq!(gross_div, 0.85 + 0.92);
+                // Interests keep accounts of their statements
+                assert_eq!(
+                    interests
+                        .iter()
+                        .map(|i| (
+                            i.transaction_date.as_str(),
+                            i.account.as_ref().map(|a| a.to_string())
+                        ))
+                        .collect::<Vec<(&str, Option<String>)>>(),
+                    vec![
+                        ("12/1/23", Some("Morgan Stanley XXX-XXXXX9-345".to_owned())),
+                        ("12/31/23", Some("Morgan Stanley XXX-XXXXX9-012".to_owned()))
+                    ]
+                );
+                Ok(())
+            }
+            Err(x) => panic!("Error in taxation process: {x}"),
+        }
+    }
+
     #[test]
     fn test_revolut_dividends_pln() -> Result<(), clap::Error> {
+        use_test_cache_dir();
         // Get all brokerage with dividends only
         let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL::default());
 
         let matches = create_cmd_line_pattern(myapp).get_matches_from(vec![
             "mytest",
@@ -395,7 +862,7 @@ mod tests {
             .expect_and_log("error getting brokarage statements pdfs names");
         let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
 
-        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false) {
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
             Ok(TaxCalculationResult {
                 gross_income: gross_div,
                 tax: tax_div,
@@ -415,9 +882,10 @@ mod tests {
 
     #[test]
     fn test_revolut_sold_and_dividends() -> Result<(), clap::Error> {
+        use_test_cache_dir();
         // Get all brokerage with dividends only
         let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL::default());
 
         let matches = create_cmd_line_pattern(myapp).get_matches_from(vec![
             "mytest",
@@ -428,7 +896,7 @@ mod tests {
             .expect_and_log("error getting brokarage statements pdfs names");
         let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
 
-        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false) {
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
             Ok(TaxCalculationResult {
                 gross_income: gross_div,
                 tax: tax_div,
@@ -448,9 +916,10 @@ mod tests {
 
     #[test]
     fn test_revolut_interests_taxation_pln() -> Result<(), clap::Error> {
+        use_test_cache_dir();
         // Get all brokerage with dividends only
         let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL::default());
 
         let matches = create_cmd_line_pattern(myapp).get_matches_from(vec![
             "mytest",
@@ -461,7 +930,7 @@ mod tests {
             .expect_and_log("error getting brokarage statements pdfs names");
         let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
 
-        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false) {
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
             Ok(TaxCalculationResult {
                 gross_income: gross_div,
                 tax: tax_div,
@@ -482,9 +951,10 @@ mod tests {
     #[test]
     #[ignore]
     fn test_sold_dividends_interests_taxation() -> Result<(), clap::Error> {
+        use_test_cache_dir();
         // Get all brokerage with dividends only
         let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL::default());
         let matches = create_cmd_line_pattern(myapp).get_matches_from(vec![
             "mytest",
             "etrade_data_2025/ClientStatements_010226.pdf",
@@ -495,7 +965,7 @@ mod tests {
             .expect_and_log("error getting brokarage statements pdfs names");
         let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
 
-        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false) {
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
             Ok(TaxCalculationResult {
                 gross_income: gross_div,
                 tax: tax_div,
@@ -516,9 +986,10 @@ mod tests {
     #[test]
     #[ignore]
     fn test_interest_adjustment_taxation() -> Result<(), clap::Error> {
+        use_test_cache_dir();
         // Get all brokerage with dividends only
         let myapp = Command::new("etradeTaxHelper").arg_required_else_help(true);
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(pl::PL::default());
         let matches = create_cmd_line_pattern(myapp)
             .get_matches_from(vec!["mytest", "data/example-interest-adj.pdf"]);
         let pdfnames = matches
@@ -526,7 +997,7 @@ mod tests {
             .expect_and_log("error getting brokarage statements pdfs names");
         let pdfnames: Vec<String> = pdfnames.map(|x| x.to_string()).collect();
 
-        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false) {
+        match etradeTaxReturnHelper::run_taxation(&rd, pdfnames, false, false, false) {
             Ok(TaxCalculationResult {
                 gross_income: gross_div,
                 tax: tax_div,
@@ -1,4295 +0,0 @@
-// SPDX-FileCopyrightText: 2024-2025 RustInFinance
-// SPDX-License-Identifier: BSD-3-Clause
-use std::collections::HashMap;
-
-use etradeTaxReturnHelper::Exchange;
-
-#[allow(clippy::approx_constant)]
-pub fn get_exchange_rates() -> HashMap<Exchange, f64> {
-    let mut exchange_rates = HashMap::new();
-    exchange_rates.insert(Exchange::EUR("2025-03-24".to_string()), 4.1832f64);
-    exchange_rates.insert(Exchange::USD("2016-07-05".to_string()), 3.9963f64);
-    exchange_rates.insert(Exchange::USD("2023-10-12".to_string()), 4.2659f64);
-    exchange_rates.insert(Exchange::USD("2024-07-12".to_string()), 3.9099f64);
-    exchange_rates.insert(Exchange::USD("2020-01-21".to_string()), 3.8284f64);
-    exchange_rates.insert(Exchange::USD("2025-03-26".to_string()), 3.8614f64);
-    exchange_rates.insert(Exchange::USD("2018-05-10".to_string()), 3.5773f64);
-    exchange_rates.insert(Exchange::USD("2019-05-22".to_string()), 3.862f64);
-    exchange_rates.insert(Exchange::USD("2024-11-15".to_string()), 4.0898f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-17".to_string()), 4.2149f64);
-    exchange_rates.insert(Exchange::USD("2024-04-12".to_string()), 3.9983f64);
-    exchange_rates.insert(Exchange::USD("2025-08-21".to_string()), 3.6483f64);
-    exchange_rates.insert(Exchange::USD("2020-06-23".to_string()), 3.9311f64);
-    exchange_rates.insert(Exchange::USD("2025-10-15".to_string()), 3.6585f64);
-    exchange_rates.insert(Exchange::USD("2015-01-22".to_string()), 3.6994f64);
-    exchange_rates.insert(Exchange::USD("2017-01-04".to_string()), 4.2037f64);
-    exchange_rates.insert(Exchange::USD("2015-11-02".to_string()), 3.859f64);
-    exchange_rates.insert(Exchange::USD("2023-09-14".to_string()), 4.312f64);
-    exchange_rates.insert(Exchange::USD("2024-12-11".to_string()), 4.0628f64);
-    exchange_rates.insert(Exchange::USD("2020-12-18".to_string()), 3.6322f64);
-    exchange_rates.insert(Exchange::USD("2025-10-20".to_string()), 3.6349f64);
-    exchange_rates.insert(Exchange::USD("2022-08-12".to_string()), 4.5459f64);
-    exchange_rates.insert(Exchange::USD("2019-11-21".to_string()), 3.8737f64);
-    exchange_rates.insert(Exchange::USD("2016-04-15".to_string()), 3.8176f64);
-    exchange_rates.insert(Exchange::USD("2015-03-13".to_string()), 3.9141f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-05".to_string()), 4.6803f64);
-    exchange_rates.insert(Exchange::USD("2021-07-28".to_string()), 3.8849f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-11".to_string()), 4.3366f64);
-    exchange_rates.insert(Exchange::USD("2012-07-02".to_string()), 3.3456f64);
-    exchange_rates.insert(Exchange::USD("2021-12-15".to_string()), 4.0999f64);
-    exchange_rates.insert(Exchange::USD("2015-03-02".to_string()), 3.7053f64);
-    exchange_rates.insert(Exchange::USD("2020-08-07".to_string()), 3.7302f64);
-    exchange_rates.insert(Exchange::USD("2013-07-25".to_string()), 3.2143f64);
-    exchange_rates.insert(Exchange::USD("2021-07-14".to_string()), 3.8955f64);
-    exchange_rates.insert(Exchange::USD("2020-08-31".to_string()), 3.6924f64);
-    exchange_rates.insert(Exchange::USD("2012-05-17".to_string()), 3.4194f64);
-    exchange_rates.insert(Exchange::USD("2012-06-21".to_string()), 3.3617f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-20".to_string()), 4.368f64);
-    exchange_rates.insert(Exchange::USD("2025-04-25".to_string()), 3.7611f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-22".to_string()), 4.3186f64);
-    exchange_rates.insert(Exchange::USD("2024-02-19".to_string()), 4.0269f64);
-    exchange_rates.insert(Exchange::USD("2018-06-06".to_string()), 3.647f64);
-    exchange_rates.insert(Exchange::USD("2021-09-15".to_string()), 3.8475f64);
-    exchange_rates.insert(Exchange::USD("2019-10-04".to_string()), 3.9469f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-16".to_string()), 4.3033f64);
-    exchange_rates.insert(Exchange::USD("2024-08-05".to_string()), 3.9331f64);
-    exchange_rates.insert(Exchange::USD("2019-02-11".to_string()), 3.819f64);
-    exchange_rates.insert(Exchange::USD("2019-02-01".to_string()), 3.7243f64);
-    exchange_rates.insert(Exchange::USD("2014-10-30".to_string()), 3.3592f64);
-    exchange_rates.insert(Exchange::USD("2016-05-16".to_string()), 3.8625f64);
-    exchange_rates.insert(Exchange::USD("2016-06-28".to_string()), 4.003f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-25".to_string()), 4.2627f64);
-    exchange_rates.insert(Exchange::USD("2023-01-11".to_string()), 4.3639f64);
-    exchange_rates.insert(Exchange::USD("2016-01-07".to_string()), 4.0085f64);
-    exchange_rates.insert(Exchange::USD("2020-01-13".to_string()), 3.8101f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-15".to_string()), 4.2378f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-11".to_string()), 4.2649f64);
-    exchange_rates.insert(Exchange::USD("2015-04-21".to_string()), 3.7275f64);
-    exchange_rates.insert(Exchange::USD("2023-05-09".to_string()), 4.1609f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-19".to_string()), 4.6278f64);
-    exchange_rates.insert(Exchange::USD("2025-03-24".to_string()), 3.859f64);
-    exchange_rates.insert(Exchange::USD("2018-10-23".to_string()), 3.7485f64);
-    exchange_rates.insert(Exchange::USD("2015-04-08".to_string()), 3.7135f64);
-    exchange_rates.insert(Exchange::USD("2023-03-15".to_string()), 4.403f64);
-    exchange_rates.insert(Exchange::USD("2019-07-03".to_string()), 3.7596f64);
-    exchange_rates.insert(Exchange::USD("2014-04-29".to_string()), 3.0318f64);
-    exchange_rates.insert(Exchange::USD("2015-07-23".to_string()), 3.7559f64);
-    exchange_rates.insert(Exchange::USD("2024-06-27".to_string()), 4.0312f64);
-    exchange_rates.insert(Exchange::USD("2012-05-28".to_string()), 3.4535f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-24".to_string()), 4.2789f64);
-    exchange_rates.insert(Exchange::USD("2019-04-15".to_string()), 3.7789f64);
-    exchange_rates.insert(Exchange::USD("2023-03-03".to_string()), 4.4341f64);
-    exchange_rates.insert(Exchange::USD("2017-02-06".to_string()), 3.9892f64);
-    exchange_rates.insert(Exchange::USD("2018-06-14".to_string()), 3.6155f64);
-    exchange_rates.insert(Exchange::USD("2013-06-26".to_string()), 3.3239f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-10".to_string()), 4.6981f64);
-    exchange_rates.insert(Exchange::USD("2024-08-13".to_string()), 3.9314f64);
-    exchange_rates.insert(Exchange::USD("2017-12-15".to_string()), 3.5786f64);
-    exchange_rates.insert(Exchange::USD("2014-05-23".to_string()), 3.049f64);
-    exchange_rates.insert(Exchange::USD("2023-10-09".to_string()), 4.3439f64);
-    exchange_rates.insert(Exchange::USD("2020-06-26".to_string()), 3.9697f64);
-    exchange_rates.insert(Exchange::USD("2019-07-29".to_string()), 3.8453f64);
-    exchange_rates.insert(Exchange::USD("2022-05-24".to_string()), 4.3071f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-02".to_string()), 4.2812f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-03".to_string()), 4.2634f64);
-    exchange_rates.insert(Exchange::USD("2020-12-08".to_string()), 3.6914f64);
-    exchange_rates.insert(Exchange::USD("2018-02-08".to_string()), 3.4119f64);
-    exchange_rates.insert(Exchange::USD("2014-08-13".to_string()), 3.1442f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-09".to_string()), 4.2772f64);
-    exchange_rates.insert(Exchange::USD("2025-09-24".to_string()), 3.6254f64);
-    exchange_rates.insert(Exchange::USD("2014-04-16".to_string()), 3.0272f64);
-    exchange_rates.insert(Exchange::USD("2012-10-29".to_string()), 3.2145f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-28".to_string()), 4.634f64);
-    exchange_rates.insert(Exchange::USD("2022-10-31".to_string()), 4.734f64);
-    exchange_rates.insert(Exchange::USD("2012-01-31".to_string()), 3.2032f64);
-    exchange_rates.insert(Exchange::USD("2015-08-21".to_string()), 3.7308f64);
-    exchange_rates.insert(Exchange::USD("2018-07-02".to_string()), 3.7703f64);
-    exchange_rates.insert(Exchange::USD("2015-11-10".to_string()), 3.957f64);
-    exchange_rates.insert(Exchange::USD("2019-05-14".to_string()), 3.8323f64);
-    exchange_rates.insert(Exchange::USD("2014-11-06".to_string()), 3.3769f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-06".to_string()), 4.2669f64);
-    exchange_rates.insert(Exchange::USD("2016-10-12".to_string()), 3.8851f64);
-    exchange_rates.insert(Exchange::USD("2013-12-30".to_string()), 3.018f64);
-    exchange_rates.insert(Exchange::USD("2022-02-04".to_string()), 3.9657f64);
-    exchange_rates.insert(Exchange::USD("2025-10-01".to_string()), 3.6245f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-17".to_string()), 4.3978f64);
-    exchange_rates.insert(Exchange::USD("2021-01-15".to_string()), 3.7466f64);
-    exchange_rates.insert(Exchange::USD("2014-02-27".to_string()), 3.0655f64);
-    exchange_rates.insert(Exchange::USD("2019-03-04".to_string()), 3.7893f64);
-    exchange_rates.insert(Exchange::USD("2020-05-18".to_string()), 4.2224f64);
-    exchange_rates.insert(Exchange::USD("2015-03-06".to_string()), 3.7649f64);
-    exchange_rates.insert(Exchange::USD("2023-12-28".to_string()), 3.899f64);
-    exchange_rates.insert(Exchange::USD("2023-05-26".to_string()), 4.2156f64);
-    exchange_rates.insert(Exchange::USD("2012-05-22".to_string()), 3.3811f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-27".to_string()), 4.6856f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-10".to_string()), 4.2583f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-17".to_string()), 4.2685f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-15".to_string()), 4.39f64);
-    exchange_rates.insert(Exchange::USD("2025-11-21".to_string()), 3.6868f64);
-    exchange_rates.insert(Exchange::USD("2017-08-24".to_string()), 3.6329f64);
-    exchange_rates.insert(Exchange::USD("2025-12-12".to_string()), 3.6027f64);
-    exchange_rates.insert(Exchange::USD("2013-03-19".to_string()), 3.2055f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-06".to_string()), 4.2881f64);
-    exchange_rates.insert(Exchange::USD("2024-02-08".to_string()), 4.0292f64);
-    exchange_rates.insert(Exchange::USD("2024-09-17".to_string()), 3.8354f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-18".to_string()), 4.2331f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-20".to_string()), 4.2575f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-12".to_string()), 4.2986f64);
-    exchange_rates.insert(Exchange::USD("2018-06-15".to_string()), 3.6922f64);
-    exchange_rates.insert(Exchange::USD("2013-11-26".to_string()), 3.0945f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-16".to_string()), 4.2585f64);
-    exchange_rates.insert(Exchange::USD("2018-07-18".to_string()), 3.7031f64);
-    exchange_rates.insert(Exchange::USD("2014-02-10".to_string()), 3.0683f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-26".to_string()), 4.3802f64);
-    exchange_rates.insert(Exchange::USD("2022-06-27".to_string()), 4.4369f64);
-    exchange_rates.insert(Exchange::USD("2024-02-27".to_string()), 3.9682f64);
-    exchange_rates.insert(Exchange::USD("2023-08-11".to_string()), 4.0501f64);
-    exchange_rates.insert(Exchange::USD("2016-10-19".to_string()), 3.9285f64);
-    exchange_rates.insert(Exchange::USD("2018-01-08".to_string()), 3.4735f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-03".to_string()), 4.2817f64);
-    exchange_rates.insert(Exchange::USD("2022-09-26".to_string()), 4.9113f64);
-    exchange_rates.insert(Exchange::USD("2020-06-17".to_string()), 3.9532f64);
-    exchange_rates.insert(Exchange::USD("2014-07-11".to_string()), 3.0426f64);
-    exchange_rates.insert(Exchange::USD("2012-03-19".to_string()), 3.1361f64);
-    exchange_rates.insert(Exchange::USD("2019-06-11".to_string()), 3.7722f64);
-    exchange_rates.insert(Exchange::USD("2024-03-26".to_string()), 3.9704f64);
-    exchange_rates.insert(Exchange::USD("2024-01-09".to_string()), 3.9612f64);
-    exchange_rates.insert(Exchange::USD("2015-04-15".to_string()), 3.7875f64);
-    exchange_rates.insert(Exchange::USD("2013-02-04".to_string()), 3.0563f64);
-    exchange_rates.insert(Exchange::USD("2023-04-14".to_string()), 4.2042f64);
-    exchange_rates.insert(Exchange::USD("2015-08-13".to_string()), 3.7625f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-25".to_string()), 4.503f64);
-    exchange_rates.insert(Exchange::USD("2023-02-07".to_string()), 4.4325f64);
-    exchange_rates.insert(Exchange::USD("2013-12-16".to_string()), 3.035f64);
-    exchange_rates.insert(Exchange::USD("2022-09-23".to_string()), 4.8796f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-19".to_string()), 4.4492f64);
-    exchange_rates.insert(Exchange::USD("2017-07-26".to_string()), 3.6661f64);
-    exchange_rates.insert(Exchange::USD("2015-12-18".to_string()), 3.9558f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-08".to_string()), 4.4375f64);
-    exchange_rates.insert(Exchange::USD("2025-02-13".to_string()), 3.996f64);
-    exchange_rates.insert(Exchange::USD("2021-06-28".to_string()), 3.7762f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-18".to_string()), 4.4016f64);
-    exchange_rates.insert(Exchange::USD("2015-10-06".to_string()), 3.789f64);
-    exchange_rates.insert(Exchange::USD("2013-01-15".to_string()), 3.075f64);
-    exchange_rates.insert(Exchange::USD("2020-08-14".to_string()), 3.7227f64);
-    exchange_rates.insert(Exchange::USD("2021-02-09".to_string()), 3.6972f64);
-    exchange_rates.insert(Exchange::USD("2019-07-26".to_string()), 3.8296f64);
-    exchange_rates.insert(Exchange::USD("2020-11-23".to_string()), 3.7616f64);
-    exchange_rates.insert(Exchange::USD("2015-03-26".to_string()), 3.705f64);
-    exchange_rates.insert(Exchange::USD("2012-11-05".to_string()), 3.2211f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-06".to_string()), 4.2521f64);
-    exchange_rates.insert(Exchange::USD("2021-06-14".to_string()), 3.7185f64);
-    exchange_rates.insert(Exchange::USD("2017-10-23".to_string()), 3.6061f64);
-    exchange_rates.insert(Exchange::USD("2017-10-27".to_string()), 3.6568f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-15".to_string()), 4.2929f64);
-    exchange_rates.insert(Exchange::USD("2015-06-29".to_string()), 3.7671f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-30".to_string()), 4.2425f64);
-    exchange_rates.insert(Exchange::USD("2018-09-13".to_string()), 3.713f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-25".to_string()), 4.2619f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-12".to_string()), 4.2567f64);
-    exchange_rates.insert(Exchange::USD("2018-08-30".to_string()), 3.6724f64);
-    exchange_rates.insert(Exchange::USD("2018-03-27".to_string()), 3.3921f64);
-    exchange_rates.insert(Exchange::USD("2021-04-21".to_string()), 3.795f64);
-    exchange_rates.insert(Exchange::USD("2016-04-26".to_string()), 3.8935f64);
-    exchange_rates.insert(Exchange::USD("2024-10-21".to_string()), 3.9775f64);
-    exchange_rates.insert(Exchange::USD("2014-06-24".to_string()), 3.0526f64);
-    exchange_rates.insert(Exchange::USD("2013-08-30".to_string()), 3.2209f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-13".to_string()), 4.2945f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-01".to_string()), 4.708f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-29".to_string()), 4.3653f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-27".to_string()), 4.6065f64);
-    exchange_rates.insert(Exchange::USD("2022-10-13".to_string()), 4.9905f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-29".to_string()), 4.2077f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-21".to_string()), 4.4501f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-26".to_string()), 4.2668f64);
-    exchange_rates.insert(Exchange::USD("2018-04-16".to_string()), 3.3666f64);
-    exchange_rates.insert(Exchange::USD("2014-12-04".to_string()), 3.3728f64);
-    exchange_rates.insert(Exchange::USD("2025-01-23".to_string()), 4.0515f64);
-    exchange_rates.insert(Exchange::USD("2024-08-12".to_string()), 3.9488f64);
-    exchange_rates.insert(Exchange::USD("2023-01-31".to_string()), 4.348f64);
-    exchange_rates.insert(Exchange::USD("2019-04-25".to_string()), 3.8537f64);
-    exchange_rates.insert(Exchange::USD("2014-07-25".to_string()), 3.0831f64);
-    exchange_rates.insert(Exchange::USD("2014-08-20".to_string()), 3.147f64);
-    exchange_rates.insert(Exchange::USD("2025-06-27".to_string()), 3.6177f64);
-    exchange_rates.insert(Exchange::USD("2012-10-23".to_string()), 3.1662f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-11".to_string()), 4.4426f64);
-    exchange_rates.insert(Exchange::USD("2014-07-29".to_string()), 3.09f64);
-    exchange_rates.insert(Exchange::USD("2015-01-15".to_string()), 3.6588f64);
-    exchange_rates.insert(Exchange::USD("2019-05-16".to_string()), 3.8259f64);
-    exchange_rates.insert(Exchange::USD("2021-03-05".to_string()), 3.8393f64);
-    exchange_rates.insert(Exchange::USD("2013-12-04".to_string()), 3.0916f64);
-    exchange_rates.insert(Exchange::USD("2021-02-11".to_string()), 3.7117f64);
-    exchange_rates.insert(Exchange::USD("2025-10-24".to_string()), 3.6481f64);
-    exchange_rates.insert(Exchange::USD("2020-10-20".to_string()), 3.8926f64);
-    exchange_rates.insert(Exchange::USD("2013-08-28".to_string()), 3.184f64);
-    exchange_rates.insert(Exchange::USD("2021-11-22".to_string()), 4.1855f64);
-    exchange_rates.insert(Exchange::USD("2012-06-13".to_string()), 3.4591f64);
-    exchange_rates.insert(Exchange::USD("2025-01-07".to_string()), 4.077f64);
-    exchange_rates.insert(Exchange::USD("2014-01-13".to_string()), 3.045f64);
-    exchange_rates.insert(Exchange::USD("2025-12-23".to_string()), 3.5848f64);
-    exchange_rates.insert(Exchange::USD("2014-07-18".to_string()), 3.0652f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-27".to_string()), 4.3067f64);
-    exchange_rates.insert(Exchange::USD("2019-11-15".to_string()), 3.8848f64);
-    exchange_rates.insert(Exchange::USD("2019-03-29".to_string()), 3.8365f64);
-    exchange_rates.insert(Exchange::USD("2021-09-01".to_string()), 3.8238f64);
-    exchange_rates.insert(Exchange::USD("2014-05-27".to_string()), 3.0532f64);
-    exchange_rates.insert(Exchange::USD("2014-06-02".to_string()), 3.0413f64);
-    exchange_rates.insert(Exchange::USD("2012-07-26".to_string()), 3.4523f64);
-    exchange_rates.insert(Exchange::USD("2016-09-20".to_string()), 3.8438f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-21".to_string()), 4.3647f64);
-    exchange_rates.insert(Exchange::USD("2017-03-29".to_string()), 3.9265f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-24".to_string()), 4.2604f64);
-    exchange_rates.insert(Exchange::USD("2020-02-17".to_string()), 3.9189f64);
-    exchange_rates.insert(Exchange::USD("2023-07-06".to_string()), 4.1191f64);
-    exchange_rates.insert(Exchange::USD("2016-09-14".to_string()), 3.8716f64);
-    exchange_rates.insert(Exchange::USD("2023-02-20".to_string()), 4.4515f64);
-    exchange_rates.insert(Exchange::USD("2017-03-30".to_string()), 3.9402f64);
-    exchange_rates.insert(Exchange::USD("2020-09-18".to_string()), 3.7585f64);
-    exchange_rates.insert(Exchange::USD("2024-05-23".to_string()), 3.9394f64);
-    exchange_rates.insert(Exchange::USD("2021-08-04".to_string()), 3.8366f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-01".to_string()), 4.419f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-18".to_string()), 4.4331f64);
-    exchange_rates.insert(Exchange::USD("2016-11-03".to_string()), 3.8965f64);
-    exchange_rates.insert(Exchange::USD("2015-04-27".to_string()), 3.7115f64);
-    exchange_rates.insert(Exchange::USD("2012-04-23".to_string()), 3.1972f64);
-    exchange_rates.insert(Exchange::USD("2022-12-06".to_string()), 4.4787f64);
-    exchange_rates.insert(Exchange::USD("2012-06-06".to_string()), 3.4703f64);
-    exchange_rates.insert(Exchange::USD("2015-09-09".to_string()), 3.7634f64);
-    exchange_rates.insert(Exchange::USD("2013-04-29".to_string()), 3.1712f64);
-    exchange_rates.insert(Exchange::USD("2021-06-21".to_string()), 3.8214f64);
-    exchange_rates.insert(Exchange::USD("2012-03-16".to_string()), 3.1688f64);
-    exchange_rates.insert(Exchange::USD("2023-06-05".to_string()), 4.1933f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-03".to_string()), 4.2527f64);
-    exchange_rates.insert(Exchange::USD("2021-08-20".to_string()), 3.9301f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-21".to_string()), 4.2489f64);
-    exchange_rates.insert(Exchange::USD("2016-10-03".to_string()), 3.8252f64);
-    exchange_rates.insert(Exchange::USD("2022-07-05".to_string()), 4.5947f64);
-    exchange_rates.insert(Exchange::USD("2013-04-08".to_string()), 3.1893f64);
-    exchange_rates.insert(Exchange::USD("2022-05-10".to_string()), 4.4223f64);
-    exchange_rates.insert(Exchange::USD("2016-12-12".to_string()), 4.209f64);
-    exchange_rates.insert(Exchange::USD("2014-03-11".to_string()), 3.0449f64);
-    exchange_rates.insert(Exchange::USD("2019-10-23".to_string()), 3.8473f64);
-    exchange_rates.insert(Exchange::USD("2025-09-03".to_string()), 3.6538f64);
-    exchange_rates.insert(Exchange::USD("2021-12-02".to_string()), 4.0625f64);
-    exchange_rates.insert(Exchange::USD("2019-05-10".to_string()), 3.8242f64);
-    exchange_rates.insert(Exchange::USD("2023-06-20".to_string()), 4.058f64);
-    exchange_rates.insert(Exchange::USD("2021-06-16".to_string()), 3.7336f64);
-    exchange_rates.insert(Exchange::USD("2018-02-07".to_string()), 3.3677f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-13".to_string()), 4.2592f64);
-    exchange_rates.insert(Exchange::USD("2014-10-10".to_string()), 3.2985f64);
-    exchange_rates.insert(Exchange::USD("2024-02-15".to_string()), 4.0495f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-13".to_string()), 4.6402f64);
-    exchange_rates.insert(Exchange::USD("2022-03-22".to_string()), 4.272f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-15".to_string()), 4.2556f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-13".to_string()), 4.2304f64);
-    exchange_rates.insert(Exchange::USD("2019-08-09".to_string()), 3.8613f64);
-    exchange_rates.insert(Exchange::USD("2014-02-25".to_string()), 3.0264f64);
-    exchange_rates.insert(Exchange::USD("2022-05-18".to_string()), 4.4279f64);
-    exchange_rates.insert(Exchange::USD("2022-06-24".to_string()), 4.4656f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-20".to_string()), 4.3186f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-15".to_string()), 4.2611f64);
-    exchange_rates.insert(Exchange::USD("2021-09-06".to_string()), 3.8041f64);
-    exchange_rates.insert(Exchange::USD("2022-12-29".to_string()), 4.4078f64);
-    exchange_rates.insert(Exchange::USD("2017-09-20".to_string()), 3.5619f64);
-    exchange_rates.insert(Exchange::USD("2014-01-08".to_string()), 3.0717f64);
-    exchange_rates.insert(Exchange::USD("2024-09-16".to_string()), 3.8438f64);
-    exchange_rates.insert(Exchange::USD("2012-02-21".to_string()), 3.1488f64);
-    exchange_rates.insert(Exchange::USD("2016-07-20".to_string()), 3.979f64);
-    exchange_rates.insert(Exchange::USD("2016-08-30".to_string()), 3.8858f64);
-    exchange_rates.insert(Exchange::USD("2012-07-27".to_string()), 3.3843f64);
-    exchange_rates.insert(Exchange::USD("2017-10-05".to_string()), 3.6502f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-05".to_string()), 4.2763f64);
-    exchange_rates.insert(Exchange::USD("2024-12-10".to_string()), 4.0518f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-23".to_string()), 4.3697f64);
-    exchange_rates.insert(Exchange::USD("2017-08-10".to_string()), 3.6432f64);
-    exchange_rates.insert(Exchange::USD("2023-10-20".to_string()), 4.2194f64);
-    exchange_rates.insert(Exchange::USD("2012-07-09".to_string()), 3.4538f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-04".to_string()), 4.6785f64);
-    exchange_rates.insert(Exchange::USD("2021-07-30".to_string()), 3.841f64);
-    exchange_rates.insert(Exchange::USD("2018-02-05".to_string()), 3.3375f64);
-    exchange_rates.insert(Exchange::USD("2016-12-28".to_string()), 4.226f64);
-    exchange_rates.insert(Exchange::USD("2014-06-20".to_string()), 3.0495f64);
-    exchange_rates.insert(Exchange::USD("2020-09-24".to_string()), 3.8833f64);
-    exchange_rates.insert(Exchange::USD("2022-05-26".to_string()), 4.3189f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-12".to_string()), 4.4471f64);
-    exchange_rates.insert(Exchange::USD("2019-10-24".to_string()), 3.8448f64);
-    exchange_rates.insert(Exchange::USD("2015-09-29".to_string()), 3.7799f64);
-    exchange_rates.insert(Exchange::USD("2022-08-24".to_string()), 4.8029f64);
-    exchange_rates.insert(Exchange::USD("2012-10-31".to_string()), 3.1806f64);
-    exchange_rates.insert(Exchange::USD("2013-08-13".to_string()), 3.1489f64);
-    exchange_rates.insert(Exchange::USD("2021-02-08".to_string()), 3.7301f64);
-    exchange_rates.insert(Exchange::USD("2022-07-13".to_string()), 4.8221f64);
-    exchange_rates.insert(Exchange::USD("2023-03-20".to_string()), 4.413f64);
-    exchange_rates.insert(Exchange::USD("2013-08-21".to_string()), 3.1698f64);
-    exchange_rates.insert(Exchange::USD("2024-08-09".to_string()), 3.9604f64);
-    exchange_rates.insert(Exchange::USD("2012-05-15".to_string()), 3.3579f64);
-    exchange_rates.insert(Exchange::USD("2019-12-18".to_string()), 3.8302f64);
-    exchange_rates.insert(Exchange::USD("2025-09-02".to_string()), 3.6626f64);
-    exchange_rates.insert(Exchange::USD("2021-06-10".to_string()), 3.6821f64);
-    exchange_rates.insert(Exchange::USD("2021-11-29".to_string()), 4.1627f64);
-    exchange_rates.insert(Exchange::USD("2024-09-09".to_string()), 3.8758f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-19".to_string()), 4.4457f64);
-    exchange_rates.insert(Exchange::USD("2020-11-30".to_string()), 3.7364f64);
-    exchange_rates.insert(Exchange::USD("2021-01-08".to_string()), 3.6919f64);
-    exchange_rates.insert(Exchange::USD("2013-03-04".to_string()), 3.1837f64);
-    exchange_rates.insert(Exchange::USD("2012-12-07".to_string()), 3.193f64);
-    exchange_rates.insert(Exchange::USD("2023-06-02".to_string()), 4.1903f64);
-    exchange_rates.insert(Exchange::USD("2022-04-25".to_string()), 4.3188f64);
-    exchange_rates.insert(Exchange::USD("2013-10-17".to_string()), 3.0625f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-30".to_string()), 4.3634f64);
-    exchange_rates.insert(Exchange::USD("2025-04-11".to_string()), 3.7606f64);
-    exchange_rates.insert(Exchange::USD("2024-06-24".to_string()), 4.0319f64);
-    exchange_rates.insert(Exchange::USD("2017-03-15".to_string()), 4.0646f64);
-    exchange_rates.insert(Exchange::USD("2014-07-28".to_string()), 3.086f64);
-    exchange_rates.insert(Exchange::USD("2013-07-19".to_string()), 3.2348f64);
-    exchange_rates.insert(Exchange::USD("2020-02-24".to_string()), 3.9772f64);
-    exchange_rates.insert(Exchange::USD("2016-05-31".to_string()), 3.9369f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-10".to_string()), 4.2672f64);
-    exchange_rates.insert(Exchange::USD("2016-09-23".to_string()), 3.8328f64);
-    exchange_rates.insert(Exchange::USD("2021-08-02".to_string()), 3.8377f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-09".to_string()), 4.2818f64);
-    exchange_rates.insert(Exchange::USD("2022-04-11".to_string()), 4.2586f64);
-    exchange_rates.insert(Exchange::USD("2021-12-16".to_string()), 4.0938f64);
-    exchange_rates.insert(Exchange::USD("2022-04-06".to_string()), 4.2606f64);
-    exchange_rates.insert(Exchange::USD("2013-08-05".to_string()), 3.1771f64);
-    exchange_rates.insert(Exchange::USD("2015-05-25".to_string()), 3.75f64);
-    exchange_rates.insert(Exchange::USD("2015-04-24".to_string()), 3.6895f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-21".to_string()), 4.3469f64);
-    exchange_rates.insert(Exchange::USD("2023-04-18".to_string()), 4.2151f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-06".to_string()), 4.313f64);
-    exchange_rates.insert(Exchange::USD("2022-01-20".to_string()), 3.9833f64);
-    exchange_rates.insert(Exchange::USD("2022-04-15".to_string()), 4.2865f64);
-    exchange_rates.insert(Exchange::USD("2018-06-05".to_string()), 3.6569f64);
-    exchange_rates.insert(Exchange::USD("2015-05-13".to_string()), 3.63f64);
-    exchange_rates.insert(Exchange::USD("2017-03-01".to_string()), 4.0756f64);
-    exchange_rates.insert(Exchange::USD("2016-02-03".to_string()), 4.0325f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-09".to_string()), 4.7363f64);
-    exchange_rates.insert(Exchange::USD("2019-01-21".to_string()), 3.7751f64);
-    exchange_rates.insert(Exchange::USD("2012-05-04".to_string()), 3.1891f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-02".to_string()), 4.239f64);
-    exchange_rates.insert(Exchange::USD("2021-07-08".to_string()), 3.8408f64);
-    exchange_rates.insert(Exchange::USD("2013-08-08".to_string()), 3.1503f64);
-    exchange_rates.insert(Exchange::USD("2019-01-24".to_string()), 3.7844f64);
-    exchange_rates.insert(Exchange::USD("2013-04-24".to_string()), 3.1823f64);
-    exchange_rates.insert(Exchange::USD("2018-12-21".to_string()), 3.7528f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-22".to_string()), 4.7538f64);
-    exchange_rates.insert(Exchange::USD("2012-09-20".to_string()), 3.2092f64);
-    exchange_rates.insert(Exchange::USD("2013-02-21".to_string()), 3.1633f64);
-    exchange_rates.insert(Exchange::USD("2014-04-10".to_string()), 3.0092f64);
-    exchange_rates.insert(Exchange::USD("2012-05-07".to_string()), 3.2279f64);
-    exchange_rates.insert(Exchange::USD("2012-09-11".to_string()), 3.2098f64);
-    exchange_rates.insert(Exchange::USD("2018-10-01".to_string()), 3.6867f64);
-    exchange_rates.insert(Exchange::USD("2017-12-06".to_string()), 3.5653f64);
-    exchange_rates.insert(Exchange::USD("2023-11-13".to_string()), 4.1474f64);
-    exchange_rates.insert(Exchange::USD("2024-01-16".to_string()), 4.0358f64);
-    exchange_rates.insert(Exchange::USD("2013-01-31".to_string()), 3.0874f64);
-    exchange_rates.insert(Exchange::USD("2015-12-07".to_string()), 3.9853f64);
-    exchange_rates.insert(Exchange::USD("2020-11-09".to_string()), 3.7787f64);
-    exchange_rates.insert(Exchange::USD("2017-11-13".to_string()), 3.6382f64);
-    exchange_rates.insert(Exchange::USD("2013-04-09".to_string()), 3.1696f64);
-    exchange_rates.insert(Exchange::USD("2016-02-25".to_string()), 3.9705f64);
-    exchange_rates.insert(Exchange::USD("2018-09-14".to_string()), 3.6817f64);
-    exchange_rates.insert(Exchange::USD("2013-12-19".to_string()), 3.049f64);
-    exchange_rates.insert(Exchange::USD("2018-01-03".to_string()), 3.4616f64);
-    exchange_rates.insert(Exchange::USD("2023-01-25".to_string()), 4.3265f64);
-    exchange_rates.insert(Exchange::USD("2021-09-14".to_string()), 3.8542f64);
-    exchange_rates.insert(Exchange::USD("2024-09-06".to_string()), 3.8489f64);
-    exchange_rates.insert(Exchange::USD("2014-03-20".to_string()), 3.05f64);
-    exchange_rates.insert(Exchange::USD("2022-01-24".to_string()), 4.0049f64);
-    exchange_rates.insert(Exchange::USD("2017-10-20".to_string()), 3.5966f64);
-    exchange_rates.insert(Exchange::USD("2013-03-26".to_string()), 3.2427f64);
-    exchange_rates.insert(Exchange::USD("2025-02-05".to_string()), 4.0426f64);
-    exchange_rates.insert(Exchange::USD("2018-11-30".to_string()), 3.7731f64);
-    exchange_rates.insert(Exchange::USD("2025-04-23".to_string()), 3.7583f64);
-    exchange_rates.insert(Exchange::USD("2024-05-10".to_string()), 3.9866f64);
-    exchange_rates.insert(Exchange::USD("2016-05-05".to_string()), 3.8475f64);
-    exchange_rates.insert(Exchange::USD("2024-05-06".to_string()), 4.0202f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-07".to_string()), 4.2483f64);
-    exchange_rates.insert(Exchange::USD("2013-04-23".to_string()), 3.1811f64);
-    exchange_rates.insert(Exchange::USD("2018-02-23".to_string()), 3.3911f64);
-    exchange_rates.insert(Exchange::USD("2024-06-18".to_string()), 4.0549f64);
-    exchange_rates.insert(Exchange::USD("2015-12-21".to_string()), 3.92f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-19".to_string()), 4.3316f64);
-    exchange_rates.insert(Exchange::USD("2023-05-05".to_string()), 4.1612f64);
-    exchange_rates.insert(Exchange::USD("2016-03-22".to_string()), 3.8005f64);
-    exchange_rates.insert(Exchange::USD("2013-04-25".to_string()), 3.1792f64);
-    exchange_rates.insert(Exchange::USD("2019-09-10".to_string()), 3.9273f64);
-    exchange_rates.insert(Exchange::USD("2022-01-26".to_string()), 4.0684f64);
-    exchange_rates.insert(Exchange::USD("2021-09-09".to_string()), 3.8292f64);
-    exchange_rates.insert(Exchange::USD("2021-11-05".to_string()), 3.9911f64);
-    exchange_rates.insert(Exchange::USD("2018-01-29".to_string()), 3.3375f64);
-    exchange_rates.insert(Exchange::USD("2017-07-18".to_string()), 3.6469f64);
-    exchange_rates.insert(Exchange::USD("2016-02-16".to_string()), 3.9404f64);
-    exchange_rates.insert(Exchange::USD("2024-07-11".to_string()), 3.9257f64);
-    exchange_rates.insert(Exchange::USD("2018-12-19".to_string()), 3.7619f64);
-    exchange_rates.insert(Exchange::USD("2014-04-18".to_string()), 3.0265f64);
-    exchange_rates.insert(Exchange::USD("2012-08-29".to_string()), 3.3081f64);
-    exchange_rates.insert(Exchange::USD("2024-05-08".to_string()), 4.0202f64);
-    exchange_rates.insert(Exchange::USD("2017-02-14".to_string()), 4.0525f64);
-    exchange_rates.insert(Exchange::USD("2019-06-13".to_string()), 3.7736f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-08".to_string()), 4.3225f64);
-    exchange_rates.insert(Exchange::USD("2013-06-18".to_string()), 3.1682f64);
-    exchange_rates.insert(Exchange::USD("2013-05-21".to_string()), 3.249f64);
-    exchange_rates.insert(Exchange::USD("2022-02-09".to_string()), 3.953f64);
-    exchange_rates.insert(Exchange::USD("2015-03-18".to_string()), 3.9073f64);
-    exchange_rates.insert(Exchange::USD("2016-03-02".to_string()), 3.9861f64);
-    exchange_rates.insert(Exchange::USD("2016-01-18".to_string()), 4.1133f64);
-    exchange_rates.insert(Exchange::USD("2019-06-10".to_string()), 3.7745f64);
-    exchange_rates.insert(Exchange::USD("2015-04-10".to_string()), 3.7894f64);
-    exchange_rates.insert(Exchange::USD("2015-07-20".to_string()), 3.7868f64);
-    exchange_rates.insert(Exchange::USD("2019-05-31".to_string()), 3.8498f64);
-    exchange_rates.insert(Exchange::USD("2014-08-06".to_string()), 3.1377f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-11".to_string()), 4.2643f64);
-    exchange_rates.insert(Exchange::USD("2024-09-11".to_string()), 3.8816f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-22".to_string()), 4.343f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-01".to_string()), 4.2574f64);
-    exchange_rates.insert(Exchange::USD("2020-10-16".to_string()), 3.8976f64);
-    exchange_rates.insert(Exchange::USD("2024-02-12".to_string()), 4.0189f64);
-    exchange_rates.insert(Exchange::USD("2024-01-22".to_string()), 3.9972f64);
-    exchange_rates.insert(Exchange::USD("2025-02-03".to_string()), 4.1352f64);
-    exchange_rates.insert(Exchange::USD("2015-08-18".to_string()), 3.7578f64);
-    exchange_rates.insert(Exchange::USD("2023-01-19".to_string()), 4.3469f64);
-    exchange_rates.insert(Exchange::USD("2023-10-06".to_string()), 4.3608f64);
-    exchange_rates.insert(Exchange::USD("2018-08-09".to_string()), 3.6846f64);
-    exchange_rates.insert(Exchange::USD("2016-09-05".to_string()), 3.8937f64);
-    exchange_rates.insert(Exchange::USD("2017-06-16".to_string()), 3.7749f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-27".to_string()), 4.3311f64);
-    exchange_rates.insert(Exchange::USD("2022-07-04".to_string()), 4.5032f64);
-    exchange_rates.insert(Exchange::USD("2024-07-29".to_string()), 3.9556f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-21".to_string()), 4.3031f64);
-    exchange_rates.insert(Exchange::USD("2024-07-25".to_string()), 3.9619f64);
-    exchange_rates.insert(Exchange::USD("2014-07-31".to_string()), 3.1094f64);
-    exchange_rates.insert(Exchange::USD("2012-07-23".to_string()), 3.4529f64);
-    exchange_rates.insert(Exchange::USD("2017-08-11".to_string()), 3.6449f64);
-    exchange_rates.insert(Exchange::USD("2017-10-30".to_string()), 3.643f64);
-    exchange_rates.insert(Exchange::USD("2025-01-22".to_string()), 4.0646f64);
-    exchange_rates.insert(Exchange::USD("2019-09-17".to_string()), 3.9412f64);
-    exchange_rates.insert(Exchange::USD("2012-07-03".to_string()), 3.3427f64);
-    exchange_rates.insert(Exchange::USD("2015-02-18".to_string()), 3.6784f64);
-    exchange_rates.insert(Exchange::USD("2013-12-09".to_string()), 3.0534f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-16".to_string()), 4.3934f64);
-    exchange_rates.insert(Exchange::USD("2013-08-07".to_string()), 3.1725f64);
-    exchange_rates.insert(Exchange::USD("2014-03-12".to_string()), 3.0508f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-22".to_string()), 4.2797f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-23".to_string()), 4.2182f64);
-    exchange_rates.insert(Exchange::USD("2019-02-15".to_string()), 3.8326f64);
-    exchange_rates.insert(Exchange::USD("2018-09-17".to_string()), 3.6876f64);
-    exchange_rates.insert(Exchange::USD("2021-11-12".to_string()), 4.0559f64);
-    exchange_rates.insert(Exchange::USD("2018-10-04".to_string()), 3.7512f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-25".to_string()), 4.4348f64);
-    exchange_rates.insert(Exchange::USD("2022-03-25".to_string()), 4.3125f64);
-    exchange_rates.insert(Exchange::USD("2019-07-05".to_string()), 3.7689f64);
-    exchange_rates.insert(Exchange::USD("2016-04-11".to_string()), 3.7587f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-07".to_string()), 4.2524f64);
-    exchange_rates.insert(Exchange::USD("2024-12-23".to_string()), 4.095f64);
-    exchange_rates.insert(Exchange::USD("2023-05-24".to_string()), 4.1573f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-20".to_string()), 4.7137f64);
-    exchange_rates.insert(Exchange::USD("2018-07-11".to_string()), 3.7f64);
-    exchange_rates.insert(Exchange::USD("2018-08-28".to_string()), 3.6548f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-11".to_string()), 4.6871f64);
-    exchange_rates.insert(Exchange::USD("2019-12-06".to_string()), 3.85f64);
-    exchange_rates.insert(Exchange::USD("2012-05-09".to_string()), 3.2412f64);
-    exchange_rates.insert(Exchange::USD("2015-02-09".to_string()), 3.6811f64);
-    exchange_rates.insert(Exchange::USD("2020-12-04".to_string()), 3.6765f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-16".to_string()), 4.7004f64);
-    exchange_rates.insert(Exchange::USD("2021-01-20".to_string()), 3.7303f64);
-    exchange_rates.insert(Exchange::USD("2022-09-09".to_string()), 4.6599f64);
-    exchange_rates.insert(Exchange::USD("2013-01-29".to_string()), 3.1229f64);
-    exchange_rates.insert(Exchange::USD("2021-04-15".to_string()), 3.8014f64);
-    exchange_rates.insert(Exchange::USD("2013-10-10".to_string()), 3.0994f64);
-    exchange_rates.insert(Exchange::USD("2024-10-14".to_string()), 3.9288f64);
-    exchange_rates.insert(Exchange::USD("2021-05-06".to_string()), 3.8066f64);
-    exchange_rates.insert(Exchange::USD("2012-05-31".to_string()), 3.5372f64);
-    exchange_rates.insert(Exchange::USD("2022-11-18".to_string()), 4.5337f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-31".to_string()), 4.4135f64);
-    exchange_rates.insert(Exchange::USD("2015-09-18".to_string()), 3.6738f64);
-    exchange_rates.insert(Exchange::USD("2012-05-23".to_string()), 3.446f64);
-    exchange_rates.insert(Exchange::USD("2020-08-21".to_string()), 3.7164f64);
-    exchange_rates.insert(Exchange::USD("2018-10-17".to_string()), 3.7123f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-25".to_string()), 4.2479f64);
-    exchange_rates.insert(Exchange::USD("2025-12-09".to_string()), 3.6336f64);
-    exchange_rates.insert(Exchange::USD("2016-12-13".to_string()), 4.1905f64);
-    exchange_rates.insert(Exchange::USD("2017-08-01".to_string()), 3.5991f64);
-    exchange_rates.insert(Exchange::USD("2017-06-26".to_string()), 3.7653f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-11".to_string()), 4.1977f64);
-    exchange_rates.insert(Exchange::USD("2014-05-29".to_string()), 3.0395f64);
-    exchange_rates.insert(Exchange::USD("2018-02-01".to_string()), 3.3346f64);
-    exchange_rates.insert(Exchange::USD("2012-06-05".to_string()), 3.5359f64);
-    exchange_rates.insert(Exchange::USD("2015-09-24".to_string()), 3.7687f64);
-    exchange_rates.insert(Exchange::USD("2016-01-15".to_string()), 4.0411f64);
-    exchange_rates.insert(Exchange::USD("2019-05-30".to_string()), 3.8548f64);
-    exchange_rates.insert(Exchange::USD("2021-02-03".to_string()), 3.7352f64);
-    exchange_rates.insert(Exchange::USD("2024-06-12".to_string()), 4.0342f64);
-    exchange_rates.insert(Exchange::USD("2017-07-07".to_string()), 3.7068f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-15".to_string()), 4.7015f64);
-    exchange_rates.insert(Exchange::USD("2019-03-01".to_string()), 3.792f64);
-    exchange_rates.insert(Exchange::USD("2023-07-24".to_string()), 4.021f64);
-    exchange_rates.insert(Exchange::USD("2022-05-06".to_string()), 4.4502f64);
-    exchange_rates.insert(Exchange::USD("2014-01-29".to_string()), 3.0829f64);
-    exchange_rates.insert(Exchange::USD("2012-11-28".to_string()), 3.1799f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-12".to_string()), 4.2271f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-26".to_string()), 4.233f64);
-    exchange_rates.insert(Exchange::USD("2014-02-07".to_string()), 3.0857f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-17".to_string()), 4.2691f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-01".to_string()), 4.5312f64);
-    exchange_rates.insert(Exchange::USD("2019-04-24".to_string()), 3.8229f64);
-    exchange_rates.insert(Exchange::USD("2015-02-12".to_string()), 3.6926f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-13".to_string()), 4.2883f64);
-    exchange_rates.insert(Exchange::USD("2020-05-05".to_string()), 4.1927f64);
-    exchange_rates.insert(Exchange::USD("2016-12-05".to_string()), 4.2213f64);
-    exchange_rates.insert(Exchange::USD("2019-12-11".to_string()), 3.866f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-09".to_string()), 4.697f64);
-    exchange_rates.insert(Exchange::USD("2021-04-26".to_string()), 3.7657f64);
-    exchange_rates.insert(Exchange::USD("2025-12-30".to_string()), 3.5936f64);
-    exchange_rates.insert(Exchange::USD("2020-10-23".to_string()), 3.8665f64);
-    exchange_rates.insert(Exchange::USD("2018-09-26".to_string()), 3.643f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-21".to_string()), 4.2445f64);
-    exchange_rates.insert(Exchange::USD("2022-10-17".to_string()), 4.9522f64);
-    exchange_rates.insert(Exchange::USD("2025-08-20".to_string()), 3.654f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-04".to_string()), 4.2923f64);
-    exchange_rates.insert(Exchange::USD("2016-03-01".to_string()), 3.9924f64);
-    exchange_rates.insert(Exchange::USD("2023-01-02".to_string()), 4.3811f64);
-    exchange_rates.insert(Exchange::USD("2020-03-13".to_string()), 3.9067f64);
-    exchange_rates.insert(Exchange::USD("2018-05-09".to_string()), 3.609f64);
-    exchange_rates.insert(Exchange::USD("2015-06-01".to_string()), 3.785f64);
-    exchange_rates.insert(Exchange::USD("2019-08-22".to_string()), 3.9337f64);
-    exchange_rates.insert(Exchange::USD("2013-05-02".to_string()), 3.1492f64);
-    exchange_rates.insert(Exchange::USD("2024-06-14".to_string()), 4.076f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-26".to_string()), 4.7239f64);
-    exchange_rates.insert(Exchange::USD("2020-12-11".to_string()), 3.663f64);
-    exchange_rates.insert(Exchange::USD("2019-01-02".to_string()), 3.7619f64);
-    exchange_rates.insert(Exchange::USD("2018-10-03".to_string()), 3.7103f64);
-    exchange_rates.insert(Exchange::USD("2020-05-12".to_string()), 4.2095f64);
-    exchange_rates.insert(Exchange::USD("2020-12-30".to_string()), 3.6901f64);
-    exchange_rates.insert(Exchange::USD("2025-12-19".to_string()), 3.5941f64);
-    exchange_rates.insert(Exchange::USD("2023-10-05".to_string()), 4.3768f64);
-    exchange_rates.insert(Exchange::USD("2025-10-07".to_string()), 3.6481f64);
-    exchange_rates.insert(Exchange::USD("2013-04-18".to_string()), 3.1529f64);
-    exchange_rates.insert(Exchange::USD("2012-12-24".to_string()), 3.08f64);
-    exchange_rates.insert(Exchange::USD("2018-05-23".to_string()), 3.6693f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-02".to_string()), 4.2952f64);
-    exchange_rates.insert(Exchange::USD("2025-12-10".to_string()), 3.6329f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-12".to_string()), 4.2626f64);
-    exchange_rates.insert(Exchange::USD("2013-05-31".to_string()), 3.2953f64);
-    exchange_rates.insert(Exchange::USD("2024-12-24".to_string()), 4.1127f64);
-    exchange_rates.insert(Exchange::USD("2022-08-04".to_string()), 4.634f64);
-    exchange_rates.insert(Exchange::USD("2019-01-22".to_string()), 3.7727f64);
-    exchange_rates.insert(Exchange::USD("2021-09-28".to_string()), 3.9546f64);
-    exchange_rates.insert(Exchange::USD("2014-09-23".to_string()), 3.243f64);
-    exchange_rates.insert(Exchange::USD("2016-04-19".to_string()), 3.7925f64);
-    exchange_rates.insert(Exchange::USD("2019-10-07".to_string()), 3.9498f64);
-    exchange_rates.insert(Exchange::USD("2024-07-05".to_string()), 3.9581f64);
-    exchange_rates.insert(Exchange::USD("2015-09-16".to_string()), 3.7315f64);
-    exchange_rates.insert(Exchange::USD("2025-06-05".to_string()), 3.7495f64);
-    exchange_rates.insert(Exchange::USD("2014-11-25".to_string()), 3.3706f64);
-    exchange_rates.insert(Exchange::USD("2020-11-25".to_string()), 3.7556f64);
-    exchange_rates.insert(Exchange::USD("2012-03-08".to_string()), 3.122f64);
-    exchange_rates.insert(Exchange::USD("2022-08-11".to_string()), 4.5206f64);
-    exchange_rates.insert(Exchange::USD("2019-04-18".to_string()), 3.8002f64);
-    exchange_rates.insert(Exchange::USD("2022-11-17".to_string()), 4.5387f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-07".to_string()), 4.6871f64);
-    exchange_rates.insert(Exchange::USD("2012-01-16".to_string()), 3.4798f64);
-    exchange_rates.insert(Exchange::USD("2017-01-16".to_string()), 4.1354f64);
-    exchange_rates.insert(Exchange::USD("2012-09-10".to_string()), 3.2189f64);
-    exchange_rates.insert(Exchange::USD("2016-02-26".to_string()), 3.9545f64);
-    exchange_rates.insert(Exchange::USD("2016-08-23".to_string()), 3.8061f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-24".to_string()), 4.1609f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-23".to_string()), 4.4981f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-07".to_string()), 4.2891f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-22".to_string()), 4.2645f64);
-    exchange_rates.insert(Exchange::USD("2013-07-05".to_string()), 3.3245f64);
-    exchange_rates.insert(Exchange::USD("2017-06-20".to_string()), 3.7862f64);
-    exchange_rates.insert(Exchange::USD("2016-03-30".to_string()), 3.7495f64);
-    exchange_rates.insert(Exchange::USD("2012-11-29".to_string()), 3.1556f64);
-    exchange_rates.insert(Exchange::USD("2015-08-26".to_string()), 3.6937f64);
-    exchange_rates.insert(Exchange::USD("2022-10-10".to_string()), 5.0239f64);
-    exchange_rates.insert(Exchange::USD("2012-04-02".to_string()), 3.1017f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-11".to_string()), 4.2586f64);
-    exchange_rates.insert(Exchange::USD("2020-07-23".to_string()), 3.806f64);
-    exchange_rates.insert(Exchange::USD("2015-07-27".to_string()), 3.7455f64);
-    exchange_rates.insert(Exchange::USD("2013-12-24".to_string()), 3.0346f64);
-    exchange_rates.insert(Exchange::USD("2020-10-13".to_string()), 3.7932f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-11".to_string()), 4.2972f64);
-    exchange_rates.insert(Exchange::USD("2018-11-09".to_string()), 3.7838f64);
-    exchange_rates.insert(Exchange::USD("2017-12-29".to_string()), 3.4813f64);
-    exchange_rates.insert(Exchange::USD("2013-12-31".to_string()), 3.012f64);
-    exchange_rates.insert(Exchange::USD("2012-07-31".to_string()), 3.3508f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-05".to_string()), 4.3233f64);
-    exchange_rates.insert(Exchange::USD("2018-11-19".to_string()), 3.784f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-06".to_string()), 4.7195f64);
-    exchange_rates.insert(Exchange::USD("2019-10-21".to_string()), 3.8307f64);
-    exchange_rates.insert(Exchange::USD("2016-05-18".to_string()), 3.9005f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-23".to_string()), 4.2274f64);
-    exchange_rates.insert(Exchange::USD("2017-08-23".to_string()), 3.6274f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-10".to_string()), 4.3029f64);
-    exchange_rates.insert(Exchange::USD("2018-03-15".to_string()), 3.4117f64);
-    exchange_rates.insert(Exchange::USD("2013-05-22".to_string()), 3.2322f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-16".to_string()), 4.7728f64);
-    exchange_rates.insert(Exchange::USD("2014-07-08".to_string()), 3.0441f64);
-    exchange_rates.insert(Exchange::USD("2021-03-25".to_string()), 3.9253f64);
-    exchange_rates.insert(Exchange::USD("2022-03-10".to_string()), 4.3482f64);
-    exchange_rates.insert(Exchange::USD("2018-08-01".to_string()), 3.6603f64);
-    exchange_rates.insert(Exchange::USD("2016-08-22".to_string()), 3.809f64);
-    exchange_rates.insert(Exchange::USD("2018-06-28".to_string()), 3.7705f64);
-    exchange_rates.insert(Exchange::USD("2013-08-14".to_string()), 3.1643f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-25".to_string()), 4.5892f64);
-    exchange_rates.insert(Exchange::USD("2024-11-05".to_string()), 4.0038f64);
-    exchange_rates.insert(Exchange::USD("2023-03-31".to_string()), 4.2934f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-14".to_string()), 4.4715f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-30".to_string()), 4.3492f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-19".to_string()), 4.293f64);
-    exchange_rates.insert(Exchange::USD("2023-10-30".to_string()), 4.204f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-23".to_string()), 4.2544f64);
-    exchange_rates.insert(Exchange::USD("2014-09-01".to_string()), 3.2035f64);
-    exchange_rates.insert(Exchange::USD("2021-08-31".to_string()), 3.8386f64);
-    exchange_rates.insert(Exchange::USD("2024-12-03".to_string()), 4.0803f64);
-    exchange_rates.insert(Exchange::USD("2023-11-08".to_string()), 4.1804f64);
-    exchange_rates.insert(Exchange::USD("2018-11-14".to_string()), 3.8114f64);
-    exchange_rates.insert(Exchange::USD("2017-05-12".to_string()), 3.8837f64);
-    exchange_rates.insert(Exchange::USD("2013-01-16".to_string()), 3.1097f64);
-    exchange_rates.insert(Exchange::USD("2018-07-05".to_string()), 3.7485f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-02".to_string()), 4.2578f64);
-    exchange_rates.insert(Exchange::USD("2020-07-28".to_string()), 3.7532f64);
-    exchange_rates.insert(Exchange::USD("2015-05-08".to_string()), 3.6095f64);
-    exchange_rates.insert(Exchange::USD("2017-12-18".to_string()), 3.574f64);
-    exchange_rates.insert(Exchange::USD("2012-03-05".to_string()), 3.1355f64);
-    exchange_rates.insert(Exchange::USD("2025-02-10".to_string()), 4.0548f64);
-    exchange_rates.insert(Exchange::USD("2025-06-03".to_string()), 3.7437f64);
-    exchange_rates.insert(Exchange::USD("2024-10-22".to_string()), 3.9862f64);
-    exchange_rates.insert(Exchange::USD("2015-01-13".to_string()), 3.6252f64);
-    exchange_rates.insert(Exchange::USD("2015-03-03".to_string()), 3.719f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-01".to_string()), 4.2981f64);
-    exchange_rates.insert(Exchange::USD("2017-07-17".to_string()), 3.6767f64);
-    exchange_rates.insert(Exchange::USD("2016-06-20".to_string()), 3.8724f64);
-    exchange_rates.insert(Exchange::USD("2024-05-15".to_string()), 3.9368f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-06".to_string()), 4.7073f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-28".to_string()), 4.3392f64);
-    exchange_rates.insert(Exchange::USD("2022-08-29".to_string()), 4.7821f64);
-    exchange_rates.insert(Exchange::USD("2020-04-16".to_string()), 4.1631f64);
-    exchange_rates.insert(Exchange::USD("2019-05-24".to_string()), 3.8436f64);
-    exchange_rates.insert(Exchange::USD("2012-09-07".to_string()), 3.2578f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-28".to_string()), 4.6816f64);
-    exchange_rates.insert(Exchange::USD("2019-08-07".to_string()), 3.8597f64);
-    exchange_rates.insert(Exchange::USD("2025-05-19".to_string()), 3.8004f64);
-    exchange_rates.insert(Exchange::USD("2013-07-12".to_string()), 3.3201f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-05".to_string()), 4.2695f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-20".to_string()), 4.7542f64);
-    exchange_rates.insert(Exchange::USD("2022-11-21".to_string()), 4.5991f64);
-    exchange_rates.insert(Exchange::USD("2017-11-28".to_string()), 3.5382f64);
-    exchange_rates.insert(Exchange::USD("2015-10-16".to_string()), 3.7243f64);
-    exchange_rates.insert(Exchange::USD("2025-09-15".to_string()), 3.6188f64);
-    exchange_rates.insert(Exchange::USD("2024-12-02".to_string()), 4.0827f64);
-    exchange_rates.insert(Exchange::USD("2022-11-15".to_string()), 4.5143f64);
-    exchange_rates.insert(Exchange::USD("2016-09-30".to_string()), 3.8558f64);
-    exchange_rates.insert(Exchange::USD("2015-10-05".to_string()), 3.773f64);
-    exchange_rates.insert(Exchange::USD("2015-10-19".to_string()), 3.7265f64);
-    exchange_rates.insert(Exchange::USD("2013-11-06".to_string()), 3.0896f64);
-    exchange_rates.insert(Exchange::USD("2013-01-07".to_string()), 3.1618f64);
-    exchange_rates.insert(Exchange::USD("2023-04-20".to_string()), 4.2024f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-14".to_string()), 4.6494f64);
-    exchange_rates.insert(Exchange::USD("2023-12-08".to_string()), 4.0181f64);
-    exchange_rates.insert(Exchange::USD("2013-04-04".to_string()), 3.275f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-10".to_string()), 4.6838f64);
-    exchange_rates.insert(Exchange::USD("2023-12-29".to_string()), 3.935f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-22".to_string()), 4.3288f64);
-    exchange_rates.insert(Exchange::USD("2023-06-14".to_string()), 4.1393f64);
-    exchange_rates.insert(Exchange::USD("2015-11-12".to_string()), 3.9434f64);
-    exchange_rates.insert(Exchange::USD("2022-08-08".to_string()), 4.6127f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-29".to_string()), 4.2737f64);
-    exchange_rates.insert(Exchange::USD("2024-07-02".to_string()), 4.0375f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-12".to_string()), 4.1721f64);
-    exchange_rates.insert(Exchange::USD("2012-04-24".to_string()), 3.1946f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-09".to_string()), 4.3238f64);
-    exchange_rates.insert(Exchange::USD("2020-08-12".to_string()), 3.7558f64);
-    exchange_rates.insert(Exchange::USD("2018-10-26".to_string()), 3.7899f64);
-    exchange_rates.insert(Exchange::USD("2020-11-24".to_string()), 3.7625f64);
-    exchange_rates.insert(Exchange::USD("2017-03-28".to_string()), 3.9169f64);
-    exchange_rates.insert(Exchange::USD("2016-08-29".to_string()), 3.8789f64);
-    exchange_rates.insert(Exchange::USD("2012-10-18".to_string()), 3.1314f64);
-    exchange_rates.insert(Exchange::USD("2022-07-14".to_string()), 4.8274f64);
-    exchange_rates.insert(Exchange::USD("2021-09-22".to_string()), 3.946f64);
-    exchange_rates.insert(Exchange::USD("2024-10-02".to_string()), 3.8792f64);
-    exchange_rates.insert(Exchange::USD("2022-10-07".to_string()), 4.9588f64);
-    exchange_rates.insert(Exchange::USD("2022-09-19".to_string()), 4.7255f64);
-    exchange_rates.insert(Exchange::USD("2014-05-14".to_string()), 3.048f64);
-    exchange_rates.insert(Exchange::USD("2018-09-04".to_string()), 3.7241f64);
-    exchange_rates.insert(Exchange::USD("2014-10-23".to_string()), 3.339f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-20".to_string()), 4.7109f64);
-    exchange_rates.insert(Exchange::USD("2025-12-29".to_string()), 3.5898f64);
-    exchange_rates.insert(Exchange::USD("2020-07-21".to_string()), 3.8812f64);
-    exchange_rates.insert(Exchange::USD("2019-02-18".to_string()), 3.8286f64);
-    exchange_rates.insert(Exchange::USD("2015-08-14".to_string()), 3.7557f64);
-    exchange_rates.insert(Exchange::USD("2022-06-28".to_string()), 4.4377f64);
-    exchange_rates.insert(Exchange::USD("2022-08-26".to_string()), 4.7465f64);
-    exchange_rates.insert(Exchange::USD("2021-11-08".to_string()), 3.9747f64);
-    exchange_rates.insert(Exchange::USD("2019-12-04".to_string()), 3.8647f64);
-    exchange_rates.insert(Exchange::USD("2017-04-18".to_string()), 3.98f64);
-    exchange_rates.insert(Exchange::USD("2019-12-27".to_string()), 3.8266f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-07".to_string()), 4.6862f64);
-    exchange_rates.insert(Exchange::USD("2019-12-10".to_string()), 3.8711f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-03".to_string()), 4.2757f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-08".to_string()), 4.3079f64);
-    exchange_rates.insert(Exchange::USD("2025-07-02".to_string()), 3.609f64);
-    exchange_rates.insert(Exchange::USD("2012-09-06".to_string()), 3.2955f64);
-    exchange_rates.insert(Exchange::USD("2020-05-21".to_string()), 4.1346f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-28".to_string()), 4.1575f64);
-    exchange_rates.insert(Exchange::USD("2020-07-03".to_string()), 3.9764f64);
-    exchange_rates.insert(Exchange::USD("2021-07-21".to_string()), 3.9035f64);
-    exchange_rates.insert(Exchange::USD("2013-05-08".to_string()), 3.1609f64);
-    exchange_rates.insert(Exchange::USD("2017-09-14".to_string()), 3.5984f64);
-    exchange_rates.insert(Exchange::USD("2024-07-17".to_string()), 3.921f64);
-    exchange_rates.insert(Exchange::USD("2016-01-04".to_string()), 3.9281f64);
-    exchange_rates.insert(Exchange::USD("2015-01-30".to_string()), 3.7204f64);
-    exchange_rates.insert(Exchange::USD("2022-03-01".to_string()), 4.2193f64);
-    exchange_rates.insert(Exchange::USD("2022-07-20".to_string()), 4.6524f64);
-    exchange_rates.insert(Exchange::USD("2015-04-16".to_string()), 3.7746f64);
-    exchange_rates.insert(Exchange::USD("2018-07-19".to_string()), 3.7163f64);
-    exchange_rates.insert(Exchange::USD("2013-03-13".to_string()), 3.1863f64);
-    exchange_rates.insert(Exchange::USD("2012-12-11".to_string()), 3.1645f64);
-    exchange_rates.insert(Exchange::USD("2022-04-04".to_string()), 4.2073f64);
-    exchange_rates.insert(Exchange::USD("2021-06-23".to_string()), 3.7878f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-26".to_string()), 4.2479f64);
-    exchange_rates.insert(Exchange::USD("2014-07-17".to_string()), 3.0579f64);
-    exchange_rates.insert(Exchange::USD("2014-11-18".to_string()), 3.3706f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-03".to_string()), 4.2923f64);
-    exchange_rates.insert(Exchange::USD("2021-10-21".to_string()), 3.9449f64);
-    exchange_rates.insert(Exchange::USD("2020-07-14".to_string()), 3.957f64);
-    exchange_rates.insert(Exchange::USD("2020-08-19".to_string()), 3.6732f64);
-    exchange_rates.insert(Exchange::USD("2015-04-29".to_string()), 3.6396f64);
-    exchange_rates.insert(Exchange::USD("2013-10-03".to_string()), 3.0951f64);
-    exchange_rates.insert(Exchange::USD("2013-05-20".to_string()), 3.238f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-15".to_string()), 4.2499f64);
-    exchange_rates.insert(Exchange::USD("2022-03-15".to_string()), 4.2992f64);
-    exchange_rates.insert(Exchange::USD("2018-09-20".to_string()), 3.6702f64);
-    exchange_rates.insert(Exchange::USD("2014-05-12".to_string()), 3.0362f64);
-    exchange_rates.insert(Exchange::USD("2025-09-26".to_string()), 3.6528f64);
-    exchange_rates.insert(Exchange::USD("2023-09-19".to_string()), 4.3472f64);
-    exchange_rates.insert(Exchange::USD("2016-03-10".to_string()), 3.932f64);
-    exchange_rates.insert(Exchange::USD("2016-08-04".to_string()), 3.8588f64);
-    exchange_rates.insert(Exchange::USD("2013-04-19".to_string()), 3.1409f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-12".to_string()), 4.6836f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-17".to_string()), 4.3174f64);
-    exchange_rates.insert(Exchange::USD("2021-12-01".to_string()), 4.1051f64);
-    exchange_rates.insert(Exchange::USD("2022-08-30".to_string()), 4.721f64);
-    exchange_rates.insert(Exchange::USD("2016-04-18".to_string()), 3.801f64);
-    exchange_rates.insert(Exchange::USD("2012-09-24".to_string()), 3.218f64);
-    exchange_rates.insert(Exchange::USD("2022-07-27".to_string()), 4.7142f64);
-    exchange_rates.insert(Exchange::USD("2019-10-22".to_string()), 3.8408f64);
-    exchange_rates.insert(Exchange::USD("2022-11-30".to_string()), 4.5066f64);
-    exchange_rates.insert(Exchange::USD("2016-10-06".to_string()), 3.8405f64);
-    exchange_rates.insert(Exchange::USD("2023-08-17".to_string()), 4.1094f64);
-    exchange_rates.insert(Exchange::USD("2012-12-28".to_string()), 3.0893f64);
-    exchange_rates.insert(Exchange::USD("2019-07-08".to_string()), 3.7857f64);
-    exchange_rates.insert(Exchange::USD("2017-10-13".to_string()), 3.601f64);
-    exchange_rates.insert(Exchange::USD("2022-08-10".to_string()), 4.6112f64);
-    exchange_rates.insert(Exchange::USD("2018-04-04".to_string()), 3.4224f64);
-    exchange_rates.insert(Exchange::USD("2024-02-09".to_string()), 4.0096f64);
-    exchange_rates.insert(Exchange::USD("2014-05-09".to_string()), 3.0247f64);
-    exchange_rates.insert(Exchange::USD("2019-11-29".to_string()), 3.9288f64);
-    exchange_rates.insert(Exchange::USD("2019-11-18".to_string()), 3.8767f64);
-    exchange_rates.insert(Exchange::USD("2017-02-23".to_string()), 4.084f64);
-    exchange_rates.insert(Exchange::USD("2012-12-21".to_string()), 3.0791f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-14".to_string()), 4.4163f64);
-    exchange_rates.insert(Exchange::USD("2025-04-08".to_string()), 3.927f64);
-    exchange_rates.insert(Exchange::USD("2018-01-26".to_string()), 3.3299f64);
-    exchange_rates.insert(Exchange::USD("2017-03-03".to_string()), 4.0955f64);
-    exchange_rates.insert(Exchange::USD("2024-05-22".to_string()), 3.9243f64);
-    exchange_rates.insert(Exchange::USD("2021-09-27".to_string()), 3.9276f64);
-    exchange_rates.insert(Exchange::USD("2012-08-06".to_string()), 3.2752f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-07".to_string()), 4.479f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-26".to_string()), 4.3087f64);
-    exchange_rates.insert(Exchange::USD("2015-07-21".to_string()), 3.7875f64);
-    exchange_rates.insert(Exchange::USD("2017-02-21".to_string()), 4.0942f64);
-    exchange_rates.insert(Exchange::USD("2012-09-27".to_string()), 3.2236f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-01".to_string()), 4.2415f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-09".to_string()), 4.5683f64);
-    exchange_rates.insert(Exchange::USD("2025-05-12".to_string()), 3.8011f64);
-    exchange_rates.insert(Exchange::USD("2020-01-23".to_string()), 3.8308f64);
-    exchange_rates.insert(Exchange::USD("2018-01-12".to_string()), 3.4366f64);
-    exchange_rates.insert(Exchange::USD("2023-03-07".to_string()), 4.3981f64);
-    exchange_rates.insert(Exchange::USD("2015-02-05".to_string()), 3.6656f64);
-    exchange_rates.insert(Exchange::USD("2014-12-09".to_string()), 3.3688f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-15".to_string()), 4.2635f64);
-    exchange_rates.insert(Exchange::USD("2015-01-28".to_string()), 3.7276f64);
-    exchange_rates.insert(Exchange::USD("2022-08-23".to_string()), 4.803f64);
-    exchange_rates.insert(Exchange::USD("2020-01-27".to_string()), 3.8725f64);
-    exchange_rates.insert(Exchange::USD("2024-11-29".to_string()), 4.077f64);
-    exchange_rates.insert(Exchange::USD("2012-02-17".to_string()), 3.179f64);
-    exchange_rates.insert(Exchange::USD("2016-11-07".to_string()), 3.9132f64);
-    exchange_rates.insert(Exchange::USD("2022-06-13".to_string()), 4.4209f64);
-    exchange_rates.insert(Exchange::USD("2022-10-25".to_string()), 4.8499f64);
-    exchange_rates.insert(Exchange::USD("2020-01-31".to_string()), 3.8999f64);
-    exchange_rates.insert(Exchange::USD("2017-11-07".to_string()), 3.6652f64);
-    exchange_rates.insert(Exchange::USD("2012-08-31".to_string()), 3.3353f64);
-    exchange_rates.insert(Exchange::USD("2023-04-06".to_string()), 4.3033f64);
-    exchange_rates.insert(Exchange::USD("2023-07-19".to_string()), 3.9612f64);
-    exchange_rates.insert(Exchange::USD("2023-06-09".to_string()), 4.1545f64);
-    exchange_rates.insert(Exchange::USD("2017-02-08".to_string()), 4.0521f64);
-    exchange_rates.insert(Exchange::USD("2018-10-18".to_string()), 3.7302f64);
-    exchange_rates.insert(Exchange::USD("2018-03-28".to_string()), 3.3956f64);
-    exchange_rates.insert(Exchange::USD("2017-02-28".to_string()), 4.077f64);
-    exchange_rates.insert(Exchange::USD("2015-09-14".to_string()), 3.7124f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-25".to_string()), 4.1664f64);
-    exchange_rates.insert(Exchange::USD("2015-07-09".to_string()), 3.829f64);
-    exchange_rates.insert(Exchange::USD("2023-07-11".to_string()), 4.0374f64);
-    exchange_rates.insert(Exchange::USD("2013-08-23".to_string()), 3.1673f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-06".to_string()), 4.3435f64);
-    exchange_rates.insert(Exchange::USD("2017-04-05".to_string()), 3.9783f64);
-    exchange_rates.insert(Exchange::USD("2022-03-02".to_string()), 4.3302f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-21".to_string()), 4.2403f64);
-    exchange_rates.insert(Exchange::USD("2023-01-13".to_string()), 4.3272f64);
-    exchange_rates.insert(Exchange::USD("2023-05-25".to_string()), 4.1994f64);
-    exchange_rates.insert(Exchange::USD("2023-12-06".to_string()), 4.0202f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-30".to_string()), 4.522f64);
-    exchange_rates.insert(Exchange::USD("2018-09-27".to_string()), 3.6557f64);
-    exchange_rates.insert(Exchange::USD("2024-10-11".to_string()), 3.9204f64);
-    exchange_rates.insert(Exchange::USD("2021-03-17".to_string()), 3.8676f64);
-    exchange_rates.insert(Exchange::USD("2016-11-18".to_string()), 4.1968f64);
-    exchange_rates.insert(Exchange::USD("2014-05-16".to_string()), 3.0567f64);
-    exchange_rates.insert(Exchange::USD("2013-12-17".to_string()), 3.036f64);
-    exchange_rates.insert(Exchange::USD("2021-12-29".to_string()), 4.0779f64);
-    exchange_rates.insert(Exchange::USD("2020-10-07".to_string()), 3.8126f64);
-    exchange_rates.insert(Exchange::USD("2025-02-26".to_string()), 3.9434f64);
-    exchange_rates.insert(Exchange::USD("2024-10-10".to_string()), 3.9355f64);
-    exchange_rates.insert(Exchange::USD("2025-08-01".to_string()), 3.7536f64);
-    exchange_rates.insert(Exchange::USD("2023-09-21".to_string()), 4.3365f64);
-    exchange_rates.insert(Exchange::USD("2019-09-24".to_string()), 3.9838f64);
-    exchange_rates.insert(Exchange::USD("2016-08-25".to_string()), 3.819f64);
-    exchange_rates.insert(Exchange::USD("2015-02-02".to_string()), 3.6801f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-09".to_string()), 4.2818f64);
-    exchange_rates.insert(Exchange::USD("2025-03-19".to_string()), 3.8473f64);
-    exchange_rates.insert(Exchange::USD("2018-11-21".to_string()), 3.7829f64);
-    exchange_rates.insert(Exchange::USD("2017-11-22".to_string()), 3.5881f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-10".to_string()), 4.2529f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-20".to_string()), 4.2665f64);
-    exchange_rates.insert(Exchange::USD("2020-03-09".to_string()), 3.7899f64);
-    exchange_rates.insert(Exchange::USD("2022-10-04".to_string()), 4.8615f64);
-    exchange_rates.insert(Exchange::USD("2021-07-07".to_string()), 3.8225f64);
-    exchange_rates.insert(Exchange::USD("2021-11-30".to_string()), 4.1214f64);
-    exchange_rates.insert(Exchange::USD("2018-01-22".to_string()), 3.4036f64);
-    exchange_rates.insert(Exchange::USD("2019-09-11".to_string()), 3.9324f64);
-    exchange_rates.insert(Exchange::USD("2025-06-20".to_string()), 3.7067f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-02".to_string()), 4.2934f64);
-    exchange_rates.insert(Exchange::USD("2024-02-16".to_string()), 4.0325f64);
-    exchange_rates.insert(Exchange::USD("2024-11-20".to_string()), 4.112f64);
-    exchange_rates.insert(Exchange::USD("2015-01-21".to_string()), 3.7358f64);
-    exchange_rates.insert(Exchange::USD("2025-07-03".to_string()), 3.6137f64);
-    exchange_rates.insert(Exchange::USD("2019-03-12".to_string()), 3.8116f64);
-    exchange_rates.insert(Exchange::USD("2017-06-29".to_string()), 3.7122f64);
-    exchange_rates.insert(Exchange::USD("2023-07-10".to_string()), 4.0632f64);
-    exchange_rates.insert(Exchange::USD("2020-08-27".to_string()), 3.7286f64);
-    exchange_rates.insert(Exchange::USD("2017-11-21".to_string()), 3.606f64);
-    exchange_rates.insert(Exchange::USD("2015-05-20".to_string()), 3.6538f64);
-    exchange_rates.insert(Exchange::USD("2025-03-13".to_string()), 3.8568f64);
-    exchange_rates.insert(Exchange::USD("2017-07-10".to_string()), 3.716f64);
-    exchange_rates.insert(Exchange::USD("2016-11-08".to_string()), 3.9169f64);
-    exchange_rates.insert(Exchange::USD("2017-06-28".to_string()), 3.7249f64);
-    exchange_rates.insert(Exchange::USD("2023-12-07".to_string()), 4.0199f64);
-    exchange_rates.insert(Exchange::USD("2015-07-30".to_string()), 3.7754f64);
-    exchange_rates.insert(Exchange::USD("2014-10-21".to_string()), 3.2987f64);
-    exchange_rates.insert(Exchange::USD("2013-05-15".to_string()), 3.234f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-16".to_string()), 4.2517f64);
-    exchange_rates.insert(Exchange::USD("2023-01-23".to_string()), 4.3242f64);
-    exchange_rates.insert(Exchange::USD("2021-05-17".to_string()), 3.7288f64);
-    exchange_rates.insert(Exchange::USD("2015-04-30".to_string()), 3.5987f64);
-    exchange_rates.insert(Exchange::USD("2022-11-28".to_string()), 4.4827f64);
-    exchange_rates.insert(Exchange::USD("2014-11-26".to_string()), 3.3546f64);
-    exchange_rates.insert(Exchange::USD("2018-08-29".to_string()), 3.6747f64);
-    exchange_rates.insert(Exchange::USD("2024-08-07".to_string()), 3.9526f64);
-    exchange_rates.insert(Exchange::USD("2024-04-30".to_string()), 4.0341f64);
-    exchange_rates.insert(Exchange::USD("2020-08-05".to_string()), 3.7143f64);
-    exchange_rates.insert(Exchange::USD("2013-08-16".to_string()), 3.1665f64);
-    exchange_rates.insert(Exchange::USD("2019-03-14".to_string()), 3.8018f64);
-    exchange_rates.insert(Exchange::USD("2014-11-19".to_string()), 3.3655f64);
-    exchange_rates.insert(Exchange::USD("2023-03-27".to_string()), 4.3518f64);
-    exchange_rates.insert(Exchange::USD("2020-12-03".to_string()), 3.6981f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-28".to_string()), 4.3116f64);
-    exchange_rates.insert(Exchange::USD("2021-10-01".to_string()), 3.9678f64);
-    exchange_rates.insert(Exchange::USD("2024-01-10".to_string()), 3.9656f64);
-    exchange_rates.insert(Exchange::USD("2022-10-18".to_string()), 4.8929f64);
-    exchange_rates.insert(Exchange::USD("2023-08-02".to_string()), 4.0497f64);
-    exchange_rates.insert(Exchange::USD("2016-12-15".to_string()), 4.2329f64);
-    exchange_rates.insert(Exchange::USD("2016-05-30".to_string()), 3.947f64);
-    exchange_rates.insert(Exchange::USD("2016-08-08".to_string()), 3.8706f64);
-    exchange_rates.insert(Exchange::USD("2014-07-23".to_string()), 3.0707f64);
-    exchange_rates.insert(Exchange::USD("2023-09-28".to_string()), 4.4062f64);
-    exchange_rates.insert(Exchange::USD("2019-10-18".to_string()), 3.8503f64);
-    exchange_rates.insert(Exchange::USD("2015-07-22".to_string()), 3.7629f64);
-    exchange_rates.insert(Exchange::USD("2013-07-31".to_string()), 3.1929f64);
-    exchange_rates.insert(Exchange::USD("2022-01-18".to_string()), 3.9734f64);
-    exchange_rates.insert(Exchange::USD("2018-07-16".to_string()), 3.6828f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-04".to_string()), 4.2899f64);
-    exchange_rates.insert(Exchange::USD("2018-10-09".to_string()), 3.7629f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-20".to_string()), 4.6109f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-12".to_string()), 4.5444f64);
-    exchange_rates.insert(Exchange::USD("2024-12-04".to_string()), 4.0845f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-30".to_string()), 4.2791f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-25".to_string()), 4.1339f64);
-    exchange_rates.insert(Exchange::USD("2025-07-30".to_string()), 3.6981f64);
-    exchange_rates.insert(Exchange::USD("2022-02-17".to_string()), 3.9687f64);
-    exchange_rates.insert(Exchange::USD("2015-09-25".to_string()), 3.7818f64);
-    exchange_rates.insert(Exchange::USD("2017-06-30".to_string()), 3.7062f64);
-    exchange_rates.insert(Exchange::USD("2023-07-04".to_string()), 4.0636f64);
-    exchange_rates.insert(Exchange::USD("2012-01-05".to_string()), 3.5081f64);
-    exchange_rates.insert(Exchange::USD("2018-12-14".to_string()), 3.8095f64);
-    exchange_rates.insert(Exchange::USD("2019-08-21".to_string()), 3.921f64);
-    exchange_rates.insert(Exchange::USD("2017-07-25".to_string()), 3.6519f64);
-    exchange_rates.insert(Exchange::USD("2017-03-13".to_string()), 4.0747f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-14".to_string()), 4.2776f64);
-    exchange_rates.insert(Exchange::USD("2020-07-16".to_string()), 3.9422f64);
-    exchange_rates.insert(Exchange::USD("2016-12-29".to_string()), 4.2161f64);
-    exchange_rates.insert(Exchange::USD("2020-08-10".to_string()), 3.7393f64);
-    exchange_rates.insert(Exchange::USD("2013-09-16".to_string()), 3.1435f64);
-    exchange_rates.insert(Exchange::USD("2023-03-21".to_string()), 4.3715f64);
-    exchange_rates.insert(Exchange::USD("2024-12-12".to_string()), 4.074f64);
-    exchange_rates.insert(Exchange::USD("2013-10-24".to_string()), 3.0323f64);
-    exchange_rates.insert(Exchange::USD("2012-08-13".to_string()), 3.3202f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-16".to_string()), 4.4904f64);
-    exchange_rates.insert(Exchange::USD("2024-05-21".to_string()), 3.9175f64);
-    exchange_rates.insert(Exchange::USD("2019-02-19".to_string()), 3.8311f64);
-    exchange_rates.insert(Exchange::USD("2016-06-23".to_string()), 3.8593f64);
-    exchange_rates.insert(Exchange::USD("2015-11-19".to_string()), 3.9754f64);
-    exchange_rates.insert(Exchange::USD("2024-04-23".to_string()), 4.061f64);
-    exchange_rates.insert(Exchange::USD("2022-02-28".to_string()), 4.1965f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-24".to_string()), 4.6911f64);
-    exchange_rates.insert(Exchange::USD("2016-05-23".to_string()), 3.9513f64);
-    exchange_rates.insert(Exchange::USD("2016-06-21".to_string()), 3.8741f64);
-    exchange_rates.insert(Exchange::USD("2019-12-24".to_string()), 3.8487f64);
-    exchange_rates.insert(Exchange::USD("2018-11-12".to_string()), 3.8163f64);
-    exchange_rates.insert(Exchange::USD("2023-10-03".to_string()), 4.4012f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-06".to_string()), 4.4974f64);
-    exchange_rates.insert(Exchange::USD("2012-03-14".to_string()), 3.1699f64);
-    exchange_rates.insert(Exchange::USD("2015-08-25".to_string()), 3.6613f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-04".to_string()), 4.2553f64);
-    exchange_rates.insert(Exchange::USD("2019-06-21".to_string()), 3.7705f64);
-    exchange_rates.insert(Exchange::USD("2012-11-06".to_string()), 3.2223f64);
-    exchange_rates.insert(Exchange::USD("2012-10-09".to_string()), 3.1501f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-09".to_string()), 4.6836f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-29".to_string()), 4.3327f64);
-    exchange_rates.insert(Exchange::USD("2018-01-02".to_string()), 3.4546f64);
-    exchange_rates.insert(Exchange::USD("2015-03-31".to_string()), 3.8125f64);
-    exchange_rates.insert(Exchange::USD("2023-10-24".to_string()), 4.1884f64);
-    exchange_rates.insert(Exchange::USD("2022-08-01".to_string()), 4.629f64);
-    exchange_rates.insert(Exchange::USD("2019-10-15".to_string()), 3.8952f64);
-    exchange_rates.insert(Exchange::USD("2025-07-17".to_string()), 3.6686f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-26".to_string()), 4.3225f64);
-    exchange_rates.insert(Exchange::USD("2014-10-13".to_string()), 3.3062f64);
-    exchange_rates.insert(Exchange::USD("2012-01-11".to_string()), 3.4919f64);
-    exchange_rates.insert(Exchange::USD("2018-01-17".to_string()), 3.4109f64);
-    exchange_rates.insert(Exchange::USD("2013-07-09".to_string()), 3.3588f64);
-    exchange_rates.insert(Exchange::USD("2025-05-06".to_string()), 3.7789f64);
-    exchange_rates.insert(Exchange::USD("2021-09-30".to_string()), 3.9925f64);
-    exchange_rates.insert(Exchange::USD("2015-02-25".to_string()), 3.6649f64);
-    exchange_rates.insert(Exchange::USD("2025-10-08".to_string()), 3.6619f64);
-    exchange_rates.insert(Exchange::USD("2021-12-13".to_string()), 4.1003f64);
-    exchange_rates.insert(Exchange::USD("2020-09-30".to_string()), 3.8658f64);
-    exchange_rates.insert(Exchange::USD("2022-08-16".to_string()), 4.6282f64);
-    exchange_rates.insert(Exchange::USD("2017-03-06".to_string()), 4.0663f64);
-    exchange_rates.insert(Exchange::USD("2016-05-02".to_string()), 3.8195f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-15".to_string()), 4.7593f64);
-    exchange_rates.insert(Exchange::USD("2021-12-03".to_string()), 4.0653f64);
-    exchange_rates.insert(Exchange::USD("2024-04-16".to_string()), 4.0687f64);
-    exchange_rates.insert(Exchange::USD("2021-11-10".to_string()), 3.9737f64);
-    exchange_rates.insert(Exchange::USD("2017-01-27".to_string()), 4.0595f64);
-    exchange_rates.insert(Exchange::USD("2017-04-21".to_string()), 3.9841f64);
-    exchange_rates.insert(Exchange::USD("2016-07-04".to_string()), 3.9886f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-03".to_string()), 4.6805f64);
-    exchange_rates.insert(Exchange::USD("2015-07-03".to_string()), 3.7726f64);
-    exchange_rates.insert(Exchange::USD("2012-10-17".to_string()), 3.1261f64);
-    exchange_rates.insert(Exchange::USD("2012-03-07".to_string()), 3.1631f64);
-    exchange_rates.insert(Exchange::USD("2022-07-21".to_string()), 4.6682f64);
-    exchange_rates.insert(Exchange::USD("2018-10-11".to_string()), 3.7399f64);
-    exchange_rates.insert(Exchange::USD("2020-01-16".to_string()), 3.7968f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-08".to_string()), 4.2484f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-27".to_string()), 4.3543f64);
-    exchange_rates.insert(Exchange::USD("2022-06-21".to_string()), 4.3917f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-14".to_string()), 4.2933f64);
-    exchange_rates.insert(Exchange::USD("2018-12-20".to_string()), 3.7331f64);
-    exchange_rates.insert(Exchange::USD("2021-07-15".to_string()), 3.8629f64);
-    exchange_rates.insert(Exchange::USD("2014-03-18".to_string()), 3.0396f64);
-    exchange_rates.insert(Exchange::USD("2019-09-09".to_string()), 3.931f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-05".to_string()), 4.4851f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-24".to_string()), 4.2624f64);
-    exchange_rates.insert(Exchange::USD("2012-01-25".to_string()), 3.299f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-27".to_string()), 4.2362f64);
-    exchange_rates.insert(Exchange::USD("2013-06-25".to_string()), 3.284f64);
-    exchange_rates.insert(Exchange::USD("2014-09-15".to_string()), 3.2522f64);
-    exchange_rates.insert(Exchange::USD("2024-12-19".to_string()), 4.0944f64);
-    exchange_rates.insert(Exchange::USD("2024-01-17".to_string()), 4.0434f64);
-    exchange_rates.insert(Exchange::USD("2024-07-22".to_string()), 3.9307f64);
-    exchange_rates.insert(Exchange::USD("2022-09-28".to_string()), 5.0381f64);
-    exchange_rates.insert(Exchange::USD("2012-07-06".to_string()), 3.4057f64);
-    exchange_rates.insert(Exchange::USD("2019-12-20".to_string()), 3.8311f64);
-    exchange_rates.insert(Exchange::USD("2023-12-01".to_string()), 3.991f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-20".to_string()), 4.2587f64);
-    exchange_rates.insert(Exchange::USD("2020-12-28".to_string()), 3.6639f64);
-    exchange_rates.insert(Exchange::USD("2016-03-25".to_string()), 3.8174f64);
-    exchange_rates.insert(Exchange::USD("2023-04-28".to_string()), 4.1753f64);
-    exchange_rates.insert(Exchange::USD("2018-09-18".to_string()), 3.6817f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-01".to_string()), 4.3494f64);
-    exchange_rates.insert(Exchange::USD("2022-07-07".to_string()), 4.7029f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-03".to_string()), 4.4585f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-06".to_string()), 4.4615f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-03".to_string()), 4.6147f64);
-    exchange_rates.insert(Exchange::USD("2014-12-03".to_string()), 3.373f64);
-    exchange_rates.insert(Exchange::USD("2023-03-28".to_string()), 4.33f64);
-    exchange_rates.insert(Exchange::USD("2021-05-14".to_string()), 3.7374f64);
-    exchange_rates.insert(Exchange::USD("2013-07-02".to_string()), 3.3222f64);
-    exchange_rates.insert(Exchange::USD("2021-06-09".to_string()), 3.6545f64);
-    exchange_rates.insert(Exchange::USD("2024-04-29".to_string()), 4.0346f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-18".to_string()), 4.3048f64);
-    exchange_rates.insert(Exchange::USD("2014-12-22".to_string()), 3.4767f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-13".to_string()), 4.6511f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-14".to_string()), 4.2737f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-13".to_string()), 4.2586f64);
-    exchange_rates.insert(Exchange::USD("2018-09-03".to_string()), 3.6991f64);
-    exchange_rates.insert(Exchange::USD("2017-12-11".to_string()), 3.5633f64);
-    exchange_rates.insert(Exchange::USD("2015-09-17".to_string()), 3.7129f64);
-    exchange_rates.insert(Exchange::USD("2014-11-13".to_string()), 3.386f64);
-    exchange_rates.insert(Exchange::USD("2023-03-10".to_string()), 4.4266f64);
-    exchange_rates.insert(Exchange::USD("2021-03-22".to_string()), 3.8768f64);
-    exchange_rates.insert(Exchange::USD("2021-06-17".to_string()), 3.7931f64);
-    exchange_rates.insert(Exchange::USD("2019-08-16".to_string()), 3.9281f64);
-    exchange_rates.insert(Exchange::USD("2017-05-16".to_string()), 3.7932f64);
-    exchange_rates.insert(Exchange::USD("2014-05-13".to_string()), 3.0381f64);
-    exchange_rates.insert(Exchange::USD("2014-11-04".to_string()), 3.3776f64);
-    exchange_rates.insert(Exchange::USD("2019-04-03".to_string()), 3.8192f64);
-    exchange_rates.insert(Exchange::USD("2025-07-08".to_string()), 3.6135f64);
-    exchange_rates.insert(Exchange::USD("2023-12-15".to_string()), 3.9326f64);
-    exchange_rates.insert(Exchange::USD("2012-02-27".to_string()), 3.1176f64);
-    exchange_rates.insert(Exchange::USD("2012-11-12".to_string()), 3.2803f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-18".to_string()), 4.3086f64);
-    exchange_rates.insert(Exchange::USD("2020-01-20".to_string()), 3.8277f64);
-    exchange_rates.insert(Exchange::USD("2020-04-09".to_string()), 4.1744f64);
-    exchange_rates.insert(Exchange::USD("2020-08-18".to_string()), 3.6895f64);
-    exchange_rates.insert(Exchange::USD("2013-04-05".to_string()), 3.2358f64);
-    exchange_rates.insert(Exchange::USD("2020-05-07".to_string()), 4.2162f64);
-    exchange_rates.insert(Exchange::USD("2020-11-05".to_string()), 3.8353f64);
-    exchange_rates.insert(Exchange::USD("2019-03-21".to_string()), 3.7581f64);
-    exchange_rates.insert(Exchange::USD("2013-01-17".to_string()), 3.0853f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-01".to_string()), 4.3247f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-23".to_string()), 4.3335f64);
-    exchange_rates.insert(Exchange::USD("2025-08-25".to_string()), 3.6421f64);
-    exchange_rates.insert(Exchange::USD("2024-01-15".to_string()), 3.9963f64);
-    exchange_rates.insert(Exchange::USD("2020-04-08".to_string()), 4.1801f64);
-    exchange_rates.insert(Exchange::USD("2021-12-08".to_string()), 4.071f64);
-    exchange_rates.insert(Exchange::USD("2024-04-03".to_string()), 3.9843f64);
-    exchange_rates.insert(Exchange::USD("2020-12-23".to_string()), 3.6919f64);
-    exchange_rates.insert(Exchange::USD("2021-12-24".to_string()), 4.0785f64);
-    exchange_rates.insert(Exchange::USD("2016-03-07".to_string()), 3.9515f64);
-    exchange_rates.insert(Exchange::USD("2025-02-28".to_string()), 3.9993f64);
-    exchange_rates.insert(Exchange::USD("2025-08-07".to_string()), 3.6477f64);
-    exchange_rates.insert(Exchange::USD("2016-09-08".to_string()), 3.8255f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-26".to_string()), 4.5887f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-30".to_string()), 4.2039f64);
-    exchange_rates.insert(Exchange::USD("2014-12-29".to_string()), 3.527f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-30".to_string()), 4.4491f64);
-    exchange_rates.insert(Exchange::USD("2012-07-17".to_string()), 3.3973f64);
-    exchange_rates.insert(Exchange::USD("2019-04-23".to_string()), 3.8118f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-14".to_string()), 4.3435f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-31".to_string()), 4.1839f64);
-    exchange_rates.insert(Exchange::USD("2023-05-12".to_string()), 4.1638f64);
-    exchange_rates.insert(Exchange::USD("2017-11-23".to_string()), 3.5557f64);
-    exchange_rates.insert(Exchange::USD("2013-11-07".to_string()), 3.0844f64);
-    exchange_rates.insert(Exchange::USD("2024-02-28".to_string()), 3.9922f64);
-    exchange_rates.insert(Exchange::USD("2021-01-22".to_string()), 3.7255f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-10".to_string()), 4.2604f64);
-    exchange_rates.insert(Exchange::USD("2023-04-04".to_string()), 4.2855f64);
-    exchange_rates.insert(Exchange::USD("2021-05-20".to_string()), 3.7077f64);
-    exchange_rates.insert(Exchange::USD("2019-12-13".to_string()), 3.8234f64);
-    exchange_rates.insert(Exchange::USD("2014-09-17".to_string()), 3.2331f64);
-    exchange_rates.insert(Exchange::USD("2021-10-26".to_string()), 3.974f64);
-    exchange_rates.insert(Exchange::USD("2020-06-22".to_string()), 3.9667f64);
-    exchange_rates.insert(Exchange::USD("2015-04-23".to_string()), 3.7371f64);
-    exchange_rates.insert(Exchange::USD("2021-02-01".to_string()), 3.7323f64);
-    exchange_rates.insert(Exchange::USD("2024-02-06".to_string()), 4.0484f64);
-    exchange_rates.insert(Exchange::USD("2023-07-25".to_string()), 4.008f64);
-    exchange_rates.insert(Exchange::USD("2022-02-16".to_string()), 3.9478f64);
-    exchange_rates.insert(Exchange::USD("2022-06-09".to_string()), 4.2764f64);
-    exchange_rates.insert(Exchange::USD("2021-02-17".to_string()), 3.7287f64);
-    exchange_rates.insert(Exchange::USD("2018-03-29".to_string()), 3.4201f64);
-    exchange_rates.insert(Exchange::USD("2018-06-08".to_string()), 3.6479f64);
-    exchange_rates.insert(Exchange::USD("2018-03-01".to_string()), 3.4356f64);
-    exchange_rates.insert(Exchange::USD("2013-11-22".to_string()), 3.1077f64);
-    exchange_rates.insert(Exchange::USD("2012-03-06".to_string()), 3.1557f64);
-    exchange_rates.insert(Exchange::USD("2020-10-22".to_string()), 3.8734f64);
-    exchange_rates.insert(Exchange::USD("2021-08-17".to_string()), 3.8797f64);
-    exchange_rates.insert(Exchange::USD("2025-07-10".to_string()), 3.6209f64);
-    exchange_rates.insert(Exchange::USD("2021-04-23".to_string()), 3.7855f64);
-    exchange_rates.insert(Exchange::USD("2022-10-20".to_string()), 4.9024f64);
-    exchange_rates.insert(Exchange::USD("2025-11-18".to_string()), 3.6525f64);
-    exchange_rates.insert(Exchange::USD("2017-04-27".to_string()), 3.8776f64);
-    exchange_rates.insert(Exchange::USD("2022-01-17".to_string()), 3.9567f64);
-    exchange_rates.insert(Exchange::USD("2015-03-24".to_string()), 3.7453f64);
-    exchange_rates.insert(Exchange::USD("2015-07-10".to_string()), 3.7709f64);
-    exchange_rates.insert(Exchange::USD("2013-05-29".to_string()), 3.2831f64);
-    exchange_rates.insert(Exchange::USD("2012-11-02".to_string()), 3.1965f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-28".to_string()), 4.5889f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-11".to_string()), 4.1783f64);
-    exchange_rates.insert(Exchange::USD("2020-02-20".to_string()), 3.9621f64);
-    exchange_rates.insert(Exchange::USD("2019-06-07".to_string()), 3.7958f64);
-    exchange_rates.insert(Exchange::USD("2013-01-28".to_string()), 3.1074f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-25".to_string()), 4.2688f64);
-    exchange_rates.insert(Exchange::USD("2024-04-19".to_string()), 4.0688f64);
-    exchange_rates.insert(Exchange::USD("2020-02-19".to_string()), 3.9556f64);
-    exchange_rates.insert(Exchange::USD("2014-07-30".to_string()), 3.0978f64);
-    exchange_rates.insert(Exchange::USD("2012-01-20".to_string()), 3.3537f64);
-    exchange_rates.insert(Exchange::USD("2025-03-28".to_string()), 3.8817f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-10".to_string()), 4.2351f64);
-    exchange_rates.insert(Exchange::USD("2013-09-27".to_string()), 3.1328f64);
-    exchange_rates.insert(Exchange::USD("2019-03-22".to_string()), 3.7948f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-31".to_string()), 4.2543f64);
-    exchange_rates.insert(Exchange::USD("2020-03-27".to_string()), 4.1127f64);
-    exchange_rates.insert(Exchange::USD("2025-04-22".to_string()), 3.722f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-04".to_string()), 4.6303f64);
-    exchange_rates.insert(Exchange::USD("2023-05-29".to_string()), 4.2234f64);
-    exchange_rates.insert(Exchange::USD("2015-12-31".to_string()), 3.9011f64);
-    exchange_rates.insert(Exchange::USD("2016-02-05".to_string()), 3.942f64);
-    exchange_rates.insert(Exchange::USD("2023-06-28".to_string()), 4.0719f64);
-    exchange_rates.insert(Exchange::USD("2015-09-04".to_string()), 3.7952f64);
-    exchange_rates.insert(Exchange::USD("2023-09-27".to_string()), 4.3622f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-28".to_string()), 4.2369f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-01".to_string()), 4.2924f64);
-    exchange_rates.insert(Exchange::USD("2025-10-27".to_string()), 3.6395f64);
-    exchange_rates.insert(Exchange::USD("2012-11-07".to_string()), 3.2031f64);
-    exchange_rates.insert(Exchange::USD("2013-06-04".to_string()), 3.2524f64);
-    exchange_rates.insert(Exchange::USD("2023-11-24".to_string()), 4.0103f64);
-    exchange_rates.insert(Exchange::USD("2013-03-11".to_string()), 3.1828f64);
-    exchange_rates.insert(Exchange::USD("2017-11-08".to_string()), 3.6595f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-04".to_string()), 4.3443f64);
-    exchange_rates.insert(Exchange::USD("2024-06-19".to_string()), 4.0387f64);
-    exchange_rates.insert(Exchange::USD("2023-08-16".to_string()), 4.0832f64);
-    exchange_rates.insert(Exchange::USD("2024-12-31".to_string()), 4.1012f64);
-    exchange_rates.insert(Exchange::USD("2013-01-25".to_string()), 3.1186f64);
-    exchange_rates.insert(Exchange::USD("2025-12-18".to_string()), 3.5893f64);
-    exchange_rates.insert(Exchange::USD("2023-09-04".to_string()), 4.1353f64);
-    exchange_rates.insert(Exchange::USD("2016-07-22".to_string()), 3.9504f64);
-    exchange_rates.insert(Exchange::USD("2018-05-16".to_string()), 3.6241f64);
-    exchange_rates.insert(Exchange::USD("2020-09-11".to_string()), 3.7534f64);
-    exchange_rates.insert(Exchange::USD("2021-05-05".to_string()), 3.8136f64);
-    exchange_rates.insert(Exchange::USD("2022-10-05".to_string()), 4.838f64);
-    exchange_rates.insert(Exchange::USD("2012-06-25".to_string()), 3.4105f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-12".to_string()), 4.2593f64);
-    exchange_rates.insert(Exchange::USD("2025-05-21".to_string()), 3.7452f64);
-    exchange_rates.insert(Exchange::USD("2023-12-19".to_string()), 3.9591f64);
-    exchange_rates.insert(Exchange::USD("2022-03-14".to_string()), 4.3221f64);
-    exchange_rates.insert(Exchange::USD("2021-12-27".to_string()), 4.0889f64);
-    exchange_rates.insert(Exchange::USD("2012-07-19".to_string()), 3.39f64);
-    exchange_rates.insert(Exchange::USD("2014-08-21".to_string()), 3.1569f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-20".to_string()), 4.2524f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-13".to_string()), 4.6915f64);
-    exchange_rates.insert(Exchange::USD("2022-01-13".to_string()), 3.9463f64);
-    exchange_rates.insert(Exchange::USD("2022-08-19".to_string()), 4.7061f64);
-    exchange_rates.insert(Exchange::USD("2021-10-12".to_string()), 3.9664f64);
-    exchange_rates.insert(Exchange::USD("2016-08-01".to_string()), 3.904f64);
-    exchange_rates.insert(Exchange::USD("2014-06-03".to_string()), 3.0449f64);
-    exchange_rates.insert(Exchange::USD("2013-02-12".to_string()), 3.1159f64);
-    exchange_rates.insert(Exchange::USD("2020-07-02".to_string()), 3.9528f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-24".to_string()), 4.7245f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-19".to_string()), 4.5367f64);
-    exchange_rates.insert(Exchange::USD("2018-01-30".to_string()), 3.3442f64);
-    exchange_rates.insert(Exchange::USD("2023-01-16".to_string()), 4.3436f64);
-    exchange_rates.insert(Exchange::USD("2020-06-15".to_string()), 3.9413f64);
-    exchange_rates.insert(Exchange::USD("2023-05-16".to_string()), 4.1228f64);
-    exchange_rates.insert(Exchange::USD("2017-03-16".to_string()), 4.03f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-14".to_string()), 4.287f64);
-    exchange_rates.insert(Exchange::USD("2023-04-03".to_string()), 4.3168f64);
-    exchange_rates.insert(Exchange::USD("2015-10-14".to_string()), 3.7129f64);
-    exchange_rates.insert(Exchange::USD("2014-01-23".to_string()), 3.0559f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-22".to_string()), 4.2447f64);
-    exchange_rates.insert(Exchange::USD("2017-04-10".to_string()), 3.9956f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-22".to_string()), 4.2461f64);
-    exchange_rates.insert(Exchange::USD("2022-02-24".to_string()), 4.1281f64);
-    exchange_rates.insert(Exchange::USD("2024-07-30".to_string()), 3.9567f64);
-    exchange_rates.insert(Exchange::USD("2021-01-12".to_string()), 3.7188f64);
-    exchange_rates.insert(Exchange::USD("2019-01-11".to_string()), 3.7289f64);
-    exchange_rates.insert(Exchange::USD("2015-08-11".to_string()), 3.8087f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-13".to_string()), 4.3214f64);
-    exchange_rates.insert(Exchange::USD("2021-07-16".to_string()), 3.8808f64);
-    exchange_rates.insert(Exchange::USD("2014-04-30".to_string()), 3.044f64);
-    exchange_rates.insert(Exchange::USD("2017-06-12".to_string()), 3.735f64);
-    exchange_rates.insert(Exchange::USD("2019-09-13".to_string()), 3.912f64);
-    exchange_rates.insert(Exchange::USD("2025-01-03".to_string()), 4.1512f64);
-    exchange_rates.insert(Exchange::USD("2025-07-24".to_string()), 3.6158f64);
-    exchange_rates.insert(Exchange::USD("2012-05-30".to_string()), 3.5139f64);
-    exchange_rates.insert(Exchange::USD("2014-11-14".to_string()), 3.3933f64);
-    exchange_rates.insert(Exchange::USD("2017-01-23".to_string()), 4.076f64);
-    exchange_rates.insert(Exchange::USD("2014-09-18".to_string()), 3.249f64);
-    exchange_rates.insert(Exchange::USD("2013-10-25".to_string()), 3.0326f64);
-    exchange_rates.insert(Exchange::USD("2017-08-31".to_string()), 3.5822f64);
-    exchange_rates.insert(Exchange::USD("2023-07-03".to_string()), 4.0714f64);
-    exchange_rates.insert(Exchange::USD("2020-01-10".to_string()), 3.8272f64);
-    exchange_rates.insert(Exchange::USD("2016-02-09".to_string()), 3.9771f64);
-    exchange_rates.insert(Exchange::USD("2022-05-20".to_string()), 4.3832f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-10".to_string()), 4.2979f64);
-    exchange_rates.insert(Exchange::USD("2025-03-25".to_string()), 3.8578f64);
-    exchange_rates.insert(Exchange::USD("2018-02-09".to_string()), 3.4263f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-14".to_string()), 4.3053f64);
-    exchange_rates.insert(Exchange::USD("2015-11-13".to_string()), 3.9388f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-29".to_string()), 4.2688f64);
-    exchange_rates.insert(Exchange::USD("2017-10-11".to_string()), 3.6219f64);
-    exchange_rates.insert(Exchange::USD("2016-08-24".to_string()), 3.8174f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-09".to_string()), 4.2633f64);
-    exchange_rates.insert(Exchange::USD("2024-09-20".to_string()), 3.8317f64);
-    exchange_rates.insert(Exchange::USD("2013-11-14".to_string()), 3.115f64);
-    exchange_rates.insert(Exchange::USD("2023-02-24".to_string()), 4.463f64);
-    exchange_rates.insert(Exchange::USD("2017-05-30".to_string()), 3.7471f64);
-    exchange_rates.insert(Exchange::USD("2012-11-15".to_string()), 3.2678f64);
-    exchange_rates.insert(Exchange::USD("2015-06-18".to_string()), 3.6556f64);
-    exchange_rates.insert(Exchange::USD("2024-08-23".to_string()), 3.8453f64);
-    exchange_rates.insert(Exchange::USD("2022-04-13".to_string()), 4.2872f64);
-    exchange_rates.insert(Exchange::USD("2024-06-20".to_string()), 4.0345f64);
-    exchange_rates.insert(Exchange::USD("2022-04-12".to_string()), 4.2926f64);
-    exchange_rates.insert(Exchange::USD("2015-09-07".to_string()), 3.7928f64);
-    exchange_rates.insert(Exchange::USD("2013-12-11".to_string()), 3.0386f64);
-    exchange_rates.insert(Exchange::USD("2021-04-02".to_string()), 3.8986f64);
-    exchange_rates.insert(Exchange::USD("2013-08-12".to_string()), 3.151f64);
-    exchange_rates.insert(Exchange::USD("2013-10-23".to_string()), 3.0385f64);
-    exchange_rates.insert(Exchange::USD("2013-03-28".to_string()), 3.2773f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-10".to_string()), 4.5488f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-18".to_string()), 4.3309f64);
-    exchange_rates.insert(Exchange::USD("2020-01-15".to_string()), 3.7992f64);
-    exchange_rates.insert(Exchange::USD("2017-11-27".to_string()), 3.5276f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-17".to_string()), 4.452f64);
-    exchange_rates.insert(Exchange::USD("2022-02-07".to_string()), 3.9872f64);
-    exchange_rates.insert(Exchange::USD("2014-08-14".to_string()), 3.1285f64);
-    exchange_rates.insert(Exchange::USD("2013-05-24".to_string()), 3.24f64);
-    exchange_rates.insert(Exchange::USD("2018-03-19".to_string()), 3.4341f64);
-    exchange_rates.insert(Exchange::USD("2025-06-11".to_string()), 3.7306f64);
-    exchange_rates.insert(Exchange::USD("2023-11-09".to_string()), 4.1513f64);
-    exchange_rates.insert(Exchange::USD("2015-05-19".to_string()), 3.6152f64);
-    exchange_rates.insert(Exchange::USD("2016-07-12".to_string()), 3.9807f64);
-    exchange_rates.insert(Exchange::USD("2014-09-16".to_string()), 3.2419f64);
-    exchange_rates.insert(Exchange::USD("2019-02-26".to_string()), 3.8124f64);
-    exchange_rates.insert(Exchange::USD("2023-09-08".to_string()), 4.2958f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-29".to_string()), 4.2274f64);
-    exchange_rates.insert(Exchange::USD("2013-05-14".to_string()), 3.1998f64);
-    exchange_rates.insert(Exchange::USD("2013-01-02".to_string()), 3.066f64);
-    exchange_rates.insert(Exchange::USD("2014-07-07".to_string()), 3.0497f64);
-    exchange_rates.insert(Exchange::USD("2015-04-02".to_string()), 3.7524f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-02".to_string()), 4.7079f64);
-    exchange_rates.insert(Exchange::USD("2015-12-30".to_string()), 3.8801f64);
-    exchange_rates.insert(Exchange::USD("2022-06-01".to_string()), 4.2817f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-05".to_string()), 4.3073f64);
-    exchange_rates.insert(Exchange::USD("2019-02-28".to_string()), 3.7853f64);
-    exchange_rates.insert(Exchange::USD("2022-06-14".to_string()), 4.4478f64);
-    exchange_rates.insert(Exchange::USD("2016-09-22".to_string()), 3.8128f64);
-    exchange_rates.insert(Exchange::USD("2014-04-03".to_string()), 3.033f64);
-    exchange_rates.insert(Exchange::USD("2018-05-04".to_string()), 3.5754f64);
-    exchange_rates.insert(Exchange::USD("2024-04-05".to_string()), 3.9571f64);
-    exchange_rates.insert(Exchange::USD("2020-11-06".to_string()), 3.8194f64);
-    exchange_rates.insert(Exchange::USD("2019-01-14".to_string()), 3.7458f64);
-    exchange_rates.insert(Exchange::USD("2022-04-01".to_string()), 4.1978f64);
-    exchange_rates.insert(Exchange::USD("2020-11-18".to_string()), 3.7621f64);
-    exchange_rates.insert(Exchange::USD("2018-06-11".to_string()), 3.6247f64);
-    exchange_rates.insert(Exchange::USD("2022-07-29".to_string()), 4.6365f64);
-    exchange_rates.insert(Exchange::USD("2021-03-23".to_string()), 3.8746f64);
-    exchange_rates.insert(Exchange::USD("2022-04-07".to_string()), 4.2727f64);
-    exchange_rates.insert(Exchange::USD("2018-10-31".to_string()), 3.8203f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-08".to_string()), 4.2533f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-12".to_string()), 4.3396f64);
-    exchange_rates.insert(Exchange::USD("2020-12-29".to_string()), 3.6778f64);
-    exchange_rates.insert(Exchange::USD("2018-06-04".to_string()), 3.6665f64);
-    exchange_rates.insert(Exchange::USD("2025-12-16".to_string()), 3.593f64);
-    exchange_rates.insert(Exchange::USD("2020-03-26".to_string()), 4.1988f64);
-    exchange_rates.insert(Exchange::USD("2023-08-03".to_string()), 4.081f64);
-    exchange_rates.insert(Exchange::USD("2019-05-02".to_string()), 3.8177f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-29".to_string()), 4.2646f64);
-    exchange_rates.insert(Exchange::USD("2013-08-19".to_string()), 3.1873f64);
-    exchange_rates.insert(Exchange::USD("2025-05-20".to_string()), 3.7747f64);
-    exchange_rates.insert(Exchange::USD("2024-09-30".to_string()), 3.8193f64);
-    exchange_rates.insert(Exchange::USD("2023-03-08".to_string()), 4.4626f64);
-    exchange_rates.insert(Exchange::USD("2022-01-05".to_string()), 4.0396f64);
-    exchange_rates.insert(Exchange::USD("2014-02-20".to_string()), 3.049f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-02".to_string()), 4.2903f64);
-    exchange_rates.insert(Exchange::USD("2016-05-19".to_string()), 3.9161f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-08".to_string()), 4.3449f64);
-    exchange_rates.insert(Exchange::USD("2018-04-10".to_string()), 3.4068f64);
-    exchange_rates.insert(Exchange::USD("2013-09-06".to_string()), 3.2732f64);
-    exchange_rates.insert(Exchange::USD("2012-02-03".to_string()), 3.1848f64);
-    exchange_rates.insert(Exchange::USD("2016-12-20".to_string()), 4.2493f64);
-    exchange_rates.insert(Exchange::USD("2016-02-23".to_string()), 3.9671f64);
-    exchange_rates.insert(Exchange::USD("2012-05-18".to_string()), 3.4431f64);
-    exchange_rates.insert(Exchange::USD("2017-02-13".to_string()), 4.0496f64);
-    exchange_rates.insert(Exchange::USD("2018-05-07".to_string()), 3.5634f64);
-    exchange_rates.insert(Exchange::USD("2012-01-04".to_string()), 3.432f64);
-    exchange_rates.insert(Exchange::USD("2017-09-13".to_string()), 3.5627f64);
-    exchange_rates.insert(Exchange::USD("2018-07-24".to_string()), 3.702f64);
-    exchange_rates.insert(Exchange::USD("2023-11-29".to_string()), 3.9478f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-27".to_string()), 4.227f64);
-    exchange_rates.insert(Exchange::USD("2012-08-10".to_string()), 3.3195f64);
-    exchange_rates.insert(Exchange::USD("2020-03-02".to_string()), 3.9023f64);
-    exchange_rates.insert(Exchange::USD("2014-10-03".to_string()), 3.3079f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-06".to_string()), 4.2566f64);
-    exchange_rates.insert(Exchange::USD("2016-03-21".to_string()), 3.7838f64);
-    exchange_rates.insert(Exchange::USD("2025-08-26".to_string()), 3.6716f64);
-    exchange_rates.insert(Exchange::USD("2023-09-13".to_string()), 4.3225f64);
-    exchange_rates.insert(Exchange::USD("2017-02-24".to_string()), 4.0681f64);
-    exchange_rates.insert(Exchange::USD("2015-02-26".to_string()), 3.6519f64);
-    exchange_rates.insert(Exchange::USD("2012-11-23".to_string()), 3.1945f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-08".to_string()), 4.2656f64);
-    exchange_rates.insert(Exchange::USD("2024-05-29".to_string()), 3.9244f64);
-    exchange_rates.insert(Exchange::USD("2024-12-17".to_string()), 4.0623f64);
-    exchange_rates.insert(Exchange::USD("2022-09-30".to_string()), 4.9533f64);
-    exchange_rates.insert(Exchange::USD("2014-12-15".to_string()), 3.3621f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-22".to_string()), 4.6888f64);
-    exchange_rates.insert(Exchange::USD("2017-02-09".to_string()), 4.0332f64);
-    exchange_rates.insert(Exchange::USD("2020-04-21".to_string()), 4.1779f64);
-    exchange_rates.insert(Exchange::USD("2016-07-29".to_string()), 3.9364f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-26".to_string()), 4.2773f64);
-    exchange_rates.insert(Exchange::USD("2024-02-23".to_string()), 4.005f64);
-    exchange_rates.insert(Exchange::USD("2020-05-08".to_string()), 4.2065f64);
-    exchange_rates.insert(Exchange::USD("2024-12-30".to_string()), 4.096f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-18".to_string()), 4.1574f64);
-    exchange_rates.insert(Exchange::USD("2017-07-12".to_string()), 3.7034f64);
-    exchange_rates.insert(Exchange::USD("2025-06-12".to_string()), 3.6929f64);
-    exchange_rates.insert(Exchange::USD("2024-03-21".to_string()), 3.9431f64);
-    exchange_rates.insert(Exchange::USD("2021-04-13".to_string()), 3.8342f64);
-    exchange_rates.insert(Exchange::USD("2016-11-10".to_string()), 3.9856f64);
-    exchange_rates.insert(Exchange::USD("2019-08-26".to_string()), 3.9335f64);
-    exchange_rates.insert(Exchange::USD("2013-03-01".to_string()), 3.1769f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-23".to_string()), 4.4834f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-19".to_string()), 4.2693f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-11".to_string()), 4.2284f64);
-    exchange_rates.insert(Exchange::USD("2022-12-15".to_string()), 4.41f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-20".to_string()), 4.3404f64);
-    exchange_rates.insert(Exchange::USD("2020-12-21".to_string()), 3.7082f64);
-    exchange_rates.insert(Exchange::USD("2016-06-03".to_string()), 3.9384f64);
-    exchange_rates.insert(Exchange::USD("2015-01-26".to_string()), 3.7601f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-10".to_string()), 4.2827f64);
-    exchange_rates.insert(Exchange::USD("2023-02-13".to_string()), 4.4856f64);
-    exchange_rates.insert(Exchange::USD("2016-08-03".to_string()), 3.8516f64);
-    exchange_rates.insert(Exchange::USD("2023-03-23".to_string()), 4.3011f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-30".to_string()), 4.2293f64);
-    exchange_rates.insert(Exchange::USD("2020-07-27".to_string()), 3.7643f64);
-    exchange_rates.insert(Exchange::USD("2021-05-19".to_string()), 3.7087f64);
-    exchange_rates.insert(Exchange::USD("2018-03-16".to_string()), 3.4214f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-31".to_string()), 4.353f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-04".to_string()), 4.2778f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-25".to_string()), 4.2634f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-27".to_string()), 4.5915f64);
-    exchange_rates.insert(Exchange::USD("2022-03-04".to_string()), 4.391f64);
-    exchange_rates.insert(Exchange::USD("2025-07-01".to_string()), 3.593f64);
-    exchange_rates.insert(Exchange::USD("2023-04-26".to_string()), 4.1557f64);
-    exchange_rates.insert(Exchange::USD("2025-12-15".to_string()), 3.5928f64);
-    exchange_rates.insert(Exchange::USD("2018-12-18".to_string()), 3.7668f64);
-    exchange_rates.insert(Exchange::USD("2025-10-21".to_string()), 3.6479f64);
-    exchange_rates.insert(Exchange::USD("2020-09-16".to_string()), 3.7526f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-26".to_string()), 4.6077f64);
-    exchange_rates.insert(Exchange::USD("2022-09-08".to_string()), 4.7268f64);
-    exchange_rates.insert(Exchange::USD("2022-03-16".to_string()), 4.2828f64);
-    exchange_rates.insert(Exchange::USD("2016-07-11".to_string()), 4.006f64);
-    exchange_rates.insert(Exchange::USD("2017-09-19".to_string()), 3.5821f64);
-    exchange_rates.insert(Exchange::USD("2022-12-01".to_string()), 4.4911f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-29".to_string()), 4.348f64);
-    exchange_rates.insert(Exchange::USD("2018-09-10".to_string()), 3.7283f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-17".to_string()), 4.3608f64);
-    exchange_rates.insert(Exchange::USD("2018-05-11".to_string()), 3.5708f64);
-    exchange_rates.insert(Exchange::USD("2015-01-12".to_string()), 3.6218f64);
-    exchange_rates.insert(Exchange::USD("2013-03-25".to_string()), 3.195f64);
-    exchange_rates.insert(Exchange::USD("2014-10-27".to_string()), 3.3288f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-26".to_string()), 4.4297f64);
-    exchange_rates.insert(Exchange::USD("2018-02-06".to_string()), 3.3498f64);
-    exchange_rates.insert(Exchange::USD("2018-08-13".to_string()), 3.7862f64);
-    exchange_rates.insert(Exchange::USD("2016-08-18".to_string()), 3.7826f64);
-    exchange_rates.insert(Exchange::USD("2015-01-20".to_string()), 3.7346f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-08".to_string()), 4.278f64);
-    exchange_rates.insert(Exchange::USD("2023-01-30".to_string()), 4.3258f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-21".to_string()), 4.2572f64);
-    exchange_rates.insert(Exchange::USD("2013-12-12".to_string()), 3.0415f64);
-    exchange_rates.insert(Exchange::USD("2025-02-18".to_string()), 3.9729f64);
-    exchange_rates.insert(Exchange::USD("2020-09-03".to_string()), 3.7337f64);
-    exchange_rates.insert(Exchange::USD("2019-11-08".to_string()), 3.8625f64);
-    exchange_rates.insert(Exchange::USD("2015-10-29".to_string()), 3.8974f64);
-    exchange_rates.insert(Exchange::USD("2017-11-03".to_string()), 3.6408f64);
-    exchange_rates.insert(Exchange::USD("2012-02-09".to_string()), 3.1487f64);
-    exchange_rates.insert(Exchange::USD("2017-01-02".to_string()), 4.2106f64);
-    exchange_rates.insert(Exchange::USD("2021-10-19".to_string()), 3.9212f64);
-    exchange_rates.insert(Exchange::USD("2019-09-06".to_string()), 3.9315f64);
-    exchange_rates.insert(Exchange::USD("2019-07-15".to_string()), 3.7856f64);
-    exchange_rates.insert(Exchange::USD("2017-03-31".to_string()), 3.9455f64);
-    exchange_rates.insert(Exchange::USD("2016-11-21".to_string()), 4.1748f64);
-    exchange_rates.insert(Exchange::USD("2015-11-30".to_string()), 4.0304f64);
-    exchange_rates.insert(Exchange::USD("2024-11-07".to_string()), 4.0414f64);
-    exchange_rates.insert(Exchange::USD("2022-05-09".to_string()), 4.4784f64);
-    exchange_rates.insert(Exchange::USD("2012-10-30".to_string()), 3.2036f64);
-    exchange_rates.insert(Exchange::USD("2019-11-22".to_string()), 3.8853f64);
-    exchange_rates.insert(Exchange::USD("2014-02-24".to_string()), 3.0255f64);
-    exchange_rates.insert(Exchange::USD("2018-07-25".to_string()), 3.6793f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-17".to_string()), 4.2559f64);
-    exchange_rates.insert(Exchange::USD("2017-05-19".to_string()), 3.7735f64);
-    exchange_rates.insert(Exchange::USD("2015-08-07".to_string()), 3.8326f64);
-    exchange_rates.insert(Exchange::USD("2025-04-16".to_string()), 3.7851f64);
-    exchange_rates.insert(Exchange::USD("2021-09-13".to_string()), 3.8495f64);
-    exchange_rates.insert(Exchange::USD("2016-05-09".to_string()), 3.873f64);
-    exchange_rates.insert(Exchange::USD("2022-02-02".to_string()), 4.0283f64);
-    exchange_rates.insert(Exchange::USD("2022-10-26".to_string()), 4.7538f64);
-    exchange_rates.insert(Exchange::USD("2020-04-01".to_string()), 4.1701f64);
-    exchange_rates.insert(Exchange::USD("2020-09-08".to_string()), 3.7652f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-08".to_string()), 4.7018f64);
-    exchange_rates.insert(Exchange::USD("2019-06-24".to_string()), 3.736f64);
-    exchange_rates.insert(Exchange::USD("2021-01-21".to_string()), 3.7312f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-02".to_string()), 4.5892f64);
-    exchange_rates.insert(Exchange::USD("2018-01-23".to_string()), 3.4084f64);
-    exchange_rates.insert(Exchange::USD("2019-11-07".to_string()), 3.8502f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-26".to_string()), 4.1394f64);
-    exchange_rates.insert(Exchange::USD("2019-08-05".to_string()), 3.8739f64);
-    exchange_rates.insert(Exchange::USD("2025-03-12".to_string()), 3.8496f64);
-    exchange_rates.insert(Exchange::USD("2019-05-07".to_string()), 3.8278f64);
-    exchange_rates.insert(Exchange::USD("2017-05-22".to_string()), 3.7526f64);
-    exchange_rates.insert(Exchange::USD("2020-03-04".to_string()), 3.8536f64);
-    exchange_rates.insert(Exchange::USD("2017-04-13".to_string()), 3.9886f64);
-    exchange_rates.insert(Exchange::USD("2017-01-13".to_string()), 4.1084f64);
-    exchange_rates.insert(Exchange::USD("2019-07-24".to_string()), 3.8229f64);
-    exchange_rates.insert(Exchange::USD("2017-10-17".to_string()), 3.5964f64);
-    exchange_rates.insert(Exchange::USD("2015-12-16".to_string()), 3.9644f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-04".to_string()), 4.2575f64);
-    exchange_rates.insert(Exchange::USD("2024-08-16".to_string()), 3.8914f64);
-    exchange_rates.insert(Exchange::USD("2023-01-26".to_string()), 4.3354f64);
-    exchange_rates.insert(Exchange::USD("2019-01-07".to_string()), 3.7575f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-05".to_string()), 4.4764f64);
-    exchange_rates.insert(Exchange::USD("2022-11-08".to_string()), 4.6893f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-30".to_string()), 4.2778f64);
-    exchange_rates.insert(Exchange::USD("2017-10-03".to_string()), 3.6737f64);
-    exchange_rates.insert(Exchange::USD("2017-12-12".to_string()), 3.5708f64);
-    exchange_rates.insert(Exchange::USD("2020-08-11".to_string()), 3.739f64);
-    exchange_rates.insert(Exchange::USD("2023-03-16".to_string()), 4.4248f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-12".to_string()), 4.4366f64);
-    exchange_rates.insert(Exchange::USD("2020-09-10".to_string()), 3.761f64);
-    exchange_rates.insert(Exchange::USD("2017-04-06".to_string()), 3.9792f64);
-    exchange_rates.insert(Exchange::USD("2013-12-03".to_string()), 3.0973f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-18".to_string()), 4.2717f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-03".to_string()), 4.68f64);
-    exchange_rates.insert(Exchange::USD("2020-08-03".to_string()), 3.7499f64);
-    exchange_rates.insert(Exchange::USD("2015-09-03".to_string()), 3.7645f64);
-    exchange_rates.insert(Exchange::USD("2023-07-27".to_string()), 3.9702f64);
-    exchange_rates.insert(Exchange::USD("2017-05-17".to_string()), 3.7808f64);
-    exchange_rates.insert(Exchange::USD("2015-06-30".to_string()), 3.7645f64);
-    exchange_rates.insert(Exchange::USD("2013-09-25".to_string()), 3.1234f64);
-    exchange_rates.insert(Exchange::USD("2024-10-25".to_string()), 4.0219f64);
-    exchange_rates.insert(Exchange::USD("2022-09-12".to_string()), 4.6225f64);
-    exchange_rates.insert(Exchange::USD("2017-08-30".to_string()), 3.5678f64);
-    exchange_rates.insert(Exchange::USD("2018-04-17".to_string()), 3.3572f64);
-    exchange_rates.insert(Exchange::USD("2021-02-02".to_string()), 3.7311f64);
-    exchange_rates.insert(Exchange::USD("2017-12-27".to_string()), 3.5253f64);
-    exchange_rates.insert(Exchange::USD("2012-03-15".to_string()), 3.1723f64);
-    exchange_rates.insert(Exchange::USD("2025-05-05".to_string()), 3.7752f64);
-    exchange_rates.insert(Exchange::USD("2018-05-30".to_string()), 3.72f64);
-    exchange_rates.insert(Exchange::USD("2019-01-17".to_string()), 3.7615f64);
-    exchange_rates.insert(Exchange::USD("2023-04-07".to_string()), 4.2932f64);
-    exchange_rates.insert(Exchange::USD("2025-04-04".to_string()), 3.856f64);
-    exchange_rates.insert(Exchange::USD("2017-05-15".to_string()), 3.8463f64);
-    exchange_rates.insert(Exchange::USD("2019-10-28".to_string()), 3.8529f64);
-    exchange_rates.insert(Exchange::USD("2017-08-03".to_string()), 3.5918f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-20".to_string()), 4.4386f64);
-    exchange_rates.insert(Exchange::USD("2013-10-28".to_string()), 3.0271f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-05".to_string()), 4.2883f64);
-    exchange_rates.insert(Exchange::USD("2018-06-01".to_string()), 3.6934f64);
-    exchange_rates.insert(Exchange::USD("2016-01-05".to_string()), 4.0073f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-04".to_string()), 4.313f64);
-    exchange_rates.insert(Exchange::USD("2016-07-19".to_string()), 3.9582f64);
-    exchange_rates.insert(Exchange::USD("2017-11-16".to_string()), 3.5958f64);
-    exchange_rates.insert(Exchange::USD("2016-12-22".to_string()), 4.2222f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-11".to_string()), 4.2626f64);
-    exchange_rates.insert(Exchange::USD("2014-02-17".to_string()), 3.025f64);
-    exchange_rates.insert(Exchange::USD("2025-11-17".to_string()), 3.64f64);
-    exchange_rates.insert(Exchange::USD("2015-05-27".to_string()), 3.7906f64);
-    exchange_rates.insert(Exchange::USD("2015-09-28".to_string()), 3.7861f64);
-    exchange_rates.insert(Exchange::USD("2013-06-21".to_string()), 3.2743f64);
-    exchange_rates.insert(Exchange::USD("2012-10-22".to_string()), 3.147f64);
-    exchange_rates.insert(Exchange::USD("2017-05-10".to_string()), 3.8777f64);
-    exchange_rates.insert(Exchange::USD("2016-06-08".to_string()), 3.815f64);
-    exchange_rates.insert(Exchange::USD("2012-11-30".to_string()), 3.1585f64);
-    exchange_rates.insert(Exchange::USD("2023-06-21".to_string()), 4.0648f64);
-    exchange_rates.insert(Exchange::USD("2025-01-24".to_string()), 4.0124f64);
-    exchange_rates.insert(Exchange::USD("2025-07-25".to_string()), 3.6228f64);
-    exchange_rates.insert(Exchange::USD("2023-01-20".to_string()), 4.352f64);
-    exchange_rates.insert(Exchange::USD("2021-10-22".to_string()), 3.9576f64);
-    exchange_rates.insert(Exchange::USD("2022-10-12".to_string()), 5.0014f64);
-    exchange_rates.insert(Exchange::USD("2025-03-31".to_string()), 3.8643f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-21".to_string()), 4.3178f64);
-    exchange_rates.insert(Exchange::USD("2016-09-07".to_string()), 3.8515f64);
-    exchange_rates.insert(Exchange::USD("2021-12-31".to_string()), 4.06f64);
-    exchange_rates.insert(Exchange::USD("2021-11-09".to_string()), 3.9622f64);
-    exchange_rates.insert(Exchange::USD("2020-10-01".to_string()), 3.8513f64);
-    exchange_rates.insert(Exchange::USD("2014-12-11".to_string()), 3.3611f64);
-    exchange_rates.insert(Exchange::USD("2012-02-16".to_string()), 3.2502f64);
-    exchange_rates.insert(Exchange::USD("2013-07-08".to_string()), 3.3576f64);
-    exchange_rates.insert(Exchange::USD("2012-12-13".to_string()), 3.1333f64);
-    exchange_rates.insert(Exchange::USD("2012-06-18".to_string()), 3.3741f64);
-    exchange_rates.insert(Exchange::USD("2013-08-02".to_string()), 3.2201f64);
-    exchange_rates.insert(Exchange::USD("2012-04-18".to_string()), 3.1902f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-19".to_string()), 4.6458f64);
-    exchange_rates.insert(Exchange::USD("2014-03-24".to_string()), 3.0483f64);
-    exchange_rates.insert(Exchange::USD("2024-05-28".to_string()), 3.9183f64);
-    exchange_rates.insert(Exchange::USD("2014-05-20".to_string()), 3.062f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-22".to_string()), 4.3804f64);
-    exchange_rates.insert(Exchange::USD("2022-04-19".to_string()), 4.2887f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-21".to_string()), 4.1979f64);
-    exchange_rates.insert(Exchange::USD("2023-08-18".to_string()), 4.1285f64);
-    exchange_rates.insert(Exchange::USD("2025-02-24".to_string()), 3.9734f64);
-    exchange_rates.insert(Exchange::USD("2014-03-10".to_string()), 3.0266f64);
-    exchange_rates.insert(Exchange::USD("2020-11-19".to_string()), 3.7872f64);
-    exchange_rates.insert(Exchange::USD("2012-11-16".to_string()), 3.2682f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-07".to_string()), 4.4279f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-01".to_string()), 4.2286f64);
-    exchange_rates.insert(Exchange::USD("2021-04-29".to_string()), 3.7786f64);
-    exchange_rates.insert(Exchange::USD("2024-01-19".to_string()), 4.0289f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-28".to_string()), 4.3191f64);
-    exchange_rates.insert(Exchange::USD("2023-01-04".to_string()), 4.3998f64);
-    exchange_rates.insert(Exchange::USD("2017-02-15".to_string()), 4.0752f64);
-    exchange_rates.insert(Exchange::USD("2017-01-26".to_string()), 4.059f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-23".to_string()), 4.2785f64);
-    exchange_rates.insert(Exchange::USD("2012-07-05".to_string()), 3.3743f64);
-    exchange_rates.insert(Exchange::USD("2018-07-17".to_string()), 3.6619f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-15".to_string()), 4.2476f64);
-    exchange_rates.insert(Exchange::USD("2023-10-26".to_string()), 4.2395f64);
-    exchange_rates.insert(Exchange::USD("2014-07-09".to_string()), 3.0322f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-16".to_string()), 4.2392f64);
-    exchange_rates.insert(Exchange::USD("2018-11-16".to_string()), 3.7876f64);
-    exchange_rates.insert(Exchange::USD("2018-01-04".to_string()), 3.4472f64);
-    exchange_rates.insert(Exchange::USD("2015-05-14".to_string()), 3.5921f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-04".to_string()), 4.5868f64);
-    exchange_rates.insert(Exchange::USD("2023-09-20".to_string()), 4.3501f64);
-    exchange_rates.insert(Exchange::USD("2019-04-17".to_string()), 3.7778f64);
-    exchange_rates.insert(Exchange::USD("2013-06-13".to_string()), 3.2f64);
-    exchange_rates.insert(Exchange::USD("2014-12-02".to_string()), 3.3466f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-27".to_string()), 4.2747f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-04".to_string()), 4.4273f64);
-    exchange_rates.insert(Exchange::USD("2014-12-31".to_string()), 3.5072f64);
-    exchange_rates.insert(Exchange::USD("2015-02-06".to_string()), 3.6395f64);
-    exchange_rates.insert(Exchange::USD("2016-11-29".to_string()), 4.1827f64);
-    exchange_rates.insert(Exchange::USD("2014-01-30".to_string()), 3.1166f64);
-    exchange_rates.insert(Exchange::USD("2017-02-07".to_string()), 4.028f64);
-    exchange_rates.insert(Exchange::USD("2013-10-04".to_string()), 3.0902f64);
-    exchange_rates.insert(Exchange::USD("2018-07-31".to_string()), 3.6481f64);
-    exchange_rates.insert(Exchange::USD("2014-02-28".to_string()), 3.0254f64);
-    exchange_rates.insert(Exchange::USD("2024-08-28".to_string()), 3.8539f64);
-    exchange_rates.insert(Exchange::USD("2022-11-14".to_string()), 4.5383f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-30".to_string()), 4.2507f64);
-    exchange_rates.insert(Exchange::USD("2025-03-07".to_string()), 3.8448f64);
-    exchange_rates.insert(Exchange::USD("2025-12-31".to_string()), 3.6016f64);
-    exchange_rates.insert(Exchange::USD("2024-01-29".to_string()), 4.0326f64);
-    exchange_rates.insert(Exchange::USD("2024-06-11".to_string()), 4.0443f64);
-    exchange_rates.insert(Exchange::USD("2020-07-30".to_string()), 3.7549f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-01".to_string()), 4.2586f64);
-    exchange_rates.insert(Exchange::USD("2014-02-19".to_string()), 3.0286f64);
-    exchange_rates.insert(Exchange::USD("2019-06-27".to_string()), 3.7426f64);
-    exchange_rates.insert(Exchange::USD("2022-01-03".to_string()), 4.0424f64);
-    exchange_rates.insert(Exchange::USD("2023-10-16".to_string()), 4.2505f64);
-    exchange_rates.insert(Exchange::USD("2014-05-22".to_string()), 3.0525f64);
-    exchange_rates.insert(Exchange::USD("2025-04-07".to_string()), 3.9097f64);
-    exchange_rates.insert(Exchange::USD("2023-08-30".to_string()), 4.1167f64);
-    exchange_rates.insert(Exchange::USD("2023-11-27".to_string()), 3.9769f64);
-    exchange_rates.insert(Exchange::USD("2012-08-22".to_string()), 3.2639f64);
-    exchange_rates.insert(Exchange::USD("2023-01-03".to_string()), 4.4373f64);
-    exchange_rates.insert(Exchange::USD("2018-04-11".to_string()), 3.3859f64);
-    exchange_rates.insert(Exchange::USD("2022-08-09".to_string()), 4.5919f64);
-    exchange_rates.insert(Exchange::USD("2020-09-29".to_string()), 3.9228f64);
-    exchange_rates.insert(Exchange::USD("2014-06-11".to_string()), 3.0431f64);
-    exchange_rates.insert(Exchange::USD("2018-03-05".to_string()), 3.3995f64);
-    exchange_rates.insert(Exchange::USD("2015-09-08".to_string()), 3.7878f64);
-    exchange_rates.insert(Exchange::USD("2022-06-17".to_string()), 4.463f64);
-    exchange_rates.insert(Exchange::USD("2022-12-22".to_string()), 4.3726f64);
-    exchange_rates.insert(Exchange::USD("2019-09-04".to_string()), 3.9414f64);
-    exchange_rates.insert(Exchange::USD("2016-05-27".to_string()), 3.9393f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-25".to_string()), 4.4674f64);
-    exchange_rates.insert(Exchange::USD("2019-07-02".to_string()), 3.7593f64);
-    exchange_rates.insert(Exchange::USD("2017-03-27".to_string()), 3.9259f64);
-    exchange_rates.insert(Exchange::USD("2024-03-28".to_string()), 4.0081f64);
-    exchange_rates.insert(Exchange::USD("2021-01-07".to_string()), 3.6656f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-18".to_string()), 4.4869f64);
-    exchange_rates.insert(Exchange::USD("2024-03-06".to_string()), 3.963f64);
-    exchange_rates.insert(Exchange::USD("2025-04-14".to_string()), 3.7564f64);
-    exchange_rates.insert(Exchange::USD("2021-10-29".to_string()), 3.9664f64);
-    exchange_rates.insert(Exchange::USD("2021-10-13".to_string()), 3.9638f64);
-    exchange_rates.insert(Exchange::USD("2012-04-26".to_string()), 3.1599f64);
-    exchange_rates.insert(Exchange::USD("2012-10-16".to_string()), 3.1438f64);
-    exchange_rates.insert(Exchange::USD("2023-08-04".to_string()), 4.062f64);
-    exchange_rates.insert(Exchange::USD("2018-09-07".to_string()), 3.6995f64);
-    exchange_rates.insert(Exchange::USD("2013-09-10".to_string()), 3.2181f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-05".to_string()), 4.2321f64);
-    exchange_rates.insert(Exchange::USD("2018-07-09".to_string()), 3.685f64);
-    exchange_rates.insert(Exchange::USD("2022-08-03".to_string()), 4.6164f64);
-    exchange_rates.insert(Exchange::USD("2015-01-09".to_string()), 3.6252f64);
-    exchange_rates.insert(Exchange::USD("2024-08-19".to_string()), 3.8682f64);
-    exchange_rates.insert(Exchange::USD("2015-08-24".to_string()), 3.6971f64);
-    exchange_rates.insert(Exchange::USD("2021-12-07".to_string()), 4.0788f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-23".to_string()), 4.4367f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-22".to_string()), 4.3199f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-28".to_string()), 4.717f64);
-    exchange_rates.insert(Exchange::USD("2016-03-17".to_string()), 3.8037f64);
-    exchange_rates.insert(Exchange::USD("2023-11-30".to_string()), 3.981f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-24".to_string()), 4.3396f64);
-    exchange_rates.insert(Exchange::USD("2016-06-15".to_string()), 3.9351f64);
-    exchange_rates.insert(Exchange::USD("2025-05-29".to_string()), 3.7588f64);
-    exchange_rates.insert(Exchange::USD("2012-01-30".to_string()), 3.2433f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-29".to_string()), 4.2847f64);
-    exchange_rates.insert(Exchange::USD("2025-01-09".to_string()), 4.1523f64);
-    exchange_rates.insert(Exchange::USD("2020-04-29".to_string()), 4.1807f64);
-    exchange_rates.insert(Exchange::USD("2019-04-26".to_string()), 3.8508f64);
-    exchange_rates.insert(Exchange::USD("2013-08-27".to_string()), 3.1849f64);
-    exchange_rates.insert(Exchange::USD("2022-03-23".to_string()), 4.2772f64);
-    exchange_rates.insert(Exchange::USD("2014-12-23".to_string()), 3.4937f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-11".to_string()), 4.2926f64);
-    exchange_rates.insert(Exchange::USD("2015-03-17".to_string()), 3.9034f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-02".to_string()), 4.6091f64);
-    exchange_rates.insert(Exchange::USD("2024-06-25".to_string()), 3.9975f64);
-    exchange_rates.insert(Exchange::USD("2019-05-17".to_string()), 3.8545f64);
-    exchange_rates.insert(Exchange::USD("2021-02-26".to_string()), 3.7247f64);
-    exchange_rates.insert(Exchange::USD("2023-06-22".to_string()), 4.033f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-05".to_string()), 4.3081f64);
-    exchange_rates.insert(Exchange::USD("2017-09-08".to_string()), 3.5239f64);
-    exchange_rates.insert(Exchange::USD("2024-12-09".to_string()), 4.0298f64);
-    exchange_rates.insert(Exchange::USD("2018-06-20".to_string()), 3.7352f64);
-    exchange_rates.insert(Exchange::USD("2017-09-27".to_string()), 3.665f64);
-    exchange_rates.insert(Exchange::USD("2021-10-05".to_string()), 3.9688f64);
-    exchange_rates.insert(Exchange::USD("2019-01-03".to_string()), 3.7827f64);
-    exchange_rates.insert(Exchange::USD("2017-07-21".to_string()), 3.632f64);
-    exchange_rates.insert(Exchange::USD("2025-06-18".to_string()), 3.7137f64);
-    exchange_rates.insert(Exchange::USD("2017-08-04".to_string()), 3.5702f64);
-    exchange_rates.insert(Exchange::USD("2014-03-25".to_string()), 3.0342f64);
-    exchange_rates.insert(Exchange::USD("2014-10-08".to_string()), 3.305f64);
-    exchange_rates.insert(Exchange::USD("2013-03-14".to_string()), 3.2f64);
-    exchange_rates.insert(Exchange::USD("2013-04-26".to_string()), 3.1943f64);
-    exchange_rates.insert(Exchange::USD("2013-09-24".to_string()), 3.1338f64);
-    exchange_rates.insert(Exchange::USD("2012-08-07".to_string()), 3.2606f64);
-    exchange_rates.insert(Exchange::USD("2020-02-14".to_string()), 3.9098f64);
-    exchange_rates.insert(Exchange::USD("2025-05-08".to_string()), 3.784f64);
-    exchange_rates.insert(Exchange::USD("2015-06-03".to_string()), 3.7108f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-06".to_string()), 4.279f64);
-    exchange_rates.insert(Exchange::USD("2014-10-06".to_string()), 3.3343f64);
-    exchange_rates.insert(Exchange::USD("2012-12-20".to_string()), 3.0769f64);
-    exchange_rates.insert(Exchange::USD("2020-12-02".to_string()), 3.7038f64);
-    exchange_rates.insert(Exchange::USD("2017-03-24".to_string()), 3.9481f64);
-    exchange_rates.insert(Exchange::USD("2016-11-25".to_string()), 4.1628f64);
-    exchange_rates.insert(Exchange::USD("2025-04-30".to_string()), 3.7617f64);
-    exchange_rates.insert(Exchange::USD("2021-07-01".to_string()), 3.8149f64);
-    exchange_rates.insert(Exchange::USD("2012-06-19".to_string()), 3.3916f64);
-    exchange_rates.insert(Exchange::USD("2023-08-31".to_string()), 4.1074f64);
-    exchange_rates.insert(Exchange::USD("2017-09-26".to_string()), 3.6234f64);
-    exchange_rates.insert(Exchange::USD("2014-06-25".to_string()), 3.0451f64);
-    exchange_rates.insert(Exchange::USD("2012-10-15".to_string()), 3.1565f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-15".to_string()), 4.2767f64);
-    exchange_rates.insert(Exchange::USD("2017-06-13".to_string()), 3.7379f64);
-    exchange_rates.insert(Exchange::USD("2020-02-03".to_string()), 3.8864f64);
-    exchange_rates.insert(Exchange::USD("2012-07-12".to_string()), 3.4383f64);
-    exchange_rates.insert(Exchange::USD("2012-12-05".to_string()), 3.1516f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-06".to_string()), 4.282f64);
-    exchange_rates.insert(Exchange::USD("2016-05-24".to_string()), 3.9789f64);
-    exchange_rates.insert(Exchange::USD("2020-11-03".to_string()), 3.9069f64);
-    exchange_rates.insert(Exchange::USD("2014-07-14".to_string()), 3.0368f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-14".to_string()), 4.2274f64);
-    exchange_rates.insert(Exchange::USD("2024-09-26".to_string()), 3.8294f64);
-    exchange_rates.insert(Exchange::USD("2022-09-20".to_string()), 4.7118f64);
-    exchange_rates.insert(Exchange::USD("2014-10-01".to_string()), 3.3172f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-18".to_string()), 4.3269f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-21".to_string()), 4.3387f64);
-    exchange_rates.insert(Exchange::USD("2016-03-03".to_string()), 3.9805f64);
-    exchange_rates.insert(Exchange::USD("2015-06-08".to_string()), 3.7381f64);
-    exchange_rates.insert(Exchange::USD("2019-10-09".to_string()), 3.9382f64);
-    exchange_rates.insert(Exchange::USD("2020-06-30".to_string()), 3.9806f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-26".to_string()), 4.1686f64);
-    exchange_rates.insert(Exchange::USD("2014-11-10".to_string()), 3.3765f64);
-    exchange_rates.insert(Exchange::USD("2024-04-18".to_string()), 4.0559f64);
-    exchange_rates.insert(Exchange::USD("2018-01-31".to_string()), 3.3318f64);
-    exchange_rates.insert(Exchange::USD("2023-04-05".to_string()), 4.2739f64);
-    exchange_rates.insert(Exchange::USD("2015-04-20".to_string()), 3.7302f64);
-    exchange_rates.insert(Exchange::USD("2023-05-02".to_string()), 4.1823f64);
-    exchange_rates.insert(Exchange::USD("2013-01-22".to_string()), 3.1341f64);
-    exchange_rates.insert(Exchange::USD("2020-04-14".to_string()), 4.1666f64);
-    exchange_rates.insert(Exchange::USD("2013-10-21".to_string()), 3.062f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-20".to_string()), 4.4511f64);
-    exchange_rates.insert(Exchange::USD("2023-05-18".to_string()), 4.1929f64);
-    exchange_rates.insert(Exchange::USD("2023-08-29".to_string()), 4.1341f64);
-    exchange_rates.insert(Exchange::USD("2018-03-30".to_string()), 3.4139f64);
-    exchange_rates.insert(Exchange::USD("2012-09-18".to_string()), 3.1509f64);
-    exchange_rates.insert(Exchange::USD("2014-01-07".to_string()), 3.0688f64);
-    exchange_rates.insert(Exchange::USD("2022-05-31".to_string()), 4.2651f64);
-    exchange_rates.insert(Exchange::USD("2020-02-10".to_string()), 3.8996f64);
-    exchange_rates.insert(Exchange::USD("2022-12-19".to_string()), 4.4153f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-08".to_string()), 4.3303f64);
-    exchange_rates.insert(Exchange::USD("2022-05-30".to_string()), 4.2617f64);
-    exchange_rates.insert(Exchange::USD("2021-04-14".to_string()), 3.8065f64);
-    exchange_rates.insert(Exchange::USD("2018-01-25".to_string()), 3.3376f64);
-    exchange_rates.insert(Exchange::USD("2012-01-10".to_string()), 3.5083f64);
-    exchange_rates.insert(Exchange::USD("2018-02-27".to_string()), 3.3816f64);
-    exchange_rates.insert(Exchange::USD("2016-08-31".to_string()), 3.9082f64);
-    exchange_rates.insert(Exchange::USD("2015-08-27".to_string()), 3.745f64);
-    exchange_rates.insert(Exchange::USD("2025-08-19".to_string()), 3.6332f64);
-    exchange_rates.insert(Exchange::USD("2022-07-28".to_string()), 4.6952f64);
-    exchange_rates.insert(Exchange::USD("2019-09-02".to_string()), 3.9819f64);
-    exchange_rates.insert(Exchange::USD("2018-05-25".to_string()), 3.6686f64);
-    exchange_rates.insert(Exchange::USD("2024-04-08".to_string()), 3.9546f64);
-    exchange_rates.insert(Exchange::USD("2023-10-31".to_string()), 4.1684f64);
-    exchange_rates.insert(Exchange::USD("2019-05-13".to_string()), 3.8321f64);
-    exchange_rates.insert(Exchange::USD("2017-11-30".to_string()), 3.5543f64);
-    exchange_rates.insert(Exchange::USD("2018-04-27".to_string()), 3.5003f64);
-    exchange_rates.insert(Exchange::USD("2014-06-16".to_string()), 3.0654f64);
-    exchange_rates.insert(Exchange::USD("2016-08-02".to_string()), 3.882f64);
-    exchange_rates.insert(Exchange::USD("2012-07-20".to_string()), 3.3961f64);
-    exchange_rates.insert(Exchange::USD("2020-03-18".to_string()), 4.0749f64);
-    exchange_rates.insert(Exchange::USD("2014-08-29".to_string()), 3.1965f64);
-    exchange_rates.insert(Exchange::USD("2021-10-06".to_string()), 3.9992f64);
-    exchange_rates.insert(Exchange::USD("2021-11-23".to_string()), 4.1893f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-12".to_string()), 4.3123f64);
-    exchange_rates.insert(Exchange::USD("2018-09-28".to_string()), 3.6754f64);
-    exchange_rates.insert(Exchange::USD("2012-01-19".to_string()), 3.3678f64);
-    exchange_rates.insert(Exchange::USD("2019-09-12".to_string()), 3.9362f64);
-    exchange_rates.insert(Exchange::USD("2014-10-14".to_string()), 3.3095f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-01".to_string()), 4.1818f64);
-    exchange_rates.insert(Exchange::USD("2018-09-06".to_string()), 3.7143f64);
-    exchange_rates.insert(Exchange::USD("2020-05-06".to_string()), 4.2022f64);
-    exchange_rates.insert(Exchange::USD("2022-11-22".to_string()), 4.5844f64);
-    exchange_rates.insert(Exchange::USD("2012-02-08".to_string()), 3.1461f64);
-    exchange_rates.insert(Exchange::USD("2013-08-29".to_string()), 3.2269f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-04".to_string()), 4.3337f64);
-    exchange_rates.insert(Exchange::USD("2019-04-12".to_string()), 3.7899f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-27".to_string()), 4.2761f64);
-    exchange_rates.insert(Exchange::USD("2016-01-29".to_string()), 4.0723f64);
-    exchange_rates.insert(Exchange::USD("2013-07-30".to_string()), 3.1798f64);
-    exchange_rates.insert(Exchange::USD("2014-10-24".to_string()), 3.3387f64);
-    exchange_rates.insert(Exchange::USD("2015-01-07".to_string()), 3.6375f64);
-    exchange_rates.insert(Exchange::USD("2012-06-20".to_string()), 3.3471f64);
-    exchange_rates.insert(Exchange::USD("2018-04-03".to_string()), 3.4177f64);
-    exchange_rates.insert(Exchange::USD("2014-01-15".to_string()), 3.0495f64);
-    exchange_rates.insert(Exchange::USD("2018-05-14".to_string()), 3.5558f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-05".to_string()), 4.2059f64);
-    exchange_rates.insert(Exchange::USD("2023-10-17".to_string()), 4.2131f64);
-    exchange_rates.insert(Exchange::USD("2021-12-21".to_string()), 4.0937f64);
-    exchange_rates.insert(Exchange::USD("2020-08-20".to_string()), 3.7164f64);
-    exchange_rates.insert(Exchange::USD("2022-04-08".to_string()), 4.2703f64);
-    exchange_rates.insert(Exchange::USD("2017-05-09".to_string()), 3.8741f64);
-    exchange_rates.insert(Exchange::USD("2016-07-28".to_string()), 3.9389f64);
-    exchange_rates.insert(Exchange::USD("2013-09-09".to_string()), 3.2357f64);
-    exchange_rates.insert(Exchange::USD("2024-06-03".to_string()), 3.9501f64);
-    exchange_rates.insert(Exchange::USD("2025-07-28".to_string()), 3.6446f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-27".to_string()), 4.2639f64);
-    exchange_rates.insert(Exchange::USD("2016-07-21".to_string()), 3.9685f64);
-    exchange_rates.insert(Exchange::USD("2012-04-25".to_string()), 3.1675f64);
-    exchange_rates.insert(Exchange::USD("2025-11-04".to_string()), 3.7019f64);
-    exchange_rates.insert(Exchange::USD("2024-07-04".to_string()), 3.9784f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-22".to_string()), 4.5269f64);
-    exchange_rates.insert(Exchange::USD("2021-04-09".to_string()), 3.8208f64);
-    exchange_rates.insert(Exchange::USD("2021-07-22".to_string()), 3.8801f64);
-    exchange_rates.insert(Exchange::USD("2023-07-12".to_string()), 4.0347f64);
-    exchange_rates.insert(Exchange::USD("2024-11-06".to_string()), 4.0595f64);
-    exchange_rates.insert(Exchange::USD("2013-10-30".to_string()), 3.0433f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-03".to_string()), 4.4321f64);
-    exchange_rates.insert(Exchange::USD("2018-11-15".to_string()), 3.8074f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-03".to_string()), 4.1791f64);
-    exchange_rates.insert(Exchange::USD("2019-02-08".to_string()), 3.804f64);
-    exchange_rates.insert(Exchange::USD("2025-07-07".to_string()), 3.6269f64);
-    exchange_rates.insert(Exchange::USD("2016-04-13".to_string()), 3.7771f64);
-    exchange_rates.insert(Exchange::USD("2015-04-17".to_string()), 3.7277f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-24".to_string()), 4.2668f64);
-    exchange_rates.insert(Exchange::USD("2013-10-15".to_string()), 3.0893f64);
-    exchange_rates.insert(Exchange::USD("2020-02-21".to_string()), 3.9623f64);
-    exchange_rates.insert(Exchange::USD("2012-02-23".to_string()), 3.138f64);
-    exchange_rates.insert(Exchange::USD("2019-12-30".to_string()), 3.8027f64);
-    exchange_rates.insert(Exchange::USD("2013-10-18".to_string()), 3.0506f64);
-    exchange_rates.insert(Exchange::USD("2014-10-22".to_string()), 3.3275f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-10".to_string()), 4.2657f64);
-    exchange_rates.insert(Exchange::USD("2015-02-19".to_string()), 3.6631f64);
-    exchange_rates.insert(Exchange::USD("2016-01-14".to_string()), 3.999f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-10".to_string()), 4.2611f64);
-    exchange_rates.insert(Exchange::USD("2020-06-19".to_string()), 3.9741f64);
-    exchange_rates.insert(Exchange::USD("2017-02-03".to_string()), 4.0014f64);
-    exchange_rates.insert(Exchange::USD("2012-12-19".to_string()), 3.069f64);
-    exchange_rates.insert(Exchange::USD("2014-08-18".to_string()), 3.1342f64);
-    exchange_rates.insert(Exchange::USD("2023-03-13".to_string()), 4.3906f64);
-    exchange_rates.insert(Exchange::USD("2015-10-20".to_string()), 3.7385f64);
-    exchange_rates.insert(Exchange::USD("2016-12-09".to_string()), 4.1772f64);
-    exchange_rates.insert(Exchange::USD("2025-01-08".to_string()), 4.1335f64);
-    exchange_rates.insert(Exchange::USD("2022-11-09".to_string()), 4.6817f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-04".to_string()), 4.2299f64);
-    exchange_rates.insert(Exchange::USD("2012-07-30".to_string()), 3.378f64);
-    exchange_rates.insert(Exchange::USD("2013-02-18".to_string()), 3.141f64);
-    exchange_rates.insert(Exchange::USD("2025-05-14".to_string()), 3.7775f64);
-    exchange_rates.insert(Exchange::USD("2023-06-30".to_string()), 4.1066f64);
-    exchange_rates.insert(Exchange::USD("2021-02-19".to_string()), 3.6997f64);
-    exchange_rates.insert(Exchange::USD("2017-09-06".to_string()), 3.5538f64);
-    exchange_rates.insert(Exchange::USD("2015-08-12".to_string()), 3.7805f64);
-    exchange_rates.insert(Exchange::USD("2025-11-20".to_string()), 3.6684f64);
-    exchange_rates.insert(Exchange::USD("2024-08-22".to_string()), 3.8456f64);
-    exchange_rates.insert(Exchange::USD("2018-03-13".to_string()), 3.4143f64);
-    exchange_rates.insert(Exchange::USD("2016-09-27".to_string()), 3.8227f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-28".to_string()), 4.3542f64);
-    exchange_rates.insert(Exchange::USD("2025-08-06".to_string()), 3.6994f64);
-    exchange_rates.insert(Exchange::USD("2021-06-01".to_string()), 3.659f64);
-    exchange_rates.insert(Exchange::USD("2024-11-28".to_string()), 4.0907f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-09".to_string()), 4.3167f64);
-    exchange_rates.insert(Exchange::USD("2024-09-18".to_string()), 3.8358f64);
-    exchange_rates.insert(Exchange::USD("2019-02-13".to_string()), 3.8261f64);
-    exchange_rates.insert(Exchange::USD("2021-07-19".to_string()), 3.8968f64);
-    exchange_rates.insert(Exchange::USD("2025-05-09".to_string()), 3.7681f64);
-    exchange_rates.insert(Exchange::USD("2023-11-10".to_string()), 4.1423f64);
-    exchange_rates.insert(Exchange::USD("2024-08-02".to_string()), 3.9672f64);
-    exchange_rates.insert(Exchange::USD("2015-08-06".to_string()), 3.8385f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-19".to_string()), 4.3308f64);
-    exchange_rates.insert(Exchange::USD("2021-01-13".to_string()), 3.7142f64);
-    exchange_rates.insert(Exchange::USD("2019-09-20".to_string()), 3.9319f64);
-    exchange_rates.insert(Exchange::USD("2013-10-31".to_string()), 3.0507f64);
-    exchange_rates.insert(Exchange::USD("2013-04-02".to_string()), 3.2552f64);
-    exchange_rates.insert(Exchange::USD("2021-03-31".to_string()), 3.9676f64);
-    exchange_rates.insert(Exchange::USD("2020-11-02".to_string()), 3.9557f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-04".to_string()), 4.2975f64);
-    exchange_rates.insert(Exchange::USD("2020-02-25".to_string()), 3.9624f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-29".to_string()), 4.3009f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-09".to_string()), 4.3026f64);
-    exchange_rates.insert(Exchange::USD("2018-01-09".to_string()), 3.4992f64);
-    exchange_rates.insert(Exchange::USD("2018-09-25".to_string()), 3.6511f64);
-    exchange_rates.insert(Exchange::USD("2013-01-10".to_string()), 3.1121f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-26".to_string()), 4.3157f64);
-    exchange_rates.insert(Exchange::USD("2017-06-07".to_string()), 3.7241f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-18".to_string()), 4.3468f64);
-    exchange_rates.insert(Exchange::USD("2025-12-05".to_string()), 3.6313f64);
-    exchange_rates.insert(Exchange::USD("2017-01-17".to_string()), 4.0989f64);
-    exchange_rates.insert(Exchange::USD("2018-04-24".to_string()), 3.4401f64);
-    exchange_rates.insert(Exchange::USD("2012-01-02".to_string()), 3.4454f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-21".to_string()), 4.6981f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-06".to_string()), 4.2806f64);
-    exchange_rates.insert(Exchange::USD("2020-12-09".to_string()), 3.6657f64);
-    exchange_rates.insert(Exchange::USD("2020-01-28".to_string()), 3.8844f64);
-    exchange_rates.insert(Exchange::USD("2022-09-27".to_string()), 4.9476f64);
-    exchange_rates.insert(Exchange::USD("2016-04-27".to_string()), 3.8793f64);
-    exchange_rates.insert(Exchange::USD("2014-04-15".to_string()), 3.0325f64);
-    exchange_rates.insert(Exchange::USD("2020-05-04".to_string()), 4.1646f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-24".to_string()), 4.3198f64);
-    exchange_rates.insert(Exchange::USD("2024-01-31".to_string()), 4.0135f64);
-    exchange_rates.insert(Exchange::USD("2014-09-11".to_string()), 3.2435f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-27".to_string()), 4.2479f64);
-    exchange_rates.insert(Exchange::USD("2014-12-24".to_string()), 3.529f64);
-    exchange_rates.insert(Exchange::USD("2012-05-21".to_string()), 3.3928f64);
-    exchange_rates.insert(Exchange::USD("2019-07-11".to_string()), 3.7859f64);
-    exchange_rates.insert(Exchange::USD("2023-04-24".to_string()), 4.1905f64);
-    exchange_rates.insert(Exchange::USD("2023-01-17".to_string()), 4.3398f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-15".to_string()), 4.2538f64);
-    exchange_rates.insert(Exchange::USD("2021-12-23".to_string()), 4.095f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-22".to_string()), 4.4335f64);
-    exchange_rates.insert(Exchange::USD("2023-09-15".to_string()), 4.3418f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-20".to_string()), 4.6529f64);
-    exchange_rates.insert(Exchange::USD("2021-04-30".to_string()), 3.7746f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-19".to_string()), 4.3846f64);
-    exchange_rates.insert(Exchange::USD("2020-10-12".to_string()), 3.7927f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-12".to_string()), 4.2017f64);
-    exchange_rates.insert(Exchange::USD("2025-03-21".to_string()), 3.8774f64);
-    exchange_rates.insert(Exchange::USD("2024-04-15".to_string()), 4.0209f64);
-    exchange_rates.insert(Exchange::USD("2021-04-06".to_string()), 3.8973f64);
-    exchange_rates.insert(Exchange::USD("2022-06-02".to_string()), 4.2931f64);
-    exchange_rates.insert(Exchange::USD("2013-09-11".to_string()), 3.1955f64);
-    exchange_rates.insert(Exchange::USD("2023-12-04".to_string()), 3.985f64);
-    exchange_rates.insert(Exchange::USD("2017-09-18".to_string()), 3.5881f64);
-    exchange_rates.insert(Exchange::USD("2023-08-28".to_string()), 4.14f64);
-    exchange_rates.insert(Exchange::USD("2014-08-04".to_string()), 3.1114f64);
-    exchange_rates.insert(Exchange::USD("2015-10-12".to_string()), 3.7148f64);
-    exchange_rates.insert(Exchange::USD("2017-07-19".to_string()), 3.6486f64);
-    exchange_rates.insert(Exchange::USD("2024-05-16".to_string()), 3.9195f64);
-    exchange_rates.insert(Exchange::USD("2015-03-25".to_string()), 3.7355f64);
-    exchange_rates.insert(Exchange::USD("2020-02-26".to_string()), 3.9573f64);
-    exchange_rates.insert(Exchange::USD("2019-08-13".to_string()), 3.8714f64);
-    exchange_rates.insert(Exchange::USD("2015-05-22".to_string()), 3.669f64);
-    exchange_rates.insert(Exchange::USD("2013-06-07".to_string()), 3.2545f64);
-    exchange_rates.insert(Exchange::USD("2021-02-18".to_string()), 3.7152f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-03".to_string()), 4.299f64);
-    exchange_rates.insert(Exchange::USD("2025-12-11".to_string()), 3.6132f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-01".to_string()), 4.4762f64);
-    exchange_rates.insert(Exchange::USD("2025-02-14".to_string()), 3.972f64);
-    exchange_rates.insert(Exchange::USD("2012-04-27".to_string()), 3.1666f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-18".to_string()), 4.7091f64);
-    exchange_rates.insert(Exchange::USD("2013-08-20".to_string()), 3.1761f64);
-    exchange_rates.insert(Exchange::USD("2015-12-03".to_string()), 4.04f64);
-    exchange_rates.insert(Exchange::USD("2013-12-05".to_string()), 3.087f64);
-    exchange_rates.insert(Exchange::USD("2021-03-02".to_string()), 3.7765f64);
-    exchange_rates.insert(Exchange::USD("2016-04-21".to_string()), 3.7981f64);
-    exchange_rates.insert(Exchange::USD("2025-02-07".to_string()), 4.0321f64);
-    exchange_rates.insert(Exchange::USD("2024-02-01".to_string()), 4.0047f64);
-    exchange_rates.insert(Exchange::USD("2013-08-06".to_string()), 3.1744f64);
-    exchange_rates.insert(Exchange::USD("2020-05-26".to_string()), 4.0885f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-20".to_string()), 4.1723f64);
-    exchange_rates.insert(Exchange::USD("2020-06-03".to_string()), 3.9121f64);
-    exchange_rates.insert(Exchange::USD("2024-05-14".to_string()), 3.9701f64);
-    exchange_rates.insert(Exchange::USD("2024-06-21".to_string()), 4.0527f64);
-    exchange_rates.insert(Exchange::USD("2019-09-05".to_string()), 3.933f64);
-    exchange_rates.insert(Exchange::USD("2017-09-05".to_string()), 3.5642f64);
-    exchange_rates.insert(Exchange::USD("2015-10-23".to_string()), 3.82f64);
-    exchange_rates.insert(Exchange::USD("2025-11-03".to_string()), 3.6933f64);
-    exchange_rates.insert(Exchange::USD("2022-11-03".to_string()), 4.8291f64);
-    exchange_rates.insert(Exchange::USD("2014-09-10".to_string()), 3.2508f64);
-    exchange_rates.insert(Exchange::USD("2019-06-04".to_string()), 3.8041f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-20".to_string()), 4.2497f64);
-    exchange_rates.insert(Exchange::USD("2025-05-15".to_string()), 3.7804f64);
-    exchange_rates.insert(Exchange::USD("2022-07-08".to_string()), 4.7417f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-09".to_string()), 4.5793f64);
-    exchange_rates.insert(Exchange::USD("2023-11-06".to_string()), 4.1512f64);
-    exchange_rates.insert(Exchange::USD("2020-04-28".to_string()), 4.184f64);
-    exchange_rates.insert(Exchange::USD("2023-07-05".to_string()), 4.0869f64);
-    exchange_rates.insert(Exchange::USD("2014-08-01".to_string()), 3.128f64);
-    exchange_rates.insert(Exchange::USD("2018-07-13".to_string()), 3.7244f64);
-    exchange_rates.insert(Exchange::USD("2013-01-24".to_string()), 3.1503f64);
-    exchange_rates.insert(Exchange::USD("2025-08-05".to_string()), 3.7066f64);
-    exchange_rates.insert(Exchange::USD("2021-09-29".to_string()), 3.9684f64);
-    exchange_rates.insert(Exchange::USD("2016-10-21".to_string()), 3.98f64);
-    exchange_rates.insert(Exchange::USD("2020-04-10".to_string()), 4.1566f64);
-    exchange_rates.insert(Exchange::USD("2025-09-08".to_string()), 3.6214f64);
-    exchange_rates.insert(Exchange::USD("2019-04-09".to_string()), 3.8003f64);
-    exchange_rates.insert(Exchange::USD("2015-10-21".to_string()), 3.7627f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-11".to_string()), 4.2661f64);
-    exchange_rates.insert(Exchange::USD("2020-11-27".to_string()), 3.7614f64);
-    exchange_rates.insert(Exchange::USD("2018-03-06".to_string()), 3.3936f64);
-    exchange_rates.insert(Exchange::USD("2020-12-17".to_string()), 3.6254f64);
-    exchange_rates.insert(Exchange::USD("2016-10-18".to_string()), 3.9148f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-18".to_string()), 4.6377f64);
-    exchange_rates.insert(Exchange::USD("2013-01-08".to_string()), 3.1457f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-27".to_string()), 4.7076f64);
-    exchange_rates.insert(Exchange::USD("2025-05-27".to_string()), 3.743f64);
-    exchange_rates.insert(Exchange::USD("2022-12-16".to_string()), 4.4227f64);
-    exchange_rates.insert(Exchange::USD("2018-03-23".to_string()), 3.4319f64);
-    exchange_rates.insert(Exchange::USD("2018-02-13".to_string()), 3.3916f64);
-    exchange_rates.insert(Exchange::USD("2024-03-11".to_string()), 3.9262f64);
-    exchange_rates.insert(Exchange::USD("2022-01-28".to_string()), 4.1048f64);
-    exchange_rates.insert(Exchange::USD("2016-05-13".to_string()), 3.8775f64);
-    exchange_rates.insert(Exchange::USD("2020-12-16".to_string()), 3.6334f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-13".to_string()), 4.2525f64);
-    exchange_rates.insert(Exchange::USD("2021-05-31".to_string()), 3.6724f64);
-    exchange_rates.insert(Exchange::USD("2025-04-01".to_string()), 3.8656f64);
-    exchange_rates.insert(Exchange::USD("2022-07-01".to_string()), 4.5106f64);
-    exchange_rates.insert(Exchange::USD("2025-01-20".to_string()), 4.1321f64);
-    exchange_rates.insert(Exchange::USD("2019-09-27".to_string()), 4.0154f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-06".to_string()), 4.5998f64);
-    exchange_rates.insert(Exchange::USD("2014-04-25".to_string()), 3.0378f64);
-    exchange_rates.insert(Exchange::USD("2022-07-12".to_string()), 4.8284f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-08".to_string()), 4.3203f64);
-    exchange_rates.insert(Exchange::USD("2016-02-18".to_string()), 3.9416f64);
-    exchange_rates.insert(Exchange::USD("2016-10-04".to_string()), 3.8472f64);
-    exchange_rates.insert(Exchange::USD("2016-10-14".to_string()), 3.8965f64);
-    exchange_rates.insert(Exchange::USD("2015-08-28".to_string()), 3.7493f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-28".to_string()), 4.2681f64);
-    exchange_rates.insert(Exchange::USD("2023-05-30".to_string()), 4.2242f64);
-    exchange_rates.insert(Exchange::USD("2018-06-19".to_string()), 3.7316f64);
-    exchange_rates.insert(Exchange::USD("2022-12-09".to_string()), 4.4351f64);
-    exchange_rates.insert(Exchange::USD("2013-11-04".to_string()), 3.0975f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-16".to_string()), 4.3414f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-14".to_string()), 4.3365f64);
-    exchange_rates.insert(Exchange::USD("2025-05-07".to_string()), 3.7642f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-30".to_string()), 4.2732f64);
-    exchange_rates.insert(Exchange::USD("2018-02-14".to_string()), 3.3769f64);
-    exchange_rates.insert(Exchange::USD("2021-08-27".to_string()), 3.8978f64);
-    exchange_rates.insert(Exchange::USD("2022-05-13".to_string()), 4.4849f64);
-    exchange_rates.insert(Exchange::USD("2016-10-11".to_string()), 3.8487f64);
-    exchange_rates.insert(Exchange::USD("2012-05-25".to_string()), 3.4589f64);
-    exchange_rates.insert(Exchange::USD("2018-10-24".to_string()), 3.7698f64);
-    exchange_rates.insert(Exchange::USD("2019-10-11".to_string()), 3.9145f64);
-    exchange_rates.insert(Exchange::USD("2017-11-06".to_string()), 3.6585f64);
-    exchange_rates.insert(Exchange::USD("2024-08-27".to_string()), 3.8331f64);
-    exchange_rates.insert(Exchange::USD("2022-10-27".to_string()), 4.7216f64);
-    exchange_rates.insert(Exchange::USD("2014-06-23".to_string()), 3.0627f64);
-    exchange_rates.insert(Exchange::USD("2022-09-01".to_string()), 4.6959f64);
-    exchange_rates.insert(Exchange::USD("2017-07-06".to_string()), 3.7351f64);
-    exchange_rates.insert(Exchange::USD("2023-10-02".to_string()), 4.3634f64);
-    exchange_rates.insert(Exchange::USD("2014-08-26".to_string()), 3.1686f64);
-    exchange_rates.insert(Exchange::USD("2021-08-16".to_string()), 3.873f64);
-    exchange_rates.insert(Exchange::USD("2018-08-06".to_string()), 3.692f64);
-    exchange_rates.insert(Exchange::USD("2025-09-19".to_string()), 3.6278f64);
-    exchange_rates.insert(Exchange::USD("2018-10-08".to_string()), 3.7574f64);
-    exchange_rates.insert(Exchange::USD("2015-11-03".to_string()), 3.8678f64);
-    exchange_rates.insert(Exchange::USD("2019-01-18".to_string()), 3.7694f64);
-    exchange_rates.insert(Exchange::USD("2014-03-28".to_string()), 3.0374f64);
-    exchange_rates.insert(Exchange::USD("2019-08-02".to_string()), 3.8829f64);
-    exchange_rates.insert(Exchange::USD("2023-09-22".to_string()), 4.3269f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-19".to_string()), 4.4529f64);
-    exchange_rates.insert(Exchange::USD("2018-06-12".to_string()), 3.6271f64);
-    exchange_rates.insert(Exchange::USD("2021-11-18".to_string()), 4.1114f64);
-    exchange_rates.insert(Exchange::USD("2015-06-10".to_string()), 3.676f64);
-    exchange_rates.insert(Exchange::USD("2019-03-06".to_string()), 3.8038f64);
-    exchange_rates.insert(Exchange::USD("2019-02-12".to_string()), 3.838f64);
-    exchange_rates.insert(Exchange::USD("2022-09-07".to_string()), 4.7584f64);
-    exchange_rates.insert(Exchange::USD("2018-02-12".to_string()), 3.4122f64);
-    exchange_rates.insert(Exchange::USD("2017-04-19".to_string()), 3.9571f64);
-    exchange_rates.insert(Exchange::USD("2016-04-01".to_string()), 3.7193f64);
-    exchange_rates.insert(Exchange::USD("2023-06-23".to_string()), 4.0893f64);
-    exchange_rates.insert(Exchange::USD("2013-09-12".to_string()), 3.1782f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-02".to_string()), 4.4484f64);
-    exchange_rates.insert(Exchange::USD("2024-01-11".to_string()), 3.968f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-12".to_string()), 4.2804f64);
-    exchange_rates.insert(Exchange::USD("2012-08-23".to_string()), 3.2488f64);
-    exchange_rates.insert(Exchange::USD("2015-05-05".to_string()), 3.6205f64);
-    exchange_rates.insert(Exchange::USD("2021-10-14".to_string()), 3.9403f64);
-    exchange_rates.insert(Exchange::USD("2025-05-30".to_string()), 3.7537f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-25".to_string()), 4.379f64);
-    exchange_rates.insert(Exchange::USD("2024-05-13".to_string()), 3.9853f64);
-    exchange_rates.insert(Exchange::USD("2025-06-13".to_string()), 3.7056f64);
-    exchange_rates.insert(Exchange::USD("2016-02-02".to_string()), 4.0333f64);
-    exchange_rates.insert(Exchange::USD("2016-09-06".to_string()), 3.8869f64);
-    exchange_rates.insert(Exchange::USD("2023-02-03".to_string()), 4.2928f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-31".to_string()), 4.2953f64);
-    exchange_rates.insert(Exchange::USD("2019-05-20".to_string()), 3.8543f64);
-    exchange_rates.insert(Exchange::USD("2019-01-31".to_string()), 3.7271f64);
-    exchange_rates.insert(Exchange::USD("2017-04-25".to_string()), 3.8873f64);
-    exchange_rates.insert(Exchange::USD("2020-01-22".to_string()), 3.8202f64);
-    exchange_rates.insert(Exchange::USD("2025-03-17".to_string()), 3.8329f64);
-    exchange_rates.insert(Exchange::USD("2023-06-27".to_string()), 4.0481f64);
-    exchange_rates.insert(Exchange::USD("2016-04-12".to_string()), 3.749f64);
-    exchange_rates.insert(Exchange::USD("2019-11-13".to_string()), 3.8902f64);
-    exchange_rates.insert(Exchange::USD("2014-03-14".to_string()), 3.0481f64);
-    exchange_rates.insert(Exchange::USD("2020-03-05".to_string()), 3.8479f64);
-    exchange_rates.insert(Exchange::USD("2014-11-28".to_string()), 3.3605f64);
-    exchange_rates.insert(Exchange::USD("2025-10-30".to_string()), 3.652f64);
-    exchange_rates.insert(Exchange::USD("2021-12-17".to_string()), 4.0882f64);
-    exchange_rates.insert(Exchange::USD("2013-09-19".to_string()), 3.0833f64);
-    exchange_rates.insert(Exchange::USD("2016-02-22".to_string()), 3.9391f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-13".to_string()), 4.4336f64);
-    exchange_rates.insert(Exchange::USD("2014-07-21".to_string()), 3.07f64);
-    exchange_rates.insert(Exchange::USD("2020-03-24".to_string()), 4.2421f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-12".to_string()), 4.357f64);
-    exchange_rates.insert(Exchange::USD("2022-11-24".to_string()), 4.5101f64);
-    exchange_rates.insert(Exchange::USD("2019-01-30".to_string()), 3.7577f64);
-    exchange_rates.insert(Exchange::USD("2015-12-23".to_string()), 3.8872f64);
-    exchange_rates.insert(Exchange::USD("2024-12-13".to_string()), 4.0738f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-29".to_string()), 4.4568f64);
-    exchange_rates.insert(Exchange::USD("2016-12-08".to_string()), 4.1107f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-09".to_string()), 4.2569f64);
-    exchange_rates.insert(Exchange::USD("2025-08-14".to_string()), 3.6542f64);
-    exchange_rates.insert(Exchange::USD("2021-11-19".to_string()), 4.1457f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-24".to_string()), 4.4803f64);
-    exchange_rates.insert(Exchange::USD("2012-11-13".to_string()), 3.2945f64);
-    exchange_rates.insert(Exchange::USD("2014-10-20".to_string()), 3.3109f64);
-    exchange_rates.insert(Exchange::USD("2015-11-04".to_string()), 3.8876f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-19".to_string()), 4.3201f64);
-    exchange_rates.insert(Exchange::USD("2025-05-26".to_string()), 3.7344f64);
-    exchange_rates.insert(Exchange::USD("2025-10-09".to_string()), 3.6659f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-21".to_string()), 4.2392f64);
-    exchange_rates.insert(Exchange::USD("2018-06-26".to_string()), 3.7151f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-17".to_string()), 4.2748f64);
-    exchange_rates.insert(Exchange::USD("2014-06-27".to_string()), 3.0485f64);
-    exchange_rates.insert(Exchange::USD("2020-10-15".to_string()), 3.8904f64);
-    exchange_rates.insert(Exchange::USD("2025-09-04".to_string()), 3.6501f64);
-    exchange_rates.insert(Exchange::USD("2018-01-18".to_string()), 3.4108f64);
-    exchange_rates.insert(Exchange::USD("2013-03-07".to_string()), 3.1808f64);
-    exchange_rates.insert(Exchange::USD("2017-06-19".to_string()), 3.7615f64);
-    exchange_rates.insert(Exchange::USD("2018-12-17".to_string()), 3.7871f64);
-    exchange_rates.insert(Exchange::USD("2016-08-05".to_string()), 3.8542f64);
-    exchange_rates.insert(Exchange::USD("2025-08-27".to_string()), 3.6793f64);
-    exchange_rates.insert(Exchange::USD("2022-12-27".to_string()), 4.3671f64);
-    exchange_rates.insert(Exchange::USD("2018-03-22".to_string()), 3.4264f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-22".to_string()), 4.4648f64);
-    exchange_rates.insert(Exchange::USD("2012-10-25".to_string()), 3.1851f64);
-    exchange_rates.insert(Exchange::USD("2025-07-11".to_string()), 3.6432f64);
-    exchange_rates.insert(Exchange::USD("2017-03-02".to_string()), 4.0765f64);
-    exchange_rates.insert(Exchange::USD("2016-12-23".to_string()), 4.2199f64);
-    exchange_rates.insert(Exchange::USD("2015-07-13".to_string()), 3.7543f64);
-    exchange_rates.insert(Exchange::USD("2022-01-11".to_string()), 4.0106f64);
-    exchange_rates.insert(Exchange::USD("2016-04-25".to_string()), 3.8962f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-05".to_string()), 4.3278f64);
-    exchange_rates.insert(Exchange::USD("2025-06-04".to_string()), 3.7525f64);
-    exchange_rates.insert(Exchange::USD("2020-05-13".to_string()), 4.2111f64);
-    exchange_rates.insert(Exchange::USD("2019-06-14".to_string()), 3.7727f64);
-    exchange_rates.insert(Exchange::USD("2014-02-12".to_string()), 3.0552f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-05".to_string()), 4.2717f64);
-    exchange_rates.insert(Exchange::USD("2013-04-11".to_string()), 3.1382f64);
-    exchange_rates.insert(Exchange::USD("2019-11-04".to_string()), 3.8099f64);
-    exchange_rates.insert(Exchange::USD("2021-01-29".to_string()), 3.746f64);
-    exchange_rates.insert(Exchange::USD("2017-05-11".to_string()), 3.8744f64);
-    exchange_rates.insert(Exchange::USD("2025-11-19".to_string()), 3.6656f64);
-    exchange_rates.insert(Exchange::USD("2021-06-24".to_string()), 3.7986f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-03".to_string()), 4.2721f64);
-    exchange_rates.insert(Exchange::USD("2012-04-19".to_string()), 3.1879f64);
-    exchange_rates.insert(Exchange::USD("2014-06-17".to_string()), 3.0495f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-19".to_string()), 4.339f64);
-    exchange_rates.insert(Exchange::USD("2019-11-27".to_string()), 3.9154f64);
-    exchange_rates.insert(Exchange::USD("2019-04-04".to_string()), 3.8215f64);
-    exchange_rates.insert(Exchange::USD("2024-11-12".to_string()), 4.1011f64);
-    exchange_rates.insert(Exchange::USD("2020-10-29".to_string()), 3.9505f64);
-    exchange_rates.insert(Exchange::USD("2021-06-07".to_string()), 3.6656f64);
-    exchange_rates.insert(Exchange::USD("2017-11-29".to_string()), 3.5385f64);
-    exchange_rates.insert(Exchange::USD("2019-06-03".to_string()), 3.8292f64);
-    exchange_rates.insert(Exchange::USD("2013-01-21".to_string()), 3.1351f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-29".to_string()), 4.2374f64);
-    exchange_rates.insert(Exchange::USD("2016-09-13".to_string()), 3.8734f64);
-    exchange_rates.insert(Exchange::USD("2022-07-25".to_string()), 4.6171f64);
-    exchange_rates.insert(Exchange::USD("2015-05-12".to_string()), 3.6447f64);
-    exchange_rates.insert(Exchange::USD("2017-05-18".to_string()), 3.7926f64);
-    exchange_rates.insert(Exchange::USD("2013-10-11".to_string()), 3.085f64);
-    exchange_rates.insert(Exchange::USD("2023-04-27".to_string()), 4.1541f64);
-    exchange_rates.insert(Exchange::USD("2013-03-18".to_string()), 3.2015f64);
-    exchange_rates.insert(Exchange::USD("2023-03-17".to_string()), 4.4202f64);
-    exchange_rates.insert(Exchange::USD("2021-01-28".to_string()), 3.7566f64);
-    exchange_rates.insert(Exchange::USD("2020-02-07".to_string()), 3.8947f64);
-    exchange_rates.insert(Exchange::USD("2021-03-09".to_string()), 3.8507f64);
-    exchange_rates.insert(Exchange::USD("2020-09-04".to_string()), 3.7533f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-11".to_string()), 4.5259f64);
-    exchange_rates.insert(Exchange::USD("2019-07-31".to_string()), 3.8504f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-09".to_string()), 4.2581f64);
-    exchange_rates.insert(Exchange::USD("2014-06-10".to_string()), 3.0303f64);
-    exchange_rates.insert(Exchange::USD("2014-06-30".to_string()), 3.0473f64);
-    exchange_rates.insert(Exchange::USD("2023-03-02".to_string()), 4.4002f64);
-    exchange_rates.insert(Exchange::USD("2020-03-17".to_string()), 4.046f64);
-    exchange_rates.insert(Exchange::USD("2025-01-21".to_string()), 4.1101f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-21".to_string()), 4.7469f64);
-    exchange_rates.insert(Exchange::USD("2023-02-06".to_string()), 4.3833f64);
-    exchange_rates.insert(Exchange::USD("2022-07-15".to_string()), 4.7966f64);
-    exchange_rates.insert(Exchange::USD("2024-01-23".to_string()), 4.0133f64);
-    exchange_rates.insert(Exchange::USD("2020-02-04".to_string()), 3.8773f64);
-    exchange_rates.insert(Exchange::USD("2016-03-31".to_string()), 3.759f64);
-    exchange_rates.insert(Exchange::USD("2017-09-21".to_string()), 3.5975f64);
-    exchange_rates.insert(Exchange::USD("2014-07-04".to_string()), 3.0495f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-06".to_string()), 4.4902f64);
-    exchange_rates.insert(Exchange::USD("2017-10-19".to_string()), 3.5896f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-31".to_string()), 4.3434f64);
-    exchange_rates.insert(Exchange::USD("2024-01-02".to_string()), 3.9432f64);
-    exchange_rates.insert(Exchange::USD("2024-02-29".to_string()), 3.9803f64);
-    exchange_rates.insert(Exchange::USD("2024-07-16".to_string()), 3.9083f64);
-    exchange_rates.insert(Exchange::USD("2021-02-10".to_string()), 3.6973f64);
-    exchange_rates.insert(Exchange::USD("2019-03-15".to_string()), 3.8014f64);
-    exchange_rates.insert(Exchange::USD("2018-06-21".to_string()), 3.7607f64);
-    exchange_rates.insert(Exchange::USD("2017-01-10".to_string()), 4.127f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-15".to_string()), 4.3103f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-18".to_string()), 4.2691f64);
-    exchange_rates.insert(Exchange::USD("2015-11-17".to_string()), 3.9775f64);
-    exchange_rates.insert(Exchange::USD("2023-11-16".to_string()), 4.0415f64);
-    exchange_rates.insert(Exchange::USD("2024-12-16".to_string()), 4.0571f64);
-    exchange_rates.insert(Exchange::USD("2016-09-12".to_string()), 3.8668f64);
-    exchange_rates.insert(Exchange::USD("2023-02-17".to_string()), 4.4888f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-27".to_string()), 4.2353f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-21".to_string()), 4.621f64);
-    exchange_rates.insert(Exchange::USD("2021-06-25".to_string()), 3.7749f64);
-    exchange_rates.insert(Exchange::USD("2024-03-12".to_string()), 3.9162f64);
-    exchange_rates.insert(Exchange::USD("2024-03-15".to_string()), 3.9392f64);
-    exchange_rates.insert(Exchange::USD("2023-02-09".to_string()), 4.4003f64);
-    exchange_rates.insert(Exchange::USD("2023-02-02".to_string()), 4.2803f64);
-    exchange_rates.insert(Exchange::USD("2023-11-20".to_string()), 3.9938f64);
-    exchange_rates.insert(Exchange::USD("2018-07-20".to_string()), 3.717f64);
-    exchange_rates.insert(Exchange::USD("2024-11-04".to_string()), 3.9869f64);
-    exchange_rates.insert(Exchange::USD("2016-11-04".to_string()), 3.8862f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-02".to_string()), 4.5148f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-03".to_string()), 4.2559f64);
-    exchange_rates.insert(Exchange::USD("2018-09-24".to_string()), 3.662f64);
-    exchange_rates.insert(Exchange::USD("2014-11-20".to_string()), 3.368f64);
-    exchange_rates.insert(Exchange::USD("2020-01-30".to_string()), 3.8856f64);
-    exchange_rates.insert(Exchange::USD("2023-06-13".to_string()), 4.1439f64);
-    exchange_rates.insert(Exchange::USD("2016-03-16".to_string()), 3.8749f64);
-    exchange_rates.insert(Exchange::USD("2024-07-01".to_string()), 3.9915f64);
-    exchange_rates.insert(Exchange::USD("2020-04-27".to_string()), 4.1696f64);
-    exchange_rates.insert(Exchange::USD("2018-08-27".to_string()), 3.682f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-02".to_string()), 4.2923f64);
-    exchange_rates.insert(Exchange::USD("2012-02-10".to_string()), 3.1703f64);
-    exchange_rates.insert(Exchange::USD("2012-07-04".to_string()), 3.3415f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-05".to_string()), 4.1545f64);
-    exchange_rates.insert(Exchange::USD("2020-06-04".to_string()), 3.9573f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-16".to_string()), 4.3197f64);
-    exchange_rates.insert(Exchange::USD("2024-10-01".to_string()), 3.859f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-16".to_string()), 4.2955f64);
-    exchange_rates.insert(Exchange::USD("2022-09-14".to_string()), 4.7233f64);
-    exchange_rates.insert(Exchange::USD("2019-10-25".to_string()), 3.8452f64);
-    exchange_rates.insert(Exchange::USD("2012-11-08".to_string()), 3.2655f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-17".to_string()), 4.1777f64);
-    exchange_rates.insert(Exchange::USD("2012-12-04".to_string()), 3.1575f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-07".to_string()), 4.3334f64);
-    exchange_rates.insert(Exchange::USD("2013-11-28".to_string()), 3.0872f64);
-    exchange_rates.insert(Exchange::USD("2013-08-01".to_string()), 3.1961f64);
-    exchange_rates.insert(Exchange::USD("2022-03-29".to_string()), 4.2917f64);
-    exchange_rates.insert(Exchange::USD("2024-05-31".to_string()), 3.9389f64);
-    exchange_rates.insert(Exchange::USD("2024-10-03".to_string()), 3.8951f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-03".to_string()), 4.7046f64);
-    exchange_rates.insert(Exchange::USD("2021-12-28".to_string()), 4.062f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-30".to_string()), 4.3213f64);
-    exchange_rates.insert(Exchange::USD("2020-10-05".to_string()), 3.8252f64);
-    exchange_rates.insert(Exchange::USD("2013-08-26".to_string()), 3.1658f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-30".to_string()), 4.2692f64);
-    exchange_rates.insert(Exchange::USD("2019-05-15".to_string()), 3.8457f64);
-    exchange_rates.insert(Exchange::USD("2019-05-27".to_string()), 3.8374f64);
-    exchange_rates.insert(Exchange::USD("2013-09-17".to_string()), 3.1552f64);
-    exchange_rates.insert(Exchange::USD("2020-03-19".to_string()), 4.2421f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-04".to_string()), 4.2815f64);
-    exchange_rates.insert(Exchange::USD("2025-05-13".to_string()), 3.8266f64);
-    exchange_rates.insert(Exchange::USD("2021-03-30".to_string()), 3.9604f64);
-    exchange_rates.insert(Exchange::USD("2017-10-06".to_string()), 3.6799f64);
-    exchange_rates.insert(Exchange::USD("2012-03-01".to_string()), 3.0887f64);
-    exchange_rates.insert(Exchange::USD("2023-12-14".to_string()), 3.9484f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-28".to_string()), 4.3478f64);
-    exchange_rates.insert(Exchange::USD("2017-07-03".to_string()), 3.7196f64);
-    exchange_rates.insert(Exchange::USD("2017-11-10".to_string()), 3.6274f64);
-    exchange_rates.insert(Exchange::USD("2018-11-23".to_string()), 3.7779f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-15".to_string()), 4.4633f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-07".to_string()), 4.2559f64);
-    exchange_rates.insert(Exchange::USD("2025-04-03".to_string()), 3.7968f64);
-    exchange_rates.insert(Exchange::USD("2021-08-12".to_string()), 3.9074f64);
-    exchange_rates.insert(Exchange::USD("2021-06-18".to_string()), 3.8198f64);
-    exchange_rates.insert(Exchange::USD("2023-07-20".to_string()), 3.971f64);
-    exchange_rates.insert(Exchange::USD("2019-08-28".to_string()), 3.9548f64);
-    exchange_rates.insert(Exchange::USD("2017-12-07".to_string()), 3.5722f64);
-    exchange_rates.insert(Exchange::USD("2014-04-22".to_string()), 3.034f64);
-    exchange_rates.insert(Exchange::USD("2020-03-03".to_string()), 3.8804f64);
-    exchange_rates.insert(Exchange::USD("2018-05-28".to_string()), 3.6849f64);
-    exchange_rates.insert(Exchange::USD("2024-11-08".to_string()), 4.0117f64);
-    exchange_rates.insert(Exchange::USD("2017-01-09".to_string()), 4.1592f64);
-    exchange_rates.insert(Exchange::USD("2015-10-07".to_string()), 3.7604f64);
-    exchange_rates.insert(Exchange::USD("2025-10-31".to_string()), 3.6751f64);
-    exchange_rates.insert(Exchange::USD("2020-04-17".to_string()), 4.1886f64);
-    exchange_rates.insert(Exchange::USD("2020-11-26".to_string()), 3.7586f64);
-    exchange_rates.insert(Exchange::USD("2015-12-28".to_string()), 3.8663f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-07".to_string()), 4.2618f64);
-    exchange_rates.insert(Exchange::USD("2020-04-20".to_string()), 4.1525f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-20".to_string()), 4.2396f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-12".to_string()), 4.2312f64);
-    exchange_rates.insert(Exchange::USD("2023-08-01".to_string()), 4.0262f64);
-    exchange_rates.insert(Exchange::USD("2020-07-24".to_string()), 3.8029f64);
-    exchange_rates.insert(Exchange::USD("2017-03-22".to_string()), 3.9731f64);
-    exchange_rates.insert(Exchange::USD("2016-11-24".to_string()), 4.188f64);
-    exchange_rates.insert(Exchange::USD("2014-01-21".to_string()), 3.0763f64);
-    exchange_rates.insert(Exchange::USD("2020-11-12".to_string()), 3.7995f64);
-    exchange_rates.insert(Exchange::USD("2016-06-07".to_string()), 3.8298f64);
-    exchange_rates.insert(Exchange::USD("2015-12-22".to_string()), 3.8787f64);
-    exchange_rates.insert(Exchange::USD("2012-12-18".to_string()), 3.1044f64);
-    exchange_rates.insert(Exchange::USD("2020-01-07".to_string()), 3.7861f64);
-    exchange_rates.insert(Exchange::USD("2022-06-29".to_string()), 4.4533f64);
-    exchange_rates.insert(Exchange::USD("2017-04-28".to_string()), 3.8696f64);
-    exchange_rates.insert(Exchange::USD("2019-10-14".to_string()), 3.8958f64);
-    exchange_rates.insert(Exchange::USD("2019-02-21".to_string()), 3.8247f64);
-    exchange_rates.insert(Exchange::USD("2025-06-17".to_string()), 3.7005f64);
-    exchange_rates.insert(Exchange::USD("2019-01-04".to_string()), 3.772f64);
-    exchange_rates.insert(Exchange::USD("2025-06-10".to_string()), 3.7352f64);
-    exchange_rates.insert(Exchange::USD("2017-07-28".to_string()), 3.6406f64);
-    exchange_rates.insert(Exchange::USD("2024-10-17".to_string()), 3.9786f64);
-    exchange_rates.insert(Exchange::USD("2025-11-25".to_string()), 3.6675f64);
-    exchange_rates.insert(Exchange::USD("2020-10-27".to_string()), 3.886f64);
-    exchange_rates.insert(Exchange::USD("2018-09-19".to_string()), 3.6685f64);
-    exchange_rates.insert(Exchange::USD("2014-02-14".to_string()), 3.0334f64);
-    exchange_rates.insert(Exchange::USD("2021-10-07".to_string()), 3.9334f64);
-    exchange_rates.insert(Exchange::USD("2019-04-10".to_string()), 3.8023f64);
-    exchange_rates.insert(Exchange::USD("2022-10-06".to_string()), 4.8989f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-03".to_string()), 4.2305f64);
-    exchange_rates.insert(Exchange::USD("2024-05-27".to_string()), 3.9196f64);
-    exchange_rates.insert(Exchange::USD("2018-05-08".to_string()), 3.5884f64);
-    exchange_rates.insert(Exchange::USD("2015-03-11".to_string()), 3.9091f64);
-    exchange_rates.insert(Exchange::USD("2019-12-23".to_string()), 3.8394f64);
-    exchange_rates.insert(Exchange::USD("2019-05-06".to_string()), 3.8313f64);
-    exchange_rates.insert(Exchange::USD("2012-08-03".to_string()), 3.3548f64);
-    exchange_rates.insert(Exchange::USD("2017-01-19".to_string()), 4.1014f64);
-    exchange_rates.insert(Exchange::USD("2016-12-21".to_string()), 4.2484f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-19".to_string()), 4.7053f64);
-    exchange_rates.insert(Exchange::USD("2016-08-12".to_string()), 3.8219f64);
-    exchange_rates.insert(Exchange::USD("2023-03-14".to_string()), 4.3793f64);
-    exchange_rates.insert(Exchange::USD("2016-05-12".to_string()), 3.8811f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-19".to_string()), 4.2424f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-25".to_string()), 4.7079f64);
-    exchange_rates.insert(Exchange::USD("2024-10-08".to_string()), 3.9299f64);
-    exchange_rates.insert(Exchange::USD("2014-03-31".to_string()), 3.0344f64);
-    exchange_rates.insert(Exchange::USD("2019-07-17".to_string()), 3.8038f64);
-    exchange_rates.insert(Exchange::USD("2016-12-19".to_string()), 4.2255f64);
-    exchange_rates.insert(Exchange::USD("2021-05-21".to_string()), 3.6778f64);
-    exchange_rates.insert(Exchange::USD("2019-09-18".to_string()), 3.9322f64);
-    exchange_rates.insert(Exchange::USD("2018-07-30".to_string()), 3.6684f64);
-    exchange_rates.insert(Exchange::USD("2021-08-11".to_string()), 3.9148f64);
-    exchange_rates.insert(Exchange::USD("2018-10-30".to_string()), 3.8062f64);
-    exchange_rates.insert(Exchange::USD("2014-10-28".to_string()), 3.3295f64);
-    exchange_rates.insert(Exchange::USD("2020-10-21".to_string()), 3.8706f64);
-    exchange_rates.insert(Exchange::USD("2020-02-27".to_string()), 3.9413f64);
-    exchange_rates.insert(Exchange::USD("2021-10-15".to_string()), 3.9413f64);
-    exchange_rates.insert(Exchange::USD("2013-04-22".to_string()), 3.1448f64);
-    exchange_rates.insert(Exchange::USD("2017-07-14".to_string()), 3.6986f64);
-    exchange_rates.insert(Exchange::USD("2023-03-01".to_string()), 4.4094f64);
-    exchange_rates.insert(Exchange::USD("2016-02-08".to_string()), 3.9525f64);
-    exchange_rates.insert(Exchange::USD("2013-02-14".to_string()), 3.1209f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-07".to_string()), 4.3158f64);
-    exchange_rates.insert(Exchange::USD("2021-07-29".to_string()), 3.8617f64);
-    exchange_rates.insert(Exchange::USD("2016-08-19".to_string()), 3.7905f64);
-    exchange_rates.insert(Exchange::USD("2013-12-02".to_string()), 3.0965f64);
-    exchange_rates.insert(Exchange::USD("2022-03-17".to_string()), 4.2403f64);
-    exchange_rates.insert(Exchange::USD("2015-01-29".to_string()), 3.7418f64);
-    exchange_rates.insert(Exchange::USD("2024-10-07".to_string()), 3.9368f64);
-    exchange_rates.insert(Exchange::USD("2025-06-09".to_string()), 3.7427f64);
-    exchange_rates.insert(Exchange::USD("2012-10-26".to_string()), 3.2119f64);
-    exchange_rates.insert(Exchange::USD("2016-11-17".to_string()), 4.1375f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-17".to_string()), 4.7062f64);
-    exchange_rates.insert(Exchange::USD("2013-10-09".to_string()), 3.1057f64);
-    exchange_rates.insert(Exchange::USD("2024-01-08".to_string()), 3.9812f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-05".to_string()), 4.3484f64);
-    exchange_rates.insert(Exchange::USD("2021-04-07".to_string()), 3.8611f64);
-    exchange_rates.insert(Exchange::USD("2012-08-20".to_string()), 3.2944f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-27".to_string()), 4.1913f64);
-    exchange_rates.insert(Exchange::USD("2018-03-14".to_string()), 3.4055f64);
-    exchange_rates.insert(Exchange::USD("2013-11-18".to_string()), 3.0953f64);
-    exchange_rates.insert(Exchange::USD("2015-02-13".to_string()), 3.6552f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-27".to_string()), 4.7162f64);
-    exchange_rates.insert(Exchange::USD("2025-08-04".to_string()), 3.6977f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-03".to_string()), 4.2927f64);
-    exchange_rates.insert(Exchange::USD("2017-05-26".to_string()), 3.7248f64);
-    exchange_rates.insert(Exchange::USD("2025-11-24".to_string()), 3.6726f64);
-    exchange_rates.insert(Exchange::USD("2018-09-21".to_string()), 3.6402f64);
-    exchange_rates.insert(Exchange::USD("2020-02-05".to_string()), 3.8654f64);
-    exchange_rates.insert(Exchange::USD("2013-02-06".to_string()), 3.0868f64);
-    exchange_rates.insert(Exchange::USD("2016-11-16".to_string()), 4.138f64);
-    exchange_rates.insert(Exchange::USD("2021-05-11".to_string()), 3.7514f64);
-    exchange_rates.insert(Exchange::USD("2016-04-05".to_string()), 3.7337f64);
-    exchange_rates.insert(Exchange::USD("2025-09-11".to_string()), 3.6459f64);
-    exchange_rates.insert(Exchange::USD("2014-03-13".to_string()), 3.0284f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-19".to_string()), 4.2666f64);
-    exchange_rates.insert(Exchange::USD("2015-11-06".to_string()), 3.9075f64);
-    exchange_rates.insert(Exchange::USD("2018-10-05".to_string()), 3.7518f64);
-    exchange_rates.insert(Exchange::USD("2012-02-28".to_string()), 3.0977f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-02".to_string()), 4.3286f64);
-    exchange_rates.insert(Exchange::USD("2022-03-24".to_string()), 4.331f64);
-    exchange_rates.insert(Exchange::USD("2020-06-24".to_string()), 3.9395f64);
-    exchange_rates.insert(Exchange::USD("2013-02-08".to_string()), 3.1056f64);
-    exchange_rates.insert(Exchange::USD("2021-05-24".to_string()), 3.6795f64);
-    exchange_rates.insert(Exchange::USD("2019-12-19".to_string()), 3.8281f64);
-    exchange_rates.insert(Exchange::USD("2016-10-07".to_string()), 3.8505f64);
-    exchange_rates.insert(Exchange::USD("2013-10-08".to_string()), 3.0873f64);
-    exchange_rates.insert(Exchange::USD("2025-02-12".to_string()), 4.0215f64);
-    exchange_rates.insert(Exchange::USD("2018-02-02".to_string()), 3.3329f64);
-    exchange_rates.insert(Exchange::USD("2017-12-13".to_string()), 3.588f64);
-    exchange_rates.insert(Exchange::USD("2013-05-09".to_string()), 3.1392f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-06".to_string()), 4.2073f64);
-    exchange_rates.insert(Exchange::USD("2015-11-09".to_string()), 3.9605f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-02".to_string()), 4.3173f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-16".to_string()), 4.3854f64);
-    exchange_rates.insert(Exchange::USD("2019-07-30".to_string()), 3.8493f64);
-    exchange_rates.insert(Exchange::USD("2014-01-14".to_string()), 3.04f64);
-    exchange_rates.insert(Exchange::USD("2017-06-23".to_string()), 3.7824f64);
-    exchange_rates.insert(Exchange::USD("2019-11-26".to_string()), 3.9039f64);
-    exchange_rates.insert(Exchange::USD("2015-10-13".to_string()), 3.7199f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-23".to_string()), 4.2769f64);
-    exchange_rates.insert(Exchange::USD("2021-03-19".to_string()), 3.8865f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-21".to_string()), 4.4768f64);
-    exchange_rates.insert(Exchange::USD("2014-02-04".to_string()), 3.1189f64);
-    exchange_rates.insert(Exchange::USD("2021-04-22".to_string()), 3.7885f64);
-    exchange_rates.insert(Exchange::USD("2024-07-31".to_string()), 3.9689f64);
-    exchange_rates.insert(Exchange::USD("2022-02-23".to_string()), 3.9937f64);
-    exchange_rates.insert(Exchange::USD("2016-01-11".to_string()), 4.0085f64);
-    exchange_rates.insert(Exchange::USD("2020-09-02".to_string()), 3.7191f64);
-    exchange_rates.insert(Exchange::USD("2017-05-24".to_string()), 3.7535f64);
-    exchange_rates.insert(Exchange::USD("2013-04-30".to_string()), 3.1721f64);
-    exchange_rates.insert(Exchange::USD("2021-05-18".to_string()), 3.705f64);
-    exchange_rates.insert(Exchange::USD("2017-06-06".to_string()), 3.725f64);
-    exchange_rates.insert(Exchange::USD("2018-10-19".to_string()), 3.7601f64);
-    exchange_rates.insert(Exchange::USD("2024-10-24".to_string()), 4.0168f64);
-    exchange_rates.insert(Exchange::USD("2020-07-10".to_string()), 3.9646f64);
-    exchange_rates.insert(Exchange::USD("2017-03-09".to_string()), 4.0864f64);
-    exchange_rates.insert(Exchange::USD("2020-05-15".to_string()), 4.2135f64);
-    exchange_rates.insert(Exchange::USD("2017-02-02".to_string()), 3.9835f64);
-    exchange_rates.insert(Exchange::USD("2021-07-27".to_string()), 3.9017f64);
-    exchange_rates.insert(Exchange::USD("2014-03-26".to_string()), 3.0318f64);
-    exchange_rates.insert(Exchange::USD("2022-11-25".to_string()), 4.5111f64);
-    exchange_rates.insert(Exchange::USD("2015-03-10".to_string()), 3.8345f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-26".to_string()), 4.2803f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-16".to_string()), 4.2622f64);
-    exchange_rates.insert(Exchange::USD("2025-04-09".to_string()), 3.8828f64);
-    exchange_rates.insert(Exchange::USD("2020-12-10".to_string()), 3.6574f64);
-    exchange_rates.insert(Exchange::USD("2019-07-04".to_string()), 3.7618f64);
-    exchange_rates.insert(Exchange::USD("2020-01-09".to_string()), 3.8251f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-03".to_string()), 4.3646f64);
-    exchange_rates.insert(Exchange::USD("2022-08-05".to_string()), 4.6024f64);
-    exchange_rates.insert(Exchange::USD("2019-11-20".to_string()), 3.8792f64);
-    exchange_rates.insert(Exchange::USD("2023-09-11".to_string()), 4.3077f64);
-    exchange_rates.insert(Exchange::USD("2019-07-25".to_string()), 3.8199f64);
-    exchange_rates.insert(Exchange::USD("2016-03-04".to_string()), 3.9545f64);
-    exchange_rates.insert(Exchange::USD("2015-12-09".to_string()), 3.9705f64);
-    exchange_rates.insert(Exchange::USD("2014-10-16".to_string()), 3.2985f64);
-    exchange_rates.insert(Exchange::USD("2016-06-22".to_string()), 3.8945f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-04".to_string()), 4.6688f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-22".to_string()), 4.6069f64);
-    exchange_rates.insert(Exchange::USD("2014-03-27".to_string()), 3.0401f64);
-    exchange_rates.insert(Exchange::USD("2023-10-13".to_string()), 4.3033f64);
-    exchange_rates.insert(Exchange::USD("2019-12-31".to_string()), 3.7977f64);
-    exchange_rates.insert(Exchange::USD("2015-03-16".to_string()), 3.926f64);
-    exchange_rates.insert(Exchange::USD("2020-12-01".to_string()), 3.7367f64);
-    exchange_rates.insert(Exchange::USD("2013-05-23".to_string()), 3.2587f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-10".to_string()), 4.7716f64);
-    exchange_rates.insert(Exchange::USD("2023-06-01".to_string()), 4.2399f64);
-    exchange_rates.insert(Exchange::USD("2024-11-26".to_string()), 4.1073f64);
-    exchange_rates.insert(Exchange::USD("2021-08-30".to_string()), 3.8724f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-04".to_string()), 4.3525f64);
-    exchange_rates.insert(Exchange::USD("2024-12-20".to_string()), 4.1002f64);
-    exchange_rates.insert(Exchange::USD("2014-08-05".to_string()), 3.1121f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-02".to_string()), 4.25f64);
-    exchange_rates.insert(Exchange::USD("2015-06-24".to_string()), 3.7103f64);
-    exchange_rates.insert(Exchange::USD("2013-03-27".to_string()), 3.2635f64);
-    exchange_rates.insert(Exchange::USD("2013-01-23".to_string()), 3.1195f64);
-    exchange_rates.insert(Exchange::USD("2023-05-23".to_string()), 4.1634f64);
-    exchange_rates.insert(Exchange::USD("2018-11-05".to_string()), 3.7907f64);
-    exchange_rates.insert(Exchange::USD("2016-01-25".to_string()), 4.1346f64);
-    exchange_rates.insert(Exchange::USD("2013-06-10".to_string()), 3.221f64);
-    exchange_rates.insert(Exchange::USD("2013-07-18".to_string()), 3.2362f64);
-    exchange_rates.insert(Exchange::USD("2021-03-12".to_string()), 3.8521f64);
-    exchange_rates.insert(Exchange::USD("2012-09-12".to_string()), 3.168f64);
-    exchange_rates.insert(Exchange::USD("2019-10-10".to_string()), 3.9226f64);
-    exchange_rates.insert(Exchange::USD("2012-11-21".to_string()), 3.2287f64);
-    exchange_rates.insert(Exchange::USD("2025-02-17".to_string()), 3.9749f64);
-    exchange_rates.insert(Exchange::USD("2015-09-23".to_string()), 3.7801f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-30".to_string()), 4.2419f64);
-    exchange_rates.insert(Exchange::USD("2024-01-18".to_string()), 4.0437f64);
-    exchange_rates.insert(Exchange::USD("2015-01-27".to_string()), 3.7348f64);
-    exchange_rates.insert(Exchange::USD("2015-09-11".to_string()), 3.7264f64);
-    exchange_rates.insert(Exchange::USD("2022-01-12".to_string()), 3.9879f64);
-    exchange_rates.insert(Exchange::USD("2020-03-06".to_string()), 3.8231f64);
-    exchange_rates.insert(Exchange::USD("2025-01-13".to_string()), 4.1904f64);
-    exchange_rates.insert(Exchange::USD("2018-09-05".to_string()), 3.733f64);
-    exchange_rates.insert(Exchange::USD("2022-07-19".to_string()), 4.6594f64);
-    exchange_rates.insert(Exchange::USD("2025-11-12".to_string()), 3.657f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-27".to_string()), 4.4224f64);
-    exchange_rates.insert(Exchange::USD("2021-04-01".to_string()), 3.9428f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-14".to_string()), 4.2614f64);
-    exchange_rates.insert(Exchange::USD("2021-08-24".to_string()), 3.9086f64);
-    exchange_rates.insert(Exchange::USD("2025-10-06".to_string()), 3.6446f64);
-    exchange_rates.insert(Exchange::USD("2013-10-16".to_string()), 3.0854f64);
-    exchange_rates.insert(Exchange::USD("2023-10-27".to_string()), 4.2267f64);
-    exchange_rates.insert(Exchange::USD("2021-04-16".to_string()), 3.7978f64);
-    exchange_rates.insert(Exchange::USD("2023-08-08".to_string()), 4.0396f64);
-    exchange_rates.insert(Exchange::USD("2021-07-09".to_string()), 3.8427f64);
-    exchange_rates.insert(Exchange::USD("2015-06-16".to_string()), 3.6933f64);
-    exchange_rates.insert(Exchange::USD("2017-11-02".to_string()), 3.6366f64);
-    exchange_rates.insert(Exchange::USD("2014-01-27".to_string()), 3.0884f64);
-    exchange_rates.insert(Exchange::USD("2014-09-30".to_string()), 3.2973f64);
-    exchange_rates.insert(Exchange::USD("2023-06-19".to_string()), 4.068f64);
-    exchange_rates.insert(Exchange::USD("2024-12-27".to_string()), 4.1036f64);
-    exchange_rates.insert(Exchange::USD("2016-05-06".to_string()), 3.8732f64);
-    exchange_rates.insert(Exchange::USD("2013-07-10".to_string()), 3.3724f64);
-    exchange_rates.insert(Exchange::USD("2012-07-24".to_string()), 3.4678f64);
-    exchange_rates.insert(Exchange::USD("2015-06-02".to_string()), 3.7676f64);
-    exchange_rates.insert(Exchange::USD("2025-09-22".to_string()), 3.6255f64);
-    exchange_rates.insert(Exchange::USD("2016-10-25".to_string()), 3.9543f64);
-    exchange_rates.insert(Exchange::USD("2017-12-14".to_string()), 3.5716f64);
-    exchange_rates.insert(Exchange::USD("2014-11-27".to_string()), 3.3528f64);
-    exchange_rates.insert(Exchange::USD("2025-09-25".to_string()), 3.6295f64);
-    exchange_rates.insert(Exchange::USD("2021-11-24".to_string()), 4.1764f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-04".to_string()), 4.2734f64);
-    exchange_rates.insert(Exchange::USD("2014-06-05".to_string()), 3.0275f64);
-    exchange_rates.insert(Exchange::USD("2025-09-01".to_string()), 3.6278f64);
-    exchange_rates.insert(Exchange::USD("2014-07-16".to_string()), 3.0503f64);
-    exchange_rates.insert(Exchange::USD("2018-01-10".to_string()), 3.4999f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-09".to_string()), 4.2399f64);
-    exchange_rates.insert(Exchange::USD("2020-07-13".to_string()), 3.9564f64);
-    exchange_rates.insert(Exchange::USD("2018-07-10".to_string()), 3.6973f64);
-    exchange_rates.insert(Exchange::USD("2015-09-22".to_string()), 3.7438f64);
-    exchange_rates.insert(Exchange::USD("2013-02-27".to_string()), 3.1841f64);
-    exchange_rates.insert(Exchange::USD("2022-02-15".to_string()), 3.974f64);
-    exchange_rates.insert(Exchange::USD("2020-07-06".to_string()), 3.9588f64);
-    exchange_rates.insert(Exchange::USD("2013-05-06".to_string()), 3.1646f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-09".to_string()), 4.4586f64);
-    exchange_rates.insert(Exchange::USD("2020-06-16".to_string()), 3.9058f64);
-    exchange_rates.insert(Exchange::USD("2012-04-06".to_string()), 3.1814f64);
-    exchange_rates.insert(Exchange::USD("2018-08-02".to_string()), 3.6783f64);
-    exchange_rates.insert(Exchange::USD("2016-06-02".to_string()), 3.9204f64);
-    exchange_rates.insert(Exchange::USD("2019-03-25".to_string()), 3.7966f64);
-    exchange_rates.insert(Exchange::USD("2017-11-24".to_string()), 3.5486f64);
-    exchange_rates.insert(Exchange::USD("2019-01-25".to_string()), 3.7893f64);
-    exchange_rates.insert(Exchange::USD("2014-04-23".to_string()), 3.0273f64);
-    exchange_rates.insert(Exchange::USD("2012-03-09".to_string()), 3.1126f64);
-    exchange_rates.insert(Exchange::USD("2017-08-09".to_string()), 3.6342f64);
-    exchange_rates.insert(Exchange::USD("2015-01-23".to_string()), 3.7687f64);
-    exchange_rates.insert(Exchange::USD("2018-05-17".to_string()), 3.6283f64);
-    exchange_rates.insert(Exchange::USD("2015-03-12".to_string()), 3.8928f64);
-    exchange_rates.insert(Exchange::USD("2025-01-02".to_string()), 4.1219f64);
-    exchange_rates.insert(Exchange::USD("2013-09-02".to_string()), 3.2164f64);
-    exchange_rates.insert(Exchange::USD("2012-02-01".to_string()), 3.1913f64);
-    exchange_rates.insert(Exchange::USD("2023-09-06".to_string()), 4.1895f64);
-    exchange_rates.insert(Exchange::USD("2024-03-18".to_string()), 3.9528f64);
-    exchange_rates.insert(Exchange::USD("2022-06-08".to_string()), 4.2904f64);
-    exchange_rates.insert(Exchange::USD("2017-11-14".to_string()), 3.6126f64);
-    exchange_rates.insert(Exchange::USD("2012-01-27".to_string()), 3.2163f64);
-    exchange_rates.insert(Exchange::USD("2018-09-12".to_string()), 3.7198f64);
-    exchange_rates.insert(Exchange::USD("2020-12-15".to_string()), 3.6554f64);
-    exchange_rates.insert(Exchange::USD("2017-10-18".to_string()), 3.6006f64);
-    exchange_rates.insert(Exchange::USD("2025-10-28".to_string()), 3.6327f64);
-    exchange_rates.insert(Exchange::USD("2018-06-25".to_string()), 3.7087f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-18".to_string()), 4.2076f64);
-    exchange_rates.insert(Exchange::USD("2017-10-09".to_string()), 3.6743f64);
-    exchange_rates.insert(Exchange::USD("2024-07-23".to_string()), 3.9355f64);
-    exchange_rates.insert(Exchange::USD("2023-02-10".to_string()), 4.4565f64);
-    exchange_rates.insert(Exchange::USD("2020-07-29".to_string()), 3.761f64);
-    exchange_rates.insert(Exchange::USD("2013-07-16".to_string()), 3.2735f64);
-    exchange_rates.insert(Exchange::USD("2012-01-26".to_string()), 3.2233f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-16".to_string()), 4.2753f64);
-    exchange_rates.insert(Exchange::USD("2019-03-27".to_string()), 3.8113f64);
-    exchange_rates.insert(Exchange::USD("2023-04-17".to_string()), 4.2261f64);
-    exchange_rates.insert(Exchange::USD("2022-08-18".to_string()), 4.6468f64);
-    exchange_rates.insert(Exchange::USD("2018-11-22".to_string()), 3.7764f64);
-    exchange_rates.insert(Exchange::USD("2014-03-03".to_string()), 3.0462f64);
-    exchange_rates.insert(Exchange::USD("2013-12-23".to_string()), 3.0443f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-03".to_string()), 4.3021f64);
-    exchange_rates.insert(Exchange::USD("2024-06-26".to_string()), 4.0291f64);
-    exchange_rates.insert(Exchange::USD("2020-12-14".to_string()), 3.6548f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-23".to_string()), 4.2347f64);
-    exchange_rates.insert(Exchange::USD("2014-07-22".to_string()), 3.0748f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-29".to_string()), 4.2369f64);
-    exchange_rates.insert(Exchange::USD("2021-04-08".to_string()), 3.8365f64);
-    exchange_rates.insert(Exchange::USD("2021-01-05".to_string()), 3.7031f64);
-    exchange_rates.insert(Exchange::USD("2018-02-16".to_string()), 3.3173f64);
-    exchange_rates.insert(Exchange::USD("2024-05-07".to_string()), 4.0056f64);
-    exchange_rates.insert(Exchange::USD("2014-03-17".to_string()), 3.043f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-08".to_string()), 4.3195f64);
-    exchange_rates.insert(Exchange::USD("2018-10-15".to_string()), 3.7091f64);
-    exchange_rates.insert(Exchange::USD("2014-12-01".to_string()), 3.3519f64);
-    exchange_rates.insert(Exchange::USD("2025-09-12".to_string()), 3.635f64);
-    exchange_rates.insert(Exchange::USD("2012-03-12".to_string()), 3.1285f64);
-    exchange_rates.insert(Exchange::USD("2020-06-29".to_string()), 3.9656f64);
-    exchange_rates.insert(Exchange::USD("2016-12-30".to_string()), 4.1793f64);
-    exchange_rates.insert(Exchange::USD("2016-05-04".to_string()), 3.83f64);
-    exchange_rates.insert(Exchange::USD("2015-01-05".to_string()), 3.5975f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-15".to_string()), 4.2192f64);
-    exchange_rates.insert(Exchange::USD("2015-07-15".to_string()), 3.747f64);
-    exchange_rates.insert(Exchange::USD("2024-06-07".to_string()), 3.9389f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-14".to_string()), 4.4361f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-07".to_string()), 4.594f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-09".to_string()), 4.2794f64);
-    exchange_rates.insert(Exchange::USD("2014-03-06".to_string()), 3.0427f64);
-    exchange_rates.insert(Exchange::USD("2013-04-10".to_string()), 3.1405f64);
-    exchange_rates.insert(Exchange::USD("2014-12-17".to_string()), 3.3928f64);
-    exchange_rates.insert(Exchange::USD("2022-06-15".to_string()), 4.4455f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-10".to_string()), 4.2641f64);
-    exchange_rates.insert(Exchange::USD("2013-06-06".to_string()), 3.2564f64);
-    exchange_rates.insert(Exchange::USD("2017-07-24".to_string()), 3.6395f64);
-    exchange_rates.insert(Exchange::USD("2018-08-14".to_string()), 3.7768f64);
-    exchange_rates.insert(Exchange::USD("2019-08-30".to_string()), 3.9717f64);
-    exchange_rates.insert(Exchange::USD("2013-02-20".to_string()), 3.1002f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-27".to_string()), 4.4642f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-17".to_string()), 4.3767f64);
-    exchange_rates.insert(Exchange::USD("2022-04-22".to_string()), 4.2935f64);
-    exchange_rates.insert(Exchange::USD("2021-03-24".to_string()), 3.9098f64);
-    exchange_rates.insert(Exchange::USD("2020-04-15".to_string()), 4.1519f64);
-    exchange_rates.insert(Exchange::USD("2018-08-17".to_string()), 3.7784f64);
-    exchange_rates.insert(Exchange::USD("2016-04-04".to_string()), 3.7254f64);
-    exchange_rates.insert(Exchange::USD("2012-06-29".to_string()), 3.3885f64);
-    exchange_rates.insert(Exchange::USD("2024-08-26".to_string()), 3.8284f64);
-    exchange_rates.insert(Exchange::USD("2013-10-29".to_string()), 3.0436f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-18".to_string()), 4.2558f64);
-    exchange_rates.insert(Exchange::USD("2013-01-18".to_string()), 3.0925f64);
-    exchange_rates.insert(Exchange::USD("2023-01-12".to_string()), 4.3556f64);
-    exchange_rates.insert(Exchange::USD("2018-06-13".to_string()), 3.6461f64);
-    exchange_rates.insert(Exchange::USD("2016-11-22".to_string()), 4.1507f64);
-    exchange_rates.insert(Exchange::USD("2016-10-13".to_string()), 3.9164f64);
-    exchange_rates.insert(Exchange::USD("2022-06-10".to_string()), 4.3363f64);
-    exchange_rates.insert(Exchange::USD("2013-11-29".to_string()), 3.0846f64);
-    exchange_rates.insert(Exchange::USD("2016-09-21".to_string()), 3.8601f64);
-    exchange_rates.insert(Exchange::USD("2023-08-25".to_string()), 4.1419f64);
-    exchange_rates.insert(Exchange::USD("2019-03-19".to_string()), 3.7823f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-27".to_string()), 4.3153f64);
-    exchange_rates.insert(Exchange::USD("2017-08-25".to_string()), 3.6112f64);
-    exchange_rates.insert(Exchange::USD("2016-11-23".to_string()), 4.1588f64);
-    exchange_rates.insert(Exchange::USD("2017-10-25".to_string()), 3.6006f64);
-    exchange_rates.insert(Exchange::USD("2016-09-15".to_string()), 3.8587f64);
-    exchange_rates.insert(Exchange::USD("2023-02-27".to_string()), 4.4697f64);
-    exchange_rates.insert(Exchange::USD("2022-12-05".to_string()), 4.4415f64);
-    exchange_rates.insert(Exchange::USD("2018-08-23".to_string()), 3.7069f64);
-    exchange_rates.insert(Exchange::USD("2013-06-19".to_string()), 3.1777f64);
-    exchange_rates.insert(Exchange::USD("2015-10-15".to_string()), 3.6948f64);
-    exchange_rates.insert(Exchange::USD("2025-09-10".to_string()), 3.6414f64);
-    exchange_rates.insert(Exchange::USD("2014-06-12".to_string()), 3.0374f64);
-    exchange_rates.insert(Exchange::USD("2019-08-20".to_string()), 3.9408f64);
-    exchange_rates.insert(Exchange::USD("2015-03-30".to_string()), 3.7685f64);
-    exchange_rates.insert(Exchange::USD("2025-10-13".to_string()), 3.6763f64);
-    exchange_rates.insert(Exchange::USD("2019-04-05".to_string()), 3.8215f64);
-    exchange_rates.insert(Exchange::USD("2013-04-17".to_string()), 3.1159f64);
-    exchange_rates.insert(Exchange::USD("2012-09-28".to_string()), 3.178f64);
-    exchange_rates.insert(Exchange::USD("2013-11-05".to_string()), 3.0981f64);
-    exchange_rates.insert(Exchange::USD("2012-08-30".to_string()), 3.3397f64);
-    exchange_rates.insert(Exchange::USD("2016-05-10".to_string()), 3.8853f64);
-    exchange_rates.insert(Exchange::USD("2017-01-24".to_string()), 4.0708f64);
-    exchange_rates.insert(Exchange::USD("2017-07-20".to_string()), 3.6604f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-23".to_string()), 4.3344f64);
-    exchange_rates.insert(Exchange::USD("2024-07-03".to_string()), 3.999f64);
-    exchange_rates.insert(Exchange::USD("2013-09-13".to_string()), 3.1696f64);
-    exchange_rates.insert(Exchange::USD("2015-06-19".to_string()), 3.6879f64);
-    exchange_rates.insert(Exchange::USD("2025-07-31".to_string()), 3.7257f64);
-    exchange_rates.insert(Exchange::USD("2020-06-05".to_string()), 3.9217f64);
-    exchange_rates.insert(Exchange::USD("2025-08-28".to_string()), 3.6658f64);
-    exchange_rates.insert(Exchange::USD("2013-10-14".to_string()), 3.0936f64);
-    exchange_rates.insert(Exchange::USD("2015-06-23".to_string()), 3.704f64);
-    exchange_rates.insert(Exchange::USD("2014-03-19".to_string()), 3.0245f64);
-    exchange_rates.insert(Exchange::USD("2018-01-24".to_string()), 3.375f64);
-    exchange_rates.insert(Exchange::USD("2018-03-08".to_string()), 3.3935f64);
-    exchange_rates.insert(Exchange::USD("2017-03-17".to_string()), 3.9917f64);
-    exchange_rates.insert(Exchange::USD("2013-04-03".to_string()), 3.2693f64);
-    exchange_rates.insert(Exchange::USD("2025-01-29".to_string()), 4.0443f64);
-    exchange_rates.insert(Exchange::USD("2013-07-23".to_string()), 3.2001f64);
-    exchange_rates.insert(Exchange::USD("2015-07-31".to_string()), 3.7929f64);
-    exchange_rates.insert(Exchange::USD("2013-07-24".to_string()), 3.18f64);
-    exchange_rates.insert(Exchange::USD("2015-05-04".to_string()), 3.632f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-13".to_string()), 4.3355f64);
-    exchange_rates.insert(Exchange::USD("2018-06-18".to_string()), 3.7003f64);
-    exchange_rates.insert(Exchange::USD("2025-11-14".to_string()), 3.6376f64);
-    exchange_rates.insert(Exchange::USD("2015-06-09".to_string()), 3.6975f64);
-    exchange_rates.insert(Exchange::USD("2021-02-23".to_string()), 3.7117f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-12".to_string()), 4.666f64);
-    exchange_rates.insert(Exchange::USD("2014-01-31".to_string()), 3.1288f64);
-    exchange_rates.insert(Exchange::USD("2018-11-02".to_string()), 3.7804f64);
-    exchange_rates.insert(Exchange::USD("2012-06-15".to_string()), 3.3971f64);
-    exchange_rates.insert(Exchange::USD("2018-07-04".to_string()), 3.7759f64);
-    exchange_rates.insert(Exchange::USD("2016-06-30".to_string()), 3.9803f64);
-    exchange_rates.insert(Exchange::USD("2014-06-09".to_string()), 3.0067f64);
-    exchange_rates.insert(Exchange::USD("2025-07-21".to_string()), 3.6455f64);
-    exchange_rates.insert(Exchange::USD("2019-03-05".to_string()), 3.7977f64);
-    exchange_rates.insert(Exchange::USD("2015-05-21".to_string()), 3.6605f64);
-    exchange_rates.insert(Exchange::USD("2012-07-13".to_string()), 3.4463f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-21".to_string()), 4.6039f64);
-    exchange_rates.insert(Exchange::USD("2015-11-18".to_string()), 3.9802f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-29".to_string()), 4.6356f64);
-    exchange_rates.insert(Exchange::USD("2012-02-22".to_string()), 3.1635f64);
-    exchange_rates.insert(Exchange::USD("2019-02-25".to_string()), 3.8241f64);
-    exchange_rates.insert(Exchange::USD("2024-09-04".to_string()), 3.8738f64);
-    exchange_rates.insert(Exchange::USD("2022-07-22".to_string()), 4.692f64);
-    exchange_rates.insert(Exchange::USD("2021-07-02".to_string()), 3.8263f64);
-    exchange_rates.insert(Exchange::USD("2023-09-29".to_string()), 4.3697f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-23".to_string()), 4.2621f64);
-    exchange_rates.insert(Exchange::USD("2025-06-26".to_string()), 3.6222f64);
-    exchange_rates.insert(Exchange::USD("2025-04-18".to_string()), 3.7661f64);
-    exchange_rates.insert(Exchange::USD("2025-05-16".to_string()), 3.7853f64);
-    exchange_rates.insert(Exchange::USD("2013-05-16".to_string()), 3.2521f64);
-    exchange_rates.insert(Exchange::USD("2021-07-05".to_string()), 3.7979f64);
-    exchange_rates.insert(Exchange::USD("2012-03-29".to_string()), 3.1234f64);
-    exchange_rates.insert(Exchange::USD("2021-05-13".to_string()), 3.7675f64);
-    exchange_rates.insert(Exchange::USD("2014-09-19".to_string()), 3.2507f64);
-    exchange_rates.insert(Exchange::USD("2024-08-21".to_string()), 3.8565f64);
-    exchange_rates.insert(Exchange::USD("2025-04-17".to_string()), 3.7684f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-26".to_string()), 4.5242f64);
-    exchange_rates.insert(Exchange::USD("2019-04-02".to_string()), 3.8403f64);
-    exchange_rates.insert(Exchange::USD("2015-09-21".to_string()), 3.708f64);
-    exchange_rates.insert(Exchange::USD("2020-09-17".to_string()), 3.7713f64);
-    exchange_rates.insert(Exchange::USD("2012-03-30".to_string()), 3.1191f64);
-    exchange_rates.insert(Exchange::USD("2014-09-22".to_string()), 3.257f64);
-    exchange_rates.insert(Exchange::USD("2017-03-10".to_string()), 4.0761f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-22".to_string()), 4.2531f64);
-    exchange_rates.insert(Exchange::USD("2021-06-15".to_string()), 3.7349f64);
-    exchange_rates.insert(Exchange::USD("2022-03-09".to_string()), 4.4147f64);
-    exchange_rates.insert(Exchange::USD("2015-04-22".to_string()), 3.7125f64);
-    exchange_rates.insert(Exchange::USD("2016-06-09".to_string()), 3.8126f64);
-    exchange_rates.insert(Exchange::USD("2013-07-01".to_string()), 3.321f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-08".to_string()), 4.6019f64);
-    exchange_rates.insert(Exchange::USD("2021-12-06".to_string()), 4.0619f64);
-    exchange_rates.insert(Exchange::USD("2021-01-14".to_string()), 3.7323f64);
-    exchange_rates.insert(Exchange::USD("2018-01-11".to_string()), 3.495f64);
-    exchange_rates.insert(Exchange::USD("2023-06-06".to_string()), 4.1964f64);
-    exchange_rates.insert(Exchange::USD("2020-03-30".to_string()), 4.0933f64);
-    exchange_rates.insert(Exchange::USD("2018-04-18".to_string()), 3.3721f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-30".to_string()), 4.4503f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-23".to_string()), 4.2727f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-06".to_string()), 4.3382f64);
-    exchange_rates.insert(Exchange::USD("2024-09-12".to_string()), 3.9025f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-02".to_string()), 4.3434f64);
-    exchange_rates.insert(Exchange::USD("2024-01-30".to_string()), 4.0301f64);
-    exchange_rates.insert(Exchange::USD("2017-09-29".to_string()), 3.6519f64);
-    exchange_rates.insert(Exchange::USD("2024-08-20".to_string()), 3.8506f64);
-    exchange_rates.insert(Exchange::USD("2012-04-04".to_string()), 3.1503f64);
-    exchange_rates.insert(Exchange::USD("2012-04-05".to_string()), 3.1678f64);
-    exchange_rates.insert(Exchange::USD("2012-08-24".to_string()), 3.268f64);
-    exchange_rates.insert(Exchange::USD("2012-11-14".to_string()), 3.275f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-23".to_string()), 4.281f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-23".to_string()), 4.2541f64);
-    exchange_rates.insert(Exchange::USD("2013-03-22".to_string()), 3.2361f64);
-    exchange_rates.insert(Exchange::USD("2016-12-06".to_string()), 4.1825f64);
-    exchange_rates.insert(Exchange::USD("2013-10-07".to_string()), 3.0976f64);
-    exchange_rates.insert(Exchange::USD("2012-10-08".to_string()), 3.143f64);
-    exchange_rates.insert(Exchange::USD("2017-06-01".to_string()), 3.7262f64);
-    exchange_rates.insert(Exchange::USD("2014-08-28".to_string()), 3.1834f64);
-    exchange_rates.insert(Exchange::USD("2013-03-06".to_string()), 3.166f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-13".to_string()), 4.2722f64);
-    exchange_rates.insert(Exchange::USD("2014-02-03".to_string()), 3.137f64);
-    exchange_rates.insert(Exchange::USD("2020-06-10".to_string()), 3.918f64);
-    exchange_rates.insert(Exchange::USD("2023-02-23".to_string()), 4.4873f64);
-    exchange_rates.insert(Exchange::USD("2019-01-28".to_string()), 3.7592f64);
-    exchange_rates.insert(Exchange::USD("2016-04-29".to_string()), 3.8738f64);
-    exchange_rates.insert(Exchange::USD("2016-09-28".to_string()), 3.8264f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-29".to_string()), 4.3542f64);
-    exchange_rates.insert(Exchange::USD("2012-05-02".to_string()), 3.1593f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-14".to_string()), 4.4486f64);
-    exchange_rates.insert(Exchange::USD("2023-11-17".to_string()), 4.0327f64);
-    exchange_rates.insert(Exchange::USD("2016-10-20".to_string()), 3.9359f64);
-    exchange_rates.insert(Exchange::USD("2015-12-01".to_string()), 4.0248f64);
-    exchange_rates.insert(Exchange::USD("2018-04-09".to_string()), 3.4174f64);
-    exchange_rates.insert(Exchange::USD("2012-04-12".to_string()), 3.1818f64);
-    exchange_rates.insert(Exchange::USD("2020-05-28".to_string()), 4.0244f64);
-    exchange_rates.insert(Exchange::USD("2015-01-19".to_string()), 3.7176f64);
-    exchange_rates.insert(Exchange::USD("2023-04-12".to_string()), 4.2713f64);
-    exchange_rates.insert(Exchange::USD("2012-06-11".to_string()), 3.4072f64);
-    exchange_rates.insert(Exchange::USD("2016-06-17".to_string()), 3.9412f64);
-    exchange_rates.insert(Exchange::USD("2023-04-13".to_string()), 4.2225f64);
-    exchange_rates.insert(Exchange::USD("2016-07-15".to_string()), 3.9598f64);
-    exchange_rates.insert(Exchange::USD("2012-11-27".to_string()), 3.1634f64);
-    exchange_rates.insert(Exchange::USD("2014-04-09".to_string()), 3.0221f64);
-    exchange_rates.insert(Exchange::USD("2022-01-19".to_string()), 3.993f64);
-    exchange_rates.insert(Exchange::USD("2012-04-17".to_string()), 3.1872f64);
-    exchange_rates.insert(Exchange::USD("2023-11-02".to_string()), 4.1963f64);
-    exchange_rates.insert(Exchange::USD("2023-11-22".to_string()), 4.018f64);
-    exchange_rates.insert(Exchange::USD("2015-06-05".to_string()), 3.6949f64);
-    exchange_rates.insert(Exchange::USD("2024-10-04".to_string()), 3.9118f64);
-    exchange_rates.insert(Exchange::USD("2012-09-03".to_string()), 3.3398f64);
-    exchange_rates.insert(Exchange::USD("2012-10-10".to_string()), 3.1695f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-18".to_string()), 4.1776f64);
-    exchange_rates.insert(Exchange::USD("2024-10-28".to_string()), 4.0207f64);
-    exchange_rates.insert(Exchange::USD("2022-04-27".to_string()), 4.4453f64);
-    exchange_rates.insert(Exchange::USD("2022-08-17".to_string()), 4.6119f64);
-    exchange_rates.insert(Exchange::USD("2021-01-26".to_string()), 3.7512f64);
-    exchange_rates.insert(Exchange::USD("2017-03-23".to_string()), 3.9687f64);
-    exchange_rates.insert(Exchange::USD("2021-12-14".to_string()), 4.1088f64);
-    exchange_rates.insert(Exchange::USD("2022-09-13".to_string()), 4.6363f64);
-    exchange_rates.insert(Exchange::USD("2018-10-25".to_string()), 3.7796f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-12".to_string()), 4.3574f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-11".to_string()), 4.2864f64);
-    exchange_rates.insert(Exchange::USD("2022-05-11".to_string()), 4.419f64);
-    exchange_rates.insert(Exchange::USD("2017-12-19".to_string()), 3.5583f64);
-    exchange_rates.insert(Exchange::USD("2020-09-07".to_string()), 3.7666f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-23".to_string()), 4.7525f64);
-    exchange_rates.insert(Exchange::USD("2015-10-08".to_string()), 3.7543f64);
-    exchange_rates.insert(Exchange::USD("2012-04-13".to_string()), 3.1765f64);
-    exchange_rates.insert(Exchange::USD("2013-03-05".to_string()), 3.1665f64);
-    exchange_rates.insert(Exchange::USD("2018-03-20".to_string()), 3.4296f64);
-    exchange_rates.insert(Exchange::USD("2018-06-29".to_string()), 3.744f64);
-    exchange_rates.insert(Exchange::USD("2015-04-28".to_string()), 3.6751f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-13".to_string()), 4.2977f64);
-    exchange_rates.insert(Exchange::USD("2020-05-25".to_string()), 4.1428f64);
-    exchange_rates.insert(Exchange::USD("2019-10-03".to_string()), 3.9652f64);
-    exchange_rates.insert(Exchange::USD("2024-04-25".to_string()), 4.0276f64);
-    exchange_rates.insert(Exchange::USD("2013-04-15".to_string()), 3.1502f64);
-    exchange_rates.insert(Exchange::USD("2016-11-09".to_string()), 3.9305f64);
-    exchange_rates.insert(Exchange::USD("2024-01-04".to_string()), 3.9684f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-30".to_string()), 4.335f64);
-    exchange_rates.insert(Exchange::USD("2016-01-28".to_string()), 4.0872f64);
-    exchange_rates.insert(Exchange::USD("2014-01-16".to_string()), 3.0566f64);
-    exchange_rates.insert(Exchange::USD("2015-08-04".to_string()), 3.7792f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-25".to_string()), 4.2301f64);
-    exchange_rates.insert(Exchange::USD("2025-07-04".to_string()), 3.6108f64);
-    exchange_rates.insert(Exchange::USD("2022-03-31".to_string()), 4.1801f64);
-    exchange_rates.insert(Exchange::USD("2012-09-05".to_string()), 3.3693f64);
-    exchange_rates.insert(Exchange::USD("2024-08-14".to_string()), 3.8963f64);
-    exchange_rates.insert(Exchange::USD("2021-01-04".to_string()), 3.6998f64);
-    exchange_rates.insert(Exchange::USD("2013-02-01".to_string()), 3.0744f64);
-    exchange_rates.insert(Exchange::USD("2015-07-14".to_string()), 3.7645f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-08".to_string()), 4.2946f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-31".to_string()), 4.7089f64);
-    exchange_rates.insert(Exchange::USD("2019-06-17".to_string()), 3.7989f64);
-    exchange_rates.insert(Exchange::USD("2024-03-27".to_string()), 3.9857f64);
-    exchange_rates.insert(Exchange::USD("2018-08-20".to_string()), 3.7695f64);
-    exchange_rates.insert(Exchange::USD("2014-11-21".to_string()), 3.3827f64);
-    exchange_rates.insert(Exchange::USD("2022-09-05".to_string()), 4.769f64);
-    exchange_rates.insert(Exchange::USD("2018-12-12".to_string()), 3.7934f64);
-    exchange_rates.insert(Exchange::USD("2013-01-11".to_string()), 3.089f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-26".to_string()), 4.2657f64);
-    exchange_rates.insert(Exchange::USD("2024-05-02".to_string()), 4.0474f64);
-    exchange_rates.insert(Exchange::USD("2016-10-31".to_string()), 3.9488f64);
-    exchange_rates.insert(Exchange::USD("2016-12-01".to_string()), 4.1987f64);
-    exchange_rates.insert(Exchange::USD("2023-09-01".to_string()), 4.1263f64);
-    exchange_rates.insert(Exchange::USD("2024-05-09".to_string()), 4.0076f64);
-    exchange_rates.insert(Exchange::USD("2019-06-18".to_string()), 3.8097f64);
-    exchange_rates.insert(Exchange::USD("2025-03-06".to_string()), 3.8674f64);
-    exchange_rates.insert(Exchange::USD("2017-07-31".to_string()), 3.6264f64);
-    exchange_rates.insert(Exchange::USD("2014-01-28".to_string()), 3.0635f64);
-    exchange_rates.insert(Exchange::USD("2013-01-04".to_string()), 3.17f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-08".to_string()), 4.5671f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-25".to_string()), 4.3091f64);
-    exchange_rates.insert(Exchange::USD("2019-01-10".to_string()), 3.736f64);
-    exchange_rates.insert(Exchange::USD("2024-04-10".to_string()), 3.9264f64);
-    exchange_rates.insert(Exchange::USD("2024-01-12".to_string()), 3.9746f64);
-    exchange_rates.insert(Exchange::USD("2013-02-26".to_string()), 3.1733f64);
-    exchange_rates.insert(Exchange::USD("2023-02-15".to_string()), 4.4372f64);
-    exchange_rates.insert(Exchange::USD("2024-03-14".to_string()), 3.9183f64);
-    exchange_rates.insert(Exchange::USD("2022-10-14".to_string()), 4.9569f64);
-    exchange_rates.insert(Exchange::USD("2014-05-06".to_string()), 3.0196f64);
-    exchange_rates.insert(Exchange::USD("2021-03-15".to_string()), 3.8429f64);
-    exchange_rates.insert(Exchange::USD("2023-03-30".to_string()), 4.3043f64);
-    exchange_rates.insert(Exchange::USD("2021-06-22".to_string()), 3.8132f64);
-    exchange_rates.insert(Exchange::USD("2021-01-25".to_string()), 3.7402f64);
-    exchange_rates.insert(Exchange::USD("2018-02-28".to_string()), 3.4188f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-24".to_string()), 4.3755f64);
-    exchange_rates.insert(Exchange::USD("2024-01-25".to_string()), 4.0189f64);
-    exchange_rates.insert(Exchange::USD("2014-10-09".to_string()), 3.2776f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-19".to_string()), 4.1582f64);
-    exchange_rates.insert(Exchange::USD("2025-03-05".to_string()), 3.8785f64);
-    exchange_rates.insert(Exchange::USD("2024-04-11".to_string()), 3.9707f64);
-    exchange_rates.insert(Exchange::USD("2024-07-15".to_string()), 3.896f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-08".to_string()), 4.2289f64);
-    exchange_rates.insert(Exchange::USD("2013-08-22".to_string()), 3.1811f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-02".to_string()), 4.3323f64);
-    exchange_rates.insert(Exchange::USD("2013-02-11".to_string()), 3.0991f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-16".to_string()), 4.2612f64);
-    exchange_rates.insert(Exchange::USD("2017-08-14".to_string()), 3.6278f64);
-    exchange_rates.insert(Exchange::USD("2025-01-14".to_string()), 4.1658f64);
-    exchange_rates.insert(Exchange::USD("2012-01-03".to_string()), 3.4277f64);
-    exchange_rates.insert(Exchange::USD("2016-12-02".to_string()), 4.215f64);
-    exchange_rates.insert(Exchange::USD("2018-08-31".to_string()), 3.6808f64);
-    exchange_rates.insert(Exchange::USD("2025-12-03".to_string()), 3.6333f64);
-    exchange_rates.insert(Exchange::USD("2020-02-12".to_string()), 3.901f64);
-    exchange_rates.insert(Exchange::USD("2013-02-05".to_string()), 3.0831f64);
-    exchange_rates.insert(Exchange::USD("2016-03-15".to_string()), 3.8649f64);
-    exchange_rates.insert(Exchange::USD("2015-09-15".to_string()), 3.7238f64);
-    exchange_rates.insert(Exchange::USD("2013-06-28".to_string()), 3.3175f64);
-    exchange_rates.insert(Exchange::USD("2012-08-17".to_string()), 3.2886f64);
-    exchange_rates.insert(Exchange::USD("2015-07-16".to_string()), 3.7694f64);
-    exchange_rates.insert(Exchange::USD("2025-10-02".to_string()), 3.621f64);
-    exchange_rates.insert(Exchange::USD("2014-08-12".to_string()), 3.1465f64);
-    exchange_rates.insert(Exchange::USD("2012-10-04".to_string()), 3.1646f64);
-    exchange_rates.insert(Exchange::USD("2016-05-17".to_string()), 3.8508f64);
-    exchange_rates.insert(Exchange::USD("2018-06-22".to_string()), 3.6977f64);
-    exchange_rates.insert(Exchange::USD("2022-01-21".to_string()), 3.9953f64);
-    exchange_rates.insert(Exchange::USD("2019-08-29".to_string()), 3.9586f64);
-    exchange_rates.insert(Exchange::USD("2021-03-10".to_string()), 3.842f64);
-    exchange_rates.insert(Exchange::USD("2018-06-07".to_string()), 3.6062f64);
-    exchange_rates.insert(Exchange::USD("2016-07-25".to_string()), 3.9697f64);
-    exchange_rates.insert(Exchange::USD("2024-02-02".to_string()), 3.9641f64);
-    exchange_rates.insert(Exchange::USD("2020-11-04".to_string()), 3.8996f64);
-    exchange_rates.insert(Exchange::USD("2014-01-09".to_string()), 3.0695f64);
-    exchange_rates.insert(Exchange::USD("2014-01-17".to_string()), 3.0596f64);
-    exchange_rates.insert(Exchange::USD("2013-07-15".to_string()), 3.2955f64);
-    exchange_rates.insert(Exchange::USD("2025-07-15".to_string()), 3.6396f64);
-    exchange_rates.insert(Exchange::USD("2017-07-04".to_string()), 3.7372f64);
-    exchange_rates.insert(Exchange::USD("2013-06-27".to_string()), 3.3241f64);
-    exchange_rates.insert(Exchange::USD("2014-09-12".to_string()), 3.2468f64);
-    exchange_rates.insert(Exchange::USD("2016-06-01".to_string()), 3.935f64);
-    exchange_rates.insert(Exchange::USD("2019-06-19".to_string()), 3.8104f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-08".to_string()), 4.7402f64);
-    exchange_rates.insert(Exchange::USD("2018-02-20".to_string()), 3.3597f64);
-    exchange_rates.insert(Exchange::USD("2024-06-17".to_string()), 4.0728f64);
-    exchange_rates.insert(Exchange::USD("2016-08-09".to_string()), 3.8482f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-27".to_string()), 4.1371f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-08".to_string()), 4.2455f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-02".to_string()), 4.2651f64);
-    exchange_rates.insert(Exchange::USD("2017-03-20".to_string()), 3.9821f64);
-    exchange_rates.insert(Exchange::USD("2018-02-15".to_string()), 3.3259f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-07".to_string()), 4.7476f64);
-    exchange_rates.insert(Exchange::USD("2021-06-08".to_string()), 3.669f64);
-    exchange_rates.insert(Exchange::USD("2014-05-02".to_string()), 3.0289f64);
-    exchange_rates.insert(Exchange::USD("2012-01-23".to_string()), 3.3353f64);
-    exchange_rates.insert(Exchange::USD("2016-03-29".to_string()), 3.8037f64);
-    exchange_rates.insert(Exchange::USD("2025-02-27".to_string()), 3.9459f64);
-    exchange_rates.insert(Exchange::USD("2019-03-11".to_string()), 3.8199f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-09".to_string()), 4.4413f64);
-    exchange_rates.insert(Exchange::USD("2025-08-11".to_string()), 3.6495f64);
-    exchange_rates.insert(Exchange::USD("2023-01-05".to_string()), 4.397f64);
-    exchange_rates.insert(Exchange::USD("2024-02-05".to_string()), 4.0244f64);
-    exchange_rates.insert(Exchange::USD("2013-12-06".to_string()), 3.0673f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-25".to_string()), 4.4758f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-26".to_string()), 4.3117f64);
-    exchange_rates.insert(Exchange::USD("2024-11-21".to_string()), 4.1332f64);
-    exchange_rates.insert(Exchange::USD("2014-12-05".to_string()), 3.3619f64);
-    exchange_rates.insert(Exchange::USD("2020-02-13".to_string()), 3.9104f64);
-    exchange_rates.insert(Exchange::USD("2020-10-08".to_string()), 3.8175f64);
-    exchange_rates.insert(Exchange::USD("2013-01-14".to_string()), 3.0828f64);
-    exchange_rates.insert(Exchange::USD("2013-12-10".to_string()), 3.0425f64);
-    exchange_rates.insert(Exchange::USD("2024-12-06".to_string()), 4.0341f64);
-    exchange_rates.insert(Exchange::USD("2018-04-05".to_string()), 3.4301f64);
-    exchange_rates.insert(Exchange::USD("2020-06-02".to_string()), 3.9303f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-23".to_string()), 4.2552f64);
-    exchange_rates.insert(Exchange::USD("2017-01-11".to_string()), 4.1465f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-19".to_string()), 4.2785f64);
-    exchange_rates.insert(Exchange::USD("2022-03-07".to_string()), 4.5722f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-30".to_string()), 4.2845f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-02".to_string()), 4.6784f64);
-    exchange_rates.insert(Exchange::USD("2022-04-29".to_string()), 4.4072f64);
-    exchange_rates.insert(Exchange::USD("2018-02-22".to_string()), 3.4009f64);
-    exchange_rates.insert(Exchange::USD("2024-03-19".to_string()), 3.9866f64);
-    exchange_rates.insert(Exchange::USD("2016-07-18".to_string()), 3.9853f64);
-    exchange_rates.insert(Exchange::USD("2021-02-05".to_string()), 3.7554f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-20".to_string()), 4.3341f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-29".to_string()), 4.469f64);
-    exchange_rates.insert(Exchange::USD("2023-01-10".to_string()), 4.372f64);
-    exchange_rates.insert(Exchange::USD("2022-02-10".to_string()), 3.9218f64);
-    exchange_rates.insert(Exchange::USD("2018-04-06".to_string()), 3.4315f64);
-    exchange_rates.insert(Exchange::USD("2025-12-08".to_string()), 3.63f64);
-    exchange_rates.insert(Exchange::USD("2024-11-19".to_string()), 4.1052f64);
-    exchange_rates.insert(Exchange::USD("2019-08-01".to_string()), 3.886f64);
-    exchange_rates.insert(Exchange::USD("2024-09-02".to_string()), 3.8684f64);
-    exchange_rates.insert(Exchange::USD("2016-03-09".to_string()), 3.9395f64);
-    exchange_rates.insert(Exchange::USD("2023-08-14".to_string()), 4.0525f64);
-    exchange_rates.insert(Exchange::USD("2020-03-23".to_string()), 4.2654f64);
-    exchange_rates.insert(Exchange::USD("2018-07-27".to_string()), 3.6937f64);
-    exchange_rates.insert(Exchange::USD("2012-07-10".to_string()), 3.4145f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-17".to_string()), 4.4945f64);
-    exchange_rates.insert(Exchange::USD("2018-12-10".to_string()), 3.76f64);
-    exchange_rates.insert(Exchange::USD("2017-01-31".to_string()), 4.0446f64);
-    exchange_rates.insert(Exchange::USD("2012-03-20".to_string()), 3.1288f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-10".to_string()), 4.4553f64);
-    exchange_rates.insert(Exchange::USD("2022-06-30".to_string()), 4.4825f64);
-    exchange_rates.insert(Exchange::USD("2012-11-09".to_string()), 3.2588f64);
-    exchange_rates.insert(Exchange::USD("2018-02-19".to_string()), 3.3411f64);
-    exchange_rates.insert(Exchange::USD("2024-10-30".to_string()), 3.9989f64);
-    exchange_rates.insert(Exchange::USD("2019-02-14".to_string()), 3.8516f64);
-    exchange_rates.insert(Exchange::USD("2022-01-31".to_string()), 4.1147f64);
-    exchange_rates.insert(Exchange::USD("2018-07-12".to_string()), 3.7055f64);
-    exchange_rates.insert(Exchange::USD("2019-08-19".to_string()), 3.9199f64);
-    exchange_rates.insert(Exchange::USD("2025-09-17".to_string()), 3.5919f64);
-    exchange_rates.insert(Exchange::USD("2015-06-17".to_string()), 3.6873f64);
-    exchange_rates.insert(Exchange::USD("2012-06-14".to_string()), 3.442f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-04".to_string()), 4.4438f64);
-    exchange_rates.insert(Exchange::USD("2017-04-24".to_string()), 3.906f64);
-    exchange_rates.insert(Exchange::USD("2020-05-11".to_string()), 4.2126f64);
-    exchange_rates.insert(Exchange::USD("2022-12-12".to_string()), 4.4424f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-16".to_string()), 4.4804f64);
-    exchange_rates.insert(Exchange::USD("2012-01-09".to_string()), 3.515f64);
-    exchange_rates.insert(Exchange::USD("2025-04-24".to_string()), 3.7599f64);
-    exchange_rates.insert(Exchange::USD("2023-08-22".to_string()), 4.0924f64);
-    exchange_rates.insert(Exchange::USD("2017-11-15".to_string()), 3.5886f64);
-    exchange_rates.insert(Exchange::USD("2018-06-27".to_string()), 3.7351f64);
-    exchange_rates.insert(Exchange::USD("2015-12-11".to_string()), 3.9718f64);
-    exchange_rates.insert(Exchange::USD("2013-09-03".to_string()), 3.2433f64);
-    exchange_rates.insert(Exchange::USD("2017-11-20".to_string()), 3.5909f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-03".to_string()), 4.4569f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-20".to_string()), 4.2289f64);
-    exchange_rates.insert(Exchange::USD("2019-06-28".to_string()), 3.7336f64);
-    exchange_rates.insert(Exchange::USD("2022-06-22".to_string()), 4.4338f64);
-    exchange_rates.insert(Exchange::USD("2016-09-29".to_string()), 3.8354f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-29".to_string()), 4.2684f64);
-    exchange_rates.insert(Exchange::USD("2025-04-10".to_string()), 3.8637f64);
-    exchange_rates.insert(Exchange::USD("2018-05-15".to_string()), 3.5931f64);
-    exchange_rates.insert(Exchange::USD("2018-12-06".to_string()), 3.7844f64);
-    exchange_rates.insert(Exchange::USD("2017-10-12".to_string()), 3.6f64);
-    exchange_rates.insert(Exchange::USD("2019-03-18".to_string()), 3.7876f64);
-    exchange_rates.insert(Exchange::USD("2016-03-11".to_string()), 3.8903f64);
-    exchange_rates.insert(Exchange::USD("2023-01-18".to_string()), 4.3322f64);
-    exchange_rates.insert(Exchange::USD("2018-11-27".to_string()), 3.7948f64);
-    exchange_rates.insert(Exchange::USD("2016-10-10".to_string()), 3.8307f64);
-    exchange_rates.insert(Exchange::USD("2020-05-22".to_string()), 4.1503f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-18".to_string()), 4.2628f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-14".to_string()), 4.1632f64);
-    exchange_rates.insert(Exchange::USD("2021-10-20".to_string()), 3.9428f64);
-    exchange_rates.insert(Exchange::USD("2023-07-17".to_string()), 3.9616f64);
-    exchange_rates.insert(Exchange::USD("2021-05-10".to_string()), 3.7493f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-15".to_string()), 4.3198f64);
-    exchange_rates.insert(Exchange::USD("2025-06-02".to_string()), 3.7282f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-02".to_string()), 4.2668f64);
-    exchange_rates.insert(Exchange::USD("2013-06-12".to_string()), 3.2002f64);
-    exchange_rates.insert(Exchange::USD("2020-06-18".to_string()), 3.9589f64);
-    exchange_rates.insert(Exchange::USD("2017-11-09".to_string()), 3.6466f64);
-    exchange_rates.insert(Exchange::USD("2013-09-04".to_string()), 3.2387f64);
-    exchange_rates.insert(Exchange::USD("2023-10-23".to_string()), 4.2022f64);
-    exchange_rates.insert(Exchange::USD("2024-02-22".to_string()), 3.9804f64);
-    exchange_rates.insert(Exchange::USD("2018-08-07".to_string()), 3.6722f64);
-    exchange_rates.insert(Exchange::USD("2016-09-09".to_string()), 3.8385f64);
-    exchange_rates.insert(Exchange::USD("2025-12-17".to_string()), 3.5976f64);
-    exchange_rates.insert(Exchange::USD("2018-12-04".to_string()), 3.7516f64);
-    exchange_rates.insert(Exchange::USD("2018-12-31".to_string()), 3.7597f64);
-    exchange_rates.insert(Exchange::USD("2019-07-23".to_string()), 3.7997f64);
-    exchange_rates.insert(Exchange::USD("2012-03-26".to_string()), 3.1348f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-19".to_string()), 4.3376f64);
-    exchange_rates.insert(Exchange::USD("2012-10-05".to_string()), 3.1353f64);
-    exchange_rates.insert(Exchange::USD("2021-08-19".to_string()), 3.9226f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-14".to_string()), 4.7847f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-29".to_string()), 4.2529f64);
-    exchange_rates.insert(Exchange::USD("2024-11-27".to_string()), 4.0974f64);
-    exchange_rates.insert(Exchange::USD("2017-05-02".to_string()), 3.8675f64);
-    exchange_rates.insert(Exchange::USD("2024-10-23".to_string()), 4.0176f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-04".to_string()), 4.2403f64);
-    exchange_rates.insert(Exchange::USD("2014-07-10".to_string()), 3.0323f64);
-    exchange_rates.insert(Exchange::USD("2018-11-28".to_string()), 3.8114f64);
-    exchange_rates.insert(Exchange::USD("2012-10-02".to_string()), 3.1833f64);
-    exchange_rates.insert(Exchange::USD("2018-07-23".to_string()), 3.6957f64);
-    exchange_rates.insert(Exchange::USD("2021-09-20".to_string()), 3.9282f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-20".to_string()), 4.2572f64);
-    exchange_rates.insert(Exchange::USD("2016-01-21".to_string()), 4.1303f64);
-    exchange_rates.insert(Exchange::USD("2022-12-23".to_string()), 4.37f64);
-    exchange_rates.insert(Exchange::USD("2025-07-29".to_string()), 3.6941f64);
-    exchange_rates.insert(Exchange::USD("2015-07-17".to_string()), 3.7676f64);
-    exchange_rates.insert(Exchange::USD("2017-09-25".to_string()), 3.5866f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-26".to_string()), 4.3093f64);
-    exchange_rates.insert(Exchange::USD("2022-09-06".to_string()), 4.7367f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-30".to_string()), 4.2798f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-12".to_string()), 4.328f64);
-    exchange_rates.insert(Exchange::USD("2022-04-20".to_string()), 4.2708f64);
-    exchange_rates.insert(Exchange::USD("2024-01-24".to_string()), 4.0131f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-06".to_string()), 4.2997f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-26".to_string()), 4.4708f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-08".to_string()), 4.2566f64);
-    exchange_rates.insert(Exchange::USD("2015-06-25".to_string()), 3.7305f64);
-    exchange_rates.insert(Exchange::USD("2017-06-14".to_string()), 3.7497f64);
-    exchange_rates.insert(Exchange::USD("2022-10-28".to_string()), 4.7477f64);
-    exchange_rates.insert(Exchange::USD("2022-02-25".to_string()), 4.1683f64);
-    exchange_rates.insert(Exchange::USD("2018-10-16".to_string()), 3.7036f64);
-    exchange_rates.insert(Exchange::USD("2023-02-21".to_string()), 4.4524f64);
-    exchange_rates.insert(Exchange::USD("2014-04-07".to_string()), 3.0446f64);
-    exchange_rates.insert(Exchange::USD("2014-05-28".to_string()), 3.0598f64);
-    exchange_rates.insert(Exchange::USD("2013-10-02".to_string()), 3.1212f64);
-    exchange_rates.insert(Exchange::USD("2023-03-06".to_string()), 4.4289f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-06".to_string()), 4.6902f64);
-    exchange_rates.insert(Exchange::USD("2021-10-04".to_string()), 3.9368f64);
-    exchange_rates.insert(Exchange::USD("2021-09-08".to_string()), 3.8213f64);
-    exchange_rates.insert(Exchange::USD("2025-08-29".to_string()), 3.6559f64);
-    exchange_rates.insert(Exchange::USD("2024-04-17".to_string()), 4.0741f64);
-    exchange_rates.insert(Exchange::USD("2017-07-27".to_string()), 3.6366f64);
-    exchange_rates.insert(Exchange::USD("2012-03-22".to_string()), 3.168f64);
-    exchange_rates.insert(Exchange::USD("2020-08-28".to_string()), 3.6983f64);
-    exchange_rates.insert(Exchange::USD("2018-03-21".to_string()), 3.4544f64);
-    exchange_rates.insert(Exchange::USD("2013-03-15".to_string()), 3.1868f64);
-    exchange_rates.insert(Exchange::USD("2019-02-06".to_string()), 3.7706f64);
-    exchange_rates.insert(Exchange::USD("2022-10-24".to_string()), 4.8669f64);
-    exchange_rates.insert(Exchange::USD("2023-10-18".to_string()), 4.2035f64);
-    exchange_rates.insert(Exchange::USD("2023-04-11".to_string()), 4.2917f64);
-    exchange_rates.insert(Exchange::USD("2023-12-22".to_string()), 3.9338f64);
-    exchange_rates.insert(Exchange::USD("2014-04-17".to_string()), 3.0317f64);
-    exchange_rates.insert(Exchange::USD("2025-01-28".to_string()), 4.0337f64);
-    exchange_rates.insert(Exchange::USD("2025-11-26".to_string()), 3.6578f64);
-    exchange_rates.insert(Exchange::USD("2012-05-24".to_string()), 3.4779f64);
-    exchange_rates.insert(Exchange::USD("2022-09-21".to_string()), 4.7919f64);
-    exchange_rates.insert(Exchange::USD("2013-12-20".to_string()), 3.0525f64);
-    exchange_rates.insert(Exchange::USD("2017-04-14".to_string()), 3.9911f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-24".to_string()), 4.2345f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-04".to_string()), 4.1568f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-15".to_string()), 4.6307f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-13".to_string()), 4.4336f64);
-    exchange_rates.insert(Exchange::USD("2012-12-10".to_string()), 3.1978f64);
-    exchange_rates.insert(Exchange::USD("2012-12-17".to_string()), 3.1035f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-19".to_string()), 4.2693f64);
-    exchange_rates.insert(Exchange::USD("2022-01-10".to_string()), 4.0064f64);
-    exchange_rates.insert(Exchange::USD("2016-04-14".to_string()), 3.8174f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-06".to_string()), 4.3294f64);
-    exchange_rates.insert(Exchange::USD("2012-07-11".to_string()), 3.4007f64);
-    exchange_rates.insert(Exchange::USD("2019-02-04".to_string()), 3.7408f64);
-    exchange_rates.insert(Exchange::USD("2015-05-18".to_string()), 3.555f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-30".to_string()), 4.474f64);
-    exchange_rates.insert(Exchange::USD("2025-04-29".to_string()), 3.7458f64);
-    exchange_rates.insert(Exchange::USD("2018-07-26".to_string()), 3.6649f64);
-    exchange_rates.insert(Exchange::USD("2023-05-31".to_string()), 4.2559f64);
-    exchange_rates.insert(Exchange::USD("2013-07-03".to_string()), 3.3516f64);
-    exchange_rates.insert(Exchange::USD("2021-02-24".to_string()), 3.7145f64);
-    exchange_rates.insert(Exchange::USD("2015-05-28".to_string()), 3.7858f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-25".to_string()), 4.2971f64);
-    exchange_rates.insert(Exchange::USD("2024-03-05".to_string()), 3.9838f64);
-    exchange_rates.insert(Exchange::USD("2018-12-07".to_string()), 3.7694f64);
-    exchange_rates.insert(Exchange::USD("2024-03-07".to_string()), 3.9485f64);
-    exchange_rates.insert(Exchange::USD("2019-08-27".to_string()), 3.9309f64);
-    exchange_rates.insert(Exchange::USD("2018-05-22".to_string()), 3.6252f64);
-    exchange_rates.insert(Exchange::USD("2015-12-10".to_string()), 3.9601f64);
-    exchange_rates.insert(Exchange::USD("2014-12-10".to_string()), 3.3572f64);
-    exchange_rates.insert(Exchange::USD("2013-09-30".to_string()), 3.1227f64);
-    exchange_rates.insert(Exchange::USD("2021-01-11".to_string()), 3.7271f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-20".to_string()), 4.3238f64);
-    exchange_rates.insert(Exchange::USD("2022-11-29".to_string()), 4.5164f64);
-    exchange_rates.insert(Exchange::USD("2018-12-13".to_string()), 3.7739f64);
-    exchange_rates.insert(Exchange::USD("2021-08-06".to_string()), 3.8519f64);
-    exchange_rates.insert(Exchange::USD("2023-12-20".to_string()), 3.9527f64);
-    exchange_rates.insert(Exchange::USD("2018-05-02".to_string()), 3.5563f64);
-    exchange_rates.insert(Exchange::USD("2016-07-14".to_string()), 3.9681f64);
-    exchange_rates.insert(Exchange::USD("2020-09-21".to_string()), 3.7963f64);
-    exchange_rates.insert(Exchange::USD("2016-01-22".to_string()), 4.1031f64);
-    exchange_rates.insert(Exchange::USD("2024-08-01".to_string()), 3.9802f64);
-    exchange_rates.insert(Exchange::USD("2015-12-15".to_string()), 3.9523f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-03".to_string()), 4.2589f64);
-    exchange_rates.insert(Exchange::USD("2012-12-03".to_string()), 3.1498f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-02".to_string()), 4.256f64);
-    exchange_rates.insert(Exchange::USD("2023-07-28".to_string()), 4.0377f64);
-    exchange_rates.insert(Exchange::USD("2019-06-05".to_string()), 3.7929f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-23".to_string()), 4.2699f64);
-    exchange_rates.insert(Exchange::USD("2025-09-23".to_string()), 3.605f64);
-    exchange_rates.insert(Exchange::USD("2022-10-21".to_string()), 4.9f64);
-    exchange_rates.insert(Exchange::USD("2016-10-28".to_string()), 3.9696f64);
-    exchange_rates.insert(Exchange::USD("2025-10-10".to_string()), 3.6778f64);
-    exchange_rates.insert(Exchange::USD("2015-02-03".to_string()), 3.6886f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-27".to_string()), 4.4286f64);
-    exchange_rates.insert(Exchange::USD("2025-01-30".to_string()), 4.0393f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-28".to_string()), 4.2345f64);
-    exchange_rates.insert(Exchange::USD("2019-03-26".to_string()), 3.7969f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-24".to_string()), 4.6129f64);
-    exchange_rates.insert(Exchange::USD("2025-02-21".to_string()), 3.9835f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-16".to_string()), 4.2239f64);
-    exchange_rates.insert(Exchange::USD("2014-08-25".to_string()), 3.168f64);
-    exchange_rates.insert(Exchange::USD("2012-08-02".to_string()), 3.3462f64);
-    exchange_rates.insert(Exchange::USD("2021-06-11".to_string()), 3.6759f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-22".to_string()), 4.2115f64);
-    exchange_rates.insert(Exchange::USD("2015-08-31".to_string()), 3.778f64);
-    exchange_rates.insert(Exchange::USD("2020-07-07".to_string()), 3.9689f64);
-    exchange_rates.insert(Exchange::USD("2022-12-13".to_string()), 4.4527f64);
-    exchange_rates.insert(Exchange::USD("2019-03-07".to_string()), 3.8038f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-20".to_string()), 4.1972f64);
-    exchange_rates.insert(Exchange::USD("2015-08-20".to_string()), 3.7659f64);
-    exchange_rates.insert(Exchange::USD("2013-11-21".to_string()), 3.1227f64);
-    exchange_rates.insert(Exchange::USD("2022-10-11".to_string()), 5.0171f64);
-    exchange_rates.insert(Exchange::USD("2023-03-09".to_string()), 4.4356f64);
-    exchange_rates.insert(Exchange::USD("2018-12-11".to_string()), 3.7763f64);
-    exchange_rates.insert(Exchange::USD("2014-10-31".to_string()), 3.3459f64);
-    exchange_rates.insert(Exchange::USD("2023-01-09".to_string()), 4.401f64);
-    exchange_rates.insert(Exchange::USD("2021-07-12".to_string()), 3.8378f64);
-    exchange_rates.insert(Exchange::USD("2021-01-18".to_string()), 3.7699f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-26".to_string()), 4.2514f64);
-    exchange_rates.insert(Exchange::USD("2022-09-02".to_string()), 4.7276f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-13".to_string()), 4.3309f64);
-    exchange_rates.insert(Exchange::USD("2025-02-04".to_string()), 4.0864f64);
-    exchange_rates.insert(Exchange::USD("2019-02-20".to_string()), 3.8221f64);
-    exchange_rates.insert(Exchange::USD("2016-08-11".to_string()), 3.8297f64);
-    exchange_rates.insert(Exchange::USD("2020-06-09".to_string()), 3.9453f64);
-    exchange_rates.insert(Exchange::USD("2012-02-15".to_string()), 3.166f64);
-    exchange_rates.insert(Exchange::USD("2021-10-08".to_string()), 3.9775f64);
-    exchange_rates.insert(Exchange::USD("2016-10-26".to_string()), 3.9517f64);
-    exchange_rates.insert(Exchange::USD("2017-08-17".to_string()), 3.6258f64);
-    exchange_rates.insert(Exchange::USD("2012-08-14".to_string()), 3.3059f64);
-    exchange_rates.insert(Exchange::USD("2012-08-08".to_string()), 3.304f64);
-    exchange_rates.insert(Exchange::USD("2023-09-18".to_string()), 4.3489f64);
-    exchange_rates.insert(Exchange::USD("2014-09-24".to_string()), 3.2525f64);
-    exchange_rates.insert(Exchange::USD("2014-07-24".to_string()), 3.0702f64);
-    exchange_rates.insert(Exchange::USD("2020-10-06".to_string()), 3.818f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-07".to_string()), 4.176f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-14".to_string()), 4.6285f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-27".to_string()), 4.3085f64);
-    exchange_rates.insert(Exchange::USD("2024-06-04".to_string()), 3.9448f64);
-    exchange_rates.insert(Exchange::USD("2024-01-26".to_string()), 4.0393f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-17".to_string()), 4.1635f64);
-    exchange_rates.insert(Exchange::USD("2012-03-23".to_string()), 3.1417f64);
-    exchange_rates.insert(Exchange::USD("2017-10-31".to_string()), 3.6529f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-16".to_string()), 4.6978f64);
-    exchange_rates.insert(Exchange::USD("2024-04-26".to_string()), 4.0245f64);
-    exchange_rates.insert(Exchange::USD("2025-08-22".to_string()), 3.6786f64);
-    exchange_rates.insert(Exchange::USD("2013-10-01".to_string()), 3.117f64);
-    exchange_rates.insert(Exchange::USD("2014-09-26".to_string()), 3.2776f64);
-    exchange_rates.insert(Exchange::USD("2024-07-19".to_string()), 3.9461f64);
-    exchange_rates.insert(Exchange::USD("2016-07-08".to_string()), 4.0001f64);
-    exchange_rates.insert(Exchange::USD("2017-01-18".to_string()), 4.087f64);
-    exchange_rates.insert(Exchange::USD("2016-06-27".to_string()), 4.0263f64);
-    exchange_rates.insert(Exchange::USD("2017-04-04".to_string()), 3.989f64);
-    exchange_rates.insert(Exchange::USD("2013-04-16".to_string()), 3.1559f64);
-    exchange_rates.insert(Exchange::USD("2015-10-22".to_string()), 3.7806f64);
-    exchange_rates.insert(Exchange::USD("2021-07-13".to_string()), 3.8533f64);
-    exchange_rates.insert(Exchange::USD("2013-12-13".to_string()), 3.0432f64);
-    exchange_rates.insert(Exchange::USD("2022-03-28".to_string()), 4.2784f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-22".to_string()), 4.285f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-28".to_string()), 4.2766f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-07".to_string()), 4.3466f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-13".to_string()), 4.1652f64);
-    exchange_rates.insert(Exchange::USD("2012-12-31".to_string()), 3.0996f64);
-    exchange_rates.insert(Exchange::USD("2019-08-12".to_string()), 3.8711f64);
-    exchange_rates.insert(Exchange::USD("2013-06-14".to_string()), 3.1654f64);
-    exchange_rates.insert(Exchange::USD("2015-04-14".to_string()), 3.8001f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-12".to_string()), 4.6888f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-23".to_string()), 4.3658f64);
-    exchange_rates.insert(Exchange::USD("2020-08-04".to_string()), 3.7364f64);
-    exchange_rates.insert(Exchange::USD("2025-05-22".to_string()), 3.7559f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-09".to_string()), 4.254f64);
-    exchange_rates.insert(Exchange::USD("2018-09-11".to_string()), 3.7087f64);
-    exchange_rates.insert(Exchange::USD("2014-05-19".to_string()), 3.0558f64);
-    exchange_rates.insert(Exchange::USD("2022-11-16".to_string()), 4.505f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-31".to_string()), 4.6755f64);
-    exchange_rates.insert(Exchange::USD("2025-06-16".to_string()), 3.6804f64);
-    exchange_rates.insert(Exchange::USD("2020-08-26".to_string()), 3.7269f64);
-    exchange_rates.insert(Exchange::USD("2019-04-11".to_string()), 3.7964f64);
-    exchange_rates.insert(Exchange::USD("2018-04-26".to_string()), 3.4827f64);
-    exchange_rates.insert(Exchange::USD("2018-11-20".to_string()), 3.79f64);
-    exchange_rates.insert(Exchange::USD("2018-11-13".to_string()), 3.8268f64);
-    exchange_rates.insert(Exchange::USD("2019-07-10".to_string()), 3.8066f64);
-    exchange_rates.insert(Exchange::USD("2017-01-25".to_string()), 4.0585f64);
-    exchange_rates.insert(Exchange::USD("2016-04-07".to_string()), 3.7414f64);
-    exchange_rates.insert(Exchange::USD("2025-07-23".to_string()), 3.626f64);
-    exchange_rates.insert(Exchange::USD("2017-12-22".to_string()), 3.5393f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-24".to_string()), 4.2514f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-16".to_string()), 4.4621f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-23".to_string()), 4.3326f64);
-    exchange_rates.insert(Exchange::USD("2019-07-22".to_string()), 3.7869f64);
-    exchange_rates.insert(Exchange::USD("2021-09-07".to_string()), 3.8041f64);
-    exchange_rates.insert(Exchange::USD("2024-11-13".to_string()), 4.0853f64);
-    exchange_rates.insert(Exchange::USD("2016-12-14".to_string()), 4.1768f64);
-    exchange_rates.insert(Exchange::USD("2022-05-02".to_string()), 4.4454f64);
-    exchange_rates.insert(Exchange::USD("2019-08-08".to_string()), 3.8538f64);
-    exchange_rates.insert(Exchange::USD("2022-02-21".to_string()), 3.9769f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-09".to_string()), 4.3351f64);
-    exchange_rates.insert(Exchange::USD("2020-03-31".to_string()), 4.1466f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-20".to_string()), 4.2779f64);
-    exchange_rates.insert(Exchange::USD("2018-01-16".to_string()), 3.419f64);
-    exchange_rates.insert(Exchange::USD("2015-11-23".to_string()), 3.988f64);
-    exchange_rates.insert(Exchange::USD("2016-08-26".to_string()), 3.8328f64);
-    exchange_rates.insert(Exchange::USD("2023-07-13".to_string()), 3.9724f64);
-    exchange_rates.insert(Exchange::USD("2017-08-08".to_string()), 3.6027f64);
-    exchange_rates.insert(Exchange::USD("2012-02-14".to_string()), 3.1801f64);
-    exchange_rates.insert(Exchange::USD("2020-02-06".to_string()), 3.8672f64);
-    exchange_rates.insert(Exchange::USD("2013-11-15".to_string()), 3.1172f64);
-    exchange_rates.insert(Exchange::USD("2016-02-19".to_string()), 3.9531f64);
-    exchange_rates.insert(Exchange::USD("2025-11-05".to_string()), 3.7091f64);
-    exchange_rates.insert(Exchange::USD("2015-04-07".to_string()), 3.7435f64);
-    exchange_rates.insert(Exchange::USD("2013-02-28".to_string()), 3.1679f64);
-    exchange_rates.insert(Exchange::USD("2015-10-02".to_string()), 3.8028f64);
-    exchange_rates.insert(Exchange::USD("2015-12-17".to_string()), 3.9646f64);
-    exchange_rates.insert(Exchange::USD("2014-11-05".to_string()), 3.3887f64);
-    exchange_rates.insert(Exchange::USD("2024-06-10".to_string()), 4.0159f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-17".to_string()), 4.3353f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-15".to_string()), 4.2913f64);
-    exchange_rates.insert(Exchange::USD("2014-02-11".to_string()), 3.0581f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-13".to_string()), 4.7895f64);
-    exchange_rates.insert(Exchange::USD("2024-10-15".to_string()), 3.9332f64);
-    exchange_rates.insert(Exchange::USD("2025-07-22".to_string()), 3.6353f64);
-    exchange_rates.insert(Exchange::USD("2023-12-13".to_string()), 4.0149f64);
-    exchange_rates.insert(Exchange::USD("2012-01-24".to_string()), 3.297f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-22".to_string()), 4.3159f64);
-    exchange_rates.insert(Exchange::USD("2025-11-06".to_string()), 3.6971f64);
-    exchange_rates.insert(Exchange::USD("2021-07-23".to_string()), 3.8851f64);
-    exchange_rates.insert(Exchange::USD("2020-03-11".to_string()), 3.8186f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-07".to_string()), 4.3116f64);
-    exchange_rates.insert(Exchange::USD("2017-05-31".to_string()), 3.7354f64);
-    exchange_rates.insert(Exchange::USD("2021-12-20".to_string()), 4.1182f64);
-    exchange_rates.insert(Exchange::USD("2025-03-14".to_string()), 3.8509f64);
-    exchange_rates.insert(Exchange::USD("2023-03-24".to_string()), 4.3742f64);
-    exchange_rates.insert(Exchange::USD("2020-04-03".to_string()), 4.2396f64);
-    exchange_rates.insert(Exchange::USD("2016-03-14".to_string()), 3.847f64);
-    exchange_rates.insert(Exchange::USD("2016-05-25".to_string()), 3.9684f64);
-    exchange_rates.insert(Exchange::USD("2012-04-16".to_string()), 3.2289f64);
-    exchange_rates.insert(Exchange::USD("2012-08-21".to_string()), 3.279f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-10".to_string()), 4.2464f64);
-    exchange_rates.insert(Exchange::USD("2020-10-30".to_string()), 3.9563f64);
-    exchange_rates.insert(Exchange::USD("2016-12-16".to_string()), 4.2296f64);
-    exchange_rates.insert(Exchange::USD("2023-08-10".to_string()), 4.0521f64);
-    exchange_rates.insert(Exchange::USD("2015-05-26".to_string()), 3.7898f64);
-    exchange_rates.insert(Exchange::USD("2022-11-07".to_string()), 4.6926f64);
-    exchange_rates.insert(Exchange::USD("2014-11-24".to_string()), 3.3842f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-27".to_string()), 4.309f64);
-    exchange_rates.insert(Exchange::USD("2019-05-21".to_string()), 3.8625f64);
-    exchange_rates.insert(Exchange::USD("2016-01-19".to_string()), 4.0917f64);
-    exchange_rates.insert(Exchange::USD("2023-12-05".to_string()), 3.991f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-05".to_string()), 4.5885f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-09".to_string()), 4.2588f64);
-    exchange_rates.insert(Exchange::USD("2018-01-19".to_string()), 3.3994f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-06".to_string()), 4.1766f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-17".to_string()), 4.2565f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-27".to_string()), 4.2176f64);
-    exchange_rates.insert(Exchange::USD("2017-12-04".to_string()), 3.5499f64);
-    exchange_rates.insert(Exchange::USD("2024-02-07".to_string()), 4.0362f64);
-    exchange_rates.insert(Exchange::USD("2017-07-11".to_string()), 3.7235f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-22".to_string()), 4.2575f64);
-    exchange_rates.insert(Exchange::USD("2022-10-19".to_string()), 4.8892f64);
-    exchange_rates.insert(Exchange::USD("2020-03-25".to_string()), 4.222f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-10".to_string()), 4.3197f64);
-    exchange_rates.insert(Exchange::USD("2022-02-14".to_string()), 4.0439f64);
-    exchange_rates.insert(Exchange::USD("2022-07-26".to_string()), 4.6222f64);
-    exchange_rates.insert(Exchange::USD("2020-01-17".to_string()), 3.8053f64);
-    exchange_rates.insert(Exchange::USD("2015-02-27".to_string()), 3.698f64);
-    exchange_rates.insert(Exchange::USD("2014-05-08".to_string()), 3.0042f64);
-    exchange_rates.insert(Exchange::USD("2019-11-19".to_string()), 3.8786f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-07".to_string()), 4.3045f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-03".to_string()), 4.692f64);
-    exchange_rates.insert(Exchange::USD("2016-09-19".to_string()), 3.8586f64);
-    exchange_rates.insert(Exchange::USD("2019-12-02".to_string()), 3.9047f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-23".to_string()), 4.2893f64);
-    exchange_rates.insert(Exchange::USD("2020-01-14".to_string()), 3.8019f64);
-    exchange_rates.insert(Exchange::USD("2023-06-07".to_string()), 4.1887f64);
-    exchange_rates.insert(Exchange::USD("2018-07-03".to_string()), 3.7723f64);
-    exchange_rates.insert(Exchange::USD("2017-09-04".to_string()), 3.5718f64);
-    exchange_rates.insert(Exchange::USD("2017-10-02".to_string()), 3.6726f64);
-    exchange_rates.insert(Exchange::USD("2014-09-29".to_string()), 3.3f64);
-    exchange_rates.insert(Exchange::USD("2015-12-24".to_string()), 3.8695f64);
-    exchange_rates.insert(Exchange::USD("2023-07-26".to_string()), 3.9949f64);
-    exchange_rates.insert(Exchange::USD("2023-10-11".to_string()), 4.2589f64);
-    exchange_rates.insert(Exchange::USD("2014-01-10".to_string()), 3.07f64);
-    exchange_rates.insert(Exchange::USD("2022-03-08".to_string()), 4.5115f64);
-    exchange_rates.insert(Exchange::USD("2024-04-24".to_string()), 4.0417f64);
-    exchange_rates.insert(Exchange::USD("2024-09-27".to_string()), 3.8368f64);
-    exchange_rates.insert(Exchange::USD("2016-05-20".to_string()), 3.9358f64);
-    exchange_rates.insert(Exchange::USD("2023-04-19".to_string()), 4.2244f64);
-    exchange_rates.insert(Exchange::USD("2014-09-03".to_string()), 3.1912f64);
-    exchange_rates.insert(Exchange::USD("2019-01-15".to_string()), 3.7542f64);
-    exchange_rates.insert(Exchange::USD("2015-12-08".to_string()), 3.9877f64);
-    exchange_rates.insert(Exchange::USD("2015-10-26".to_string()), 3.8645f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-02".to_string()), 4.675f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-11".to_string()), 4.6209f64);
-    exchange_rates.insert(Exchange::USD("2023-05-10".to_string()), 4.1532f64);
-    exchange_rates.insert(Exchange::USD("2017-10-26".to_string()), 3.5939f64);
-    exchange_rates.insert(Exchange::USD("2013-07-17".to_string()), 3.241f64);
-    exchange_rates.insert(Exchange::USD("2021-12-09".to_string()), 4.0837f64);
-    exchange_rates.insert(Exchange::USD("2022-01-04".to_string()), 4.0468f64);
-    exchange_rates.insert(Exchange::USD("2021-08-05".to_string()), 3.8485f64);
-    exchange_rates.insert(Exchange::USD("2012-06-26".to_string()), 3.4088f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-05".to_string()), 4.2791f64);
-    exchange_rates.insert(Exchange::USD("2023-09-25".to_string()), 4.3188f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-21".to_string()), 4.4408f64);
-    exchange_rates.insert(Exchange::USD("2015-02-20".to_string()), 3.6895f64);
-    exchange_rates.insert(Exchange::USD("2015-03-23".to_string()), 3.818f64);
-    exchange_rates.insert(Exchange::USD("2023-02-28".to_string()), 4.4475f64);
-    exchange_rates.insert(Exchange::USD("2025-04-15".to_string()), 3.7692f64);
-    exchange_rates.insert(Exchange::USD("2017-11-17".to_string()), 3.5965f64);
-    exchange_rates.insert(Exchange::USD("2015-08-17".to_string()), 3.7613f64);
-    exchange_rates.insert(Exchange::USD("2020-05-19".to_string()), 4.1645f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-06".to_string()), 4.3106f64);
-    exchange_rates.insert(Exchange::USD("2021-12-22".to_string()), 4.0984f64);
-    exchange_rates.insert(Exchange::USD("2018-02-21".to_string()), 3.3697f64);
-    exchange_rates.insert(Exchange::USD("2022-02-18".to_string()), 3.9798f64);
-    exchange_rates.insert(Exchange::USD("2017-02-22".to_string()), 4.0899f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-10".to_string()), 4.5581f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-07".to_string()), 4.2975f64);
-    exchange_rates.insert(Exchange::USD("2022-04-14".to_string()), 4.2581f64);
-    exchange_rates.insert(Exchange::USD("2014-10-15".to_string()), 3.3262f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-13".to_string()), 4.196f64);
-    exchange_rates.insert(Exchange::USD("2013-01-03".to_string()), 3.1005f64);
-    exchange_rates.insert(Exchange::USD("2019-11-25".to_string()), 3.9036f64);
-    exchange_rates.insert(Exchange::USD("2021-10-27".to_string()), 3.9815f64);
-    exchange_rates.insert(Exchange::USD("2022-06-03".to_string()), 4.2689f64);
-    exchange_rates.insert(Exchange::USD("2014-04-11".to_string()), 3.0086f64);
-    exchange_rates.insert(Exchange::USD("2013-01-30".to_string()), 3.0938f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-23".to_string()), 4.7186f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-25".to_string()), 4.3319f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-29".to_string()), 4.2862f64);
-    exchange_rates.insert(Exchange::USD("2013-09-18".to_string()), 3.1689f64);
-    exchange_rates.insert(Exchange::USD("2021-06-04".to_string()), 3.6931f64);
-    exchange_rates.insert(Exchange::USD("2018-10-29".to_string()), 3.793f64);
-    exchange_rates.insert(Exchange::USD("2021-10-25".to_string()), 3.9502f64);
-    exchange_rates.insert(Exchange::USD("2018-12-05".to_string()), 3.7832f64);
-    exchange_rates.insert(Exchange::USD("2014-08-27".to_string()), 3.1764f64);
-    exchange_rates.insert(Exchange::USD("2014-08-11".to_string()), 3.1378f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-19".to_string()), 4.2633f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-20".to_string()), 4.4675f64);
-    exchange_rates.insert(Exchange::USD("2024-11-14".to_string()), 4.122f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-05".to_string()), 4.6709f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-04".to_string()), 4.3201f64);
-    exchange_rates.insert(Exchange::USD("2022-05-19".to_string()), 4.4231f64);
-    exchange_rates.insert(Exchange::USD("2017-04-03".to_string()), 3.9664f64);
-    exchange_rates.insert(Exchange::USD("2016-02-17".to_string()), 3.9431f64);
-    exchange_rates.insert(Exchange::USD("2025-10-14".to_string()), 3.6897f64);
-    exchange_rates.insert(Exchange::USD("2020-10-09".to_string()), 3.7913f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-01".to_string()), 4.6925f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-16".to_string()), 4.4583f64);
-    exchange_rates.insert(Exchange::USD("2023-03-29".to_string()), 4.3247f64);
-    exchange_rates.insert(Exchange::USD("2022-05-16".to_string()), 4.4785f64);
-    exchange_rates.insert(Exchange::USD("2019-03-08".to_string()), 3.8409f64);
-    exchange_rates.insert(Exchange::USD("2012-01-12".to_string()), 3.4945f64);
-    exchange_rates.insert(Exchange::USD("2025-07-16".to_string()), 3.6631f64);
-    exchange_rates.insert(Exchange::USD("2012-02-24".to_string()), 3.1103f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-30".to_string()), 4.2738f64);
-    exchange_rates.insert(Exchange::USD("2025-12-02".to_string()), 3.6512f64);
-    exchange_rates.insert(Exchange::USD("2019-01-29".to_string()), 3.7563f64);
-    exchange_rates.insert(Exchange::USD("2021-05-04".to_string()), 3.794f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-18".to_string()), 4.2954f64);
-    exchange_rates.insert(Exchange::USD("2014-12-30".to_string()), 3.5458f64);
-    exchange_rates.insert(Exchange::USD("2024-07-24".to_string()), 3.9498f64);
-    exchange_rates.insert(Exchange::USD("2021-05-28".to_string()), 3.6766f64);
-    exchange_rates.insert(Exchange::USD("2012-11-22".to_string()), 3.2021f64);
-    exchange_rates.insert(Exchange::USD("2024-04-22".to_string()), 4.054f64);
-    exchange_rates.insert(Exchange::USD("2023-03-22".to_string()), 4.3467f64);
-    exchange_rates.insert(Exchange::USD("2019-01-16".to_string()), 3.7619f64);
-    exchange_rates.insert(Exchange::USD("2022-09-16".to_string()), 4.7384f64);
-    exchange_rates.insert(Exchange::USD("2025-02-11".to_string()), 4.0497f64);
-    exchange_rates.insert(Exchange::USD("2019-08-14".to_string()), 3.889f64);
-    exchange_rates.insert(Exchange::USD("2025-03-11".to_string()), 3.8481f64);
-    exchange_rates.insert(Exchange::USD("2017-05-29".to_string()), 3.7371f64);
-    exchange_rates.insert(Exchange::USD("2016-03-08".to_string()), 3.9333f64);
-    exchange_rates.insert(Exchange::USD("2013-11-20".to_string()), 3.0947f64);
-    exchange_rates.insert(Exchange::USD("2013-02-13".to_string()), 3.1012f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-16".to_string()), 4.2504f64);
-    exchange_rates.insert(Exchange::USD("2019-03-13".to_string()), 3.8077f64);
-    exchange_rates.insert(Exchange::USD("2017-03-14".to_string()), 4.0697f64);
-    exchange_rates.insert(Exchange::USD("2013-04-12".to_string()), 3.1537f64);
-    exchange_rates.insert(Exchange::USD("2017-09-07".to_string()), 3.5511f64);
-    exchange_rates.insert(Exchange::USD("2020-09-09".to_string()), 3.7871f64);
-    exchange_rates.insert(Exchange::USD("2022-08-22".to_string()), 4.7427f64);
-    exchange_rates.insert(Exchange::USD("2020-08-17".to_string()), 3.7076f64);
-    exchange_rates.insert(Exchange::USD("2018-08-08".to_string()), 3.6782f64);
-    exchange_rates.insert(Exchange::USD("2012-10-01".to_string()), 3.1809f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-07".to_string()), 4.2515f64);
-    exchange_rates.insert(Exchange::USD("2019-06-26".to_string()), 3.754f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-20".to_string()), 4.2709f64);
-    exchange_rates.insert(Exchange::USD("2018-10-12".to_string()), 3.713f64);
-    exchange_rates.insert(Exchange::USD("2014-05-21".to_string()), 3.054f64);
-    exchange_rates.insert(Exchange::USD("2019-08-23".to_string()), 3.9371f64);
-    exchange_rates.insert(Exchange::USD("2015-03-09".to_string()), 3.789f64);
-    exchange_rates.insert(Exchange::USD("2015-10-01".to_string()), 3.8005f64);
-    exchange_rates.insert(Exchange::USD("2024-03-08".to_string()), 3.9392f64);
-    exchange_rates.insert(Exchange::USD("2016-11-15".to_string()), 4.0837f64);
-    exchange_rates.insert(Exchange::USD("2018-12-27".to_string()), 3.7616f64);
-    exchange_rates.insert(Exchange::USD("2024-01-05".to_string()), 3.985f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-26".to_string()), 4.4248f64);
-    exchange_rates.insert(Exchange::USD("2012-12-12".to_string()), 3.1459f64);
-    exchange_rates.insert(Exchange::USD("2021-02-25".to_string()), 3.6943f64);
-    exchange_rates.insert(Exchange::USD("2014-10-02".to_string()), 3.3039f64);
-    exchange_rates.insert(Exchange::USD("2025-03-20".to_string()), 3.8675f64);
-    exchange_rates.insert(Exchange::USD("2024-11-22".to_string()), 4.1752f64);
-    exchange_rates.insert(Exchange::USD("2014-06-04".to_string()), 3.0491f64);
-    exchange_rates.insert(Exchange::USD("2024-09-24".to_string()), 3.83f64);
-    exchange_rates.insert(Exchange::USD("2025-05-28".to_string()), 3.7456f64);
-    exchange_rates.insert(Exchange::USD("2015-10-27".to_string()), 3.8669f64);
-    exchange_rates.insert(Exchange::USD("2013-08-09".to_string()), 3.1336f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-05".to_string()), 4.2476f64);
-    exchange_rates.insert(Exchange::USD("2022-01-25".to_string()), 4.0593f64);
-    exchange_rates.insert(Exchange::USD("2013-05-10".to_string()), 3.1861f64);
-    exchange_rates.insert(Exchange::USD("2023-05-15".to_string()), 4.149f64);
-    exchange_rates.insert(Exchange::USD("2019-01-23".to_string()), 3.7747f64);
-    exchange_rates.insert(Exchange::USD("2024-11-18".to_string()), 4.0997f64);
-    exchange_rates.insert(Exchange::USD("2025-09-05".to_string()), 3.6357f64);
-    exchange_rates.insert(Exchange::USD("2014-09-04".to_string()), 3.1947f64);
-    exchange_rates.insert(Exchange::USD("2013-05-17".to_string()), 3.252f64);
-    exchange_rates.insert(Exchange::USD("2012-09-04".to_string()), 3.3281f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-14".to_string()), 4.2455f64);
-    exchange_rates.insert(Exchange::USD("2014-05-05".to_string()), 3.031f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-15".to_string()), 4.2851f64);
-    exchange_rates.insert(Exchange::USD("2022-08-25".to_string()), 4.7546f64);
-    exchange_rates.insert(Exchange::USD("2013-03-29".to_string()), 3.259f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-30".to_string()), 4.6801f64);
-    exchange_rates.insert(Exchange::USD("2024-06-06".to_string()), 3.953f64);
-    exchange_rates.insert(Exchange::USD("2025-08-08".to_string()), 3.6577f64);
-    exchange_rates.insert(Exchange::USD("2021-01-27".to_string()), 3.7507f64);
-    exchange_rates.insert(Exchange::USD("2022-07-06".to_string()), 4.6796f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-11".to_string()), 4.2933f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-17".to_string()), 4.2559f64);
-    exchange_rates.insert(Exchange::USD("2019-05-09".to_string()), 3.841f64);
-    exchange_rates.insert(Exchange::USD("2021-11-03".to_string()), 3.9681f64);
-    exchange_rates.insert(Exchange::USD("2015-12-29".to_string()), 3.8659f64);
-    exchange_rates.insert(Exchange::USD("2015-07-07".to_string()), 3.8313f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-23".to_string()), 4.4552f64);
-    exchange_rates.insert(Exchange::USD("2022-12-07".to_string()), 4.4834f64);
-    exchange_rates.insert(Exchange::USD("2020-07-31".to_string()), 3.7166f64);
-    exchange_rates.insert(Exchange::USD("2020-05-27".to_string()), 4.0504f64);
-    exchange_rates.insert(Exchange::USD("2014-02-26".to_string()), 3.026f64);
-    exchange_rates.insert(Exchange::USD("2025-10-23".to_string()), 3.6519f64);
-    exchange_rates.insert(Exchange::USD("2014-01-20".to_string()), 3.068f64);
-    exchange_rates.insert(Exchange::USD("2024-03-04".to_string()), 3.982f64);
-    exchange_rates.insert(Exchange::USD("2017-12-28".to_string()), 3.5046f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-12".to_string()), 4.5329f64);
-    exchange_rates.insert(Exchange::EUR("2023-12-19".to_string()), 4.3321f64);
-    exchange_rates.insert(Exchange::USD("2012-05-14".to_string()), 3.3422f64);
-    exchange_rates.insert(Exchange::USD("2014-06-26".to_string()), 3.0361f64);
-    exchange_rates.insert(Exchange::USD("2018-04-12".to_string()), 3.3924f64);
-    exchange_rates.insert(Exchange::USD("2017-02-01".to_string()), 4.0071f64);
-    exchange_rates.insert(Exchange::USD("2017-08-22".to_string()), 3.6334f64);
-    exchange_rates.insert(Exchange::USD("2019-10-17".to_string()), 3.8638f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-22".to_string()), 4.2438f64);
-    exchange_rates.insert(Exchange::USD("2023-06-16".to_string()), 4.0715f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-17".to_string()), 4.2231f64);
-    exchange_rates.insert(Exchange::USD("2025-11-07".to_string()), 3.6818f64);
-    exchange_rates.insert(Exchange::USD("2019-05-23".to_string()), 3.8696f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-07".to_string()), 4.4803f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-12".to_string()), 4.2631f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-17".to_string()), 4.2599f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-01".to_string()), 4.2813f64);
-    exchange_rates.insert(Exchange::USD("2021-12-30".to_string()), 4.0631f64);
-    exchange_rates.insert(Exchange::USD("2022-12-08".to_string()), 4.476f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-22".to_string()), 4.2801f64);
-    exchange_rates.insert(Exchange::USD("2025-09-29".to_string()), 3.6411f64);
-    exchange_rates.insert(Exchange::USD("2022-05-12".to_string()), 4.4863f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-03".to_string()), 4.1827f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-08".to_string()), 4.4584f64);
-    exchange_rates.insert(Exchange::USD("2020-01-02".to_string()), 3.8f64);
-    exchange_rates.insert(Exchange::USD("2022-03-21".to_string()), 4.2444f64);
-    exchange_rates.insert(Exchange::USD("2017-08-16".to_string()), 3.6435f64);
-    exchange_rates.insert(Exchange::USD("2016-07-26".to_string()), 3.9682f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-21".to_string()), 4.2879f64);
-    exchange_rates.insert(Exchange::USD("2024-11-25".to_string()), 4.1297f64);
-    exchange_rates.insert(Exchange::USD("2020-09-28".to_string()), 3.9083f64);
-    exchange_rates.insert(Exchange::USD("2024-01-03".to_string()), 3.9909f64);
-    exchange_rates.insert(Exchange::USD("2014-09-02".to_string()), 3.2112f64);
-    exchange_rates.insert(Exchange::USD("2024-05-17".to_string()), 3.9363f64);
-    exchange_rates.insert(Exchange::USD("2025-01-17".to_string()), 4.1462f64);
-    exchange_rates.insert(Exchange::USD("2022-06-20".to_string()), 4.44f64);
-    exchange_rates.insert(Exchange::USD("2017-06-21".to_string()), 3.809f64);
-    exchange_rates.insert(Exchange::USD("2014-09-09".to_string()), 3.2605f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-31".to_string()), 4.2678f64);
-    exchange_rates.insert(Exchange::USD("2020-04-06".to_string()), 4.2257f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-31".to_string()), 4.2267f64);
-    exchange_rates.insert(Exchange::USD("2025-01-15".to_string()), 4.133f64);
-    exchange_rates.insert(Exchange::USD("2021-10-18".to_string()), 3.9471f64);
-    exchange_rates.insert(Exchange::USD("2022-04-26".to_string()), 4.3469f64);
-    exchange_rates.insert(Exchange::USD("2022-11-02".to_string()), 4.7485f64);
-    exchange_rates.insert(Exchange::USD("2022-12-14".to_string()), 4.3973f64);
-    exchange_rates.insert(Exchange::USD("2015-09-10".to_string()), 3.7648f64);
-    exchange_rates.insert(Exchange::USD("2017-02-27".to_string()), 4.0745f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-28".to_string()), 4.4257f64);
-    exchange_rates.insert(Exchange::USD("2016-12-27".to_string()), 4.212f64);
-    exchange_rates.insert(Exchange::USD("2013-03-12".to_string()), 3.1887f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-06".to_string()), 4.3662f64);
-    exchange_rates.insert(Exchange::USD("2021-04-19".to_string()), 3.7816f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-28".to_string()), 4.1819f64);
-    exchange_rates.insert(Exchange::USD("2014-04-08".to_string()), 3.0276f64);
-    exchange_rates.insert(Exchange::USD("2012-11-20".to_string()), 3.2333f64);
-    exchange_rates.insert(Exchange::USD("2021-07-20".to_string()), 3.9012f64);
-    exchange_rates.insert(Exchange::USD("2015-07-08".to_string()), 3.8225f64);
-    exchange_rates.insert(Exchange::USD("2012-05-10".to_string()), 3.2735f64);
-    exchange_rates.insert(Exchange::USD("2015-07-06".to_string()), 3.796f64);
-    exchange_rates.insert(Exchange::USD("2015-01-08".to_string()), 3.6482f64);
-    exchange_rates.insert(Exchange::USD("2024-07-08".to_string()), 3.947f64);
-    exchange_rates.insert(Exchange::USD("2019-05-29".to_string()), 3.8554f64);
-    exchange_rates.insert(Exchange::USD("2012-06-28".to_string()), 3.4408f64);
-    exchange_rates.insert(Exchange::USD("2012-10-24".to_string()), 3.1996f64);
-    exchange_rates.insert(Exchange::USD("2015-05-29".to_string()), 3.7671f64);
-    exchange_rates.insert(Exchange::USD("2021-07-06".to_string()), 3.8021f64);
-    exchange_rates.insert(Exchange::USD("2012-04-20".to_string()), 3.1828f64);
-    exchange_rates.insert(Exchange::USD("2018-10-10".to_string()), 3.7507f64);
-    exchange_rates.insert(Exchange::USD("2014-11-12".to_string()), 3.3966f64);
-    exchange_rates.insert(Exchange::USD("2017-08-02".to_string()), 3.5863f64);
-    exchange_rates.insert(Exchange::USD("2025-11-27".to_string()), 3.651f64);
-    exchange_rates.insert(Exchange::USD("2025-11-28".to_string()), 3.6624f64);
-    exchange_rates.insert(Exchange::USD("2022-10-03".to_string()), 4.9207f64);
-    exchange_rates.insert(Exchange::USD("2013-03-08".to_string()), 3.169f64);
-    exchange_rates.insert(Exchange::USD("2014-08-07".to_string()), 3.1397f64);
-    exchange_rates.insert(Exchange::USD("2023-08-09".to_string()), 4.0592f64);
-    exchange_rates.insert(Exchange::USD("2024-03-13".to_string()), 3.9269f64);
-    exchange_rates.insert(Exchange::USD("2021-04-28".to_string()), 3.7939f64);
-    exchange_rates.insert(Exchange::USD("2023-05-11".to_string()), 4.1414f64);
-    exchange_rates.insert(Exchange::USD("2020-11-13".to_string()), 3.8015f64);
-    exchange_rates.insert(Exchange::USD("2012-08-28".to_string()), 3.2685f64);
-    exchange_rates.insert(Exchange::USD("2012-10-19".to_string()), 3.1499f64);
-    exchange_rates.insert(Exchange::USD("2016-07-27".to_string()), 3.9739f64);
-    exchange_rates.insert(Exchange::USD("2013-05-28".to_string()), 3.2465f64);
-    exchange_rates.insert(Exchange::USD("2012-06-01".to_string()), 3.5777f64);
-    exchange_rates.insert(Exchange::USD("2014-05-07".to_string()), 3.0187f64);
-    exchange_rates.insert(Exchange::USD("2019-02-05".to_string()), 3.7542f64);
-    exchange_rates.insert(Exchange::USD("2019-09-25".to_string()), 3.9856f64);
-    exchange_rates.insert(Exchange::EUR("2023-09-04".to_string()), 4.4653f64);
-    exchange_rates.insert(Exchange::USD("2013-01-09".to_string()), 3.1516f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-16".to_string()), 4.262f64);
-    exchange_rates.insert(Exchange::USD("2018-04-19".to_string()), 3.3693f64);
-    exchange_rates.insert(Exchange::USD("2017-04-11".to_string()), 4.0009f64);
-    exchange_rates.insert(Exchange::USD("2017-08-07".to_string()), 3.5995f64);
-    exchange_rates.insert(Exchange::USD("2016-10-27".to_string()), 3.976f64);
-    exchange_rates.insert(Exchange::USD("2021-03-16".to_string()), 3.8519f64);
-    exchange_rates.insert(Exchange::USD("2016-12-07".to_string()), 4.1268f64);
-    exchange_rates.insert(Exchange::USD("2021-09-10".to_string()), 3.8402f64);
-    exchange_rates.insert(Exchange::USD("2015-05-11".to_string()), 3.6489f64);
-    exchange_rates.insert(Exchange::USD("2019-01-09".to_string()), 3.7509f64);
-    exchange_rates.insert(Exchange::USD("2021-09-21".to_string()), 3.9337f64);
-    exchange_rates.insert(Exchange::USD("2020-11-10".to_string()), 3.8168f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-18".to_string()), 4.4488f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-24".to_string()), 4.3722f64);
-    exchange_rates.insert(Exchange::USD("2015-07-02".to_string()), 3.7859f64);
-    exchange_rates.insert(Exchange::USD("2024-04-04".to_string()), 3.9515f64);
-    exchange_rates.insert(Exchange::USD("2023-02-22".to_string()), 4.4687f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-21".to_string()), 4.3163f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-12".to_string()), 4.3354f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-17".to_string()), 4.275f64);
-    exchange_rates.insert(Exchange::USD("2016-08-16".to_string()), 3.7929f64);
-    exchange_rates.insert(Exchange::USD("2019-06-12".to_string()), 3.763f64);
-    exchange_rates.insert(Exchange::USD("2020-09-23".to_string()), 3.8399f64);
-    exchange_rates.insert(Exchange::USD("2012-02-02".to_string()), 3.1955f64);
-    exchange_rates.insert(Exchange::USD("2022-05-27".to_string()), 4.2966f64);
-    exchange_rates.insert(Exchange::USD("2013-05-07".to_string()), 3.1767f64);
-    exchange_rates.insert(Exchange::USD("2016-06-13".to_string()), 3.8943f64);
-    exchange_rates.insert(Exchange::USD("2023-05-22".to_string()), 4.1881f64);
-    exchange_rates.insert(Exchange::USD("2024-06-28".to_string()), 4.032f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-09".to_string()), 4.2414f64);
-    exchange_rates.insert(Exchange::USD("2015-08-05".to_string()), 3.8359f64);
-    exchange_rates.insert(Exchange::USD("2024-12-05".to_string()), 4.0554f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-28".to_string()), 4.46f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-20".to_string()), 4.3242f64);
-    exchange_rates.insert(Exchange::USD("2016-03-24".to_string()), 3.824f64);
-    exchange_rates.insert(Exchange::USD("2023-08-21".to_string()), 4.1124f64);
-    exchange_rates.insert(Exchange::USD("2012-10-12".to_string()), 3.1577f64);
-    exchange_rates.insert(Exchange::USD("2017-04-07".to_string()), 3.9755f64);
-    exchange_rates.insert(Exchange::USD("2015-04-13".to_string()), 3.8088f64);
-    exchange_rates.insert(Exchange::USD("2012-08-16".to_string()), 3.3253f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-08".to_string()), 4.2805f64);
-    exchange_rates.insert(Exchange::USD("2017-09-28".to_string()), 3.6682f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-21".to_string()), 4.2554f64);
-    exchange_rates.insert(Exchange::USD("2015-04-09".to_string()), 3.7414f64);
-    exchange_rates.insert(Exchange::USD("2015-05-07".to_string()), 3.583f64);
-    exchange_rates.insert(Exchange::USD("2023-10-25".to_string()), 4.2284f64);
-    exchange_rates.insert(Exchange::USD("2020-07-01".to_string()), 3.9591f64);
-    exchange_rates.insert(Exchange::USD("2016-08-10".to_string()), 3.825f64);
-    exchange_rates.insert(Exchange::USD("2022-06-06".to_string()), 4.2675f64);
-    exchange_rates.insert(Exchange::USD("2016-07-01".to_string()), 3.9548f64);
-    exchange_rates.insert(Exchange::USD("2013-02-25".to_string()), 3.1412f64);
-    exchange_rates.insert(Exchange::USD("2020-04-23".to_string()), 4.2093f64);
-    exchange_rates.insert(Exchange::USD("2021-12-10".to_string()), 4.0887f64);
-    exchange_rates.insert(Exchange::USD("2021-09-03".to_string()), 3.7998f64);
-    exchange_rates.insert(Exchange::USD("2025-08-13".to_string()), 3.6353f64);
-    exchange_rates.insert(Exchange::USD("2020-09-14".to_string()), 3.7522f64);
-    exchange_rates.insert(Exchange::USD("2016-01-27".to_string()), 4.1144f64);
-    exchange_rates.insert(Exchange::USD("2019-03-28".to_string()), 3.8202f64);
-    exchange_rates.insert(Exchange::USD("2016-11-14".to_string()), 4.0805f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-15".to_string()), 4.5096f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-19".to_string()), 4.2094f64);
-    exchange_rates.insert(Exchange::USD("2012-10-03".to_string()), 3.1896f64);
-    exchange_rates.insert(Exchange::USD("2015-07-29".to_string()), 3.7471f64);
-    exchange_rates.insert(Exchange::USD("2020-10-02".to_string()), 3.8366f64);
-    exchange_rates.insert(Exchange::USD("2012-01-17".to_string()), 3.4215f64);
-    exchange_rates.insert(Exchange::USD("2023-07-31".to_string()), 3.9995f64);
-    exchange_rates.insert(Exchange::USD("2019-10-02".to_string()), 4.0152f64);
-    exchange_rates.insert(Exchange::USD("2012-01-13".to_string()), 3.4323f64);
-    exchange_rates.insert(Exchange::USD("2025-03-03".to_string()), 4.0112f64);
-    exchange_rates.insert(Exchange::USD("2019-06-25".to_string()), 3.7376f64);
-    exchange_rates.insert(Exchange::USD("2022-04-21".to_string()), 4.2596f64);
-    exchange_rates.insert(Exchange::USD("2021-11-25".to_string()), 4.16f64);
-    exchange_rates.insert(Exchange::USD("2025-06-06".to_string()), 3.7565f64);
-    exchange_rates.insert(Exchange::USD("2023-11-03".to_string()), 4.1857f64);
-    exchange_rates.insert(Exchange::USD("2023-06-15".to_string()), 4.1203f64);
-    exchange_rates.insert(Exchange::USD("2013-06-05".to_string()), 3.2421f64);
-    exchange_rates.insert(Exchange::USD("2025-08-18".to_string()), 3.6434f64);
-    exchange_rates.insert(Exchange::USD("2023-12-11".to_string()), 4.0277f64);
-    exchange_rates.insert(Exchange::USD("2018-03-07".to_string()), 3.3744f64);
-    exchange_rates.insert(Exchange::USD("2015-10-09".to_string()), 3.7242f64);
-    exchange_rates.insert(Exchange::USD("2015-12-14".to_string()), 3.9695f64);
-    exchange_rates.insert(Exchange::USD("2014-03-05".to_string()), 3.0477f64);
-    exchange_rates.insert(Exchange::USD("2013-07-29".to_string()), 3.1859f64);
-    exchange_rates.insert(Exchange::USD("2020-04-02".to_string()), 4.1917f64);
-    exchange_rates.insert(Exchange::USD("2025-07-09".to_string()), 3.6198f64);
-    exchange_rates.insert(Exchange::USD("2017-07-05".to_string()), 3.7471f64);
-    exchange_rates.insert(Exchange::USD("2021-01-19".to_string()), 3.7416f64);
-    exchange_rates.insert(Exchange::USD("2018-11-26".to_string()), 3.7701f64);
-    exchange_rates.insert(Exchange::USD("2018-12-24".to_string()), 3.7588f64);
-    exchange_rates.insert(Exchange::USD("2017-12-21".to_string()), 3.5369f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-05".to_string()), 4.5974f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-10".to_string()), 4.2644f64);
-    exchange_rates.insert(Exchange::USD("2019-07-16".to_string()), 3.7867f64);
-    exchange_rates.insert(Exchange::EUR("2025-06-13".to_string()), 4.272f64);
-    exchange_rates.insert(Exchange::USD("2018-04-25".to_string()), 3.4548f64);
-    exchange_rates.insert(Exchange::USD("2023-02-01".to_string()), 4.3254f64);
-    exchange_rates.insert(Exchange::USD("2021-03-18".to_string()), 3.8705f64);
-    exchange_rates.insert(Exchange::USD("2021-05-26".to_string()), 3.6691f64);
-    exchange_rates.insert(Exchange::USD("2015-10-28".to_string()), 3.8826f64);
-    exchange_rates.insert(Exchange::USD("2023-06-12".to_string()), 4.1169f64);
-    exchange_rates.insert(Exchange::USD("2022-09-29".to_string()), 5.0328f64);
-    exchange_rates.insert(Exchange::USD("2014-03-04".to_string()), 3.0489f64);
-    exchange_rates.insert(Exchange::USD("2017-03-21".to_string()), 3.9502f64);
-    exchange_rates.insert(Exchange::USD("2015-02-11".to_string()), 3.7094f64);
-    exchange_rates.insert(Exchange::USD("2013-11-12".to_string()), 3.1448f64);
-    exchange_rates.insert(Exchange::USD("2023-08-24".to_string()), 4.118f64);
-    exchange_rates.insert(Exchange::USD("2024-03-29".to_string()), 3.9886f64);
-    exchange_rates.insert(Exchange::EUR("2023-02-17".to_string()), 4.7747f64);
-    exchange_rates.insert(Exchange::USD("2024-07-18".to_string()), 3.9296f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-18".to_string()), 4.5355f64);
-    exchange_rates.insert(Exchange::USD("2018-08-10".to_string()), 3.7481f64);
-    exchange_rates.insert(Exchange::USD("2022-02-22".to_string()), 4.012f64);
-    exchange_rates.insert(Exchange::USD("2012-05-11".to_string()), 3.2765f64);
-    exchange_rates.insert(Exchange::USD("2020-07-15".to_string()), 3.9117f64);
-    exchange_rates.insert(Exchange::USD("2014-04-24".to_string()), 3.0285f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-07".to_string()), 4.3185f64);
-    exchange_rates.insert(Exchange::USD("2014-04-01".to_string()), 3.0278f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-13".to_string()), 4.4783f64);
-    exchange_rates.insert(Exchange::USD("2023-07-14".to_string()), 3.9617f64);
-    exchange_rates.insert(Exchange::USD("2014-05-26".to_string()), 3.0466f64);
-    exchange_rates.insert(Exchange::USD("2024-10-18".to_string()), 3.9718f64);
-    exchange_rates.insert(Exchange::USD("2013-02-07".to_string()), 3.0886f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-14".to_string()), 4.2812f64);
-    exchange_rates.insert(Exchange::USD("2016-06-29".to_string()), 3.9905f64);
-    exchange_rates.insert(Exchange::USD("2013-03-20".to_string()), 3.2169f64);
-    exchange_rates.insert(Exchange::USD("2020-11-16".to_string()), 3.7782f64);
-    exchange_rates.insert(Exchange::USD("2025-02-06".to_string()), 4.0613f64);
-    exchange_rates.insert(Exchange::USD("2012-06-27".to_string()), 3.4015f64);
-    exchange_rates.insert(Exchange::USD("2021-03-29".to_string()), 3.957f64);
-    exchange_rates.insert(Exchange::USD("2022-07-18".to_string()), 4.7152f64);
-    exchange_rates.insert(Exchange::USD("2012-02-20".to_string()), 3.1598f64);
-    exchange_rates.insert(Exchange::USD("2012-06-12".to_string()), 3.4552f64);
-    exchange_rates.insert(Exchange::USD("2017-09-01".to_string()), 3.5693f64);
-    exchange_rates.insert(Exchange::USD("2023-02-08".to_string()), 4.4074f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-14".to_string()), 4.3581f64);
-    exchange_rates.insert(Exchange::USD("2020-04-24".to_string()), 4.2142f64);
-    exchange_rates.insert(Exchange::USD("2021-10-28".to_string()), 3.9938f64);
-    exchange_rates.insert(Exchange::USD("2018-04-23".to_string()), 3.4156f64);
-    exchange_rates.insert(Exchange::USD("2013-11-08".to_string()), 3.1133f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-23".to_string()), 4.6817f64);
-    exchange_rates.insert(Exchange::USD("2018-08-22".to_string()), 3.7104f64);
-    exchange_rates.insert(Exchange::USD("2015-06-11".to_string()), 3.6817f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-05".to_string()), 4.276f64);
-    exchange_rates.insert(Exchange::USD("2016-06-16".to_string()), 3.9469f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-22".to_string()), 4.267f64);
-    exchange_rates.insert(Exchange::USD("2019-05-08".to_string()), 3.8325f64);
-    exchange_rates.insert(Exchange::USD("2013-07-26".to_string()), 3.1831f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-24".to_string()), 4.4517f64);
-    exchange_rates.insert(Exchange::USD("2015-06-22".to_string()), 3.6799f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-31".to_string()), 4.213f64);
-    exchange_rates.insert(Exchange::USD("2019-07-12".to_string()), 3.7889f64);
-    exchange_rates.insert(Exchange::USD("2013-02-22".to_string()), 3.1443f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-18".to_string()), 4.6286f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-24".to_string()), 4.4604f64);
-    exchange_rates.insert(Exchange::USD("2016-02-29".to_string()), 3.9935f64);
-    exchange_rates.insert(Exchange::USD("2013-12-18".to_string()), 3.0393f64);
-    exchange_rates.insert(Exchange::USD("2021-04-12".to_string()), 3.8144f64);
-    exchange_rates.insert(Exchange::USD("2024-06-13".to_string()), 4.0119f64);
-    exchange_rates.insert(Exchange::USD("2014-11-07".to_string()), 3.4084f64);
-    exchange_rates.insert(Exchange::USD("2017-04-12".to_string()), 4.0026f64);
-    exchange_rates.insert(Exchange::USD("2013-07-22".to_string()), 3.2142f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-14".to_string()), 4.179f64);
-    exchange_rates.insert(Exchange::USD("2020-02-18".to_string()), 3.9448f64);
-    exchange_rates.insert(Exchange::USD("2023-05-17".to_string()), 4.1504f64);
-    exchange_rates.insert(Exchange::USD("2022-02-03".to_string()), 4.0267f64);
-    exchange_rates.insert(Exchange::USD("2017-05-04".to_string()), 3.863f64);
-    exchange_rates.insert(Exchange::USD("2022-12-21".to_string()), 4.3947f64);
-    exchange_rates.insert(Exchange::USD("2015-10-30".to_string()), 3.8748f64);
-    exchange_rates.insert(Exchange::USD("2022-02-08".to_string()), 3.9693f64);
-    exchange_rates.insert(Exchange::USD("2015-09-02".to_string()), 3.7626f64);
-    exchange_rates.insert(Exchange::USD("2013-05-13".to_string()), 3.2051f64);
-    exchange_rates.insert(Exchange::USD("2019-10-30".to_string()), 3.8388f64);
-    exchange_rates.insert(Exchange::USD("2024-07-10".to_string()), 3.9324f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-28".to_string()), 4.2447f64);
-    exchange_rates.insert(Exchange::USD("2012-12-27".to_string()), 3.0816f64);
-    exchange_rates.insert(Exchange::USD("2014-09-08".to_string()), 3.2303f64);
-    exchange_rates.insert(Exchange::USD("2019-04-19".to_string()), 3.8051f64);
-    exchange_rates.insert(Exchange::USD("2024-03-01".to_string()), 3.9922f64);
-    exchange_rates.insert(Exchange::USD("2024-10-16".to_string()), 3.9468f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-25".to_string()), 4.3533f64);
-    exchange_rates.insert(Exchange::USD("2023-06-29".to_string()), 4.0832f64);
-    exchange_rates.insert(Exchange::USD("2018-12-28".to_string()), 3.755f64);
-    exchange_rates.insert(Exchange::USD("2014-05-15".to_string()), 3.0589f64);
-    exchange_rates.insert(Exchange::USD("2012-12-14".to_string()), 3.1271f64);
-    exchange_rates.insert(Exchange::USD("2022-12-20".to_string()), 4.4001f64);
-    exchange_rates.insert(Exchange::USD("2017-06-02".to_string()), 3.7323f64);
-    exchange_rates.insert(Exchange::EUR("2023-06-09".to_string()), 4.4717f64);
-    exchange_rates.insert(Exchange::USD("2022-01-07".to_string()), 4.0279f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-13".to_string()), 4.2896f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-10".to_string()), 4.1872f64);
-    exchange_rates.insert(Exchange::USD("2020-08-06".to_string()), 3.724f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-08".to_string()), 4.2714f64);
-    exchange_rates.insert(Exchange::USD("2016-05-11".to_string()), 3.8855f64);
-    exchange_rates.insert(Exchange::USD("2021-02-15".to_string()), 3.6949f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-18".to_string()), 4.2564f64);
-    exchange_rates.insert(Exchange::USD("2022-02-11".to_string()), 3.9665f64);
-    exchange_rates.insert(Exchange::USD("2023-12-27".to_string()), 3.9178f64);
-    exchange_rates.insert(Exchange::USD("2014-11-17".to_string()), 3.3819f64);
-    exchange_rates.insert(Exchange::USD("2015-02-16".to_string()), 3.6665f64);
-    exchange_rates.insert(Exchange::USD("2015-03-19".to_string()), 3.8596f64);
-    exchange_rates.insert(Exchange::USD("2015-11-26".to_string()), 4.0298f64);
-    exchange_rates.insert(Exchange::USD("2022-03-18".to_string()), 4.2707f64);
-    exchange_rates.insert(Exchange::USD("2013-07-04".to_string()), 3.3246f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-29".to_string()), 4.685f64);
-    exchange_rates.insert(Exchange::USD("2022-07-11".to_string()), 4.7416f64);
-    exchange_rates.insert(Exchange::USD("2021-05-07".to_string()), 3.7861f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-05".to_string()), 4.4498f64);
-    exchange_rates.insert(Exchange::USD("2012-04-10".to_string()), 3.1904f64);
-    exchange_rates.insert(Exchange::USD("2014-01-03".to_string()), 3.0517f64);
-    exchange_rates.insert(Exchange::USD("2021-02-22".to_string()), 3.7135f64);
-    exchange_rates.insert(Exchange::USD("2015-11-20".to_string()), 3.9706f64);
-    exchange_rates.insert(Exchange::USD("2022-05-17".to_string()), 4.4379f64);
-    exchange_rates.insert(Exchange::USD("2015-08-19".to_string()), 3.7718f64);
-    exchange_rates.insert(Exchange::USD("2019-06-06".to_string()), 3.8065f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-29".to_string()), 4.319f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-11".to_string()), 4.2533f64);
-    exchange_rates.insert(Exchange::USD("2017-10-04".to_string()), 3.6597f64);
-    exchange_rates.insert(Exchange::USD("2022-09-15".to_string()), 4.7202f64);
-    exchange_rates.insert(Exchange::USD("2014-10-29".to_string()), 3.3192f64);
-    exchange_rates.insert(Exchange::USD("2014-07-01".to_string()), 3.0355f64);
-    exchange_rates.insert(Exchange::USD("2025-11-10".to_string()), 3.661f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-03".to_string()), 4.2718f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-17".to_string()), 4.2879f64);
-    exchange_rates.insert(Exchange::USD("2015-11-24".to_string()), 4.0021f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-31".to_string()), 4.2661f64);
-    exchange_rates.insert(Exchange::USD("2014-04-28".to_string()), 3.0368f64);
-    exchange_rates.insert(Exchange::USD("2012-09-17".to_string()), 3.1207f64);
-    exchange_rates.insert(Exchange::USD("2017-02-16".to_string()), 4.0567f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-14".to_string()), 4.2639f64);
-    exchange_rates.insert(Exchange::USD("2016-10-05".to_string()), 3.8307f64);
-    exchange_rates.insert(Exchange::USD("2020-06-01".to_string()), 3.968f64);
-    exchange_rates.insert(Exchange::USD("2024-07-26".to_string()), 3.9415f64);
-    exchange_rates.insert(Exchange::USD("2019-02-27".to_string()), 3.7886f64);
-    exchange_rates.insert(Exchange::USD("2016-09-01".to_string()), 3.9116f64);
-    exchange_rates.insert(Exchange::USD("2016-06-06".to_string()), 3.8602f64);
-    exchange_rates.insert(Exchange::USD("2014-04-14".to_string()), 3.0288f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-28".to_string()), 4.2938f64);
-    exchange_rates.insert(Exchange::USD("2024-08-08".to_string()), 3.952f64);
-    exchange_rates.insert(Exchange::USD("2025-04-28".to_string()), 3.7701f64);
-    exchange_rates.insert(Exchange::USD("2024-04-09".to_string()), 3.9223f64);
-    exchange_rates.insert(Exchange::USD("2016-11-02".to_string()), 3.8954f64);
-    exchange_rates.insert(Exchange::USD("2014-07-03".to_string()), 3.0418f64);
-    exchange_rates.insert(Exchange::USD("2023-01-24".to_string()), 4.3341f64);
-    exchange_rates.insert(Exchange::USD("2015-01-14".to_string()), 3.6525f64);
-    exchange_rates.insert(Exchange::USD("2023-09-12".to_string()), 4.3711f64);
-    exchange_rates.insert(Exchange::USD("2016-10-24".to_string()), 3.969f64);
-    exchange_rates.insert(Exchange::USD("2023-04-21".to_string()), 4.2006f64);
-    exchange_rates.insert(Exchange::USD("2024-03-20".to_string()), 3.9895f64);
-    exchange_rates.insert(Exchange::USD("2017-06-09".to_string()), 3.7465f64);
-    exchange_rates.insert(Exchange::USD("2018-03-26".to_string()), 3.4145f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-29".to_string()), 4.319f64);
-    exchange_rates.insert(Exchange::USD("2024-10-09".to_string()), 3.9266f64);
-    exchange_rates.insert(Exchange::USD("2021-09-17".to_string()), 3.8882f64);
-    exchange_rates.insert(Exchange::USD("2014-09-25".to_string()), 3.2823f64);
-    exchange_rates.insert(Exchange::USD("2016-01-26".to_string()), 4.1475f64);
-    exchange_rates.insert(Exchange::USD("2012-09-14".to_string()), 3.1131f64);
-    exchange_rates.insert(Exchange::USD("2021-02-16".to_string()), 3.694f64);
-    exchange_rates.insert(Exchange::USD("2014-04-02".to_string()), 3.0288f64);
-    exchange_rates.insert(Exchange::USD("2014-12-18".to_string()), 3.4428f64);
-    exchange_rates.insert(Exchange::USD("2012-06-22".to_string()), 3.4025f64);
-    exchange_rates.insert(Exchange::USD("2017-10-10".to_string()), 3.6468f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-13".to_string()), 4.5417f64);
-    exchange_rates.insert(Exchange::USD("2025-02-20".to_string()), 3.9974f64);
-    exchange_rates.insert(Exchange::EUR("2025-11-05".to_string()), 4.2597f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-13".to_string()), 4.6848f64);
-    exchange_rates.insert(Exchange::USD("2013-10-22".to_string()), 3.0564f64);
-    exchange_rates.insert(Exchange::USD("2023-11-07".to_string()), 4.1667f64);
-    exchange_rates.insert(Exchange::USD("2018-11-07".to_string()), 3.7398f64);
-    exchange_rates.insert(Exchange::USD("2022-12-02".to_string()), 4.4492f64);
-    exchange_rates.insert(Exchange::USD("2015-01-16".to_string()), 3.7174f64);
-    exchange_rates.insert(Exchange::USD("2013-11-27".to_string()), 3.0899f64);
-    exchange_rates.insert(Exchange::USD("2020-03-20".to_string()), 4.24f64);
-    exchange_rates.insert(Exchange::USD("2018-11-29".to_string()), 3.7728f64);
-    exchange_rates.insert(Exchange::USD("2019-12-05".to_string()), 3.8566f64);
-    exchange_rates.insert(Exchange::USD("2017-01-05".to_string()), 4.1667f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-11".to_string()), 4.3456f64);
-    exchange_rates.insert(Exchange::USD("2020-01-03".to_string()), 3.8213f64);
-    exchange_rates.insert(Exchange::USD("2020-10-19".to_string()), 3.8761f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-09".to_string()), 4.2993f64);
-    exchange_rates.insert(Exchange::USD("2019-12-09".to_string()), 3.8704f64);
-    exchange_rates.insert(Exchange::USD("2022-03-30".to_string()), 4.1688f64);
-    exchange_rates.insert(Exchange::USD("2020-01-08".to_string()), 3.8123f64);
-    exchange_rates.insert(Exchange::USD("2015-11-25".to_string()), 4.0215f64);
-    exchange_rates.insert(Exchange::USD("2015-02-23".to_string()), 3.6933f64);
-    exchange_rates.insert(Exchange::USD("2025-06-24".to_string()), 3.6753f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-13".to_string()), 4.2715f64);
-    exchange_rates.insert(Exchange::USD("2023-05-04".to_string()), 4.1547f64);
-    exchange_rates.insert(Exchange::USD("2024-09-10".to_string()), 3.8798f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-24".to_string()), 4.7073f64);
-    exchange_rates.insert(Exchange::USD("2012-09-21".to_string()), 3.1712f64);
-    exchange_rates.insert(Exchange::USD("2025-11-13".to_string()), 3.6417f64);
-    exchange_rates.insert(Exchange::USD("2016-02-10".to_string()), 3.9383f64);
-    exchange_rates.insert(Exchange::USD("2022-05-05".to_string()), 4.4017f64);
-    exchange_rates.insert(Exchange::USD("2013-05-27".to_string()), 3.2416f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-21".to_string()), 4.3331f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-10".to_string()), 4.1857f64);
-    exchange_rates.insert(Exchange::USD("2017-05-08".to_string()), 3.8391f64);
-    exchange_rates.insert(Exchange::USD("2014-04-04".to_string()), 3.0397f64);
-    exchange_rates.insert(Exchange::USD("2015-08-10".to_string()), 3.8246f64);
-    exchange_rates.insert(Exchange::USD("2020-02-11".to_string()), 3.9041f64);
-    exchange_rates.insert(Exchange::USD("2020-10-28".to_string()), 3.9313f64);
-    exchange_rates.insert(Exchange::USD("2014-06-06".to_string()), 3.0222f64);
-    exchange_rates.insert(Exchange::USD("2022-06-23".to_string()), 4.4881f64);
-    exchange_rates.insert(Exchange::USD("2021-07-26".to_string()), 3.8915f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-28".to_string()), 4.2092f64);
-    exchange_rates.insert(Exchange::USD("2019-11-12".to_string()), 3.878f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-25".to_string()), 4.598f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-18".to_string()), 4.332f64);
-    exchange_rates.insert(Exchange::USD("2023-11-28".to_string()), 3.975f64);
-    exchange_rates.insert(Exchange::USD("2020-03-16".to_string()), 3.9231f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-13".to_string()), 4.3416f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-11".to_string()), 4.4547f64);
-    exchange_rates.insert(Exchange::USD("2020-08-13".to_string()), 3.7176f64);
-    exchange_rates.insert(Exchange::USD("2015-11-16".to_string()), 3.9581f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-29".to_string()), 4.3043f64);
-    exchange_rates.insert(Exchange::USD("2021-03-26".to_string()), 3.9307f64);
-    exchange_rates.insert(Exchange::USD("2021-10-11".to_string()), 3.9862f64);
-    exchange_rates.insert(Exchange::USD("2016-01-20".to_string()), 4.0877f64);
-    exchange_rates.insert(Exchange::USD("2015-05-06".to_string()), 3.6116f64);
-    exchange_rates.insert(Exchange::USD("2014-02-13".to_string()), 3.0532f64);
-    exchange_rates.insert(Exchange::USD("2018-03-02".to_string()), 3.42f64);
-    exchange_rates.insert(Exchange::USD("2023-05-19".to_string()), 4.2053f64);
-    exchange_rates.insert(Exchange::USD("2021-08-25".to_string()), 3.8869f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-18".to_string()), 4.2509f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-15".to_string()), 4.3748f64);
-    exchange_rates.insert(Exchange::USD("2024-02-14".to_string()), 4.0593f64);
-    exchange_rates.insert(Exchange::USD("2022-05-23".to_string()), 4.3369f64);
-    exchange_rates.insert(Exchange::USD("2012-11-26".to_string()), 3.1744f64);
-    exchange_rates.insert(Exchange::USD("2017-05-25".to_string()), 3.7177f64);
-    exchange_rates.insert(Exchange::USD("2017-12-08".to_string()), 3.5808f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-10".to_string()), 4.2274f64);
-    exchange_rates.insert(Exchange::USD("2014-11-03".to_string()), 3.3772f64);
-    exchange_rates.insert(Exchange::USD("2020-06-25".to_string()), 3.9623f64);
-    exchange_rates.insert(Exchange::USD("2018-11-06".to_string()), 3.7802f64);
-    exchange_rates.insert(Exchange::USD("2025-07-14".to_string()), 3.6475f64);
-    exchange_rates.insert(Exchange::USD("2024-10-29".to_string()), 4.0251f64);
-    exchange_rates.insert(Exchange::USD("2019-04-01".to_string()), 3.8267f64);
-    exchange_rates.insert(Exchange::USD("2023-08-23".to_string()), 4.1438f64);
-    exchange_rates.insert(Exchange::USD("2025-09-30".to_string()), 3.6315f64);
-    exchange_rates.insert(Exchange::USD("2022-12-30".to_string()), 4.4018f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-17".to_string()), 4.4681f64);
-    exchange_rates.insert(Exchange::USD("2015-03-27".to_string()), 3.789f64);
-    exchange_rates.insert(Exchange::USD("2019-04-30".to_string()), 3.8291f64);
-    exchange_rates.insert(Exchange::USD("2025-01-31".to_string()), 4.0576f64);
-    exchange_rates.insert(Exchange::USD("2024-02-13".to_string()), 4.0136f64);
-    exchange_rates.insert(Exchange::USD("2020-09-01".to_string()), 3.6707f64);
-    exchange_rates.insert(Exchange::USD("2019-09-19".to_string()), 3.9267f64);
-    exchange_rates.insert(Exchange::USD("2020-01-29".to_string()), 3.8871f64);
-    exchange_rates.insert(Exchange::USD("2023-07-07".to_string()), 4.1189f64);
-    exchange_rates.insert(Exchange::USD("2017-10-16".to_string()), 3.6004f64);
-    exchange_rates.insert(Exchange::USD("2019-04-08".to_string()), 3.8188f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-17".to_string()), 4.2904f64);
-    exchange_rates.insert(Exchange::USD("2020-05-20".to_string()), 4.1619f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-11".to_string()), 4.5183f64);
-    exchange_rates.insert(Exchange::USD("2018-08-03".to_string()), 3.6926f64);
-    exchange_rates.insert(Exchange::USD("2019-07-18".to_string()), 3.7933f64);
-    exchange_rates.insert(Exchange::USD("2024-09-03".to_string()), 3.8701f64);
-    exchange_rates.insert(Exchange::USD("2016-04-28".to_string()), 3.8695f64);
-    exchange_rates.insert(Exchange::USD("2022-05-25".to_string()), 4.3069f64);
-    exchange_rates.insert(Exchange::USD("2021-05-27".to_string()), 3.6929f64);
-    exchange_rates.insert(Exchange::USD("2023-11-15".to_string()), 4.0453f64);
-    exchange_rates.insert(Exchange::USD("2019-02-07".to_string()), 3.7919f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-04".to_string()), 4.2515f64);
-    exchange_rates.insert(Exchange::USD("2025-06-30".to_string()), 3.6164f64);
-    exchange_rates.insert(Exchange::USD("2012-02-07".to_string()), 3.1806f64);
-    exchange_rates.insert(Exchange::USD("2012-03-21".to_string()), 3.1173f64);
-    exchange_rates.insert(Exchange::USD("2014-07-15".to_string()), 3.0454f64);
-    exchange_rates.insert(Exchange::USD("2014-01-02".to_string()), 3.0315f64);
-    exchange_rates.insert(Exchange::USD("2018-11-08".to_string()), 3.7599f64);
-    exchange_rates.insert(Exchange::USD("2021-03-08".to_string()), 3.8665f64);
-    exchange_rates.insert(Exchange::USD("2025-05-23".to_string()), 3.7506f64);
-    exchange_rates.insert(Exchange::USD("2017-06-05".to_string()), 3.7066f64);
-    exchange_rates.insert(Exchange::USD("2023-12-21".to_string()), 3.9645f64);
-    exchange_rates.insert(Exchange::USD("2021-11-02".to_string()), 3.9772f64);
-    exchange_rates.insert(Exchange::USD("2020-11-17".to_string()), 3.7877f64);
-    exchange_rates.insert(Exchange::USD("2025-09-16".to_string()), 3.6034f64);
-    exchange_rates.insert(Exchange::USD("2025-08-12".to_string()), 3.6702f64);
-    exchange_rates.insert(Exchange::USD("2019-07-01".to_string()), 3.743f64);
-    exchange_rates.insert(Exchange::USD("2018-01-15".to_string()), 3.401f64);
-    exchange_rates.insert(Exchange::USD("2017-09-22".to_string()), 3.5651f64);
-    exchange_rates.insert(Exchange::USD("2016-01-12".to_string()), 4.017f64);
-    exchange_rates.insert(Exchange::USD("2017-03-08".to_string()), 4.0818f64);
-    exchange_rates.insert(Exchange::USD("2014-08-08".to_string()), 3.1509f64);
-    exchange_rates.insert(Exchange::USD("2012-04-30".to_string()), 3.1509f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-29".to_string()), 4.5294f64);
-    exchange_rates.insert(Exchange::USD("2020-10-26".to_string()), 3.8759f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-31".to_string()), 4.273f64);
-    exchange_rates.insert(Exchange::USD("2016-03-18".to_string()), 3.7882f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-19".to_string()), 4.242f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-04".to_string()), 4.2921f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-12".to_string()), 4.2818f64);
-    exchange_rates.insert(Exchange::USD("2016-07-07".to_string()), 4.0036f64);
-    exchange_rates.insert(Exchange::USD("2016-02-04".to_string()), 3.9492f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-27".to_string()), 4.2528f64);
-    exchange_rates.insert(Exchange::USD("2024-05-20".to_string()), 3.9149f64);
-    exchange_rates.insert(Exchange::USD("2015-04-01".to_string()), 3.789f64);
-    exchange_rates.insert(Exchange::USD("2025-12-01".to_string()), 3.6383f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-07".to_string()), 4.3431f64);
-    exchange_rates.insert(Exchange::USD("2022-04-28".to_string()), 4.4613f64);
-    exchange_rates.insert(Exchange::USD("2020-04-30".to_string()), 4.1729f64);
-    exchange_rates.insert(Exchange::USD("2020-07-09".to_string()), 3.9486f64);
-    exchange_rates.insert(Exchange::USD("2019-01-08".to_string()), 3.7559f64);
-    exchange_rates.insert(Exchange::USD("2015-03-05".to_string()), 3.7525f64);
-    exchange_rates.insert(Exchange::USD("2012-05-16".to_string()), 3.4353f64);
-    exchange_rates.insert(Exchange::EUR("2025-09-24".to_string()), 4.2676f64);
-    exchange_rates.insert(Exchange::USD("2014-12-12".to_string()), 3.3639f64);
-    exchange_rates.insert(Exchange::USD("2021-08-03".to_string()), 3.8315f64);
-    exchange_rates.insert(Exchange::USD("2022-01-14".to_string()), 3.9597f64);
-    exchange_rates.insert(Exchange::USD("2016-07-06".to_string()), 4.0225f64);
-    exchange_rates.insert(Exchange::USD("2015-11-05".to_string()), 3.8925f64);
-    exchange_rates.insert(Exchange::USD("2014-10-07".to_string()), 3.3106f64);
-    exchange_rates.insert(Exchange::USD("2014-12-08".to_string()), 3.3978f64);
-    exchange_rates.insert(Exchange::USD("2023-11-21".to_string()), 3.9882f64);
-    exchange_rates.insert(Exchange::USD("2017-04-20".to_string()), 3.9588f64);
-    exchange_rates.insert(Exchange::USD("2020-06-08".to_string()), 3.9197f64);
-    exchange_rates.insert(Exchange::EUR("2023-05-31".to_string()), 4.5376f64);
-    exchange_rates.insert(Exchange::USD("2017-12-05".to_string()), 3.5454f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-22".to_string()), 4.356f64);
-    exchange_rates.insert(Exchange::USD("2016-06-10".to_string()), 3.8545f64);
-    exchange_rates.insert(Exchange::USD("2023-10-10".to_string()), 4.2985f64);
-    exchange_rates.insert(Exchange::USD("2017-06-08".to_string()), 3.74f64);
-    exchange_rates.insert(Exchange::USD("2017-09-12".to_string()), 3.5552f64);
-    exchange_rates.insert(Exchange::USD("2023-08-07".to_string()), 4.0347f64);
-    exchange_rates.insert(Exchange::USD("2016-06-14".to_string()), 3.9483f64);
-    exchange_rates.insert(Exchange::USD("2022-11-23".to_string()), 4.548f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-02".to_string()), 4.4582f64);
-    exchange_rates.insert(Exchange::USD("2016-04-22".to_string()), 3.8399f64);
-    exchange_rates.insert(Exchange::USD("2021-08-26".to_string()), 3.8912f64);
-    exchange_rates.insert(Exchange::USD("2020-07-20".to_string()), 3.8997f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-24".to_string()), 4.473f64);
-    exchange_rates.insert(Exchange::USD("2019-03-20".to_string()), 3.7767f64);
-    exchange_rates.insert(Exchange::USD("2016-08-17".to_string()), 3.8075f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-30".to_string()), 4.716f64);
-    exchange_rates.insert(Exchange::USD("2020-03-10".to_string()), 3.8058f64);
-    exchange_rates.insert(Exchange::USD("2023-07-18".to_string()), 3.9451f64);
-    exchange_rates.insert(Exchange::USD("2017-08-29".to_string()), 3.5372f64);
-    exchange_rates.insert(Exchange::USD("2018-07-06".to_string()), 3.7222f64);
-    exchange_rates.insert(Exchange::USD("2018-05-21".to_string()), 3.6615f64);
-    exchange_rates.insert(Exchange::USD("2015-03-20".to_string()), 3.8645f64);
-    exchange_rates.insert(Exchange::USD("2014-10-17".to_string()), 3.2964f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-07".to_string()), 4.2757f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-12".to_string()), 4.2337f64);
-    exchange_rates.insert(Exchange::USD("2016-11-28".to_string()), 4.1521f64);
-    exchange_rates.insert(Exchange::USD("2019-07-09".to_string()), 3.8074f64);
-    exchange_rates.insert(Exchange::EUR("2025-08-21".to_string()), 4.2521f64);
-    exchange_rates.insert(Exchange::USD("2019-11-28".to_string()), 3.9252f64);
-    exchange_rates.insert(Exchange::USD("2021-06-29".to_string()), 3.7914f64);
-    exchange_rates.insert(Exchange::USD("2020-07-22".to_string()), 3.8534f64);
-    exchange_rates.insert(Exchange::USD("2025-01-16".to_string()), 4.1433f64);
-    exchange_rates.insert(Exchange::USD("2013-09-20".to_string()), 3.1152f64);
-    exchange_rates.insert(Exchange::USD("2019-05-28".to_string()), 3.8386f64);
-    exchange_rates.insert(Exchange::USD("2017-01-20".to_string()), 4.1005f64);
-    exchange_rates.insert(Exchange::USD("2021-04-20".to_string()), 3.7737f64);
-    exchange_rates.insert(Exchange::USD("2018-08-24".to_string()), 3.7014f64);
-    exchange_rates.insert(Exchange::USD("2025-09-18".to_string()), 3.5986f64);
-    exchange_rates.insert(Exchange::USD("2013-06-17".to_string()), 3.1719f64);
-    exchange_rates.insert(Exchange::USD("2020-12-24".to_string()), 3.6981f64);
-    exchange_rates.insert(Exchange::USD("2019-09-03".to_string()), 3.9882f64);
-    exchange_rates.insert(Exchange::USD("2018-04-20".to_string()), 3.3881f64);
-    exchange_rates.insert(Exchange::USD("2017-05-05".to_string()), 3.849f64);
-    exchange_rates.insert(Exchange::USD("2020-01-24".to_string()), 3.8475f64);
-    exchange_rates.insert(Exchange::USD("2012-08-09".to_string()), 3.2915f64);
-    exchange_rates.insert(Exchange::USD("2015-03-04".to_string()), 3.7485f64);
-    exchange_rates.insert(Exchange::USD("2015-04-03".to_string()), 3.7449f64);
-    exchange_rates.insert(Exchange::USD("2015-12-02".to_string()), 4.0305f64);
-    exchange_rates.insert(Exchange::USD("2025-03-10".to_string()), 3.8573f64);
-    exchange_rates.insert(Exchange::USD("2020-11-20".to_string()), 3.7677f64);
-    exchange_rates.insert(Exchange::USD("2018-05-24".to_string()), 3.659f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-17".to_string()), 4.4389f64);
-    exchange_rates.insert(Exchange::USD("2013-06-20".to_string()), 3.2693f64);
-    exchange_rates.insert(Exchange::USD("2019-04-16".to_string()), 3.7802f64);
-    exchange_rates.insert(Exchange::USD("2014-03-21".to_string()), 3.0425f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-11".to_string()), 4.681f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-28".to_string()), 4.313f64);
-    exchange_rates.insert(Exchange::USD("2017-08-28".to_string()), 3.5736f64);
-    exchange_rates.insert(Exchange::USD("2015-07-01".to_string()), 3.7625f64);
-    exchange_rates.insert(Exchange::USD("2015-11-27".to_string()), 4.0333f64);
-    exchange_rates.insert(Exchange::USD("2018-10-02".to_string()), 3.7326f64);
-    exchange_rates.insert(Exchange::USD("2016-01-13".to_string()), 4.0068f64);
-    exchange_rates.insert(Exchange::USD("2020-02-28".to_string()), 3.9255f64);
-    exchange_rates.insert(Exchange::USD("2016-09-26".to_string()), 3.8324f64);
-    exchange_rates.insert(Exchange::USD("2015-09-30".to_string()), 3.7754f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-11".to_string()), 4.2563f64);
-    exchange_rates.insert(Exchange::USD("2023-09-26".to_string()), 4.3485f64);
-    exchange_rates.insert(Exchange::USD("2015-06-12".to_string()), 3.7094f64);
-    exchange_rates.insert(Exchange::USD("2019-10-29".to_string()), 3.8598f64);
-    exchange_rates.insert(Exchange::USD("2018-03-12".to_string()), 3.4066f64);
-    exchange_rates.insert(Exchange::USD("2016-07-13".to_string()), 3.9809f64);
-    exchange_rates.insert(Exchange::USD("2012-08-27".to_string()), 3.257f64);
-    exchange_rates.insert(Exchange::USD("2020-05-14".to_string()), 4.2269f64);
-    exchange_rates.insert(Exchange::USD("2017-06-22".to_string()), 3.8002f64);
-    exchange_rates.insert(Exchange::USD("2016-04-20".to_string()), 3.7842f64);
-    exchange_rates.insert(Exchange::USD("2019-10-08".to_string()), 3.9405f64);
-    exchange_rates.insert(Exchange::USD("2022-01-27".to_string()), 4.083f64);
-    exchange_rates.insert(Exchange::USD("2012-09-25".to_string()), 3.2136f64);
-    exchange_rates.insert(Exchange::USD("2012-05-29".to_string()), 3.4675f64);
-    exchange_rates.insert(Exchange::USD("2018-12-03".to_string()), 3.7672f64);
-    exchange_rates.insert(Exchange::USD("2024-04-02".to_string()), 4.0009f64);
-    exchange_rates.insert(Exchange::USD("2014-02-18".to_string()), 3.03f64);
-    exchange_rates.insert(Exchange::USD("2019-10-01".to_string()), 4.0147f64);
-    exchange_rates.insert(Exchange::USD("2016-09-02".to_string()), 3.9164f64);
-    exchange_rates.insert(Exchange::EUR("2023-01-17".to_string()), 4.6946f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-27".to_string()), 4.2802f64);
-    exchange_rates.insert(Exchange::USD("2016-02-15".to_string()), 3.922f64);
-    exchange_rates.insert(Exchange::USD("2021-08-09".to_string()), 3.8885f64);
-    exchange_rates.insert(Exchange::USD("2023-12-12".to_string()), 4.0204f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-24".to_string()), 4.2353f64);
-    exchange_rates.insert(Exchange::USD("2014-03-07".to_string()), 3.0185f64);
-    exchange_rates.insert(Exchange::USD("2025-12-04".to_string()), 3.6221f64);
-    exchange_rates.insert(Exchange::USD("2024-08-30".to_string()), 3.8644f64);
-    exchange_rates.insert(Exchange::USD("2020-09-15".to_string()), 3.7445f64);
-    exchange_rates.insert(Exchange::USD("2016-02-01".to_string()), 4.0638f64);
-    exchange_rates.insert(Exchange::USD("2024-03-25".to_string()), 3.9833f64);
-    exchange_rates.insert(Exchange::USD("2012-11-19".to_string()), 3.2503f64);
-    exchange_rates.insert(Exchange::USD("2013-07-11".to_string()), 3.3225f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-01".to_string()), 4.3176f64);
-    exchange_rates.insert(Exchange::USD("2018-05-29".to_string()), 3.7299f64);
-    exchange_rates.insert(Exchange::USD("2016-04-08".to_string()), 3.7701f64);
-    exchange_rates.insert(Exchange::USD("2018-05-18".to_string()), 3.6385f64);
-    exchange_rates.insert(Exchange::USD("2016-04-06".to_string()), 3.751f64);
-    exchange_rates.insert(Exchange::USD("2019-04-29".to_string()), 3.8468f64);
-    exchange_rates.insert(Exchange::USD("2023-04-25".to_string()), 4.1649f64);
-    exchange_rates.insert(Exchange::USD("2015-06-26".to_string()), 3.7275f64);
-    exchange_rates.insert(Exchange::USD("2021-03-11".to_string()), 3.8287f64);
-    exchange_rates.insert(Exchange::USD("2017-01-03".to_string()), 4.2271f64);
-    exchange_rates.insert(Exchange::USD("2019-07-19".to_string()), 3.7855f64);
-    exchange_rates.insert(Exchange::USD("2025-07-18".to_string()), 3.6535f64);
-    exchange_rates.insert(Exchange::USD("2017-05-23".to_string()), 3.7354f64);
-    exchange_rates.insert(Exchange::EUR("2024-12-24".to_string()), 4.2739f64);
-    exchange_rates.insert(Exchange::EUR("2024-06-25".to_string()), 4.2909f64);
-    exchange_rates.insert(Exchange::USD("2020-06-12".to_string()), 3.9299f64);
-    exchange_rates.insert(Exchange::USD("2020-08-25".to_string()), 3.7144f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-07".to_string()), 4.4513f64);
-    exchange_rates.insert(Exchange::USD("2021-08-10".to_string()), 3.8937f64);
-    exchange_rates.insert(Exchange::EUR("2024-08-16".to_string()), 4.2732f64);
-    exchange_rates.insert(Exchange::USD("2025-10-16".to_string()), 3.6467f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-07".to_string()), 4.1898f64);
-    exchange_rates.insert(Exchange::USD("2012-06-04".to_string()), 3.5431f64);
-    exchange_rates.insert(Exchange::USD("2013-06-03".to_string()), 3.2865f64);
-    exchange_rates.insert(Exchange::USD("2021-06-30".to_string()), 3.8035f64);
-    exchange_rates.insert(Exchange::USD("2020-12-22".to_string()), 3.6921f64);
-    exchange_rates.insert(Exchange::USD("2021-02-04".to_string()), 3.7509f64);
-    exchange_rates.insert(Exchange::USD("2012-01-18".to_string()), 3.4f64);
-    exchange_rates.insert(Exchange::USD("2023-06-26".to_string()), 4.0669f64);
-    exchange_rates.insert(Exchange::USD("2013-02-19".to_string()), 3.136f64);
-    exchange_rates.insert(Exchange::USD("2012-03-02".to_string()), 3.1023f64);
-    exchange_rates.insert(Exchange::USD("2013-11-19".to_string()), 3.0925f64);
-    exchange_rates.insert(Exchange::USD("2025-10-22".to_string()), 3.6591f64);
-    exchange_rates.insert(Exchange::USD("2019-02-22".to_string()), 3.8242f64);
-    exchange_rates.insert(Exchange::USD("2018-10-22".to_string()), 3.7273f64);
-    exchange_rates.insert(Exchange::USD("2014-06-13".to_string()), 3.0382f64);
-    exchange_rates.insert(Exchange::USD("2014-07-02".to_string()), 3.0387f64);
-    exchange_rates.insert(Exchange::USD("2022-08-31".to_string()), 4.736f64);
-    exchange_rates.insert(Exchange::USD("2013-09-05".to_string()), 3.2463f64);
-    exchange_rates.insert(Exchange::USD("2012-04-03".to_string()), 3.099f64);
-    exchange_rates.insert(Exchange::USD("2025-01-27".to_string()), 4.021f64);
-    exchange_rates.insert(Exchange::USD("2014-12-19".to_string()), 3.4791f64);
-    exchange_rates.insert(Exchange::USD("2022-05-04".to_string()), 4.4456f64);
-    exchange_rates.insert(Exchange::USD("2019-12-17".to_string()), 3.8211f64);
-    exchange_rates.insert(Exchange::USD("2014-02-21".to_string()), 3.0389f64);
-    exchange_rates.insert(Exchange::USD("2018-08-21".to_string()), 3.7375f64);
-    exchange_rates.insert(Exchange::USD("2025-04-02".to_string()), 3.8707f64);
-    exchange_rates.insert(Exchange::USD("2017-03-07".to_string()), 4.0803f64);
-    exchange_rates.insert(Exchange::EUR("2023-11-10".to_string()), 4.4227f64);
-    exchange_rates.insert(Exchange::USD("2024-02-20".to_string()), 3.9994f64);
-    exchange_rates.insert(Exchange::USD("2013-09-26".to_string()), 3.1277f64);
-    exchange_rates.insert(Exchange::USD("2013-02-15".to_string()), 3.1398f64);
-    exchange_rates.insert(Exchange::USD("2022-11-10".to_string()), 4.7328f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-25".to_string()), 4.2544f64);
-    exchange_rates.insert(Exchange::USD("2023-12-18".to_string()), 3.9625f64);
-    exchange_rates.insert(Exchange::USD("2023-10-04".to_string()), 4.4155f64);
-    exchange_rates.insert(Exchange::USD("2020-12-07".to_string()), 3.7001f64);
-    exchange_rates.insert(Exchange::USD("2016-02-12".to_string()), 3.9074f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-16".to_string()), 4.2604f64);
-    exchange_rates.insert(Exchange::USD("2021-06-02".to_string()), 3.6659f64);
-    exchange_rates.insert(Exchange::USD("2019-09-23".to_string()), 3.9983f64);
-    exchange_rates.insert(Exchange::EUR("2025-07-28".to_string()), 4.2565f64);
-    exchange_rates.insert(Exchange::USD("2021-08-13".to_string()), 3.8934f64);
-    exchange_rates.insert(Exchange::USD("2018-03-09".to_string()), 3.4132f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-24".to_string()), 4.3177f64);
-    exchange_rates.insert(Exchange::USD("2024-09-25".to_string()), 3.8117f64);
-    exchange_rates.insert(Exchange::USD("2019-08-06".to_string()), 3.8482f64);
-    exchange_rates.insert(Exchange::USD("2019-11-05".to_string()), 3.8308f64);
-    exchange_rates.insert(Exchange::USD("2021-05-25".to_string()), 3.6549f64);
-    exchange_rates.insert(Exchange::USD("2012-03-28".to_string()), 3.11f64);
-    exchange_rates.insert(Exchange::USD("2018-02-26".to_string()), 3.3799f64);
-    exchange_rates.insert(Exchange::USD("2012-06-08".to_string()), 3.4566f64);
-    exchange_rates.insert(Exchange::EUR("2023-04-17".to_string()), 4.6341f64);
-    exchange_rates.insert(Exchange::USD("2024-02-21".to_string()), 3.9966f64);
-    exchange_rates.insert(Exchange::EUR("2025-05-02".to_string()), 4.275f64);
-    exchange_rates.insert(Exchange::USD("2024-10-31".to_string()), 4.0059f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-08".to_string()), 4.3244f64);
-    exchange_rates.insert(Exchange::USD("2020-03-12".to_string()), 3.8642f64);
-    exchange_rates.insert(Exchange::USD("2022-12-28".to_string()), 4.4127f64);
-    exchange_rates.insert(Exchange::USD("2014-01-22".to_string()), 3.0761f64);
-    exchange_rates.insert(Exchange::USD("2023-09-07".to_string()), 4.289f64);
-    exchange_rates.insert(Exchange::USD("2024-12-18".to_string()), 4.0621f64);
-    exchange_rates.insert(Exchange::USD("2018-01-05".to_string()), 3.4488f64);
-    exchange_rates.insert(Exchange::USD("2021-05-12".to_string()), 3.7456f64);
-    exchange_rates.insert(Exchange::USD("2017-01-12".to_string()), 4.1103f64);
-    exchange_rates.insert(Exchange::USD("2017-02-10".to_string()), 4.0435f64);
-    exchange_rates.insert(Exchange::USD("2012-02-06".to_string()), 3.2084f64);
-    exchange_rates.insert(Exchange::USD("2021-03-04".to_string()), 3.7851f64);
-    exchange_rates.insert(Exchange::USD("2015-02-04".to_string()), 3.644f64);
-    exchange_rates.insert(Exchange::EUR("2024-03-05".to_string()), 4.3228f64);
-    exchange_rates.insert(Exchange::USD("2017-06-27".to_string()), 3.7442f64);
-    exchange_rates.insert(Exchange::USD("2020-12-31".to_string()), 3.7584f64);
-    exchange_rates.insert(Exchange::USD("2012-07-25".to_string()), 3.4719f64);
-    exchange_rates.insert(Exchange::USD("2019-11-14".to_string()), 3.9011f64);
-    exchange_rates.insert(Exchange::USD("2023-02-14".to_string()), 4.4463f64);
-    exchange_rates.insert(Exchange::USD("2019-10-31".to_string()), 3.8174f64);
-    exchange_rates.insert(Exchange::USD("2025-12-22".to_string()), 3.5904f64);
-    exchange_rates.insert(Exchange::USD("2023-02-16".to_string()), 4.4601f64);
-    exchange_rates.insert(Exchange::USD("2012-02-29".to_string()), 3.073f64);
-    exchange_rates.insert(Exchange::USD("2020-07-17".to_string()), 3.9263f64);
-    exchange_rates.insert(Exchange::USD("2019-09-26".to_string()), 4.0092f64);
-    exchange_rates.insert(Exchange::USD("2015-09-01".to_string()), 3.7503f64);
-    exchange_rates.insert(Exchange::EUR("2025-01-24".to_string()), 4.21f64);
-    exchange_rates.insert(Exchange::USD("2017-01-30".to_string()), 4.0472f64);
-    exchange_rates.insert(Exchange::USD("2017-12-20".to_string()), 3.5459f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-28".to_string()), 4.3085f64);
-    exchange_rates.insert(Exchange::USD("2017-10-24".to_string()), 3.6031f64);
-    exchange_rates.insert(Exchange::USD("2021-09-02".to_string()), 3.8044f64);
-    exchange_rates.insert(Exchange::USD("2022-06-07".to_string()), 4.2935f64);
-    exchange_rates.insert(Exchange::USD("2021-03-03".to_string()), 3.7509f64);
-    exchange_rates.insert(Exchange::USD("2018-04-30".to_string()), 3.4868f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-10".to_string()), 4.465f64);
-    exchange_rates.insert(Exchange::USD("2022-09-22".to_string()), 4.8343f64);
-    exchange_rates.insert(Exchange::USD("2019-12-16".to_string()), 3.8311f64);
-    exchange_rates.insert(Exchange::USD("2021-11-15".to_string()), 4.0497f64);
-    exchange_rates.insert(Exchange::USD("2024-05-24".to_string()), 3.9376f64);
-    exchange_rates.insert(Exchange::USD("2013-06-24".to_string()), 3.3137f64);
-    exchange_rates.insert(Exchange::USD("2023-07-21".to_string()), 3.999f64);
-    exchange_rates.insert(Exchange::USD("2021-03-01".to_string()), 3.7572f64);
-    exchange_rates.insert(Exchange::USD("2021-09-24".to_string()), 3.9268f64);
-    exchange_rates.insert(Exchange::USD("2014-12-16".to_string()), 3.3522f64);
-    exchange_rates.insert(Exchange::EUR("2024-11-05".to_string()), 4.3607f64);
-    exchange_rates.insert(Exchange::USD("2023-10-19".to_string()), 4.2264f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-02".to_string()), 4.1794f64);
-    exchange_rates.insert(Exchange::USD("2024-08-29".to_string()), 3.867f64);
-    exchange_rates.insert(Exchange::USD("2015-02-24".to_string()), 3.6948f64);
-    exchange_rates.insert(Exchange::USD("2012-07-16".to_string()), 3.4353f64);
-    exchange_rates.insert(Exchange::USD("2020-09-25".to_string()), 3.9016f64);
-    exchange_rates.insert(Exchange::USD("2024-09-23".to_string()), 3.8571f64);
-    exchange_rates.insert(Exchange::USD("2014-08-22".to_string()), 3.152f64);
-    exchange_rates.insert(Exchange::USD("2025-01-10".to_string()), 4.1415f64);
-    exchange_rates.insert(Exchange::USD("2012-02-13".to_string()), 3.1643f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-28".to_string()), 4.4716f64);
-    exchange_rates.insert(Exchange::USD("2025-02-19".to_string()), 3.9875f64);
-    exchange_rates.insert(Exchange::USD("2025-10-29".to_string()), 3.6399f64);
-    exchange_rates.insert(Exchange::EUR("2025-04-18".to_string()), 4.2823f64);
-    exchange_rates.insert(Exchange::USD("2022-11-04".to_string()), 4.7975f64);
-    exchange_rates.insert(Exchange::USD("2019-09-30".to_string()), 4f64);
-    exchange_rates.insert(Exchange::USD("2016-03-23".to_string()), 3.801f64);
-    exchange_rates.insert(Exchange::USD("2016-11-30".to_string()), 4.1721f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-28".to_string()), 4.2592f64);
-    exchange_rates.insert(Exchange::USD("2022-08-02".to_string()), 4.5984f64);
-    exchange_rates.insert(Exchange::USD("2017-09-11".to_string()), 3.5293f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-12".to_string()), 4.2666f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-21".to_string()), 4.1705f64);
-    exchange_rates.insert(Exchange::USD("2012-12-06".to_string()), 3.1538f64);
-    exchange_rates.insert(Exchange::USD("2012-10-11".to_string()), 3.1819f64);
-    exchange_rates.insert(Exchange::USD("2024-03-22".to_string()), 3.9928f64);
-    exchange_rates.insert(Exchange::USD("2024-06-05".to_string()), 3.9607f64);
-    exchange_rates.insert(Exchange::USD("2023-05-08".to_string()), 4.1384f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-08".to_string()), 4.3548f64);
-    exchange_rates.insert(Exchange::USD("2023-01-27".to_string()), 4.3252f64);
-    exchange_rates.insert(Exchange::USD("2021-08-18".to_string()), 3.8861f64);
-    exchange_rates.insert(Exchange::USD("2022-04-05".to_string()), 4.2233f64);
-    exchange_rates.insert(Exchange::USD("2016-02-11".to_string()), 3.9219f64);
-    exchange_rates.insert(Exchange::USD("2014-08-19".to_string()), 3.1356f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-22".to_string()), 4.3203f64);
-    exchange_rates.insert(Exchange::USD("2016-06-24".to_string()), 3.9994f64);
-    exchange_rates.insert(Exchange::USD("2013-09-23".to_string()), 3.126f64);
-    exchange_rates.insert(Exchange::USD("2014-05-30".to_string()), 3.0435f64);
-    exchange_rates.insert(Exchange::USD("2012-09-26".to_string()), 3.2187f64);
-    exchange_rates.insert(Exchange::USD("2017-09-15".to_string()), 3.5863f64);
-    exchange_rates.insert(Exchange::USD("2021-09-16".to_string()), 3.8847f64);
-    exchange_rates.insert(Exchange::USD("2012-03-13".to_string()), 3.1288f64);
-    exchange_rates.insert(Exchange::USD("2024-08-06".to_string()), 3.9467f64);
-    exchange_rates.insert(Exchange::USD("2019-09-16".to_string()), 3.9116f64);
-    exchange_rates.insert(Exchange::USD("2016-09-16".to_string()), 3.8497f64);
-    exchange_rates.insert(Exchange::USD("2021-08-23".to_string()), 3.9107f64);
-    exchange_rates.insert(Exchange::USD("2019-12-03".to_string()), 3.8706f64);
-    exchange_rates.insert(Exchange::USD("2023-11-14".to_string()), 4.121f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-09".to_string()), 4.234f64);
-    exchange_rates.insert(Exchange::USD("2015-02-10".to_string()), 3.722f64);
-    exchange_rates.insert(Exchange::USD("2021-11-04".to_string()), 3.9807f64);
-    exchange_rates.insert(Exchange::USD("2015-02-17".to_string()), 3.6788f64);
-    exchange_rates.insert(Exchange::USD("2025-03-04".to_string()), 3.9543f64);
-    exchange_rates.insert(Exchange::EUR("2023-08-31".to_string()), 4.4684f64);
-    exchange_rates.insert(Exchange::EUR("2024-04-25".to_string()), 4.3198f64);
-    exchange_rates.insert(Exchange::USD("2016-01-08".to_string()), 3.9963f64);
-    exchange_rates.insert(Exchange::USD("2012-09-13".to_string()), 3.1852f64);
-    exchange_rates.insert(Exchange::USD("2025-09-09".to_string()), 3.6173f64);
-    exchange_rates.insert(Exchange::USD("2015-12-04".to_string()), 3.9607f64);
-    exchange_rates.insert(Exchange::USD("2014-09-05".to_string()), 3.2354f64);
-    exchange_rates.insert(Exchange::USD("2025-06-25".to_string()), 3.659f64);
-    exchange_rates.insert(Exchange::USD("2017-02-20".to_string()), 4.0692f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-01".to_string()), 4.2846f64);
-    exchange_rates.insert(Exchange::USD("2015-01-02".to_string()), 3.5725f64);
-    exchange_rates.insert(Exchange::USD("2017-02-17".to_string()), 4.0693f64);
-    exchange_rates.insert(Exchange::USD("2017-04-26".to_string()), 3.8757f64);
-    exchange_rates.insert(Exchange::USD("2025-02-25".to_string()), 3.947f64);
-    exchange_rates.insert(Exchange::USD("2025-05-02".to_string()), 3.7722f64);
-    exchange_rates.insert(Exchange::USD("2025-03-18".to_string()), 3.8152f64);
-    exchange_rates.insert(Exchange::EUR("2024-10-14".to_string()), 4.2928f64);
-    exchange_rates.insert(Exchange::EUR("2025-02-04".to_string()), 4.2249f64);
-    exchange_rates.insert(Exchange::USD("2018-08-16".to_string()), 3.7871f64);
-    exchange_rates.insert(Exchange::EUR("2025-12-03".to_string()), 4.2312f64);
-    exchange_rates.insert(Exchange::USD("2017-08-21".to_string()), 3.6408f64);
-    exchange_rates.insert(Exchange::USD("2012-04-11".to_string()), 3.2034f64);
-    exchange_rates.insert(Exchange::USD("2020-04-22".to_string()), 4.1736f64);
-    exchange_rates.insert(Exchange::USD("2014-02-05".to_string()), 3.0987f64);
-    exchange_rates.insert(Exchange::USD("2013-12-27".to_string()), 3.0105f64);
-    exchange_rates.insert(Exchange::USD("2024-09-19".to_string()), 3.8249f64);
-    exchange_rates.insert(Exchange::USD("2025-06-23".to_string()), 3.7212f64);
-    exchange_rates.insert(Exchange::USD("2017-08-18".to_string()), 3.6459f64);
-    exchange_rates.insert(Exchange::USD("2024-02-26".to_string()), 3.9776f64);
-    exchange_rates.insert(Exchange::USD("2016-02-24".to_string()), 3.9803f64);
-    exchange_rates.insert(Exchange::USD("2013-03-21".to_string()), 3.2387f64);
-    exchange_rates.insert(Exchange::USD("2014-02-06".to_string()), 3.095f64);
-    exchange_rates.insert(Exchange::USD("2018-04-13".to_string()), 3.3862f64);
-    exchange_rates.insert(Exchange::USD("2012-05-08".to_string()), 3.2238f64);
-    exchange_rates.insert(Exchange::USD("2025-10-17".to_string()), 3.6388f64);
-    exchange_rates.insert(Exchange::USD("2015-08-03".to_string()), 3.7705f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-24".to_string()), 4.2796f64);
-    exchange_rates.insert(Exchange::USD("2012-07-18".to_string()), 3.395f64);
-    exchange_rates.insert(Exchange::EUR("2023-03-14".to_string()), 4.6909f64);
-    exchange_rates.insert(Exchange::USD("2021-11-26".to_string()), 4.1754f64);
-    exchange_rates.insert(Exchange::USD("2020-05-29".to_string()), 4.0031f64);
-    exchange_rates.insert(Exchange::USD("2013-06-11".to_string()), 3.2201f64);
-    exchange_rates.insert(Exchange::USD("2015-07-24".to_string()), 3.7654f64);
-    exchange_rates.insert(Exchange::USD("2017-07-13".to_string()), 3.7133f64);
-    exchange_rates.insert(Exchange::USD("2020-08-24".to_string()), 3.7273f64);
-    exchange_rates.insert(Exchange::USD("2022-03-03".to_string()), 4.3257f64);
-    exchange_rates.insert(Exchange::USD("2012-09-19".to_string()), 3.1631f64);
-    exchange_rates.insert(Exchange::USD("2021-02-12".to_string()), 3.7186f64);
-    exchange_rates.insert(Exchange::USD("2013-11-13".to_string()), 3.1358f64);
-    exchange_rates.insert(Exchange::USD("2024-09-05".to_string()), 3.8487f64);
-    exchange_rates.insert(Exchange::EUR("2023-10-31".to_string()), 4.4475f64);
-    exchange_rates.insert(Exchange::EUR("2024-05-16".to_string()), 4.2617f64);
-    exchange_rates.insert(Exchange::USD("2019-11-06".to_string()), 3.8497f64);
-    exchange_rates.insert(Exchange::USD("2021-04-27".to_string()), 3.7826f64);
-    exchange_rates.insert(Exchange::USD("2020-10-14".to_string()), 3.8301f64);
-    exchange_rates.insert(Exchange::EUR("2025-10-14".to_string()), 4.2645f64);
-    exchange_rates.insert(Exchange::USD("2015-06-15".to_string()), 3.6905f64);
-    exchange_rates.insert(Exchange::USD("2019-10-16".to_string()), 3.8934f64);
-    exchange_rates.insert(Exchange::USD("2023-09-05".to_string()), 4.1643f64);
-    exchange_rates.insert(Exchange::USD("2024-07-09".to_string()), 3.9391f64);
-    exchange_rates.insert(Exchange::USD("2020-09-22".to_string()), 3.8359f64);
-    exchange_rates.insert(Exchange::USD("2023-11-23".to_string()), 3.9969f64);
-    exchange_rates.insert(Exchange::USD("2025-03-27".to_string()), 3.8913f64);
-    exchange_rates.insert(Exchange::USD("2021-11-17".to_string()), 4.1204f64);
-    exchange_rates.insert(Exchange::USD("2020-04-07".to_string()), 4.1717f64);
-    exchange_rates.insert(Exchange::EUR("2024-02-15".to_string()), 4.3463f64);
-    exchange_rates.insert(Exchange::USD("2022-03-11".to_string()), 4.3694f64);
-    exchange_rates.insert(Exchange::EUR("2024-09-26".to_string()), 4.2665f64);
-    exchange_rates.insert(Exchange::USD("2015-07-28".to_string()), 3.7303f64);
-    exchange_rates.insert(Exchange::USD("2014-01-24".to_string()), 3.0727f64);
-    exchange_rates.insert(Exchange::EUR("2024-07-05".to_string()), 4.2858f64);
-    exchange_rates.insert(Exchange::USD("2017-12-01".to_string()), 3.5352f64);
-    exchange_rates.insert(Exchange::USD("2025-10-03".to_string()), 3.6253f64);
-    exchange_rates.insert(Exchange::USD("2020-07-08".to_string()), 3.9666f64);
-    exchange_rates.insert(Exchange::USD("2012-08-01".to_string()), 3.342f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-10".to_string()), 4.3413f64);
-    exchange_rates.insert(Exchange::EUR("2024-01-11".to_string()), 4.3505f64);
-    exchange_rates.insert(Exchange::USD("2016-10-17".to_string()), 3.9265f64);
-    exchange_rates.insert(Exchange::EUR("2025-03-19".to_string()), 4.1945f64);
-    exchange_rates.insert(Exchange::USD("2014-06-18".to_string()), 3.0589f64);
-    exchange_rates.insert(Exchange::USD("2024-09-13".to_string()), 3.8659f64);
-    exchange_rates.insert(Exchange::USD("2022-02-01".to_string()), 4.0689f64);
-    exchange_rates.insert(Exchange::USD("2012-03-27".to_string()), 3.0939f64);
-    exchange_rates.insert(Exchange::USD("2019-12-12".to_string()), 3.8505f64);
-    exchange_rates.insert(Exchange::USD("2015-05-15".to_string()), 3.5719f64);
-    exchange_rates.insert(Exchange::EUR("2023-07-06".to_string()), 4.4754f64);
-    exchange_rates.insert(Exchange::USD("2021-11-16".to_string()), 4.1061f64);
-    exchange_rates.insert(Exchange::USD("2013-11-25".to_string()), 3.1019f64);
-    exchange_rates.insert(Exchange::USD("2021-09-23".to_string()), 3.9256f64);
-    exchange_rates
-}
@@ -1,9 +1,11 @@
 // SPDX-FileCopyrightText: 2022-2025 RustInFinance
 // SPDX-License-Identifier: BSD-3-Clause
 
+use chrono::Datelike;
 use pdf::file::File;
 use pdf::object::PageRc;
 use pdf::primitive::Primitive;
+use std::sync::OnceLock;
 
 pub use crate::logging::ResultExt;
 
@@ -12,6 +14,11 @@ enum StatementType {
     UnknownDocument,
     BrokerageStatement,
     AccountStatement,
+    ReleaseConfirmation,
+    PurchaseConfirmation,
+    TradeConfirmation,
+    Form1042S,
+    Form1099,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -20,14 +27,65 @@ enum TransactionType {
     Dividends,
     Sold,
     Tax,
-    Trade,
+    TaxAdjustment,
 }
 
+// Interests: transaction date, gross_us, tax_us, whether reportable for the prior year
+type InterestTransaction = (String, f32, f32, bool);
+// Dividend: transaction date, gross_us, tax_us, company
+type DividendTransaction = (String, f32, f32, Option<String>);
+// Sold shares: trade_date, settlement_date, quantity, price, amount_sold, company
+type SoldTransaction = (String, String, f32, f32, f32, Option<String>);
+// Transactions found in statement: interests, dividends and sold shares. Followed by
+// securities held at the end of statement period and adjustments of tax withheld from dividends
+type ParsedStatement = (
+    Vec<InterestTransaction>,
+    Vec<DividendTransaction>,
+    Vec<SoldTransaction>,
+    Vec<crate::Holding>,
+    Vec<crate::TaxWithholdingAdjustment>,
+);
+// Transactions of cash flow block collected while statement is parsed
+#[derive(Default)]
+struct StatementTransactions {
+    interests: Vec<InterestTransaction>,
+    dividends: Vec<DividendTransaction>,
+    sold: Vec<SoldTransaction>,
+    tax_adjustments: Vec<crate::TaxWithholdingAdjustment>,
+}
+// Security of holdings block: name, symbol and amounts listed after it
+type HoldingEntry = (String, String, Vec<f64>);
+
+// Brokers issuing statements. Legacy brokerage statements and trade confirmations come from
+// E*TRADE, account statements, confirmations of releases and purchases and tax forms from
+// Morgan Stanley
+const BROKER_ETRADE: &str = "E*TRADE";
+const BROKER_MORGAN_STANLEY: &str = "Morgan Stanley";
+
+/// Transactions found in PDF document depending on its type
 #[derive(Debug, PartialEq)]
+pub enum PdfDocument {
+    // Account the statement was issued for and its transactions
+    Statement(crate::Account, ParsedStatement),
+    // Account confirmations were issued for and confirmed events
+    ReleaseConfirmation(crate::Account, Vec<crate::VestEvent>),
+    PurchaseConfirmation(crate::Account, Vec<crate::EsppPurchase>),
+    TradeConfirmation(crate::Account, Vec<crate::TradeConfirmation>),
+    Form1042S(crate::Account, Vec<crate::Form1042S>),
+    // Account, dividends (if any) and sold lots of consolidated Form 1099
+    Form1099(
+        crate::Account,
+        Option<crate::Form1099Div>,
+        Vec<crate::Form1099BLot>,
+    ),
+}
+
+#[derive(Clone, Debug, PartialEq)]
 enum ParserState {
     SearchingYear,
     ProcessingYear,
     SearchingCashFlowBlock,
+    ProcessingHoldings,
     SearchingTransactionEntry,
     ProcessingTransaction(TransactionType),
 }
@@ -51,12 +109,49 @@ pub trait Entry {
     fn is_pattern(&self) -> bool {
         false
     }
+    // Whether parsed token is a description of security (e.g. company name) that is to be
+    // kept as a part of transaction
+    fn is_security(&self) -> bool {
+        false
+    }
+    // Whether parsed token is a comment of transaction (e.g. "DIV PAYMENT") that is to be
+    // kept as a part of transaction
+    fn is_comment(&self) -> bool {
+        false
+    }
 }
 
 struct F32Entry {
     pub val: f32,
 }
 
+// Amount of credit e.g. "5.25" or debit e.g. "(5.25)" which is negative
+struct SignedF32Entry {
+    pub val: f32,
+}
+
+impl Entry for SignedF32Entry {
+    fn parse(&mut self, pstr: &pdf::primitive::PdfString) {
+        let mystr = pstr
+            .clone()
+            .into_string()
+            .unwrap_or_else(|_| panic!("Error parsing : {:#?} to f32", pstr));
+        let amount = mystr.trim().replace([',', '$'], "");
+        self.val = match amount.strip_prefix('(').and_then(|a| a.strip_suffix(')')) {
+            Some(debit) => -debit
+                .parse::<f32>()
+                .unwrap_or_else(|_| panic!("Error parsing : {} to f32", mystr)),
+            None => amount
+                .parse::<f32>()
+                .unwrap_or_else(|_| panic!("Error parsing : {} to f32", mystr)),
+        };
+        log::info!("Parsed signed f32 value: {}", self.val);
+    }
+    fn getf32(&self) -> Option<f32> {
+        Some(self.val)
+    }
+}
+
 impl Entry for F32Entry {
     fn parse(&mut self, pstr: &pdf::primitive::PdfString) {
         let mystr = pstr
@@ -143,24 +238,127 @@ impl Entry for StringEntry {
     }
 }
 
+// Description of security (company, fund) as it is written in statement e.g. "INTEL CORP".
+// Securities are recognized by position in transaction row, so any text that is not
+// an amount or a date is accepted (unless patterns are given)
+struct SecurityEntry {
+    pub val: String,
+    pub patterns: Vec<String>,
+}
+
+impl Entry for SecurityEntry {
+    fn parse(&mut self, pstr: &pdf::primitive::PdfString) {
+        self.val = pstr
+            .clone()
+            .into_string()
+            .unwrap_or_else(|_| panic!("Error parsing : {:#?} to String", pstr))
+            .trim()
+            .to_owned();
+        log::info!("Parsed security description: {}", self.val);
+    }
+    fn getstring(&self) -> Option<String> {
+        Some(self.val.clone())
+    }
+    fn is_pattern(&self) -> bool {
+        let amount = self
+            .val
+            .replace([',', '(', ')', '$'], "")
+            .trim()
+            .parse::<f32>()
+            .is_ok();
+        static DATE: OnceLock<regex::Regex> = OnceLock::new();
+        let date = DATE
+            .get_or_init(|| {
+                regex::Regex::new(r"^\d{1,2}/\d{1,2}(/\d{2,4})?$")
+                    .expect("Unable to create regular expression to match dates")
+            })
+            .is_match(&self.val);
+        let description = !self.val.is_empty() && self.val != "$" && !amount && !date;
+        description && (self.patterns.is_empty() || self.patterns.contains(&self.val))
+    }
+    fn is_security(&self) -> bool {
+        true
+    }
+}
+
+// Comment of transaction matching one of patterns (if any) e.g. "DIV PAYMENT"
+struct CommentEntry {
+    pub val: String,
+    pub patterns: Vec<String>,
+}
+
+impl Entry for CommentEntry {
+    fn parse(&mut self, pstr: &pdf::primitive::PdfString) {
+        self.val = pstr
+            .clone()
+            .into_string()
+            .unwrap_or_else(|_| panic!("Error parsing : {:#?} to String", pstr))
+            .trim()
+            .to_owned();
+        log::info!("Parsed comment: {}", self.val);
+    }
+    fn getstring(&self) -> Option<String> {
+        Some(self.val.clone())
+    }
+    fn is_pattern(&self) -> bool {
+        self.patterns.is_empty() || self.patterns.contains(&self.val)
+    }
+    fn is_comment(&self) -> bool {
+        true
+    }
+}
+
+// Symbol of security e.g. "INTC" (descriptions preceding it are skipped)
+struct SymbolEntry {
+    pub val: String,
+}
+
+impl Entry for SymbolEntry {
+    fn parse(&mut self, pstr: &pdf::primitive::PdfString) {
+        self.val = pstr
+            .clone()
+            .into_string()
+            .unwrap_or_else(|_| panic!("Error parsing : {:#?} to String", pstr))
+            .trim()
+            .to_owned();
+        log::info!("Parsed security symbol: {}", self.val);
+    }
+    fn getstring(&self) -> Option<String> {
+        Some(self.val.clone())
+    }
+    fn is_pattern(&self) -> bool {
+        static SYMBOL: OnceLock<regex::Regex> = OnceLock::new();
+        SYMBOL
+            .get_or_init(|| {
+                regex::Regex::new(r"^[A-Z]{1,5}(\.[A-Z])?$")
+                    .expect("Unable to create regular expression to match security symbol")
+            })
+            .is_match(&self.val)
+    }
+    fn is_security(&self) -> bool {
+        true
+    }
+}
+
+// Dividend in (legacy) brokerage statement
 fn create_dividend_parsing_sequence(sequence: &mut std::collections::VecDeque<Box<dyn Entry>>) {
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["INTC".to_owned(), "DLB".to_owned()],
-    })); // INTC, DLB
+    sequence.push_back(Box::new(SymbolEntry { val: String::new() })); // e.g. INTC, DLB
     sequence.push_back(Box::new(F32Entry { val: 0.0 })); // Tax Entry
     sequence.push_back(Box::new(F32Entry { val: 0.0 })); // Income Entry
 }
 
+// Sold shares in (legacy) brokerage statement
+fn create_sold_parsing_sequence(sequence: &mut std::collections::VecDeque<Box<dyn Entry>>) {
+    sequence.push_back(Box::new(SymbolEntry { val: String::new() })); // e.g. INTC, DLB
+    sequence.push_back(Box::new(F32Entry { val: 0.0 })); // Quantity (negative)
+    sequence.push_back(Box::new(F32Entry { val: 0.0 })); // Price
+    sequence.push_back(Box::new(F32Entry { val: 0.0 })); // Amount Sold
+}
+
 fn create_tax_parsing_sequence(sequence: &mut std::collections::VecDeque<Box<dyn Entry>>) {
-    sequence.push_back(Box::new(StringEntry {
+    sequence.push_back(Box::new(SecurityEntry {
         val: String::new(),
-        patterns: vec![
-            "TREASURY LIQUIDITY FUND".to_owned(),
-            "INTEL CORP".to_owned(),
-            "ADVANCED MICRO DEVICES".to_owned(),
-            "DYNATRACE INC".to_owned(),
-        ],
+        patterns: vec![],
     }));
     sequence.push_back(Box::new(F32Entry { val: 0.0 })); // Tax Entry
 }
@@ -169,32 +367,32 @@ fn create_tax_withholding_adjusted_parsing_sequence(
     sequence: &mut std::collections::VecDeque<Box<dyn Entry>>,
 ) {
     // Description
-    sequence.push_back(Box::new(StringEntry {
+    sequence.push_back(Box::new(SecurityEntry {
         val: String::new(),
         patterns: vec![],
     }));
-    // Comment
-    sequence.push_back(Box::new(StringEntry {
+    // Comment (may refer to pay date of adjusted dividend)
+    sequence.push_back(Box::new(CommentEntry {
         val: String::new(),
         patterns: vec![],
     }));
-    // Money returned to tax-payer
-    sequence.push_back(Box::new(F32Entry { val: 0.0 }));
+    // Money returned to tax-payer (or withheld additionally when negative)
+    sequence.push_back(Box::new(SignedF32Entry { val: 0.0 }));
 }
 
+// Comment of interests paid in January that are reported (e.g. in 1099) for the prior year
+const PRIOR_YEAR_REPORTABLE: &str = "Transaction Reportable for the Prior Year.";
+
 fn create_interests_fund_parsing_sequence(
     sequence: &mut std::collections::VecDeque<Box<dyn Entry>>,
 ) {
-    sequence.push_back(Box::new(StringEntry {
+    sequence.push_back(Box::new(SecurityEntry {
         val: String::new(),
         patterns: vec!["TREASURY LIQUIDITY FUND".to_owned()],
     }));
-    sequence.push_back(Box::new(StringEntry {
+    sequence.push_back(Box::new(CommentEntry {
         val: String::new(),
-        patterns: vec![
-            "DIV PAYMENT".to_owned(),
-            "Transaction Reportable for the Prior Year.".to_owned(),
-        ],
+        patterns: vec!["DIV PAYMENT".to_owned(), PRIOR_YEAR_REPORTABLE.to_owned()],
     }));
     sequence.push_back(Box::new(F32Entry { val: 0.0 })); // Income Entry
 }
@@ -220,21 +418,17 @@ fn create_interest_adjustment_parsing_sequence(
 fn create_qualified_dividend_parsing_sequence(
     sequence: &mut std::collections::VecDeque<Box<dyn Entry>>,
 ) {
-    sequence.push_back(Box::new(StringEntry {
+    sequence.push_back(Box::new(SecurityEntry {
         val: String::new(),
-        patterns: vec!["INTEL CORP".to_owned()],
+        patterns: vec![],
     }));
     sequence.push_back(Box::new(F32Entry { val: 0.0 })); // Income Entry
 }
 
 fn create_sold_2_parsing_sequence(sequence: &mut std::collections::VecDeque<Box<dyn Entry>>) {
-    sequence.push_back(Box::new(StringEntry {
+    sequence.push_back(Box::new(SecurityEntry {
         val: String::new(),
-        patterns: vec![
-            "INTEL CORP".to_owned(),
-            "ADVANCED MICRO DEVICES".to_owned(),
-            "DYNATRACE INC".to_owned(),
-        ],
+        patterns: vec![],
     }));
     sequence.push_back(Box::new(StringEntry {
         val: String::new(),
@@ -249,83 +443,6 @@ fn create_sold_2_parsing_sequence(sequence: &mut std::collections::VecDeque<Box<
     sequence.push_back(Box::new(F32Entry { val: 0.0 })); // Amount Sold
 }
 
-fn create_trade_parsing_sequence(sequence: &mut std::collections::VecDeque<Box<dyn Entry>>) {
-    sequence.push_back(Box::new(DateEntry { val: String::new() })); // Trade date
-    sequence.push_back(Box::new(DateEntry { val: String::new() })); // Settlement date
-    sequence.push_back(Box::new(I32Entry { val: 0 })); // MKT /
-    sequence.push_back(Box::new(I32Entry { val: 0 })); // / CPT
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["INTC".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["SELL".to_owned()],
-    }));
-    sequence.push_back(Box::new(I32Entry { val: 0 })); // Quantity
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["$".to_owned()],
-    })); // $...
-    sequence.push_back(Box::new(F32Entry { val: 0.0 })); // ..<price>
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["Stock".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["Plan".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["PRINCIPAL".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["$".to_owned()],
-    })); // $...
-    sequence.push_back(Box::new(F32Entry { val: 0.0 })); // ..<principal>
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["INTEL".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["CORP".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["COMMISSION".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["$".to_owned()],
-    })); // $...
-    sequence.push_back(Box::new(F32Entry { val: 0.0 })); // ..<commission>
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["FEE".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["$".to_owned()],
-    })); // $...
-    sequence.push_back(Box::new(F32Entry { val: 0.0 })); // ..<fee>
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["NET".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["AMOUNT".to_owned()],
-    }));
-    sequence.push_back(Box::new(StringEntry {
-        val: String::new(),
-        patterns: vec!["$".to_owned()],
-    })); // $...
-    sequence.push_back(Box::new(F32Entry { val: 0.0 })); // ..<net amount>
-}
-
 fn yield_sold_transaction(
     transaction: &mut std::slice::Iter<'_, Box<dyn Entry>>,
     transaction_dates: &mut Vec<String>,
@@ -385,8 +502,18 @@ fn yield_sold_transaction(
     ))
 }
 
+// Marks of confirmation and tax form documents types
+const CONFIRMATIONS: [(&str, StatementType); 5] = [
+    ("RELEASE CONFIRMATION", StatementType::ReleaseConfirmation),
+    ("PURCHASE CONFIRMATION", StatementType::PurchaseConfirmation),
+    ("TRADE CONFIRMATION", StatementType::TradeConfirmation),
+    ("FORM 1042-S", StatementType::Form1042S),
+    ("FORM 1099", StatementType::Form1099),
+];
+
 /// Recognize whether PDF document is of Brokerage Statement type (old e-trade type of PDF
 /// document) or maybe Single account statment (newer e-trade/morgan stanley type of document)
+/// or RSU release/ESPP purchase/trade confirmation or IRS Form 1042-S or consolidated 1099
 fn recognize_statement(page: PageRc) -> Result<StatementType, String> {
     log::info!("Starting to recognize PDF document type");
     let contents = page
@@ -395,8 +522,23 @@ fn recognize_statement(page: PageRc) -> Result<StatementType, String> {
         .ok_or("Unable to get content of first PDF page")?;
 
     let mut statement_type = StatementType::UnknownDocument;
+    let mut confirmation_type: Option<StatementType> = None;
     contents.operations.iter().try_for_each(|op| {
         log::trace!("Detected PDF command: {}",op.operator);
+        // Statements may mention confirmations, so they are recognized only when no marks
+        // of statement are found
+        if let Some(Ok(text)) = text_of_operation(op).map(|t| t.into_string()) {
+            let text = text.trim().to_uppercase();
+            if confirmation_type.is_none() {
+                confirmation_type = CONFIRMATIONS
+                    .iter()
+                    .find(|(mark, _)| text.contains(mark))
+                    .map(|(mark, confirmation)| {
+                        log::info!("PDF parser found mark of {confirmation:?} document: \"{mark}\"");
+                        confirmation.clone()
+                    });
+            }
+        }
         match op.operator.as_ref() {
             "TJ" => {
                 // Text show
@@ -457,20 +599,22 @@ fn recognize_statement(page: PageRc) -> Result<StatementType, String> {
         Ok::<(),String>(())
     })?;
 
-    Ok(statement_type)
+    match (statement_type, confirmation_type) {
+        (StatementType::UnknownDocument, Some(confirmation)) => {
+            log::info!("PDF parser recognized {confirmation:?} document");
+            Ok(confirmation)
+        }
+        (statement_type, _) => Ok(statement_type),
+    }
 }
 
-fn process_transaction(
-    interests_transactions: &mut Vec<(String, f32, f32)>,
-    div_transactions: &mut Vec<(String, f32, f32, Option<String>)>,
-    sold_transactions: &mut Vec<(String, String, f32, f32, f32, Option<String>)>,
+// Parse token with next expected entry of sequence. Returns false if there was no
+// entry expected
+fn parse_sequence_entry(
     actual_string: &pdf::primitive::PdfString,
-    transaction_dates: &mut Vec<String>,
     processed_sequence: &mut Vec<Box<dyn Entry>>,
     sequence: &mut std::collections::VecDeque<Box<dyn Entry>>,
-    transaction_type: TransactionType,
-) -> Result<ParserState, String> {
-    let state;
+) -> bool {
     let possible_obj = sequence.pop_front();
     match possible_obj {
         // Move executed parser objects into Vector
@@ -481,15 +625,10 @@ fn process_transaction(
             // attach to sequence the same string parser if pattern is not met
             match obj.getstring() {
                 Some(token) => {
-                    let support_companies = vec![
-                        "TREASURY LIQUIDITY FUND".to_owned(),
-                        "INTEL CORP".to_owned(),
-                        "ADVANCED MICRO DEVICES".to_owned(),
-                        "DYNATRACE INC".to_owned(),
-                        "INTEREST ADJUSTMENT".to_owned(),
-                    ];
+                    // Only descriptions of securities and comments are kept. Other strings
+                    // are just expected parts of transaction
                     if obj.is_pattern() == true {
-                        if support_companies.contains(&token) == true {
+                        if obj.is_security() || obj.is_comment() {
                             processed_sequence.push(obj);
                         }
                     } else {
@@ -501,7 +640,23 @@ fn process_transaction(
 
                 None => processed_sequence.push(obj),
             }
+            true
+        }
+        None => false,
+    }
+}
 
+fn process_transaction(
+    transactions: &mut StatementTransactions,
+    actual_string: &pdf::primitive::PdfString,
+    transaction_dates: &mut Vec<String>,
+    processed_sequence: &mut Vec<Box<dyn Entry>>,
+    sequence: &mut std::collections::VecDeque<Box<dyn Entry>>,
+    transaction_type: TransactionType,
+) -> Result<ParserState, String> {
+    let state;
+    match parse_sequence_entry(actual_string, processed_sequence, sequence) {
+        true => {
             // If sequence of expected entries is
             // empty then extract data from
             // processeed elements
@@ -531,12 +686,14 @@ fn process_transaction(
                             &mut f32,
                             &mut f32,
                             Option<String>,
-                        )> = interests_transactions
+                        )> = transactions
+                            .interests
                             .iter_mut()
                             .map(|x| (&mut x.0, &mut x.1, &mut x.2, None))
                             .collect();
                         let mut div_as_ref: Vec<(&mut String, &mut f32, &mut f32, Option<String>)> =
-                            div_transactions
+                            transactions
+                                .dividends
                                 .iter_mut()
                                 .map(|x| (&mut x.0, &mut x.1, &mut x.2, x.3.clone()))
                                 .collect();
@@ -551,23 +708,24 @@ fn process_transaction(
                         log::info!("Completed parsing Tax transaction");
                     }
                     TransactionType::Interests => {
-                        let _symbol = transaction
-                            .next()
-                            .unwrap()
-                            .getstring()
-                            .expect_and_log("Processing of Interests transaction went wrong");
+                        // Description of fund (if any) is not needed
                         let gross_us = transaction
-                            .next()
-                            .unwrap()
-                            .getf32()
+                            .find_map(|entry| entry.getf32())
                             .ok_or("Processing of Interests transaction went wrong")?;
+                        let prior_year = processed_sequence.iter().any(|entry| {
+                            entry.getstring().as_deref() == Some(PRIOR_YEAR_REPORTABLE)
+                        });
+                        if prior_year {
+                            log::info!("Interests transaction is reportable for the prior year");
+                        }
 
-                        interests_transactions.push((
+                        transactions.interests.push((
                             transaction_dates
                                 .pop()
                                 .ok_or("Error: missing transaction dates when parsing")?,
                             gross_us,
                             0.0, // No tax info yet. It may be added later in Tax section
+                            prior_year,
                         ));
                         log::info!("Completed parsing Interests transaction");
                     }
@@ -583,7 +741,7 @@ fn process_transaction(
                             .getf32()
                             .ok_or("Processing of Dividend transaction went wrong")?;
 
-                        div_transactions.push((
+                        transactions.dividends.push((
                             transaction_dates
                                 .pop()
                                 .ok_or("Error: missing transaction dates when parsing")?,
@@ -593,17 +751,39 @@ fn process_transaction(
                         ));
                         log::info!("Completed parsing Dividend transaction");
                     }
+                    TransactionType::TaxAdjustment => {
+                        let company =
+                            transaction.next().unwrap().getstring().expect_and_log(
+                                "Processing of Tax adjustment transaction went wrong",
+                            );
+                        let comment = transaction.next().unwrap().getstring();
+                        let amount = transaction
+                            .next()
+                            .unwrap()
+                            .getf32()
+                            .ok_or("Processing of Tax adjustment transaction went wrong")?;
+                        let date = transaction_dates
+                            .pop()
+                            .ok_or("Error: missing transaction dates when parsing")?;
+                        let pay_date = comment.and_then(|c| yield_pay_date(&c, &date));
+                        transactions
+                            .tax_adjustments
+                            .push(crate::TaxWithholdingAdjustment {
+                                date,
+                                company: Some(company),
+                                pay_date,
+                                amount,
+                            });
+                        log::info!("Completed parsing Tax adjustment transaction");
+                    }
                     TransactionType::Sold => {
                         if let Some(trans_details) =
                             yield_sold_transaction(&mut transaction, transaction_dates)
                         {
-                            sold_transactions.push(trans_details);
+                            transactions.sold.push(trans_details);
                         }
                         log::info!("Completed parsing Sold transaction");
                     }
-                    TransactionType::Trade => {
-                        return Err("TransactionType::Trade should not appear during account statement processing!".to_string());
-                    }
                 }
                 processed_sequence.clear();
             } else {
@@ -613,7 +793,7 @@ fn process_transaction(
 
         // In nothing more to be done then just extract
         // parsed data from paser objects
-        None => {
+        false => {
             state = ParserState::ProcessingTransaction(transaction_type);
         }
     }
@@ -655,26 +835,59 @@ fn check_if_transaction(
         log::info!("Starting to parse Tax transaction");
     } else if candidate_string == "TAX WITHHOLDING ADJ" {
         create_tax_withholding_adjusted_parsing_sequence(sequence);
-        state = ParserState::ProcessingTransaction(TransactionType::Dividends);
-        log::info!("Starting to parse Tax transaction");
+        state = ParserState::ProcessingTransaction(TransactionType::TaxAdjustment);
+        log::info!("Starting to parse Tax adjustment transaction");
     } else if candidate_string == "NET CREDITS/(DEBITS)" {
         // "NET CREDITS/(DEBITS)" is marking the end of CASH FLOW ACTIVITIES block
         state = ParserState::SearchingCashFlowBlock;
         log::info!("Finished parsing transactions");
     } else {
-        let datemonth_pattern =
-            regex::Regex::new(r"^(0?[1-9]|1[012])/(0?[1-9]|[12][0-9]|3[01])$").unwrap();
+        static DATEMONTH: OnceLock<regex::Regex> = OnceLock::new();
+        let datemonth_pattern = DATEMONTH.get_or_init(|| {
+            regex::Regex::new(r"^(0?[1-9]|1[012])/(0?[1-9]|[12][0-9]|3[01])$")
+                .expect("Unable to create regular expression to match transaction date")
+        });
         if datemonth_pattern.is_match(candidate_string) {
+            trace_parser(|| format!("\"{candidate_string}\": transaction date"));
             dates.push(candidate_string.to_owned() + "/" + actual_year.as_str());
         }
     }
     Ok(state)
 }
 
+/// Get pay date (%m/%d/%y) of dividend from comment of tax adjustment e.g. "PAY DATE 12/01/23".
+/// Pay date without year (e.g. "12/1") precedes date of adjustment
+fn yield_pay_date(comment: &str, adjustment_date: &str) -> Option<String> {
+    static PAY_DATE: OnceLock<regex::Regex> = OnceLock::new();
+    let re = PAY_DATE.get_or_init(|| {
+        regex::Regex::new(r"\b(\d{1,2})/(\d{1,2})(?:/(\d{2}|\d{4}))?\b")
+            .expect("Unable to create regular expression to capture pay date")
+    });
+    let captures = re.captures(comment)?;
+    let adjusted = chrono::NaiveDate::parse_from_str(adjustment_date, "%m/%d/%y").ok()?;
+    let (month, day) = (captures[1].parse().ok()?, captures[2].parse().ok()?);
+    let pay_date = match captures.get(3) {
+        Some(year) => parse_confirmation_date(&format!("{month}/{day}/{}", year.as_str()))?,
+        None => {
+            let this_year = chrono::NaiveDate::from_ymd_opt(adjusted.year(), month, day)?;
+            let pay_date = if this_year > adjusted {
+                chrono::NaiveDate::from_ymd_opt(adjusted.year() - 1, month, day)?
+            } else {
+                this_year
+            };
+            pay_date.format("%m/%d/%y").to_string()
+        }
+    };
+    Some(pay_date)
+}
+
 /// Get last two digits of year from pattern like:  "31, 2023)"
 fn yield_year(rust_string: &str) -> Option<String> {
-    let re = regex::Regex::new(r"\b\d{4}\b")
-        .expect("Unable to create regular expression to capture fiscal year");
+    static YEAR: OnceLock<regex::Regex> = OnceLock::new();
+    let re = YEAR.get_or_init(|| {
+        regex::Regex::new(r"\b\d{4}\b")
+            .expect("Unable to create regular expression to capture fiscal year")
+    });
     let maybe = re.find(rust_string);
     if let Some(year) = maybe {
         Some(year.as_str()[year.len() - 2..].to_string())
@@ -683,71 +896,411 @@ fn yield_year(rust_string: &str) -> Option<String> {
     }
 }
 
-/// Parse borkerage statement document type
-fn parse_account_statement<'a, I>(
-    pages_iter: I,
-) -> Result<
-    (
-        Vec<(String, f32, f32)>,
-        Vec<(String, f32, f32, Option<String>)>,
-        Vec<(String, String, f32, f32, f32, Option<String>)>,
-        Vec<(String, String, i32, f32, f32, f32, f32, f32)>,
-    ),
-    String,
->
+/// Get end of statement period (%m/%d/%y) from pattern like: "For the Period December 1- 31, 2023"
+/// or "For the Period November 1- December 31, 2023"
+fn yield_period_end(rust_string: &str) -> Option<String> {
+    static PERIOD: OnceLock<regex::Regex> = OnceLock::new();
+    let re = PERIOD.get_or_init(|| {
+        regex::Regex::new(r"(?i)([A-Z]+)\s+\d{1,2}\s*-\s*(?:([A-Z]+)\s+)?(\d{1,2}),\s*(\d{4})")
+            .expect("Unable to create regular expression to capture statement period")
+    });
+    let captures = re.captures(rust_string)?;
+    let month = captures.get(2).unwrap_or(captures.get(1)?).as_str();
+    chrono::NaiveDate::parse_from_str(
+        &format!("{month} {} {}", &captures[3], &captures[4]),
+        "%B %d %Y",
+    )
+    .ok()
+    .map(|d| d.format("%m/%d/%y").to_string())
+}
+
+/// Holdings are listed as security description e.g. "INTEL CORP (INTC)" followed by
+/// quantity, share price, total cost (unless not provided), market value and others.
+/// Market value is the first amount (after price) equal to quantity * price
+fn check_if_holding(
+    rust_string: &str,
+    entry: &mut Option<HoldingEntry>,
+    holdings: &mut Vec<crate::Holding>,
+    date: &str,
+) -> ParserState {
+    if rust_string == "CASH FLOW ACTIVITY BY DATE" {
+        if let Some((name, _, _)) = entry.take() {
+            log::info!("Unable to find market value of holding: {name}");
+        }
+        log::info!("Finished parsing holdings. Start to parse transactions");
+        return ParserState::SearchingTransactionEntry;
+    }
+    if let Some((name, symbol)) = yield_security_symbol(rust_string) {
+        *entry = Some((name, symbol, vec![]));
+        return ParserState::ProcessingHoldings;
+    }
+    let amount = rust_string.replace([',', '(', ')'], "").parse::<f64>().ok();
+    if let (Some((name, symbol, amounts)), Some(amount)) = (entry.as_mut(), amount) {
+        amounts.push(amount);
+        if let [quantity, price, .., market_value] = amounts[..] {
+            if (quantity * price - market_value).abs() < 0.01 {
+                trace_parser(|| format!("\"{rust_string}\": market value of {symbol}"));
+                let holding = crate::Holding {
+                    date: date.to_owned(),
+                    company: name.clone(),
+                    symbol: symbol.clone(),
+                    quantity: quantity as f32,
+                    price: price as f32,
+                    market_value: market_value as f32,
+                    account: None,
+                };
+                log::info!("Parsed holding: {holding:?}");
+                holdings.push(holding);
+                *entry = None;
+            } else if amounts.len() > 4 {
+                log::info!("Unable to find market value of holding: {name}");
+                *entry = None;
+            }
+        }
+    }
+    ParserState::ProcessingHoldings
+}
+
+thread_local! {
+    // Decisions of parsers recorded when document is dumped (None when it is not)
+    static PARSER_TRACE: std::cell::RefCell<Option<Vec<String>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+fn trace_parser<F: FnOnce() -> String>(decision: F) {
+    PARSER_TRACE.with(|trace| {
+        if let Some(trace) = trace.borrow_mut().as_mut() {
+            trace.push(decision());
+        }
+    });
+}
+
+// Transitions of statement parser state machine and tokens consumed by parsing sequences
+fn trace_parser_state(
+    token: &str,
+    previous: &ParserState,
+    state: &ParserState,
+    expected_entries: usize,
+) {
+    match (previous, state) {
+        (ParserState::ProcessingTransaction(_), ParserState::SearchingTransactionEntry) => {
+            trace_parser(|| {
+                format!("\"{token}\": {previous:?} -> {state:?} (transaction completed)")
+            })
+        }
+        (_, ParserState::ProcessingTransaction(_)) if previous != state => {
+            trace_parser(|| {
+                format!("\"{token}\": {previous:?} -> {state:?} (sequence of {expected_entries} entries)")
+            })
+        }
+        (_, ParserState::ProcessingTransaction(_)) => trace_parser(|| {
+            format!("\"{token}\": {state:?} (entries expected: {expected_entries})")
+        }),
+        _ if previous != state => {
+            trace_parser(|| format!("\"{token}\": {previous:?} -> {state:?}"))
+        }
+        _ => (),
+    }
+}
+
+// Text shown by PDF operation ("TJ" arrays are joined, kerning is skipped)
+fn text_of_operation(op: &pdf::content::Operation) -> Option<pdf::primitive::PdfString> {
+    match (op.operator.as_ref(), op.operands.first()) {
+        ("Tj", Some(Primitive::String(actual_string))) => Some(actual_string.clone()),
+        ("TJ", Some(Primitive::Array(c))) => {
+            let bytes: Vec<u8> = c
+                .iter()
+                .filter_map(|e| match e {
+                    Primitive::String(s) => Some(s.as_bytes().to_vec()),
+                    _ => None,
+                })
+                .flatten()
+                .collect();
+            Some(pdf::primitive::PdfString::new(bytes))
+        }
+        _ => None,
+    }
+}
+
+fn check_if_brokerage_transaction(
+    candidate_string: &str,
+    dates: &mut Vec<String>,
+    sequence: &mut std::collections::VecDeque<Box<dyn Entry>>,
+) -> ParserState {
+    if candidate_string == "Dividend" || candidate_string == "Qualified Dividend" {
+        create_dividend_parsing_sequence(sequence);
+        log::info!("Starting to parse Dividend transaction");
+        ParserState::ProcessingTransaction(TransactionType::Dividends)
+    } else if candidate_string == "Sold" {
+        create_sold_parsing_sequence(sequence);
+        log::info!("Starting to parse Sold transaction");
+        ParserState::ProcessingTransaction(TransactionType::Sold)
+    } else {
+        // Dates (trade, settlement) are preceding transactions
+        if chrono::NaiveDate::parse_from_str(candidate_string, "%m/%d/%y").is_ok() {
+            trace_parser(|| format!("\"{candidate_string}\": transaction date"));
+            dates.push(candidate_string.to_owned());
+        }
+        ParserState::SearchingTransactionEntry
+    }
+}
+
+fn process_brokerage_transaction(
+    div_transactions: &mut Vec<DividendTransaction>,
+    sold_transactions: &mut Vec<SoldTransaction>,
+    actual_string: &pdf::primitive::PdfString,
+    transaction_dates: &mut Vec<String>,
+    processed_sequence: &mut Vec<Box<dyn Entry>>,
+    sequence: &mut std::collections::VecDeque<Box<dyn Entry>>,
+    transaction_type: TransactionType,
+) -> Result<ParserState, String> {
+    if !parse_sequence_entry(actual_string, processed_sequence, sequence) || !sequence.is_empty() {
+        return Ok(ParserState::ProcessingTransaction(transaction_type));
+    }
+    let mut transaction = processed_sequence.iter();
+    match transaction_type {
+        TransactionType::Dividends => {
+            let symbol = transaction.next().and_then(|e| e.getstring());
+            let tax_us = transaction
+                .next()
+                .and_then(|e| e.getf32())
+                .ok_or("Processing of Dividend transaction went wrong")?;
+            let gross_us = transaction
+                .next()
+                .and_then(|e| e.getf32())
+                .ok_or("Processing of Dividend transaction went wrong")?;
+            div_transactions.push((
+                transaction_dates
+                    .pop()
+                    .ok_or("Error: missing transaction dates when parsing")?,
+                gross_us,
+                tax_us,
+                symbol,
+            ));
+            log::info!("Completed parsing Dividend transaction");
+        }
+        TransactionType::Sold => {
+            // Sold quantity is shown as negative number
+            if let Some((trade_date, settlement_date, quantity, price, amount_sold, symbol)) =
+                yield_sold_transaction(&mut transaction, transaction_dates)
+            {
+                sold_transactions.push((
+                    trade_date,
+                    settlement_date,
+                    quantity.abs(),
+                    price,
+                    amount_sold,
+                    symbol,
+                ));
+            }
+            log::info!("Completed parsing Sold transaction");
+        }
+        _ => {
+            return Err(format!(
+                "{transaction_type:?} should not appear during brokerage statement processing!"
+            ))
+        }
+    }
+    processed_sequence.clear();
+    Ok(ParserState::SearchingTransactionEntry)
+}
+
+/// Parse (legacy e-trade) brokerage statement document type. Dividends and sold shares
+/// are reported there (with symbol of security as company)
+fn parse_brokerage_statement<I>(pages_iter: I) -> Result<ParsedStatement, String>
 where
     I: Iterator<Item = Result<PageRc, pdf::error::PdfError>>,
 {
-    let mut interests_transactions: Vec<(String, f32, f32)> = vec![];
-    let mut div_transactions: Vec<(String, f32, f32, Option<String>)> = vec![];
-    let mut sold_transactions: Vec<(String, String, f32, f32, f32, Option<String>)> = vec![];
-    let trades: Vec<(String, String, i32, f32, f32, f32, f32, f32)> = vec![];
-    let mut state = ParserState::SearchingYear;
+    let mut div_transactions: Vec<DividendTransaction> = vec![];
+    let mut sold_transactions: Vec<SoldTransaction> = vec![];
+    let mut state = ParserState::SearchingTransactionEntry;
     let mut sequence: std::collections::VecDeque<Box<dyn Entry>> =
         std::collections::VecDeque::new();
     let mut processed_sequence: Vec<Box<dyn Entry>> = vec![];
     // Queue for transaction dates. Pop last one or last two as trade and settlement dates
     let mut transaction_dates: Vec<String> = vec![];
-    let mut year: Option<String> = None;
 
     for page in pages_iter {
-        let page = page.unwrap();
-        let contents = page.contents.as_ref().unwrap();
+        let page = page.map_err(|e| format!("Unable to get page of PDF file: {e}"))?;
+        let contents = page
+            .contents
+            .as_ref()
+            .ok_or("Unable to get content of PDF page")?;
         for op in contents.operations.iter() {
-            match op.operator.as_ref() {
-                "Tj" => {
-                    // Text show
-                    if op.operands.len() > 0 {
-                        //transaction_date = op.operands[0];
-                        let a = &op.operands[0];
-                        log::trace!("Parsing account statement: Detected PDF object: {a}");
-                        match a {
-                            Primitive::String(actual_string) => {
+            let Some(actual_string) = text_of_operation(op) else {
+                continue;
+            };
+            let rust_string = match actual_string.clone().into_string() {
+                Ok(r) => r.trim().to_owned(),
+                Err(_) => continue,
+            };
+            log::trace!("Parsing brokerage statement: Detected text: {rust_string}");
+            // Ignore empty tokens
+            if rust_string.is_empty() {
+                continue;
+            }
+            let previous = state.clone();
+            state = match state {
+                ParserState::ProcessingTransaction(transaction_type) => {
+                    process_brokerage_transaction(
+                        &mut div_transactions,
+                        &mut sold_transactions,
+                        &actual_string,
+                        &mut transaction_dates,
+                        &mut processed_sequence,
+                        &mut sequence,
+                        transaction_type,
+                    )?
+                }
+                _ => check_if_brokerage_transaction(
+                    &rust_string,
+                    &mut transaction_dates,
+                    &mut sequence,
+                ),
+            };
+            trace_parser_state(&rust_string, &previous, &state, sequence.len());
+        }
+    }
+
+    Ok((vec![], div_transactions, sold_transactions, vec![], vec![]))
+}
+
+/// Get account number from text like: "ACCT: XXXX-1234" or "Account Number: 123-456789-012"
+fn yield_account_number(rust_string: &str) -> Option<String> {
+    static ACCOUNT: OnceLock<regex::Regex> = OnceLock::new();
+    let re = ACCOUNT.get_or_init(|| {
+        regex::Regex::new(
+            r"(?i)^(?:ACCT|ACCOUNT NUMBER|ACCOUNT NO\.?)\s*:?\s*([0-9X*#][0-9X*# -]*[0-9])$",
+        )
+        .expect("Unable to create regular expression to capture account number")
+    });
+    let captures = re.captures(rust_string.trim())?;
+    Some(captures[1].to_owned())
+}
+
+/// Account of statement of given broker. Account number is expected in page header
+fn yield_account(page: &PageRc, broker: &str) -> crate::Account {
+    let number = page.contents.as_ref().and_then(|contents| {
+        contents
+            .operations
+            .iter()
+            .filter_map(|op| text_of_operation(op)?.into_string().ok())
+            .find_map(|text| yield_account_number(&text))
+    });
+    if number.is_none() {
+        log::info!("Account number of {broker} statement not found");
+    }
+    let account = crate::Account::new(broker, number.as_deref());
+    log::info!("Statement of account: {account}");
+    account
+}
+
+/// Account of confirmations (or tax form) of given broker
+fn confirmations_account(number: Option<String>, broker: &str) -> crate::Account {
+    if number.is_none() {
+        log::info!("Account number of {broker} confirmation not found");
+    }
+    let account = crate::Account::new(broker, number.as_deref());
+    log::info!("Confirmation of account: {account}");
+    account
+}
+
+/// Get security name and its symbol from description like: "INTEL CORP (INTC)"
+fn yield_security_symbol(rust_string: &str) -> Option<(String, String)> {
+    static SECURITY: OnceLock<regex::Regex> = OnceLock::new();
+    let re = SECURITY.get_or_init(|| {
+        regex::Regex::new(r"^([A-Z0-9][A-Z0-9 .,&'/-]*?)\s+\(([A-Z]{1,5}(\.[A-Z])?)\)$")
+            .expect("Unable to create regular expression to capture security symbol")
+    });
+    let captures = re.captures(rust_string)?;
+    Some((captures[1].to_owned(), captures[2].to_owned()))
+}
+
+// Statement may refer to security with its symbol (e.g. INTC) or name (e.g. INTEL CORP).
+// Symbols found in statement are replaced with names, so that company is named uniformly
+fn apply_security_names(
+    symbols: &std::collections::HashMap<String, String>,
+    companies: Vec<&mut Option<String>>,
+) {
+    companies.into_iter().for_each(|company| {
+        if let Some(name) = company.as_ref().and_then(|c| symbols.get(c)) {
+            log::info!("Security symbol: {:?} recognized as: {name}", company);
+            *company = Some(name.clone());
+        }
+    });
+}
+
+/// Parse borkerage statement document type
+fn parse_account_statement<I>(pages_iter: I) -> Result<ParsedStatement, String>
+where
+    I: Iterator<Item = Result<PageRc, pdf::error::PdfError>>,
+{
+    // Pattern to match "For the Period"
+    static FOR_THE_PERIOD: OnceLock<regex::Regex> = OnceLock::new();
+    let date_pattern = FOR_THE_PERIOD.get_or_init(|| {
+        regex::Regex::new(r"(?i)For the Period")
+            .expect("Unable to create regular expression to capture fiscal year")
+    });
+    let mut transactions = StatementTransactions::default();
+    let mut state = ParserState::SearchingYear;
+    let mut sequence: std::collections::VecDeque<Box<dyn Entry>> =
+        std::collections::VecDeque::new();
+    let mut processed_sequence: Vec<Box<dyn Entry>> = vec![];
+    // Queue for transaction dates. Pop last one or last two as trade and settlement dates
+    let mut transaction_dates: Vec<String> = vec![];
+    let mut year: Option<String> = None;
+    // Text of statement period e.g. "For the Period December 1- 31, 2023" and its end date
+    let mut period = String::new();
+    let mut period_end: Option<String> = None;
+    let mut holdings: Vec<crate::Holding> = vec![];
+    let mut holding_entry: Option<HoldingEntry> = None;
+    // Securities listed in statement. Key: symbol, value: name
+    let mut symbols: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+
+    for page in pages_iter {
+        let page = page.unwrap();
+        let contents = page.contents.as_ref().unwrap();
+        for op in contents.operations.iter() {
+            match op.operator.as_ref() {
+                "Tj" => {
+                    // Text show
+                    if op.operands.len() > 0 {
+                        //transaction_date = op.operands[0];
+                        let a = &op.operands[0];
+                        log::trace!("Parsing account statement: Detected PDF object: {a}");
+                        match a {
+                            Primitive::String(actual_string) => {
                                 let raw_string = actual_string.clone().into_string();
                                 let rust_string = if let Ok(r) = raw_string {
                                     r.trim().to_uppercase().replace("$", "")
                                 } else {
                                     "".to_owned()
                                 };
+                                if let Some((name, symbol)) = yield_security_symbol(&rust_string) {
+                                    log::info!("Found security: {name} ({symbol})");
+                                    symbols.insert(symbol, name);
+                                }
                                 // Ignore empty tokens
                                 if rust_string != "" {
+                                    let previous = state.clone();
                                     match state {
                                         ParserState::SearchingYear => {
-                                            // Pattern to match "For the Period"
-                                            let date_pattern = regex::Regex::new(r"(?i)For the Period").map_err(|_| "Unable to create regular expression to capture fiscal year")?;
-
                                             if date_pattern.find(rust_string.as_str()).is_some()
                                                 && year.is_none()
                                             {
                                                 log::info!("Found pattern: \"For the Period\". Starting to parsing year");
+                                                period = rust_string.clone();
                                                 state = ParserState::ProcessingYear;
                                             }
                                         }
                                         ParserState::ProcessingYear => {
                                             log::trace!("Parsing year. Token: {rust_string}");
+                                            period = format!("{period} {rust_string}");
                                             year = yield_year(&rust_string);
                                             if year.is_some() {
                                                 log::info!("Parsed year: {year:?}");
+                                                period_end = yield_period_end(&period);
+                                                log::info!("Parsed end of statement period: {period_end:?}");
                                                 state = ParserState::SearchingCashFlowBlock;
                                             }
                                         }
@@ -758,8 +1311,24 @@ where
                                             if rust_string == "CASH FLOW ACTIVITY BY DATE" {
                                                 state = ParserState::SearchingTransactionEntry;
                                                 log::info!("Parsing account statement: \"CASH FLOW ACTIVITY BY DATE\" detected. Start to parse transactions");
+                                            } else if rust_string == "HOLDINGS" {
+                                                // Holdings are dated with end of statement period
+                                                if period_end.is_some() {
+                                                    state = ParserState::ProcessingHoldings;
+                                                    log::info!("Parsing account statement: \"HOLDINGS\" detected. Start to parse holdings");
+                                                } else {
+                                                    log::info!("Unable to determine end of statement period. Holdings are skipped");
+                                                }
                                             }
                                         }
+                                        ParserState::ProcessingHoldings => {
+                                            state = check_if_holding(
+                                                &rust_string,
+                                                &mut holding_entry,
+                                                &mut holdings,
+                                                period_end.as_deref().unwrap_or_default(),
+                                            );
+                                        }
                                         ParserState::SearchingTransactionEntry => {
                                             state = check_if_transaction(
                                                 &rust_string,
@@ -770,9 +1339,7 @@ where
                                         }
                                         ParserState::ProcessingTransaction(transaction_type) => {
                                             state = process_transaction(
-                                                &mut interests_transactions,
-                                                &mut div_transactions,
-                                                &mut sold_transactions,
+                                                &mut transactions,
                                                 &actual_string,
                                                 &mut transaction_dates,
                                                 &mut processed_sequence,
@@ -781,6 +1348,12 @@ where
                                             )?
                                         }
                                     }
+                                    trace_parser_state(
+                                        &rust_string,
+                                        &previous,
+                                        &state,
+                                        sequence.len(),
+                                    );
                                 }
                             }
                             _ => (),
@@ -792,34 +1365,500 @@ where
         }
     }
 
+    apply_security_names(
+        &symbols,
+        transactions
+            .dividends
+            .iter_mut()
+            .map(|t| &mut t.3)
+            .chain(transactions.sold.iter_mut().map(|t| &mut t.5))
+            .chain(
+                transactions
+                    .tax_adjustments
+                    .iter_mut()
+                    .map(|t| &mut t.company),
+            )
+            .collect(),
+    );
+
     Ok((
-        interests_transactions,
-        div_transactions,
-        sold_transactions,
-        trades,
+        transactions.interests,
+        transactions.dividends,
+        transactions.sold,
+        holdings,
+        transactions.tax_adjustments,
     ))
 }
+// Fields of confirmations (RSU release, ESPP purchase, trade) and tax forms. Value of field
+// is text following its label
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum ConfirmationField {
+    Company,
+    AwardNumber,
+    ReleaseDate,
+    SharesReleased,
+    MarketValuePerShare,
+    SharesWithheld,
+    OfferingDate,
+    PurchaseDate,
+    OfferingMarketValue,
+    PurchaseMarketValue,
+    PurchasePrice,
+    SharesPurchased,
+    TradeDate,
+    SettlementDate,
+    Symbol,
+    Action,
+    Quantity,
+    Price,
+    Principal,
+    Commission,
+    Fee,
+    SupplementalFee,
+    NetAmount,
+    IncomeCode,
+    GrossIncome,
+    FederalTaxWithheld,
+    OrdinaryDividends,
+    QualifiedDividends,
+    ForeignTaxPaid,
+}
+
+type ConfirmationFields = std::collections::HashMap<ConfirmationField, String>;
+
+const RELEASE_FIELDS: [(&str, ConfirmationField); 8] = [
+    ("COMPANY NAME (SYMBOL)", ConfirmationField::Company),
+    ("AWARD NUMBER", ConfirmationField::AwardNumber),
+    ("RELEASE DATE", ConfirmationField::ReleaseDate),
+    ("VEST DATE", ConfirmationField::ReleaseDate),
+    ("SHARES RELEASED", ConfirmationField::SharesReleased),
+    (
+        "MARKET VALUE PER SHARE",
+        ConfirmationField::MarketValuePerShare,
+    ),
+    ("SHARES WITHHELD", ConfirmationField::SharesWithheld),
+    ("SHARES TRADED", ConfirmationField::SharesWithheld),
+];
+
+const PURCHASE_FIELDS: [(&str, ConfirmationField); 10] = [
+    ("COMPANY NAME (SYMBOL)", ConfirmationField::Company),
+    ("GRANT DATE", ConfirmationField::OfferingDate),
+    ("OFFERING DATE", ConfirmationField::OfferingDate),
+    ("PURCHASE DATE", ConfirmationField::PurchaseDate),
+    (
+        "GRANT DATE MARKET VALUE",
+        ConfirmationField::OfferingMarketValue,
+    ),
+    (
+        "OFFERING DATE FAIR MARKET VALUE",
+        ConfirmationField::OfferingMarketValue,
+    ),
+    (
+        "PURCHASE DATE FAIR MARKET VALUE",
+        ConfirmationField::PurchaseMarketValue,
+    ),
+    (
+        "PURCHASE VALUE PER SHARE",
+        ConfirmationField::PurchaseMarketValue,
+    ),
+    ("PURCHASE PRICE PER SHARE", ConfirmationField::PurchasePrice),
+    ("SHARES PURCHASED", ConfirmationField::SharesPurchased),
+];
+
+const TRADE_FIELDS: [(&str, ConfirmationField); 15] = [
+    ("TRADE DATE", ConfirmationField::TradeDate),
+    ("SETTLEMENT DATE", ConfirmationField::SettlementDate),
+    ("SECURITY DESCRIPTION", ConfirmationField::Company),
+    ("COMPANY NAME (SYMBOL)", ConfirmationField::Company),
+    ("SYMBOL", ConfirmationField::Symbol),
+    ("ACTION", ConfirmationField::Action),
+    ("TRANSACTION TYPE", ConfirmationField::Action),
+    ("QUANTITY", ConfirmationField::Quantity),
+    ("PRICE", ConfirmationField::Price),
+    ("PRINCIPAL", ConfirmationField::Principal),
+    ("COMMISSION", ConfirmationField::Commission),
+    ("FEE", ConfirmationField::Fee),
+    ("TRANSACTION FEE", ConfirmationField::Fee),
+    (
+        "SUPPLEMENTAL TRANSACTION FEE",
+        ConfirmationField::SupplementalFee,
+    ),
+    ("NET AMOUNT", ConfirmationField::NetAmount),
+];
+
+const FORM_1042S_FIELDS: [(&str, ConfirmationField); 6] = [
+    ("1 INCOME CODE", ConfirmationField::IncomeCode),
+    ("INCOME CODE", ConfirmationField::IncomeCode),
+    ("2 GROSS INCOME", ConfirmationField::GrossIncome),
+    ("GROSS INCOME", ConfirmationField::GrossIncome),
+    (
+        "7A FEDERAL TAX WITHHELD",
+        ConfirmationField::FederalTaxWithheld,
+    ),
+    (
+        "FEDERAL TAX WITHHELD",
+        ConfirmationField::FederalTaxWithheld,
+    ),
+];
+
+// Labels of account number (value follows label) in confirmations and tax forms
+const ACCOUNT_NUMBER_LABELS: [&str; 3] = [
+    "ACCOUNT NUMBER",
+    "RECIPIENT'S ACCOUNT NUMBER",
+    "13K RECIPIENT'S ACCOUNT NUMBER",
+];
+
+const FORM_1099_DIV_FIELDS: [(&str, ConfirmationField); 4] = [
+    (
+        "1A TOTAL ORDINARY DIVIDENDS",
+        ConfirmationField::OrdinaryDividends,
+    ),
+    (
+        "1B QUALIFIED DIVIDENDS",
+        ConfirmationField::QualifiedDividends,
+    ),
+    (
+        "4 FEDERAL INCOME TAX WITHHELD",
+        ConfirmationField::FederalTaxWithheld,
+    ),
+    ("7 FOREIGN TAX PAID", ConfirmationField::ForeignTaxPaid),
+];
+
+fn confirmation_field(
+    labels: &[(&str, ConfirmationField)],
+    rust_string: &str,
+) -> Option<ConfirmationField> {
+    let label = rust_string.trim_end_matches(':').trim().to_uppercase();
+    labels
+        .iter()
+        .find(|(name, _)| *name == label)
+        .map(|(_, field)| *field)
+}
+
+// Amounts of confirmations are like: $35.1200 or 1,000.0000
+fn parse_confirmation_amount(value: &str) -> Option<f32> {
+    value.replace(['$', ','], "").trim().parse::<f32>().ok()
+}
+
+fn confirmation_value(
+    fields: &ConfirmationFields,
+    field: ConfirmationField,
+) -> Result<&String, String> {
+    fields
+        .get(&field)
+        .ok_or(format!("Error: {field:?} is missing in confirmation"))
+}
+
+fn confirmation_amount(
+    fields: &ConfirmationFields,
+    field: ConfirmationField,
+) -> Result<f32, String> {
+    let value = confirmation_value(fields, field)?;
+    parse_confirmation_amount(value).ok_or(format!("Error: unable to parse {field:?}: {value}"))
+}
+
+// Dates are converted to format of account statements: %m/%d/%y
+fn parse_confirmation_date(value: &str) -> Option<String> {
+    ["%m/%d/%Y", "%m-%d-%Y", "%m/%d/%y"]
+        .iter()
+        .find_map(|fmt| chrono::NaiveDate::parse_from_str(value, fmt).ok())
+        .map(|d| d.format("%m/%d/%y").to_string())
+}
+
+fn confirmation_date(
+    fields: &ConfirmationFields,
+    field: ConfirmationField,
+) -> Result<String, String> {
+    let value = confirmation_value(fields, field)?;
+    parse_confirmation_date(value).ok_or(format!("Error: unable to parse {field:?}: {value}"))
+}
+
+// Company name and symbol of e.g. "INTEL CORP (INTC)"
+fn confirmation_company(fields: &ConfirmationFields) -> (Option<String>, Option<String>) {
+    match fields.get(&ConfirmationField::Company) {
+        Some(c) => match yield_security_symbol(&c.to_uppercase()) {
+            Some((name, symbol)) => (Some(name), Some(symbol)),
+            None => (Some(c.clone()), None),
+        },
+        None => (None, None),
+    }
+}
+
+fn yield_vest_event(fields: &ConfirmationFields) -> Result<crate::VestEvent, String> {
+    let (company, symbol) = confirmation_company(fields);
+    Ok(crate::VestEvent {
+        vest_date: confirmation_date(fields, ConfirmationField::ReleaseDate)?,
+        company,
+        symbol,
+        award_number: fields.get(&ConfirmationField::AwardNumber).cloned(),
+        shares_released: confirmation_amount(fields, ConfirmationField::SharesReleased)?,
+        shares_withheld: match fields.contains_key(&ConfirmationField::SharesWithheld) {
+            true => confirmation_amount(fields, ConfirmationField::SharesWithheld)?,
+            false => 0.0,
+        },
+        fmv: confirmation_amount(fields, ConfirmationField::MarketValuePerShare)?,
+        account: None,
+    })
+}
+
+fn yield_espp_purchase(fields: &ConfirmationFields) -> Result<crate::EsppPurchase, String> {
+    let (company, symbol) = confirmation_company(fields);
+    Ok(crate::EsppPurchase {
+        offering_date: confirmation_date(fields, ConfirmationField::OfferingDate).ok(),
+        purchase_date: confirmation_date(fields, ConfirmationField::PurchaseDate)?,
+        company,
+        symbol,
+        shares_purchased: confirmation_amount(fields, ConfirmationField::SharesPurchased)?,
+        offering_fmv: confirmation_amount(fields, ConfirmationField::OfferingMarketValue).ok(),
+        purchase_fmv: confirmation_amount(fields, ConfirmationField::PurchaseMarketValue)?,
+        purchase_price: confirmation_amount(fields, ConfirmationField::PurchasePrice)?,
+        account: None,
+    })
+}
+
+fn yield_trade_confirmation(
+    fields: &ConfirmationFields,
+) -> Result<crate::TradeConfirmation, String> {
+    let (company, symbol) = confirmation_company(fields);
+    let action = confirmation_value(fields, ConfirmationField::Action)?;
+    // Fees not charged may be missing in confirmation
+    let optional_amount = |field: ConfirmationField| match fields.contains_key(&field) {
+        true => confirmation_amount(fields, field),
+        false => Ok(0.0),
+    };
+    Ok(crate::TradeConfirmation {
+        trade_date: confirmation_date(fields, ConfirmationField::TradeDate)?,
+        settlement_date: confirmation_date(fields, ConfirmationField::SettlementDate)?,
+        company,
+        symbol: fields.get(&ConfirmationField::Symbol).cloned().or(symbol),
+        action: match action.to_uppercase().as_str() {
+            "SELL" | "SOLD" => crate::TradeAction::Sell,
+            "BUY" | "BOUGHT" => crate::TradeAction::Buy,
+            _ => return Err(format!("Error: unsupported trade action: {action}")),
+        },
+        // Sold quantity may be shown as negative number
+        quantity: confirmation_amount(fields, ConfirmationField::Quantity)?.abs(),
+        price: confirmation_amount(fields, ConfirmationField::Price)?,
+        principal: confirmation_amount(fields, ConfirmationField::Principal)?,
+        commission: optional_amount(ConfirmationField::Commission)?,
+        fees: optional_amount(ConfirmationField::Fee)?
+            + optional_amount(ConfirmationField::SupplementalFee)?,
+        net_amount: confirmation_amount(fields, ConfirmationField::NetAmount)?,
+    })
+}
+
+fn yield_form_1042s(fields: &ConfirmationFields) -> Result<crate::Form1042S, String> {
+    Ok(crate::Form1042S {
+        income_code: confirmation_value(fields, ConfirmationField::IncomeCode)?.clone(),
+        gross_income: confirmation_amount(fields, ConfirmationField::GrossIncome)?,
+        tax_withheld: match fields.contains_key(&ConfirmationField::FederalTaxWithheld) {
+            true => confirmation_amount(fields, ConfirmationField::FederalTaxWithheld)?,
+            false => 0.0,
+        },
+    })
+}
+
+/// Parse confirmation document type (labels followed by values). Document may consist of
+/// several confirmations, each of them is yielded as a separate record. Returned is account
+/// number (if found) and records
+fn parse_confirmations<I, T>(
+    pages_iter: I,
+    labels: &[(&str, ConfirmationField)],
+    yield_record: fn(&ConfirmationFields) -> Result<T, String>,
+) -> Result<(Option<String>, Vec<T>), String>
+where
+    I: Iterator<Item = Result<PageRc, pdf::error::PdfError>>,
+{
+    let mut records: Vec<T> = vec![];
+    let mut fields = ConfirmationFields::new();
+    let mut expected_value: Option<ConfirmationField> = None;
+    let mut account_number: Option<String> = None;
+    let mut expected_account_number = false;
+
+    for page in pages_iter {
+        let page = page.map_err(|e| format!("Unable to get page of PDF file: {e}"))?;
+        let contents = page
+            .contents
+            .as_ref()
+            .ok_or("Unable to get content of PDF page")?;
+        for op in contents.operations.iter() {
+            let Some(Ok(text)) = text_of_operation(op).map(|t| t.into_string()) else {
+                continue;
+            };
+            let rust_string = text.trim();
+            if rust_string.is_empty() {
+                continue;
+            }
+            // Account number is given once per confirmation (or copy of form), so it is
+            // not a field of record
+            let label = rust_string.trim_end_matches(':').trim().to_uppercase();
+            if ACCOUNT_NUMBER_LABELS.contains(&label.as_str()) {
+                expected_account_number = true;
+                expected_value = None;
+                continue;
+            }
+            let number = match expected_account_number {
+                true => Some(rust_string.to_owned()),
+                false => yield_account_number(rust_string),
+            };
+            expected_account_number = false;
+            if let Some(number) = number {
+                log::info!("Confirmation: account number: {number}");
+                account_number.get_or_insert(number);
+                continue;
+            }
+            if let Some(field) = confirmation_field(labels, rust_string) {
+                // Field seen again is the beginning of next confirmation
+                if fields.contains_key(&field) {
+                    trace_parser(|| format!("\"{rust_string}\": {field:?} repeated, next record"));
+                    records.push(yield_record(&fields)?);
+                    fields.clear();
+                }
+                expected_value = Some(field);
+            } else if let Some(field) = expected_value.take() {
+                log::info!("Confirmation: {field:?}: {rust_string}");
+                trace_parser(|| format!("\"{rust_string}\": value of {field:?}"));
+                fields.insert(field, rust_string.to_owned());
+            }
+        }
+    }
+    if !fields.is_empty() {
+        records.push(yield_record(&fields)?);
+    }
+    Ok((account_number, records))
+}
+
+fn yield_form_1099_div(
+    tax_year: &Option<String>,
+    fields: &ConfirmationFields,
+) -> Result<crate::Form1099Div, String> {
+    // Boxes with no amount may be missing in form
+    let optional_amount = |field: ConfirmationField| match fields.contains_key(&field) {
+        true => confirmation_amount(fields, field),
+        false => Ok(0.0),
+    };
+    Ok(crate::Form1099Div {
+        tax_year: tax_year
+            .clone()
+            .ok_or("Error: tax year is missing in Form 1099")?,
+        ordinary_dividends: confirmation_amount(fields, ConfirmationField::OrdinaryDividends)?,
+        qualified_dividends: optional_amount(ConfirmationField::QualifiedDividends)?,
+        federal_tax_withheld: optional_amount(ConfirmationField::FederalTaxWithheld)?,
+        foreign_tax_paid: optional_amount(ConfirmationField::ForeignTaxPaid)?,
+    })
+}
+
+// Columns of 1099-B lot: date sold, quantity, proceeds, date acquired, cost basis
+fn yield_form_1099b_lot(
+    columns: &[String],
+    (company, symbol): &(Option<String>, Option<String>),
+) -> Result<crate::Form1099BLot, String> {
+    let amount = |value: &String| {
+        parse_confirmation_amount(value)
+            .ok_or(format!("Error: unable to parse 1099-B amount: {value}"))
+    };
+    Ok(crate::Form1099BLot {
+        date_sold: parse_confirmation_date(&columns[0]).ok_or(format!(
+            "Error: unable to parse 1099-B date: {}",
+            columns[0]
+        ))?,
+        date_acquired: match columns[3].to_uppercase().as_str() {
+            "VARIOUS" => None,
+            date => Some(
+                parse_confirmation_date(date)
+                    .ok_or(format!("Error: unable to parse 1099-B date: {date}"))?,
+            ),
+        },
+        company: company.clone(),
+        symbol: symbol.clone(),
+        quantity: amount(&columns[1])?,
+        proceeds: amount(&columns[2])?,
+        cost_basis: amount(&columns[4])?,
+    })
+}
+
+/// Parse consolidated Form 1099: boxes of 1099-DIV (labels followed by values) and rows of
+/// 1099-B sold lots grouped under security description e.g. "INTEL CORP (INTC)"
+fn parse_form_1099<I>(
+    pages_iter: I,
+) -> Result<(Option<crate::Form1099Div>, Vec<crate::Form1099BLot>), String>
+where
+    I: Iterator<Item = Result<PageRc, pdf::error::PdfError>>,
+{
+    let mut tax_year: Option<String> = None;
+    let mut fields = ConfirmationFields::new();
+    let mut expected_value: Option<ConfirmationField> = None;
+    let mut in_form_1099b = false;
+    let mut security: (Option<String>, Option<String>) = (None, None);
+    let mut columns: Vec<String> = vec![];
+    let mut lots: Vec<crate::Form1099BLot> = vec![];
+
+    for page in pages_iter {
+        let page = page.map_err(|e| format!("Unable to get page of PDF file: {e}"))?;
+        let contents = page
+            .contents
+            .as_ref()
+            .ok_or("Unable to get content of PDF page")?;
+        for op in contents.operations.iter() {
+            let Some(Ok(text)) = text_of_operation(op).map(|t| t.into_string()) else {
+                continue;
+            };
+            let rust_string = text.trim();
+            if rust_string.is_empty() {
+                continue;
+            }
+            let upper = rust_string.to_uppercase();
+            if tax_year.is_none() && upper.contains("TAX REPORTING STATEMENT") {
+                tax_year = yield_year(&upper);
+                log::info!("Form 1099 tax year: {tax_year:?}");
+            }
+            if upper.contains("1099-B") {
+                in_form_1099b = true;
+            } else if let Some(field) = confirmation_field(&FORM_1099_DIV_FIELDS, rust_string) {
+                expected_value = Some(field);
+            } else if let Some(field) = expected_value.take() {
+                log::info!("Form 1099-DIV: {field:?}: {rust_string}");
+                trace_parser(|| format!("\"{rust_string}\": value of {field:?}"));
+                fields.insert(field, rust_string.to_owned());
+            } else if in_form_1099b {
+                if let Some((name, symbol)) = yield_security_symbol(&upper) {
+                    security = (Some(name), Some(symbol));
+                } else if !columns.is_empty() || parse_confirmation_date(rust_string).is_some() {
+                    // Row of lot starts with date sold, columns following cost basis
+                    // (wash sale, gain or loss...) are skipped
+                    columns.push(rust_string.to_owned());
+                    if columns.len() == 5 {
+                        let lot = yield_form_1099b_lot(&columns, &security)?;
+                        log::info!("Form 1099-B lot: {lot:?}");
+                        trace_parser(|| format!("Form 1099-B lot: {columns:?}"));
+                        lots.push(lot);
+                        columns.clear();
+                    }
+                }
+            }
+        }
+    }
+    let dividends = match fields.is_empty() {
+        true => None,
+        false => Some(yield_form_1099_div(&tax_year, &fields)?),
+    };
+    Ok((dividends, lots))
+}
+
 ///  This function parses given PDF document
-///  and returns result of parsing which is a tuple of
+///  and returns result of parsing. In case of account/brokerage statement it is a tuple of
 ///  interest rate transactions
 ///  found Dividends paid transactions (div_transactions),
 ///  Sold stock transactions (sold_transactions)
-///  information on transactions in case of parsing trade document (trades)
 ///  Dividends paid transaction is:
 ///        transaction date, gross_us, tax_us, company
 ///  Sold stock transaction is :
 ///     (trade_date, settlement_date, quantity, price, amount_sold, company)
-pub fn parse_statement(
-    pdftoparse: &str,
-) -> Result<
-    (
-        Vec<(String, f32, f32)>,
-        Vec<(String, f32, f32, Option<String>)>,
-        Vec<(String, String, f32, f32, f32, Option<String>)>,
-        Vec<(String, String, i32, f32, f32, f32, f32, f32)>,
-    ),
-    String,
-> {
+///  In case of RSU release confirmation it is a list of vest events and in case of ESPP
+///  purchase confirmation it is a list of purchases. Trade confirmations are a list of trades
+///  with their commission and fees. Form 1042-S is a list of incomes (one per income code)
+///  and consolidated Form 1099 are dividends of 1099-DIV and sold lots of 1099-B
+pub fn parse_document(pdftoparse: &str) -> Result<PdfDocument, String> {
     //2. parsing each pdf
     let mypdffile = File::<Vec<u8>>::open(pdftoparse)
         .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
@@ -833,36 +1872,248 @@ pub fn parse_statement(
         .unwrap()
         .map_err(|_| "Unable to get first page of PDF file".to_string())?;
 
-    let document_type = recognize_statement(first_page)?;
+    let document_type = recognize_statement(first_page.clone())?;
 
-    let (interests_transactions, div_transactions, sold_transactions, trades) = match document_type
-    {
+    match document_type {
         StatementType::UnknownDocument => {
             log::info!("Processing unknown document PDF");
-            return Err(format!("Unsupported PDF document type: {pdftoparse}"));
+            Err(format!("Unsupported PDF document type: {pdftoparse}"))
         }
         StatementType::BrokerageStatement => {
             log::info!("Processing brokerage statement PDF");
-            return Err(format!("Processing brokerage statement PDF is unsupported: document type: {pdftoparse}.To have it supported please use release 0.7.4 "));
+            // Transactions may be already on the first page
+            Ok(PdfDocument::Statement(
+                yield_account(&first_page, BROKER_ETRADE),
+                parse_brokerage_statement(mypdffile.pages())?,
+            ))
         }
         StatementType::AccountStatement => {
             log::info!("Processing Account statement PDF");
-            parse_account_statement(pdffile_iter)?
+            Ok(PdfDocument::Statement(
+                yield_account(&first_page, BROKER_MORGAN_STANLEY),
+                parse_account_statement(pdffile_iter)?,
+            ))
+        }
+        StatementType::ReleaseConfirmation => {
+            log::info!("Processing Release Confirmation PDF");
+            let (number, vests) =
+                parse_confirmations(mypdffile.pages(), &RELEASE_FIELDS, yield_vest_event)?;
+            Ok(PdfDocument::ReleaseConfirmation(
+                confirmations_account(number, BROKER_MORGAN_STANLEY),
+                vests,
+            ))
+        }
+        StatementType::PurchaseConfirmation => {
+            log::info!("Processing ESPP Purchase Confirmation PDF");
+            let (number, purchases) =
+                parse_confirmations(mypdffile.pages(), &PURCHASE_FIELDS, yield_espp_purchase)?;
+            Ok(PdfDocument::PurchaseConfirmation(
+                confirmations_account(number, BROKER_MORGAN_STANLEY),
+                purchases,
+            ))
+        }
+        StatementType::TradeConfirmation => {
+            log::info!("Processing Trade Confirmation PDF");
+            let (number, trades) =
+                parse_confirmations(mypdffile.pages(), &TRADE_FIELDS, yield_trade_confirmation)?;
+            Ok(PdfDocument::TradeConfirmation(
+                confirmations_account(number, BROKER_ETRADE),
+                trades,
+            ))
+        }
+        StatementType::Form1042S => {
+            log::info!("Processing Form 1042-S PDF");
+            let (number, forms) =
+                parse_confirmations(mypdffile.pages(), &FORM_1042S_FIELDS, yield_form_1042s)?;
+            // Document holds several copies (B, C, D...) of the same form
+            let mut unique_forms: Vec<crate::Form1042S> = vec![];
+            forms.into_iter().for_each(|form| {
+                if !unique_forms.contains(&form) {
+                    unique_forms.push(form);
+                }
+            });
+            Ok(PdfDocument::Form1042S(
+                confirmations_account(number, BROKER_MORGAN_STANLEY),
+                unique_forms,
+            ))
         }
+        StatementType::Form1099 => {
+            log::info!("Processing Form 1099 PDF");
+            let (dividends, lots) = parse_form_1099(mypdffile.pages())?;
+            Ok(PdfDocument::Form1099(
+                yield_account(&first_page, BROKER_MORGAN_STANLEY),
+                dividends,
+                lots,
+            ))
+        }
+    }
+}
+
+// Digits of amounts and account numbers are masked. Dates, years, names of forms
+// (e.g. 1099-DIV) and identifiers (e.g. Form1042S, 1a) are kept
+fn mask_text(text: &str) -> String {
+    static KEPT: OnceLock<regex::Regex> = OnceLock::new();
+    let kept = KEPT.get_or_init(|| {
+        regex::Regex::new(
+            r"^(\d{1,2}/\d{1,2}(/\d{2}|/\d{4})?|(19|20)\d{2}|\d{4}-[A-Z]+|\d{1,2}[A-Za-z]|[A-Za-z_][A-Za-z0-9_]*)$",
+        )
+        .expect("Unable to create regular expression to capture dates")
+    });
+    text.split(' ')
+        .map(|word| {
+            let core = word.trim_matches(|c: char| !c.is_ascii_alphanumeric() && c != '/');
+            match kept.is_match(core) {
+                true => word.to_owned(),
+                false => word
+                    .chars()
+                    .map(|c| if c.is_ascii_digit() { '#' } else { c })
+                    .collect(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+// Text shown on page: operator (TJ/Tj), position (origin of text matrix) and text (None
+// when it can not be decoded)
+pub(crate) type PageText = Vec<(String, (f32, f32), Option<String>)>;
+
+fn page_text(page: &PageRc) -> Result<PageText, String> {
+    let contents = page
+        .contents
+        .as_ref()
+        .ok_or("Unable to get content of PDF page")?;
+    let mut texts = vec![];
+    // Position of text (x, y) and start of current line
+    let (mut position, mut line) = ((0.0, 0.0), (0.0, 0.0));
+    for op in contents.operations.iter() {
+        let number = |i: usize| {
+            op.operands
+                .get(i)
+                .and_then(|o| o.as_number().ok())
+                .unwrap_or(0.0)
+        };
+        match op.operator.as_ref() {
+            "BT" => line = (0.0, 0.0),
+            "Tm" => line = (number(4), number(5)),
+            "Td" | "TD" => line = (line.0 + number(0), line.1 + number(1)),
+            _ => (),
+        }
+        if ["BT", "Tm", "Td", "TD"].contains(&op.operator.as_str()) {
+            position = line;
+        }
+        if let Some(text) = text_of_operation(op) {
+            texts.push((op.operator.clone(), position, text.into_string().ok()));
+        }
+    }
+    Ok(texts)
+}
+
+/// Text of all pages of PDF document
+pub(crate) fn extract_text(pdftoparse: &str) -> Result<Vec<PageText>, String> {
+    let mypdffile = File::<Vec<u8>>::open(pdftoparse)
+        .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
+    mypdffile
+        .pages()
+        .map(|page| {
+            page.map_err(|e| format!("Unable to get page of PDF file: {e}"))
+                .and_then(|page| page_text(&page))
+        })
+        .collect()
+}
+
+/// Dump of PDF document for diagnosing parsing problems: text operators (TJ/Tj) of each
+/// page with their positions (origin of text matrix), decisions of parser and result
+/// of parsing. Amounts and account numbers can be masked so that dump can be shared
+pub fn dump_document(pdftoparse: &str, mask: bool) -> Result<Vec<String>, String> {
+    let mypdffile = File::<Vec<u8>>::open(pdftoparse)
+        .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
+    let masked = |text: &str| match mask {
+        true => mask_text(text),
+        false => text.to_owned(),
     };
+    let mut lines = vec![format!(
+        "Document: {pdftoparse}, pages: {}",
+        mypdffile.num_pages()
+    )];
+
+    for (i, page) in mypdffile.pages().enumerate() {
+        let page = page.map_err(|e| format!("Unable to get page of PDF file: {e}"))?;
+        lines.push(format!("=== Page {} ===", i + 1));
+        if i == 0 {
+            lines.push(format!(
+                "Document type: {:?}",
+                recognize_statement(page.clone())?
+            ));
+        }
+        for (operator, (x, y), text) in page_text(&page)? {
+            lines.push(format!(
+                "[{x:8.2}, {y:8.2}] {operator} \"{}\"",
+                text.map(|t| masked(&t))
+                    .unwrap_or("<unable to decode text>".to_owned())
+            ));
+        }
+    }
 
-    Ok((
-        interests_transactions,
-        div_transactions,
-        sold_transactions,
-        trades,
-    ))
+    lines.push("=== Parser decisions ===".to_owned());
+    PARSER_TRACE.with(|trace| *trace.borrow_mut() = Some(vec![]));
+    let result = parse_document(pdftoparse);
+    let decisions = PARSER_TRACE.with(|trace| trace.borrow_mut().take().unwrap_or_default());
+    // Only tokens (quoted) of decisions are masked
+    lines.extend(decisions.iter().map(|d| {
+        d.split('"')
+            .enumerate()
+            .map(|(i, part)| match i % 2 {
+                1 => masked(part),
+                _ => part.to_owned(),
+            })
+            .collect::<Vec<String>>()
+            .join("\"")
+    }));
+
+    lines.push("=== Result ===".to_owned());
+    lines.extend(format!("{result:#?}").lines().map(masked));
+    Ok(lines)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // Transactions of account/brokerage statement (reportability of interests for the prior
+    // year is checked by test_interests_reportable_for_prior_year)
+    fn parse_statement(pdftoparse: &str) -> Result<CashFlowTransactions, String> {
+        match parse_document(pdftoparse)? {
+            PdfDocument::Statement(_, (interests, dividends, sold, _, _)) => Ok((
+                interests
+                    .into_iter()
+                    .map(|(date, gross_us, tax_us, _)| (date, gross_us, tax_us))
+                    .collect(),
+                dividends,
+                sold,
+            )),
+            other => Err(format!("Not a statement: {other:?}")),
+        }
+    }
+
+    // Account of statement or Form 1099
+    fn parse_account(pdftoparse: &str) -> Result<crate::Account, String> {
+        match parse_document(pdftoparse)? {
+            PdfDocument::Statement(account, _) | PdfDocument::Form1099(account, _, _) => {
+                Ok(account)
+            }
+            other => Err(format!("No account in document: {other:?}")),
+        }
+    }
+
+    // Holdings of account statement
+    fn parse_holdings(pdftoparse: &str) -> Result<Vec<crate::Holding>, String> {
+        match parse_document(pdftoparse)? {
+            PdfDocument::Statement(_, (_, _, _, holdings, _)) => Ok(holdings),
+            other => Err(format!("Not a statement: {other:?}")),
+        }
+    }
+
     #[test]
     fn test_parser() -> Result<(), String> {
         // quantity
@@ -926,6 +2177,253 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_security_entry() {
+        let mut s = SecurityEntry {
+            val: String::new(),
+            patterns: vec![],
+        };
+        let mut check = |token: &str| {
+            s.parse(&pdf::primitive::PdfString::new(token.as_bytes().to_vec()));
+            s.is_pattern()
+        };
+        assert!(check("NVIDIA CORP"));
+        assert!(check("DOLBY LABORATORIES INC"));
+        assert!(!check("1,205.00"));
+        assert!(!check("(57.98)"));
+        assert!(!check("12/21"));
+        assert!(!check("$"));
+
+        let mut s = SecurityEntry {
+            val: String::new(),
+            patterns: vec!["TREASURY LIQUIDITY FUND".to_owned()],
+        };
+        s.parse(&pdf::primitive::PdfString::new(b"INTEL CORP".to_vec()));
+        assert!(!s.is_pattern());
+        assert!(s.is_security());
+    }
+
+    #[test]
+    fn test_yield_security_symbol() {
+        assert_eq!(
+            yield_security_symbol("INTEL CORP (INTC)"),
+            Some(("INTEL CORP".to_owned(), "INTC".to_owned()))
+        );
+        assert_eq!(
+            yield_security_symbol("BERKSHIRE HATHAWAY INC CL B (BRK.B)"),
+            Some(("BERKSHIRE HATHAWAY INC CL B".to_owned(), "BRK.B".to_owned()))
+        );
+        assert_eq!(yield_security_symbol("NET CREDITS/(DEBITS)"), None);
+        assert_eq!(yield_security_symbol("INTEL CORP"), None);
+    }
+
+    #[test]
+    fn test_apply_security_names() {
+        let symbols = std::collections::HashMap::from([(
+            "DLB".to_owned(),
+            "DOLBY LABORATORIES INC".to_owned(),
+        )]);
+        let mut companies = vec![Some("DLB".to_owned()), Some("INTEL CORP".to_owned()), None];
+        apply_security_names(&symbols, companies.iter_mut().collect());
+        assert_eq!(
+            companies,
+            vec![
+                Some("DOLBY LABORATORIES INC".to_owned()),
+                Some("INTEL CORP".to_owned()),
+                None
+            ]
+        );
+    }
+
+    // Parsed (interests, dividends, sold) transactions
+    type CashFlowTransactions = (
+        Vec<(String, f32, f32)>,
+        Vec<(String, f32, f32, Option<String>)>,
+        Vec<(String, String, f32, f32, f32, Option<String>)>,
+    );
+
+    // Feed tokens of "CASH FLOW ACTIVITY BY DATE" block to parser
+    fn parse_cash_flow_tokens(tokens: &[&str]) -> Result<ParsedStatement, String> {
+        let mut transactions = StatementTransactions::default();
+        let mut state = ParserState::SearchingTransactionEntry;
+        let mut sequence = std::collections::VecDeque::new();
+        let mut processed_sequence = vec![];
+        let mut dates = vec![];
+        for token in tokens {
+            let pdf_string = pdf::primitive::PdfString::new(token.as_bytes().to_vec());
+            state = match state {
+                ParserState::ProcessingTransaction(transaction_type) => process_transaction(
+                    &mut transactions,
+                    &pdf_string,
+                    &mut dates,
+                    &mut processed_sequence,
+                    &mut sequence,
+                    transaction_type,
+                )?,
+                _ => check_if_transaction(
+                    &token.to_uppercase(),
+                    &mut dates,
+                    &mut sequence,
+                    Some("24".to_owned()),
+                )?,
+            };
+        }
+        Ok((
+            transactions.interests,
+            transactions.dividends,
+            transactions.sold,
+            vec![],
+            transactions.tax_adjustments,
+        ))
+    }
+
+    #[test]
+    fn test_transactions_of_any_company() -> Result<(), String> {
+        let (interests, divs, sold, _, _) = parse_cash_flow_tokens(&[
+            "3/15",
+            "QUALIFIED DIVIDEND",
+            "NVIDIA CORP",
+            "12.00",
+            "3/15",
+            "TAX WITHHOLDING",
+            "NVIDIA CORP",
+            "(1.80)",
+            "6/10",
+            "6/12",
+            "SOLD",
+            "NVIDIA CORP",
+            "ACTED AS AGENT",
+            "UNSOLICITED TRADE",
+            "10.000",
+            "120.50",
+            "1,205.00",
+            "12/1",
+            "DIVIDEND",
+            "TREASURY LIQUIDITY FUND",
+            "DIV PAYMENT",
+            "1.22",
+        ])?;
+        assert_eq!(interests, vec![("12/1/24".to_owned(), 1.22, 0.0, false)]);
+        assert_eq!(
+            divs,
+            vec![(
+                "3/15/24".to_owned(),
+                12.0,
+                1.8,
+                Some("NVIDIA CORP".to_owned())
+            )]
+        );
+        assert_eq!(
+            sold,
+            vec![(
+                "6/10/24".to_owned(),
+                "6/12/24".to_owned(),
+                10.0,
+                120.5,
+                1205.0,
+                Some("NVIDIA CORP".to_owned())
+            )]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_interests_reportable_for_prior_year() -> Result<(), String> {
+        let (interests, _, _, _, _) = parse_cash_flow_tokens(&[
+            "1/2",
+            "DIVIDEND",
+            "TREASURY LIQUIDITY FUND",
+            "Transaction Reportable for the Prior Year.",
+            "0.92",
+            "1/2",
+            "TAX WITHHOLDING",
+            "TREASURY LIQUIDITY FUND",
+            "(0.22)",
+            "2/1",
+            "DIVIDEND",
+            "TREASURY LIQUIDITY FUND",
+            "DIV PAYMENT",
+            "1.05",
+        ])?;
+        assert_eq!(
+            interests,
+            vec![
+                ("1/2/24".to_owned(), 0.92, 0.22, true),
+                ("2/1/24".to_owned(), 1.05, 0.0, false)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_tax_withholding_adjustment() -> Result<(), String> {
+        // Adjustments are not dividends and they do not take tax of other transactions
+        let (_, divs, _, _, tax_adjustments) = parse_cash_flow_tokens(&[
+            "12/20",
+            "TAX WITHHOLDING ADJ",
+            "INTEL CORP",
+            "ADJ NON-RES ALIEN WH PAY DATE 12/1",
+            "5.25",
+            "12/1",
+            "QUALIFIED DIVIDEND",
+            "NVIDIA CORP",
+            "12.00",
+            "12/1",
+            "TAX WITHHOLDING",
+            "NVIDIA CORP",
+            "(1.80)",
+            "12/28",
+            "TAX WITHHOLDING ADJ",
+            "NVIDIA CORP",
+            "ADJ NON-RES ALIEN WH",
+            "(0.60)",
+        ])?;
+        assert_eq!(
+            divs,
+            vec![(
+                "12/1/24".to_owned(),
+                12.0,
+                1.8,
+                Some("NVIDIA CORP".to_owned())
+            )]
+        );
+        assert_eq!(
+            tax_adjustments,
+            vec![
+                crate::TaxWithholdingAdjustment {
+                    date: "12/20/24".to_owned(),
+                    company: Some("INTEL CORP".to_owned()),
+                    pay_date: Some("12/01/24".to_owned()),
+                    amount: 5.25,
+                },
+                crate::TaxWithholdingAdjustment {
+                    date: "12/28/24".to_owned(),
+                    company: Some("NVIDIA CORP".to_owned()),
+                    pay_date: None,
+                    amount: -0.6,
+                }
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_yield_pay_date() {
+        assert_eq!(
+            yield_pay_date("PAY DATE 12/01/2023", "01/15/24"),
+            Some("12/01/23".to_owned())
+        );
+        assert_eq!(
+            yield_pay_date("PAY DATE 12/1", "1/15/24"),
+            Some("12/01/23".to_owned())
+        );
+        assert_eq!(
+            yield_pay_date("PAY DATE 3/1", "3/20/24"),
+            Some("03/01/24".to_owned())
+        );
+        assert_eq!(yield_pay_date("ADJ NON-RES ALIEN WH", "3/20/24"), None);
+    }
+
     #[test]
     fn test_transaction_validation() -> Result<(), String> {
         let mut transaction_dates: Vec<String> =
@@ -1042,6 +2540,111 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_yield_account_number() {
+        assert_eq!(
+            yield_account_number("ACCT: XXXX-1234"),
+            Some("XXXX-1234".to_owned())
+        );
+        assert_eq!(
+            yield_account_number("Account Number: 123-456789-012"),
+            Some("123-456789-012".to_owned())
+        );
+        assert_eq!(yield_account_number("Account Detail"), None);
+        assert_eq!(yield_account_number("ACCOUNT NUMBER:"), None);
+    }
+
+    #[test]
+    fn test_statement_accounts() -> Result<(), String> {
+        assert_eq!(
+            parse_account("data/brokerage_statement_example.pdf"),
+            Ok(crate::Account::new("E*TRADE", Some("XXXX-1234")))
+        );
+        // Account number of account statement is on its first page
+        assert_eq!(
+            parse_account("data/account_statement_example.pdf")?.to_string(),
+            "Morgan Stanley XXX-XXXXX9-012"
+        );
+        assert_eq!(
+            parse_account("data/form_1099_example.pdf")?.to_string(),
+            "Morgan Stanley XXX-XXXXX1-098"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_yield_period_end() {
+        assert_eq!(
+            yield_period_end("FOR THE PERIOD DECEMBER 1- 31, 2023"),
+            Some("12/31/23".to_owned())
+        );
+        assert_eq!(
+            yield_period_end("FOR THE PERIOD NOVEMBER 1- DECEMBER 31, 2023)"),
+            Some("12/31/23".to_owned())
+        );
+        assert_eq!(
+            yield_period_end("FOR THE PERIOD SEPTEMBER 1-30, 2024"),
+            Some("09/30/24".to_owned())
+        );
+        assert_eq!(yield_period_end("FOR THE PERIOD 2023"), None);
+    }
+
+    #[test]
+    fn test_account_statement_holdings() -> Result<(), String> {
+        // Synthetic statement with holdings block followed by cash flow activity
+        let pdftoparse = "data/account_statement_example.pdf";
+        let mypdffile = File::<Vec<u8>>::open(pdftoparse)
+            .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
+        let first_page = mypdffile
+            .pages()
+            .next()
+            .unwrap()
+            .map_err(|_| "Unable to get first page of PDF file".to_string())?;
+        assert_eq!(
+            recognize_statement(first_page)?,
+            StatementType::AccountStatement
+        );
+
+        // Total cost of NVIDIA is not provided
+        assert_eq!(
+            parse_holdings(pdftoparse),
+            Ok(vec![
+                crate::Holding {
+                    date: "12/31/23".to_owned(),
+                    company: "INTEL CORP".to_owned(),
+                    symbol: "INTC".to_owned(),
+                    quantity: 100.0,
+                    price: 50.25,
+                    market_value: 5025.0,
+                    account: None,
+                },
+                crate::Holding {
+                    date: "12/31/23".to_owned(),
+                    company: "NVIDIA CORP".to_owned(),
+                    symbol: "NVDA".to_owned(),
+                    quantity: 20.0,
+                    price: 495.22,
+                    market_value: 9904.4,
+                    account: None,
+                },
+            ])
+        );
+        assert_eq!(
+            parse_statement(pdftoparse),
+            Ok((
+                vec![],
+                vec![(
+                    "12/1/23".to_owned(),
+                    12.5,
+                    1.88,
+                    Some("INTEL CORP".to_owned())
+                )],
+                vec![],
+            ))
+        );
+        Ok(())
+    }
+
     #[test]
     #[ignore]
     fn test_recognize_document_type_ms() -> Result<(), String> {
@@ -1110,6 +2713,364 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_brokerage_statement() -> Result<(), String> {
+        // Synthetic statement in a layout of legacy e-trade brokerage statements
+        let pdftoparse = "data/brokerage_statement_example.pdf";
+        let mypdffile = File::<Vec<u8>>::open(pdftoparse)
+            .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
+        let first_page = mypdffile
+            .pages()
+            .next()
+            .unwrap()
+            .map_err(|_| "Unable to get first page of PDF file".to_string())?;
+        assert_eq!(
+            recognize_statement(first_page)?,
+            StatementType::BrokerageStatement
+        );
+
+        // Unsettled sale (single date) is skipped
+        assert_eq!(
+            parse_statement(pdftoparse),
+            Ok((
+                vec![],
+                vec![
+                    (
+                        "03/01/22".to_owned(),
+                        698.25,
+                        104.74,
+                        Some("INTC".to_owned())
+                    ),
+                    ("03/15/22".to_owned(), 24.0, 3.6, Some("DLB".to_owned()))
+                ],
+                vec![(
+                    "03/02/22".to_owned(),
+                    "03/04/22".to_owned(),
+                    1.0,
+                    43.69,
+                    43.67,
+                    Some("INTC".to_owned())
+                )],
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_release_confirmation() -> Result<(), String> {
+        // Synthetic document with two RSU release confirmations
+        let pdftoparse = "data/release_confirmation_example.pdf";
+        let mypdffile = File::<Vec<u8>>::open(pdftoparse)
+            .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
+        let first_page = mypdffile
+            .pages()
+            .next()
+            .unwrap()
+            .map_err(|_| "Unable to get first page of PDF file".to_string())?;
+        assert_eq!(
+            recognize_statement(first_page)?,
+            StatementType::ReleaseConfirmation
+        );
+
+        let vest = |vest_date: &str, award: &str, released: f32, withheld: f32, fmv: f32| {
+            crate::VestEvent {
+                vest_date: vest_date.to_owned(),
+                company: Some("INTEL CORP".to_owned()),
+                symbol: Some("INTC".to_owned()),
+                award_number: Some(award.to_owned()),
+                shares_released: released,
+                shares_withheld: withheld,
+                fmv,
+                account: None,
+            }
+        };
+        assert_eq!(
+            parse_document(pdftoparse),
+            Ok(PdfDocument::ReleaseConfirmation(
+                crate::Account::new("Morgan Stanley", Some("XXXX-1234")),
+                vec![
+                    vest("02/15/23", "R123456", 40.0, 14.0, 28.5),
+                    vest("05/15/23", "R123457", 20.0, 7.0, 29.25),
+                ]
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_confirmation_field() {
+        assert_eq!(
+            confirmation_field(&RELEASE_FIELDS, "Release Date"),
+            Some(ConfirmationField::ReleaseDate)
+        );
+        assert_eq!(
+            confirmation_field(&RELEASE_FIELDS, "SHARES TRADED:"),
+            Some(ConfirmationField::SharesWithheld)
+        );
+        assert_eq!(confirmation_field(&RELEASE_FIELDS, "Award Date"), None);
+        assert_eq!(confirmation_field(&RELEASE_FIELDS, "Purchase Date"), None);
+        assert_eq!(
+            confirmation_field(&PURCHASE_FIELDS, "Purchase Date"),
+            Some(ConfirmationField::PurchaseDate)
+        );
+        assert_eq!(parse_confirmation_amount("$1,140.5000"), Some(1140.5));
+        assert_eq!(parse_confirmation_amount("N/A"), None);
+    }
+
+    #[test]
+    fn test_purchase_confirmation() -> Result<(), String> {
+        // Synthetic ESPP purchase confirmation
+        let pdftoparse = "data/espp_purchase_confirmation_example.pdf";
+        let mypdffile = File::<Vec<u8>>::open(pdftoparse)
+            .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
+        let first_page = mypdffile
+            .pages()
+            .next()
+            .unwrap()
+            .map_err(|_| "Unable to get first page of PDF file".to_string())?;
+        assert_eq!(
+            recognize_statement(first_page)?,
+            StatementType::PurchaseConfirmation
+        );
+
+        assert_eq!(
+            parse_document(pdftoparse),
+            Ok(PdfDocument::PurchaseConfirmation(
+                crate::Account::new("Morgan Stanley", Some("XXXX-1234")),
+                vec![crate::EsppPurchase {
+                    offering_date: Some("01/03/23".to_owned()),
+                    purchase_date: "06/30/23".to_owned(),
+                    company: Some("INTEL CORP".to_owned()),
+                    symbol: Some("INTC".to_owned()),
+                    shares_purchased: 50.0,
+                    offering_fmv: Some(27.0),
+                    purchase_fmv: 33.44,
+                    purchase_price: 22.95,
+                    account: None,
+                }]
+            ))
+        );
+        Ok(())
+    }
+    #[test]
+    fn test_trade_confirmation() -> Result<(), String> {
+        // Synthetic document with two trade confirmations
+        let pdftoparse = "data/trade_confirmation_example.pdf";
+        let mypdffile = File::<Vec<u8>>::open(pdftoparse)
+            .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
+        let first_page = mypdffile
+            .pages()
+            .next()
+            .unwrap()
+            .map_err(|_| "Unable to get first page of PDF file".to_string())?;
+        assert_eq!(
+            recognize_statement(first_page)?,
+            StatementType::TradeConfirmation
+        );
+
+        let trade = |trade_date: &str, settlement_date: &str, quantity: f32, price: f32| {
+            crate::TradeConfirmation {
+                trade_date: trade_date.to_owned(),
+                settlement_date: settlement_date.to_owned(),
+                company: Some("INTEL CORP".to_owned()),
+                symbol: Some("INTC".to_owned()),
+                action: crate::TradeAction::Sell,
+                quantity,
+                price,
+                principal: quantity * price,
+                commission: 0.0,
+                fees: 0.0,
+                net_amount: 0.0,
+            }
+        };
+        assert_eq!(
+            parse_document(pdftoparse),
+            Ok(PdfDocument::TradeConfirmation(
+                crate::Account::new("E*TRADE", Some("XXXX-1234")),
+                vec![
+                    crate::TradeConfirmation {
+                        commission: 0.01,
+                        fees: 0.01,
+                        net_amount: 46.9,
+                        ..trade("04/11/22", "04/13/22", 1.0, 46.92)
+                    },
+                    crate::TradeConfirmation {
+                        fees: 0.03,
+                        net_amount: 449.97,
+                        ..trade("04/20/22", "04/22/22", 10.0, 45.0)
+                    },
+                ]
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_form_1042s() -> Result<(), String> {
+        // Synthetic document with two copies of dividends form and interests form
+        let pdftoparse = "data/form_1042s_example.pdf";
+        let mypdffile = File::<Vec<u8>>::open(pdftoparse)
+            .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
+        let first_page = mypdffile
+            .pages()
+            .next()
+            .unwrap()
+            .map_err(|_| "Unable to get first page of PDF file".to_string())?;
+        assert_eq!(recognize_statement(first_page)?, StatementType::Form1042S);
+
+        assert_eq!(
+            parse_document(pdftoparse),
+            Ok(PdfDocument::Form1042S(
+                crate::Account::new("Morgan Stanley", Some("XXXX-1234")),
+                vec![
+                    crate::Form1042S {
+                        income_code: "06".to_owned(),
+                        gross_income: 723.0,
+                        tax_withheld: 108.0,
+                    },
+                    crate::Form1042S {
+                        income_code: "01".to_owned(),
+                        gross_income: 12.0,
+                        tax_withheld: 0.0,
+                    },
+                ]
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_form_1099() -> Result<(), String> {
+        // Synthetic consolidated 1099 with 1099-DIV and three 1099-B lots of two securities
+        let pdftoparse = "data/form_1099_example.pdf";
+        let mypdffile = File::<Vec<u8>>::open(pdftoparse)
+            .map_err(|_| format!("Error opening and parsing file: {}", pdftoparse))?;
+        let first_page = mypdffile
+            .pages()
+            .next()
+            .unwrap()
+            .map_err(|_| "Unable to get first page of PDF file".to_string())?;
+        assert_eq!(recognize_statement(first_page)?, StatementType::Form1099);
+
+        let lot = |date_sold: &str, date_acquired: &str, company: &str, symbol: &str| {
+            crate::Form1099BLot {
+                date_sold: date_sold.to_owned(),
+                date_acquired: Some(date_acquired.to_owned()),
+                company: Some(company.to_owned()),
+                symbol: Some(symbol.to_owned()),
+                quantity: 0.0,
+                proceeds: 0.0,
+                cost_basis: 0.0,
+            }
+        };
+        assert_eq!(
+            parse_document(pdftoparse),
+            Ok(PdfDocument::Form1099(
+                crate::Account::new("Morgan Stanley", Some("987-654321-098")),
+                Some(crate::Form1099Div {
+                    tax_year: "23".to_owned(),
+                    ordinary_dividends: 345.6,
+                    qualified_dividends: 300.0,
+                    federal_tax_withheld: 0.0,
+                    foreign_tax_paid: 12.4,
+                }),
+                vec![
+                    crate::Form1099BLot {
+                        quantity: 10.0,
+                        proceeds: 250.0,
+                        cost_basis: 230.0,
+                        ..lot("03/01/23", "09/15/22", "INTEL CORP", "INTC")
+                    },
+                    crate::Form1099BLot {
+                        quantity: 5.0,
+                        proceeds: 140.0,
+                        cost_basis: 150.0,
+                        ..lot("06/05/23", "01/10/23", "INTEL CORP", "INTC")
+                    },
+                    crate::Form1099BLot {
+                        quantity: 2.0,
+                        proceeds: 740.0,
+                        cost_basis: 480.0,
+                        ..lot("11/20/23", "01/10/23", "MICROSOFT CORP", "MSFT")
+                    },
+                ]
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_form_1099b_lot() -> Result<(), String> {
+        let columns = |acquired: &str| {
+            ["12/01/2023", "3", "$1,200.50", acquired, "$900.00"]
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<String>>()
+        };
+        let security = (Some("INTEL CORP".to_owned()), Some("INTC".to_owned()));
+        assert_eq!(
+            yield_form_1099b_lot(&columns("Various"), &security)?,
+            crate::Form1099BLot {
+                date_sold: "12/01/23".to_owned(),
+                date_acquired: None,
+                company: Some("INTEL CORP".to_owned()),
+                symbol: Some("INTC".to_owned()),
+                quantity: 3.0,
+                proceeds: 1200.5,
+                cost_basis: 900.0,
+            }
+        );
+        assert!(yield_form_1099b_lot(&columns("N/A"), &security).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mask_text() {
+        assert_eq!(mask_text("ACCT: 123-456789"), "ACCT: ###-######");
+        assert_eq!(
+            mask_text("March 1, 2022 - March 31, 2022"),
+            "March #, 2022 - March ##, 2022"
+        );
+        assert_eq!(mask_text("03/02/22 -5 $1,234.50"), "03/02/22 -# $#,###.##");
+        assert_eq!(
+            mask_text("Form 1099-DIV 1a Form1042S"),
+            "Form 1099-DIV 1a Form1042S"
+        );
+    }
+
+    #[test]
+    fn test_dump_document() -> Result<(), String> {
+        let dump = dump_document("data/brokerage_statement_example.pdf", true)?;
+        assert_eq!(dump[2], "Document type: BrokerageStatement");
+        assert!(dump.contains(&"[   40.00,   732.00] TJ \"ACCT: XXXX-####\"".to_owned()));
+        assert!(dump.contains(
+            &"\"Sold\": SearchingTransactionEntry -> ProcessingTransaction(Sold) (sequence of 4 entries)"
+                .to_owned()
+        ));
+        assert!(dump.contains(
+            &"\"##.##\": ProcessingTransaction(Sold) -> SearchingTransactionEntry (transaction completed)"
+                .to_owned()
+        ));
+        assert!(dump.contains(&"=== Result ===".to_owned()));
+        // Parser decisions are recorded only when document is dumped
+        parse_document("data/brokerage_statement_example.pdf")?;
+        assert_eq!(PARSER_TRACE.with(|trace| trace.borrow().clone()), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbol_entry() {
+        let mut s = SymbolEntry { val: String::new() };
+        let mut check = |token: &str| {
+            s.parse(&pdf::primitive::PdfString::new(token.as_bytes().to_vec()));
+            s.is_pattern()
+        };
+        assert!(check("INTC"));
+        assert!(check("BRK.B"));
+        assert!(!check("INTEL CORP"));
+        assert!(!check("104.74"));
+    }
+
     #[test]
     #[ignore]
     fn test_account_statement() -> Result<(), String> {
@@ -1131,7 +3092,6 @@ mod tests {
                     3808.86,
                     Some("INTEL CORP".to_string())
                 )],
-                vec![]
             )))
         );
         Ok(())
@@ -1142,12 +3102,7 @@ mod tests {
     fn test_account_statement_tax_on_interests() -> Result<(), String> {
         assert_eq!(
             parse_statement("data/example_interests_taxing.pdf"),
-            (Ok((
-                vec![("1/2/24".to_owned(), 0.92, 0.22)],
-                vec![],
-                vec![],
-                vec![]
-            )))
+            (Ok((vec![("1/2/24".to_owned(), 0.92, 0.22)], vec![], vec![],)))
         );
         Ok(())
     }
@@ -1418,7 +3373,6 @@ mod tests {
                         Some("INTEL CORP".to_string())
                     )
                 ],
-                vec![]
             )))
         );
         Ok(())
@@ -1450,7 +3404,6 @@ mod tests {
                         Some("ADVANCED MICRO DEVICES".to_string())
                     ),
                 ],
-                vec![]
             ))
         );
 
@@ -4,9 +4,26 @@
 use chrono::Datelike;
 use serde::{Deserialize, Serialize};
 
-pub struct PL {}
+pub struct PL {
+    // Sources of exchange rates given by user (e.g. corporate rates), asked before NBP ones
+    custom_providers: Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>>,
+}
+
+impl PL {
+    pub(crate) fn with_exchange_rate_providers(
+        providers: Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>>,
+    ) -> Self {
+        PL {
+            custom_providers: providers,
+        }
+    }
+}
 
-type ReqwestClient = reqwest::blocking::Client;
+impl Default for PL {
+    fn default() -> Self {
+        Self::with_exchange_rate_providers(vec![])
+    }
+}
 
 // Example response: {"table":"A",
 //                    "currency":"dolar amerykański",
@@ -32,6 +49,25 @@ pub struct ExchangeRate {
 }
 
 // Function checking if given date is a day when market(NBP) was not working
+// Calendar of holidays is used for days not covered by NBP exchange rates data.
+// It is initialized once (for all years it supports)
+static HOLIDAYS: std::sync::OnceLock<Result<(), String>> = std::sync::OnceLock::new();
+
+fn init_holidays() -> Result<(), String> {
+    HOLIDAYS
+        .get_or_init(|| {
+            holidays::Builder::new()
+                .countries(&[holidays::Country::PL])
+                .years(2000..2031)
+                .init()
+                .map_err(|_| "Holiday module initialization failed".to_owned())
+        })
+        .clone()
+}
+
+// Day without NBP exchange rates publication. Days covered by NBP exchange rates data
+// are checked against the days rates were actually published (so ad hoc days off are
+// taken into account), holidays are checked only for other days
 fn is_non_working_day(date: &chrono::NaiveDate) -> Result<bool, String> {
     let weekend = match date.weekday() {
         chrono::Weekday::Sat | chrono::Weekday::Sun => true,
@@ -42,30 +78,117 @@ fn is_non_working_day(date: &chrono::NaiveDate) -> Result<bool, String> {
         return Ok(true);
     }
 
-    let year = date.year();
+    if let Some(published) = etradeTaxReturnHelper::is_nbp_publication_day(date) {
+        return Ok(!published);
+    }
 
     // Check if a date is a holiday
-
-    holidays::Builder::new()
-        .countries(&[holidays::Country::PL])
-        .years(year - 20..year + 1)
-        .init()
-        .map_err(|_| "Holiday module initialization failed")?;
-
+    init_holidays()?;
     holidays::contains(holidays::Country::PL, *date)
         .map_err(|_| format!("Error checking if given date: {date} is holiday"))
 }
 
+// NBP API does not serve more than 93 days in a single query
+const MAX_DAYS_PER_QUERY: i64 = 93;
+// How far back (from a transaction date) to look for a previous publication of exchange rates
+const MAX_DAYS_WITHOUT_PUBLICATION: i64 = 10;
+
+// Published exchange rates. Key: (currency, date of publication "%Y-%m-%d"), value: (rate, table number)
+type PublishedRates = std::collections::BTreeMap<(String, String), (f32, String)>;
+// Date ranges (currency, from, to)
+type DateRanges = Vec<(String, chrono::NaiveDate, chrono::NaiveDate)>;
+
+// Date ranges (currency, from, to) to be queried so that all missing exchange rates
+// (currency, date) are covered. Overlapping ranges are merged and then split into
+// chunks accepted by NBP API
+fn get_query_ranges(
+    missing: &[(String, String)],
+) -> Result<Vec<(String, chrono::NaiveDate, chrono::NaiveDate)>, String> {
+    let mut windows: Vec<(String, chrono::NaiveDate, chrono::NaiveDate)> = missing
+        .iter()
+        .map(|(currency, date)| {
+            let date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| format!("Error parsing date: {date}"))?;
+            Ok((
+                currency.clone(),
+                date - chrono::Duration::days(MAX_DAYS_WITHOUT_PUBLICATION),
+                date,
+            ))
+        })
+        .collect::<Result<_, String>>()?;
+    windows.sort();
+
+    let mut merged: Vec<(String, chrono::NaiveDate, chrono::NaiveDate)> = vec![];
+    for (currency, from, to) in windows {
+        match merged.last_mut() {
+            Some((c, _, last_to))
+                if *c == currency && from <= *last_to + chrono::Duration::days(1) =>
+            {
+                *last_to = std::cmp::max(*last_to, to);
+            }
+            _ => merged.push((currency, from, to)),
+        }
+    }
+
+    let mut ranges = vec![];
+    for (currency, from, to) in merged {
+        let mut start = from;
+        while start <= to {
+            let end = std::cmp::min(start + chrono::Duration::days(MAX_DAYS_PER_QUERY - 1), to);
+            ranges.push((currency.clone(), start, end));
+            start = end + chrono::Duration::days(1);
+        }
+    }
+    Ok(ranges)
+}
+
+// Error for a transaction with no exchange rate published before. NBP answering
+// 404 (no table for a given dates) is reported distinctly
+fn rate_not_found_error(no_tables: &DateRanges, currency: &str, date: chrono::NaiveDate) -> String {
+    let rate_date = date - chrono::Duration::days(1);
+    match no_tables
+        .iter()
+        .find(|(c, from, to)| c == currency && *from <= rate_date && rate_date <= *to)
+    {
+        Some((_, from, to)) => format!(
+            "Error: NBP has no exchange rates table for {currency} from {from} to {to} (404 Not Found). Unable to get exchange rate for transaction of {date}"
+        ),
+        None => format!(
+            "Error: NBP exchange rate ({currency}) preceding {} not found",
+            date.format("%m/%d/%y")
+        ),
+    }
+}
+
+// Exchange rate (date, rate, table number) published most recently before given date
+fn find_previous_publication(
+    published: &PublishedRates,
+    currency: &str,
+    date: chrono::NaiveDate,
+) -> Option<(String, f32, String)> {
+    let earliest = date - chrono::Duration::days(MAX_DAYS_WITHOUT_PUBLICATION + 1);
+    published
+        .range(
+            (currency.to_owned(), earliest.format("%Y-%m-%d").to_string())
+                ..(currency.to_owned(), date.format("%Y-%m-%d").to_string()),
+        )
+        .next_back()
+        .map(|((_, rate_date), (rate, table))| (rate_date.clone(), *rate, table.clone()))
+}
+
 // We search a exchange rate from a working day preceeding given date (settlement date for
 // etrade)
 //Art. 11a ust. 1 (Dz.U. 2024 poz. 226):
 // Przychody w walutach obcych przelicza się na złote według kursu średniego walut obcych ogłaszanego przez Narodowy Bank Polski z ostatniego dnia roboczego poprzedzającego dzień uzyskania przychodu.
 // Iterate through dates and find where value is None
-// and then try to get for that specific date from cache
-fn get_exchange_rates_from_cache(
+// and then try to get for that specific date from cache.
+// Fill in exchange rates from cache (rates bundled with the program) and
+// return list of (currency, date) of exchange rates that were not found
+fn get_missing_exchange_rates_from_cache(
     dates: &mut std::collections::HashMap<etradeTaxReturnHelper::Exchange, Option<(String, f32)>>,
-) -> Result<bool, String> {
-    let mut all_filled = true;
+    rate_date: etradeTaxReturnHelper::RateDatePolicy,
+) -> Result<Vec<(String, String)>, String> {
+    let mut missing: Vec<(String, String)> = vec![];
     dates.iter_mut().try_for_each(|(exchange, val)| {
         // If there is a exchange value filled (from cached)
         // then skip this entry (Make UT)
@@ -82,20 +205,11 @@ fn get_exchange_rates_from_cache(
             } // For PLN to PLN follow fast path
         };
 
-        let mut converted_date = chrono::NaiveDate::parse_from_str(&date, "%m/%d/%y")
-            .map_err(|_| format!("Error parsing date: {date}"))?;
-        let mut is_working_day = false;
+        let converted_date = rate_date(
+            &chrono::NaiveDate::parse_from_str(date, "%m/%d/%y")
+                .map_err(|_| format!("Error parsing date: {date}"))?,
+        )?;
 
-        while is_working_day == false {
-            converted_date = converted_date
-                .checked_sub_signed(chrono::Duration::days(1))
-                .ok_or("Error traversing date")?;
-
-            // if given day is not working day then skip it
-            is_working_day = is_non_working_day(&converted_date)? == false;
-        }
-
-        let exchange_rates = crate::nbp::get_exchange_rates();
         let exchange_rate_date = converted_date.clone().format("%Y-%m-%d").to_string();
         let curr_exchange = match exchange {
             etradeTaxReturnHelper::Exchange::USD(_) => {
@@ -115,129 +229,227 @@ fn get_exchange_rates_from_cache(
             }
         };
         // If there is no proper date in a cache then skip it
-        if exchange_rates.contains_key(&curr_exchange) {
-            let exchange_rate = exchange_rates
-                .get(&curr_exchange)
-                .ok_or("Error: exchange rate not found in cache")?;
-
+        if let Some(exchange_rate) = etradeTaxReturnHelper::get_nbp_exchange_rate(&curr_exchange) {
             log::info!(
                 "Found cached exchange rate. Date:{} Rate: {}",
                 exchange_rate_date,
                 exchange_rate
             );
-            *val = Some((exchange_rate_date, *exchange_rate as f32));
+            *val = Some((exchange_rate_date, exchange_rate));
         } else {
             log::info!(
                 "Not Found cached exchange rate. Date:{} ",
                 exchange_rate_date
             );
-            all_filled = false;
+            missing.push((
+                etradeTaxReturnHelper::currency_code(exchange).to_owned(),
+                exchange_rate_date,
+            ));
         }
 
         Ok::<(), String>(())
     })?;
-    Ok(all_filled)
+    Ok(missing)
 }
 
-impl etradeTaxReturnHelper::Residency for PL {
-    // We search a exchange rate from a working day preceeding given date (settlement date for
-    // etrade)
-    //Art. 11a ust. 1 (Dz.U. 2024 poz. 226):
-    // Przychody w walutach obcych przelicza się na złote według kursu średniego walut obcych ogłaszanego przez Narodowy Bank Polski z ostatniego dnia roboczego poprzedzającego dzień uzyskania przychodu.
-    fn get_exchange_rates(
+// Working day preceding given date
+fn previous_working_day(date: &chrono::NaiveDate) -> Result<chrono::NaiveDate, String> {
+    let mut converted_date = *date;
+    let mut is_working_day = false;
+
+    while !is_working_day {
+        converted_date = converted_date
+            .checked_sub_signed(chrono::Duration::days(1))
+            .ok_or("Error traversing date")?;
+
+        // if given day is not working day then skip it
+        is_working_day = !is_non_working_day(&converted_date)?;
+    }
+    Ok(converted_date)
+}
+
+/// NBP (table A) exchange rates: bundled with the program and fetched from NBP API
+pub struct NbpRateProvider {
+    base_url: String,
+    rate_date: etradeTaxReturnHelper::RateDatePolicy,
+}
+
+impl NbpRateProvider {
+    pub fn new(rate_date: etradeTaxReturnHelper::RateDatePolicy) -> Self {
+        Self::with_base_url(&etradeTaxReturnHelper::nbp_api_url(), rate_date)
+    }
+
+    pub fn with_base_url(base_url: &str, rate_date: etradeTaxReturnHelper::RateDatePolicy) -> Self {
+        Self {
+            base_url: base_url.to_owned(),
+            rate_date,
+        }
+    }
+}
+
+impl NbpRateProvider {
+    // Get exchange rates published around missing dates (currency, date)
+    // and ranges (currency, from, to) for which NBP has no table
+    fn fetch_published_rates(
         &self,
-        dates: &mut std::collections::HashMap<
-            etradeTaxReturnHelper::Exchange,
-            Option<(String, f32)>,
-        >,
+        missing: &[(String, String)],
+    ) -> Result<(PublishedRates, DateRanges), String> {
+        let client = etradeTaxReturnHelper::create_http_client()?;
+
+        let base_exchange_rate_url = format!("{}/", self.base_url.trim_end_matches('/'));
+
+        // Get all missing exchange rates with a few range queries per currency
+        let mut published: PublishedRates = std::collections::BTreeMap::new();
+        let mut no_tables: DateRanges = vec![];
+        for (currency, from, to) in get_query_ranges(missing)? {
+            let exchange_rate_url = format!(
+                "{base_exchange_rate_url}{}/{}/{}/?format=json",
+                currency.to_lowercase(),
+                from.format("%Y-%m-%d"),
+                to.format("%Y-%m-%d")
+            );
+            let actual_body = etradeTaxReturnHelper::send_with_retry(
+                || client.get(&exchange_rate_url),
+                &etradeTaxReturnHelper::RetryPolicy::default(),
+            )
+            .map_err(|e| {
+                format!(
+                    "Getting Exchange Rate from NBP ({}) failed: {e}",
+                    exchange_rate_url
+                )
+            })?;
+            // NBP responds with 404 when no table was published in a given range
+            if actual_body.status() == reqwest::StatusCode::NOT_FOUND {
+                log::info!("No exchange rates published: {exchange_rate_url}");
+                no_tables.push((currency, from, to));
+                continue;
+            }
+            if !actual_body.status().is_success() {
+                return Err(format!(
+                    "Getting Exchange Rate from NBP ({}) failed: {}",
+                    exchange_rate_url,
+                    actual_body.status()
+                ));
+            }
+            let nbp_response = actual_body
+                .json::<NBPResponse<ExchangeRate>>()
+                .map_err(|_| "Error: getting exchange rate from NBP")?;
+            log::info!("body of exchange_rate = {:#?}", nbp_response);
+            nbp_response.rates.into_iter().for_each(|rate| {
+                published.insert((currency.clone(), rate.effectiveDate), (rate.mid, rate.no));
+            });
+        }
+        Ok((published, no_tables))
+    }
+}
+
+impl etradeTaxReturnHelper::ExchangeRateProvider for NbpRateProvider {
+    fn name(&self) -> &str {
+        "NBP"
+    }
+
+    fn fill_exchange_rates(
+        &self,
+        dates: &mut etradeTaxReturnHelper::ExchangeRates,
     ) -> Result<(), String> {
         // Try to get exchange rates from cached data (output from program gen_exchange_rates)
-        if get_exchange_rates_from_cache(dates)? == true {
+        let missing = get_missing_exchange_rates_from_cache(dates, self.rate_date)?;
+        if missing.is_empty() {
             log::info!("All needed Exchange rates were taken from cache.");
             return Ok(());
+        } else if etradeTaxReturnHelper::is_offline_mode() {
+            return Err(etradeTaxReturnHelper::format_missing_exchange_rates(
+                &missing,
+            ));
         } else {
             log::info!("Some of the Exchange rates were not found in cache. Trying to get them using NBP API.");
         }
 
-        // proxies are taken from env vars: http_proxy and https_proxy
-        let http_proxy = std::env::var("http_proxy");
-        let https_proxy = std::env::var("https_proxy");
-
-        // If there is proxy then pick first URL
-        let base_client = ReqwestClient::builder();
-        let client = match &http_proxy {
-            Ok(proxy) => base_client.proxy(
-                reqwest::Proxy::http(proxy)
-                    .map_err(|x| format!("Error setting HTTP proxy. \nDetails: {}", x))?,
-            ),
-            Err(_) => base_client,
-        };
-        let client = match &https_proxy {
-            Ok(proxy) => client.proxy(
-                reqwest::Proxy::https(proxy)
-                    .map_err(|x| format!("Error setting HTTPS proxy. \nDetails: {}", x))?,
-            ),
-            Err(_) => client,
-        };
-        let client = client
-            .build()
-            .map_err(|_| "Could not create REST API client")?;
-
-        let base_exchange_rate_url = "https://api.nbp.pl/api/exchangerates/rates/a/";
+        let (published, no_tables) = self.fetch_published_rates(&missing)?;
 
+        // Match transactions to exchange rate of previous publication day
         dates.iter_mut().try_for_each(|(exchange, val)| {
-            // If there is a exchange value filled (from cached)
-            // then skip this entry (Make UT)
             if val.is_some() {
                 return Ok::<(), String>(());
             }
-
-            let (from, date) = match exchange {
-                etradeTaxReturnHelper::Exchange::USD(date) => ("usd", date),
-                etradeTaxReturnHelper::Exchange::EUR(date) => ("eur", date),
+            let date = match exchange {
+                etradeTaxReturnHelper::Exchange::USD(date)
+                | etradeTaxReturnHelper::Exchange::EUR(date) => date,
                 etradeTaxReturnHelper::Exchange::PLN(_) => {
                     *val = Some(("N/A".to_owned(), 1.0));
                     return Ok::<(), String>(());
                 } // For PLN to PLN follow fast path
             };
-
-            let mut converted_date = chrono::NaiveDate::parse_from_str(&date, "%m/%d/%y").unwrap();
-
-            // Try to get exchange rate going backwards with dates till success
-            let mut is_success = false;
-            while is_success == false {
-                converted_date = converted_date
-                    .checked_sub_signed(chrono::Duration::days(1))
-                    .ok_or("Error traversing date")?;
-
-                let exchange_rate_url: String = base_exchange_rate_url.to_string()
-                    + format!("{}/{}", from, converted_date.format("%Y-%m-%d")).as_str()
-                    + "/?format=json";
-
-                let body = client.get(&(exchange_rate_url)).send();
-                let actual_body = body.map_err(|_| {
-                    format!(
-                        "Getting Exchange Rate from NBP ({}) failed",
-                        exchange_rate_url
-                    )
-                })?;
-                is_success = actual_body.status().is_success();
-                if is_success == true {
-                    log::info!("RESPONSE {:#?}", actual_body);
-
-                    let nbp_response = actual_body
-                        .json::<NBPResponse<ExchangeRate>>()
-                        .map_err(|_| "Error: getting exchange rate from NBP")?;
-                    log::info!("body of exchange_rate = {:#?}", nbp_response);
-                    let exchange_rate = nbp_response.rates[0].mid;
-                    let exchange_rate_date = format!("{}", converted_date.format("%Y-%m-%d"));
-                    *val = Some((exchange_rate_date, exchange_rate));
-                };
-            }
+            let converted_date = chrono::NaiveDate::parse_from_str(date, "%m/%d/%y")
+                .map_err(|_| format!("Error parsing date: {date}"))?;
+            let currency = etradeTaxReturnHelper::currency_code(exchange);
+            // Rate published on the day chosen by policy or most recently before it
+            let rate_date = (self.rate_date)(&converted_date)?
+                .succ_opt()
+                .ok_or("Error traversing date")?;
+            let (exchange_rate_date, exchange_rate, table) =
+                find_previous_publication(&published, currency, rate_date)
+                    .ok_or_else(|| rate_not_found_error(&no_tables, currency, converted_date))?;
+            etradeTaxReturnHelper::store_exchange_rate(
+                "NBP",
+                &etradeTaxReturnHelper::exchange_with_date(exchange, exchange_rate_date.clone()),
+                exchange_rate,
+                &table,
+            );
+            *val = Some((exchange_rate_date, exchange_rate));
             Ok::<(), String>(())
         })?;
         Ok(())
     }
+}
+
+impl etradeTaxReturnHelper::Residency for PL {
+    fn currency(&self) -> &'static str {
+        "PLN"
+    }
+
+    // We search a exchange rate from a working day preceeding given date (settlement date for
+    // etrade)
+    //Art. 11a ust. 1 (Dz.U. 2024 poz. 226):
+    // Przychody w walutach obcych przelicza się na złote według kursu średniego walut obcych ogłaszanego przez Narodowy Bank Polski z ostatniego dnia roboczego poprzedzającego dzień uzyskania przychodu.
+    fn rate_date_rule(&self) -> etradeTaxReturnHelper::RateDateRule {
+        etradeTaxReturnHelper::RateDateRule {
+            sale_date: etradeTaxReturnHelper::SaleDate::Settlement,
+            rate_date: previous_working_day,
+        }
+    }
+
+    // Discount is not taxed at purchase. Cost of shares is price actually paid for them
+    // (Art. 22 ust. 1f pkt 1: wydatki na objęcie lub nabycie udziałów)
+    fn espp_policy(&self) -> etradeTaxReturnHelper::EsppPolicy {
+        etradeTaxReturnHelper::EsppPolicy {
+            discount_is_income: false,
+            cost_basis: etradeTaxReturnHelper::EsppCostBasis::PurchasePrice,
+        }
+    }
+
+    // Income is taxed in the year it was received (Art. 11 ust. 1: otrzymane lub postawione
+    // do dyspozycji podatnika pieniądze)
+    fn tax_year_rule(&self) -> etradeTaxReturnHelper::TaxYearRule {
+        etradeTaxReturnHelper::TaxYearRule::CashReceived
+    }
+
+    fn custom_exchange_rate_providers(
+        &self,
+    ) -> &[Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>] {
+        &self.custom_providers
+    }
+
+    fn exchange_rate_providers(&self) -> Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>> {
+        let rate_date = self.rate_date_rule().rate_date;
+        vec![
+            Box::new(etradeTaxReturnHelper::FileRateProvider::new(rate_date)),
+            Box::new(etradeTaxReturnHelper::CacheRateProvider::new(
+                "NBP", rate_date,
+            )),
+            Box::new(NbpRateProvider::new(rate_date)),
+        ]
+    }
 
     fn present_result(
         &self,
@@ -281,7 +493,7 @@ mod tests {
     use super::*;
     #[test]
     fn test_present_result_pl() -> Result<(), String> {
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(PL::default());
 
         let gross_div = 100.0f32;
         let tax_div = 15.0f32;
@@ -308,6 +520,7 @@ mod tests {
 
     #[test]
     fn test_get_exchange_rates_pl() -> Result<(), String> {
+        crate::tests::use_test_cache_dir();
         let mut dates: std::collections::HashMap<
             etradeTaxReturnHelper::Exchange,
             Option<(String, f32)>,
@@ -325,7 +538,7 @@ mod tests {
             None,
         );
 
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(crate::pl::PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(crate::pl::PL::default());
         rd.get_exchange_rates(&mut dates).map_err(|x| "Error: unable to get exchange rates.  Please check your internet connection or proxy settings\n\nDetails:".to_string()+x.as_str())?;
 
         let mut expected_result: std::collections::HashMap<
@@ -352,7 +565,7 @@ mod tests {
 
     #[test]
     fn test_present_result_double_taxation_warning_pl() -> Result<(), String> {
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(PL {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(PL::default());
 
         let gross_div = 100.0f32;
         let tax_div = 30.0f32;
@@ -397,6 +610,16 @@ mod tests {
         assert_eq!(is_non_working_day(&date), Ok(false));
         Ok(())
     }
+
+    #[test]
+    fn test_is_non_working_day_holidays() -> Result<(), String> {
+        // Days before bundled NBP data are checked against holidays
+        let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!(is_non_working_day(&d("2005-05-03")), Ok(true));
+        assert_eq!(is_non_working_day(&d("2005-05-04")), Ok(false));
+        assert_eq!(previous_working_day(&d("2005-05-04"))?, d("2005-05-02"));
+        Ok(())
+    }
     #[test]
     fn test_get_exchange_rates_from_cache() -> Result<(), String> {
         let mut rates = std::collections::HashMap::from([
@@ -421,7 +644,9 @@ mod tests {
             ),
         ]);
 
-        assert_eq!(get_exchange_rates_from_cache(&mut rates)?, true);
+        assert!(
+            get_missing_exchange_rates_from_cache(&mut rates, previous_working_day)?.is_empty()
+        );
 
         assert_eq!(rates, expected_rates);
 
@@ -437,7 +662,10 @@ mod tests {
             ),
         ]);
 
-        assert_eq!(get_exchange_rates_from_cache(&mut rates)?, false);
+        assert_eq!(
+            get_missing_exchange_rates_from_cache(&mut rates, previous_working_day)?,
+            vec![("USD".to_owned(), "2000-02-25".to_owned())]
+        );
 
         assert_eq!(rates, rates);
 
@@ -463,9 +691,188 @@ mod tests {
             ),
         ]);
 
-        assert_eq!(get_exchange_rates_from_cache(&mut rates)?, true);
+        assert!(
+            get_missing_exchange_rates_from_cache(&mut rates, previous_working_day)?.is_empty()
+        );
 
         assert_eq!(rates, expected_rates);
         Ok(())
     }
+
+    #[test]
+    fn test_get_missing_exchange_rates_from_cache() -> Result<(), String> {
+        // User supplied rates are used when there is no bundled one
+        let user_rates =
+            etradeTaxReturnHelper::read_exchange_rates_file("data/exchange_rates.csv")?;
+
+        let mut rates = std::collections::HashMap::from([
+            (
+                etradeTaxReturnHelper::Exchange::USD("03/02/00".to_owned()),
+                None,
+            ),
+            (
+                etradeTaxReturnHelper::Exchange::EUR("03/06/00".to_owned()),
+                None,
+            ),
+        ]);
+
+        use etradeTaxReturnHelper::ExchangeRateProvider;
+        etradeTaxReturnHelper::FileRateProvider::with_rates(user_rates, previous_working_day)
+            .fill_exchange_rates(&mut rates)?;
+        assert_eq!(
+            get_missing_exchange_rates_from_cache(&mut rates, previous_working_day)?,
+            vec![("EUR".to_owned(), "2000-03-03".to_owned())]
+        );
+        assert_eq!(
+            rates[&etradeTaxReturnHelper::Exchange::USD("03/02/00".to_owned())],
+            Some(("2000-03-01".to_owned(), 3.8855))
+        );
+        assert_eq!(
+            rates[&etradeTaxReturnHelper::Exchange::EUR("03/06/00".to_owned())],
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_query_ranges() -> Result<(), String> {
+        let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let missing = vec![
+            ("USD".to_owned(), "2024-01-12".to_owned()),
+            ("USD".to_owned(), "2024-01-05".to_owned()),
+            ("EUR".to_owned(), "2024-01-05".to_owned()),
+            ("USD".to_owned(), "2024-06-28".to_owned()),
+            ("USD".to_owned(), "2024-12-31".to_owned()),
+        ];
+        assert_eq!(
+            get_query_ranges(&missing)?,
+            vec![
+                ("EUR".to_owned(), d("2023-12-26"), d("2024-01-05")),
+                ("USD".to_owned(), d("2023-12-26"), d("2024-01-12")),
+                ("USD".to_owned(), d("2024-06-18"), d("2024-06-28")),
+                ("USD".to_owned(), d("2024-12-21"), d("2024-12-31")),
+            ]
+        );
+
+        // Weekly dates through the whole year are served with a few queries
+        let missing: Vec<(String, String)> = (0..52)
+            .map(|w| {
+                (
+                    "USD".to_owned(),
+                    (d("2024-01-05") + chrono::Duration::weeks(w))
+                        .format("%Y-%m-%d")
+                        .to_string(),
+                )
+            })
+            .collect();
+        let ranges = get_query_ranges(&missing)?;
+        assert_eq!(ranges.len(), 4);
+        ranges.iter().for_each(|(_, from, to)| {
+            assert!((*to - *from).num_days() < MAX_DAYS_PER_QUERY);
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_previous_publication() {
+        let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let mut published: PublishedRates = std::collections::BTreeMap::new();
+        published.insert(
+            ("USD".to_owned(), "2021-02-26".to_owned()),
+            (3.7247, "039/A/NBP/2021".to_owned()),
+        );
+        published.insert(
+            ("USD".to_owned(), "2021-03-01".to_owned()),
+            (3.7364, "040/A/NBP/2021".to_owned()),
+        );
+        published.insert(
+            ("EUR".to_owned(), "2021-03-02".to_owned()),
+            (4.5186, "041/A/NBP/2021".to_owned()),
+        );
+
+        // Rate of the same day is not to be used
+        assert_eq!(
+            find_previous_publication(&published, "USD", d("2021-03-01")),
+            Some(("2021-02-26".to_owned(), 3.7247, "039/A/NBP/2021".to_owned()))
+        );
+        assert_eq!(
+            find_previous_publication(&published, "USD", d("2021-03-04")),
+            Some(("2021-03-01".to_owned(), 3.7364, "040/A/NBP/2021".to_owned()))
+        );
+        assert_eq!(
+            find_previous_publication(&published, "EUR", d("2021-03-02")),
+            None
+        );
+        assert_eq!(
+            find_previous_publication(&published, "USD", d("2021-04-01")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_nbp_rate_provider_fake_server() -> Result<(), String> {
+        crate::tests::use_test_cache_dir();
+        let server = etradeTaxReturnHelper::fake_http_server::FakeHttpServer::start(vec![
+            etradeTaxReturnHelper::fake_http_server::FakeResponse {
+                path_prefix: "/api/exchangerates/rates/a/usd/".to_owned(),
+                status: 200,
+                content_type: "application/json; charset=utf-8".to_owned(),
+                body: include_str!("../data/nbp_example_response.json").to_owned(),
+                ..Default::default()
+            },
+        ]);
+        let provider = NbpRateProvider::with_base_url(
+            &format!("{}/api/exchangerates/rates/a/", server.url),
+            previous_working_day,
+        );
+
+        // Two transactions close to each other are served with a single query.
+        // EUR is not served (404) so there is nothing published
+        let (published, no_tables) = provider.fetch_published_rates(&[
+            ("USD".to_owned(), "2021-02-26".to_owned()),
+            ("USD".to_owned(), "2021-02-25".to_owned()),
+            ("EUR".to_owned(), "2021-02-26".to_owned()),
+        ])?;
+        assert_eq!(
+            server.requests(),
+            vec![
+                "/api/exchangerates/rates/a/eur/2021-02-16/2021-02-26/?format=json",
+                "/api/exchangerates/rates/a/usd/2021-02-15/2021-02-26/?format=json",
+            ]
+        );
+        assert_eq!(published.len(), 6);
+        let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!(
+            no_tables,
+            vec![("EUR".to_owned(), d("2021-02-16"), d("2021-02-26"))]
+        );
+        assert_eq!(
+            rate_not_found_error(&no_tables, "EUR", d("2021-02-27")),
+            "Error: NBP has no exchange rates table for EUR from 2021-02-16 to 2021-02-26 (404 Not Found). Unable to get exchange rate for transaction of 2021-02-27"
+        );
+        assert_eq!(
+            rate_not_found_error(&no_tables, "USD", d("2021-02-27")),
+            "Error: NBP exchange rate (USD) preceding 02/27/21 not found"
+        );
+        assert_eq!(
+            find_previous_publication(
+                &published,
+                "USD",
+                chrono::NaiveDate::from_ymd_opt(2021, 3, 1).unwrap()
+            ),
+            Some(("2021-02-26".to_owned(), 3.7247, "039/A/NBP/2021".to_owned()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_date_rule_pl() -> Result<(), String> {
+        use etradeTaxReturnHelper::Residency;
+        let rule = PL::default().rate_date_rule();
+        assert_eq!(rule.sale_date, etradeTaxReturnHelper::SaleDate::Settlement);
+        // Rate of Friday for transaction settled on Monday
+        let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!((rule.rate_date)(&d("2023-02-20"))?, d("2023-02-17"));
+        Ok(())
+    }
 }
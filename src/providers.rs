@@ -0,0 +1,683 @@
+// SPDX-FileCopyrightText: 2025 RustInFinance
+// SPDX-License-Identifier: BSD-3-Clause
+
+use chrono::Datelike;
+use regex::Regex;
+use std::collections::HashMap;
+
+use crate::cache::{get_cached_exchange_rate, store_exchange_rate};
+use crate::rates::{
+    currency_code, ecb_api_url, exchange_rates_org_url, exchange_with_date,
+    format_missing_exchange_rates, get_user_exchange_rate, is_offline_mode,
+};
+use crate::Exchange;
+
+/// Map of exchange rates to be filled in. Key: Exchange::<currency>(transaction date in
+/// "%m/%d/%y" format), value: (rate date "%Y-%m-%d", rate)
+pub type ExchangeRates = HashMap<Exchange, Option<(String, f32)>>;
+
+/// Rule of choosing a date of exchange rate for a given transaction date
+pub type RateDatePolicy = fn(&chrono::NaiveDate) -> Result<chrono::NaiveDate, String>;
+
+/// Date of sale that income from it is converted with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaleDate {
+    Trade,
+    Settlement,
+}
+
+/// Rules of residency for choosing exchange rates. The same rate date policy is applied to
+/// dividends, interests, sale proceeds and acquisition costs
+#[derive(Clone, Copy)]
+pub struct RateDateRule {
+    /// Date of sale determining exchange rate of proceeds
+    pub sale_date: SaleDate,
+    /// Date of exchange rate for date of event (payment, sale or acquisition)
+    pub rate_date: RateDatePolicy,
+}
+
+impl RateDateRule {
+    /// Date (of trade or settlement) determining exchange rate of sale proceeds
+    pub fn proceeds_date<'a>(&self, trade_date: &'a str, settlement_date: &'a str) -> &'a str {
+        match self.sale_date {
+            SaleDate::Trade => trade_date,
+            SaleDate::Settlement => settlement_date,
+        }
+    }
+}
+
+impl std::fmt::Debug for RateDateRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RateDateRule")
+            .field("sale_date", &self.sale_date)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Source of exchange rates (independent of taxation rules of residency)
+pub trait ExchangeRateProvider {
+    fn name(&self) -> &str;
+
+    /// Fill in exchange rates for entries that are not having them yet. Entries that
+    /// provider is unable to serve are left empty (for next providers)
+    fn fill_exchange_rates(&self, dates: &mut ExchangeRates) -> Result<(), String>;
+}
+
+/// Ask providers (in order) for exchange rates until all of them are filled in
+pub fn fill_exchange_rates(
+    providers: &[Box<dyn ExchangeRateProvider>],
+    dates: &mut ExchangeRates,
+) -> Result<(), String> {
+    fill_exchange_rates_in_order(providers.iter().map(|p| p.as_ref()), dates)
+}
+
+pub(crate) fn fill_exchange_rates_in_order<'a>(
+    providers: impl Iterator<Item = &'a dyn ExchangeRateProvider>,
+    dates: &mut ExchangeRates,
+) -> Result<(), String> {
+    for provider in providers {
+        if dates.values().all(|v| v.is_some()) {
+            break;
+        }
+        log::info!("Getting exchange rates using: {}", provider.name());
+        provider.fill_exchange_rates(dates)?;
+    }
+
+    let mut missing: Vec<String> = dates
+        .iter()
+        .filter(|(_, v)| v.is_none())
+        .map(|(exchange, _)| format!("{:?}", exchange))
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        missing.sort();
+        Err(format!(
+            "Error: exchange rates not found for: {}",
+            missing.join(", ")
+        ))
+    }
+}
+
+fn transaction_date(exchange: &Exchange) -> Result<chrono::NaiveDate, String> {
+    let date = match exchange {
+        Exchange::USD(date) | Exchange::EUR(date) | Exchange::PLN(date) => date,
+    };
+    chrono::NaiveDate::parse_from_str(date, "%m/%d/%y")
+        .map_err(|x| format!("Unable to convert date {x}"))
+}
+
+/// Exchange rate from the day of transaction
+pub fn same_day(date: &chrono::NaiveDate) -> Result<chrono::NaiveDate, String> {
+    Ok(*date)
+}
+
+/// Exchange rate from the day preceding transaction
+pub fn previous_day(date: &chrono::NaiveDate) -> Result<chrono::NaiveDate, String> {
+    date.checked_sub_signed(chrono::Duration::days(1))
+        .ok_or("Error traversing date".to_owned())
+}
+
+/// Exchange rate from the business day (Monday to Friday) preceding transaction
+pub fn previous_business_day(date: &chrono::NaiveDate) -> Result<chrono::NaiveDate, String> {
+    let mut day = previous_day(date)?;
+    while matches!(day.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun) {
+        day = previous_day(&day)?;
+    }
+    Ok(day)
+}
+
+// Fill in entries using lookup of rate for a date chosen by policy
+fn fill_using<F>(
+    dates: &mut ExchangeRates,
+    rate_date: RateDatePolicy,
+    lookup: F,
+) -> Result<(), String>
+where
+    F: Fn(&Exchange) -> Option<f32>,
+{
+    dates.iter_mut().try_for_each(|(exchange, val)| {
+        if val.is_some() {
+            return Ok::<(), String>(());
+        }
+        // Entries for which rate date cannot be determined are left to next providers
+        let date = match transaction_date(exchange).and_then(|d| rate_date(&d)) {
+            Ok(date) => date.format("%Y-%m-%d").to_string(),
+            Err(e) => {
+                log::warn!(
+                    "Unable to determine exchange rate date for {:?}: {e}",
+                    exchange
+                );
+                return Ok(());
+            }
+        };
+        if let Some(rate) = lookup(&exchange_with_date(exchange, date.clone())) {
+            *val = Some((date, rate));
+        }
+        Ok(())
+    })
+}
+
+/// The same rate for all transactions (e.g. no conversion needed)
+pub struct FixedRateProvider {
+    rate_date: String,
+    rate: f32,
+}
+
+impl FixedRateProvider {
+    pub fn new(rate_date: &str, rate: f32) -> Self {
+        Self {
+            rate_date: rate_date.to_owned(),
+            rate,
+        }
+    }
+}
+
+impl ExchangeRateProvider for FixedRateProvider {
+    fn name(&self) -> &str {
+        "fixed rate"
+    }
+
+    fn fill_exchange_rates(&self, dates: &mut ExchangeRates) -> Result<(), String> {
+        dates
+            .values_mut()
+            .filter(|v| v.is_none())
+            .for_each(|v| *v = Some((self.rate_date.clone(), self.rate)));
+        Ok(())
+    }
+}
+
+/// Exchange rates loaded from user supplied files (see load_exchange_rates_file) or given
+/// table of rates (see read_exchange_rates_file)
+pub struct FileRateProvider {
+    rates: Option<HashMap<Exchange, f32>>,
+    rate_date: RateDatePolicy,
+}
+
+impl FileRateProvider {
+    pub fn new(rate_date: RateDatePolicy) -> Self {
+        Self {
+            rates: None,
+            rate_date,
+        }
+    }
+
+    pub fn with_rates(rates: HashMap<Exchange, f32>, rate_date: RateDatePolicy) -> Self {
+        Self {
+            rates: Some(rates),
+            rate_date,
+        }
+    }
+}
+
+impl ExchangeRateProvider for FileRateProvider {
+    fn name(&self) -> &str {
+        "exchange rates files"
+    }
+
+    fn fill_exchange_rates(&self, dates: &mut ExchangeRates) -> Result<(), String> {
+        match &self.rates {
+            Some(rates) => fill_using(dates, self.rate_date, |exchange| {
+                rates.get(exchange).copied()
+            }),
+            None => fill_using(dates, self.rate_date, get_user_exchange_rate),
+        }
+    }
+}
+
+/// Exchange rates of given source stored in local cache
+pub struct CacheRateProvider {
+    source: String,
+    rate_date: RateDatePolicy,
+}
+
+impl CacheRateProvider {
+    pub fn new(source: &str, rate_date: RateDatePolicy) -> Self {
+        Self {
+            source: source.to_owned(),
+            rate_date,
+        }
+    }
+}
+
+impl ExchangeRateProvider for CacheRateProvider {
+    fn name(&self) -> &str {
+        "local cache"
+    }
+
+    fn fill_exchange_rates(&self, dates: &mut ExchangeRates) -> Result<(), String> {
+        fill_using(dates, self.rate_date, |exchange| {
+            get_cached_exchange_rate(&self.source, exchange)
+        })
+    }
+}
+
+// ECB does not publish rates on TARGET holidays (up to several days in a row), so rate is
+// looked up within a week ending on the day chosen by policy
+const ECB_LOOKBACK_DAYS: i64 = 7;
+
+/// European Central Bank exchange rates (USD to EUR) from the day chosen by policy or the
+/// latest day preceding it ECB published rates for
+pub struct EcbRateProvider {
+    base_url: String,
+    rate_date: RateDatePolicy,
+}
+
+impl EcbRateProvider {
+    pub fn new(rate_date: RateDatePolicy) -> Self {
+        Self::with_base_url(&ecb_api_url(), rate_date)
+    }
+
+    pub fn with_base_url(base_url: &str, rate_date: RateDatePolicy) -> Self {
+        Self {
+            base_url: base_url.to_owned(),
+            rate_date,
+        }
+    }
+}
+
+impl ExchangeRateProvider for EcbRateProvider {
+    fn name(&self) -> &str {
+        "ECB"
+    }
+
+    fn fill_exchange_rates(&self, dates: &mut ExchangeRates) -> Result<(), String> {
+        let mut missing: Vec<(String, String)> = vec![];
+        dates.iter_mut().try_for_each(|(exchange, val)| {
+            if val.is_some() {
+                return Ok::<(), String>(());
+            }
+            let rate_date = (self.rate_date)(&transaction_date(exchange)?)?;
+            let rate_date_str = rate_date.format("%Y-%m-%d").to_string();
+            if is_offline_mode() {
+                missing.push((currency_code(exchange).to_owned(), rate_date_str));
+                return Ok(());
+            }
+
+            let (exchange_rate_date, exchange_rate) = crate::ecb::get_eur_to_usd_exchange_rate(
+                &self.base_url,
+                rate_date - chrono::Duration::days(ECB_LOOKBACK_DAYS),
+                rate_date,
+            )
+            .map_err(|x| format!("Error getting exchange rate from ECB: {x}"))?;
+            let exchange_rate_date = exchange_rate_date.format("%Y-%m-%d").to_string();
+            if exchange_rate_date != rate_date_str {
+                log::info!(
+                    "No ECB exchange rate of {rate_date_str}, rate of {exchange_rate_date} is used"
+                );
+            }
+            store_exchange_rate(
+                "ECB",
+                &exchange_with_date(exchange, exchange_rate_date.clone()),
+                exchange_rate,
+                "N/A",
+            );
+
+            *val = Some((exchange_rate_date, exchange_rate));
+            Ok::<(), String>(())
+        })?;
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(format_missing_exchange_rates(&missing))
+        }
+    }
+}
+
+/// Exchange rates scraped from exchange-rates.org from the day chosen by policy
+pub struct ExchangeRatesOrgProvider {
+    to: String,
+    base_url: String,
+    rate_date: RateDatePolicy,
+}
+
+impl ExchangeRatesOrgProvider {
+    pub fn new(to: &str, rate_date: RateDatePolicy) -> Self {
+        Self::with_base_url(to, &exchange_rates_org_url(), rate_date)
+    }
+
+    pub fn with_base_url(to: &str, base_url: &str, rate_date: RateDatePolicy) -> Self {
+        Self {
+            to: to.to_owned(),
+            base_url: base_url.to_owned(),
+            rate_date,
+        }
+    }
+
+    fn source(&self) -> String {
+        format!("EXCHANGE-RATES.ORG-{}", self.to.to_uppercase())
+    }
+}
+
+// to find examplery "1 USD</span> = 0.82831 EUR ... USD to EUR on 2023-2-20"
+fn parse_exchange_rates_org(body: &str, from: &str, to: &str) -> Result<(f32, String), String> {
+    let pattern = format!("1 {from}</span> =");
+    let start_offset = body
+        .find(&pattern)
+        .ok_or(format!("Error finding pattern: {}", pattern))?;
+    let end_offset = std::cmp::min(start_offset + 100, body.len()); // 100 characters should be enough
+    let pattern_slice = &body[start_offset..end_offset];
+    // Extract exchange rate (fp32 value)
+    log::info!("Exchange rate slice:  {}", pattern_slice);
+    let re = Regex::new(r"[0-9]+[.][0-9]+").map_err(|e| e.to_string())?;
+    let exchange_rate: f32 = re
+        .find(pattern_slice)
+        .ok_or(format!("Error finding exchange rate in: {pattern_slice}"))?
+        .as_str()
+        .parse::<f32>()
+        .map_err(|e| format!("Error parsing exchange rate: {e}"))?;
+
+    // Parse date
+    let pattern = format!("{from} to {to} on ");
+    let start_date_offset = body
+        .find(&pattern)
+        .ok_or(format!("Error finding pattern: {}", pattern))?;
+    let date_pattern_slice = &body[start_date_offset + pattern.chars().count()..];
+
+    let re = Regex::new(r"[0-9]+[-][0-9]+-[0-9]+").map_err(|e| e.to_string())?;
+    let date_string: &str = re
+        .find(date_pattern_slice)
+        .ok_or("Error finding exchange rate date")?
+        .as_str();
+    let exchange_rate_date = chrono::NaiveDate::parse_from_str(date_string, "%Y-%m-%d")
+        .map_err(|e| format!("Error parsing exchange rate date: {e}"))?;
+
+    Ok((
+        exchange_rate,
+        format!("{}", exchange_rate_date.format("%Y-%m-%d")),
+    ))
+}
+
+impl ExchangeRateProvider for ExchangeRatesOrgProvider {
+    fn name(&self) -> &str {
+        "exchange-rates.org"
+    }
+
+    fn fill_exchange_rates(&self, dates: &mut ExchangeRates) -> Result<(), String> {
+        let mut missing: Vec<(String, String)> = vec![];
+        let client = crate::create_http_client()?;
+
+        // Example URL: https://www.exchange-rates.org/Rate/USD/EUR/2-27-2021
+        let base_exchange_rate_url = format!("{}/", self.base_url.trim_end_matches('/'));
+
+        dates.iter_mut().try_for_each(|(exchange, val)| {
+            if val.is_some() {
+                return Ok::<(), String>(());
+            }
+            let from = currency_code(exchange);
+            let converted_date = (self.rate_date)(&transaction_date(exchange)?)?;
+            let rate_date = converted_date.format("%Y-%m-%d").to_string();
+            if is_offline_mode() {
+                missing.push((from.to_owned(), rate_date));
+                return Ok(());
+            }
+
+            let fms = format!(
+                "{}/{}/{}",
+                from.to_lowercase(),
+                self.to.to_lowercase(),
+                converted_date.format("%m-%d-%Y")
+            ) + "/?format=json";
+            let exchange_rate_url: String = base_exchange_rate_url.clone() + fms.as_str();
+
+            let actual_body = crate::send_with_retry(
+                || client.get(&exchange_rate_url),
+                &crate::RetryPolicy::default(),
+            )
+            .map_err(|e| {
+                format!(
+                    "Getting Exchange Rate from Exchange-Rates.org ({}) failed: {e}",
+                    exchange_rate_url
+                )
+            })?;
+            if !actual_body.status().is_success() {
+                return Err(format!(
+                    "Error getting exchange rate ({}): {}",
+                    exchange_rate_url,
+                    actual_body.status()
+                ));
+            }
+            log::info!("RESPONSE {:#?}", actual_body);
+            let exchange_rates_response = actual_body
+                .text()
+                .map_err(|_| "Error converting response to Text")?;
+            log::info!("body of exchange_rate = {:#?}", &exchange_rates_response);
+            // parsing text response
+            if let Ok((exchange_rate, exchange_rate_date)) =
+                parse_exchange_rates_org(&exchange_rates_response, from, &self.to.to_uppercase())
+            {
+                store_exchange_rate(
+                    &self.source(),
+                    &exchange_with_date(exchange, exchange_rate_date.clone()),
+                    exchange_rate,
+                    "N/A",
+                );
+                *val = Some((exchange_rate_date, exchange_rate));
+            }
+            Ok(())
+        })?;
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(format_missing_exchange_rates(&missing))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Provider serving rates (of transaction date) from a map
+    struct MockProvider {
+        rates: HashMap<Exchange, f32>,
+    }
+
+    impl ExchangeRateProvider for MockProvider {
+        fn name(&self) -> &str {
+            "mock"
+        }
+
+        fn fill_exchange_rates(&self, dates: &mut ExchangeRates) -> Result<(), String> {
+            fill_using(
+                dates,
+                |d| Ok(*d),
+                |exchange| self.rates.get(exchange).copied(),
+            )
+        }
+    }
+
+    #[test]
+    fn test_fill_exchange_rates_in_order() -> Result<(), String> {
+        let providers: Vec<Box<dyn ExchangeRateProvider>> = vec![
+            Box::new(MockProvider {
+                rates: HashMap::from([(Exchange::USD("2021-03-01".to_owned()), 3.7247)]),
+            }),
+            Box::new(FixedRateProvider::new("N/A", 1.0)),
+        ];
+        let mut dates: ExchangeRates = HashMap::from([
+            (Exchange::USD("03/01/21".to_owned()), None),
+            (Exchange::EUR("03/01/21".to_owned()), None),
+            (
+                Exchange::USD("03/02/21".to_owned()),
+                Some(("2021-03-01".to_owned(), 3.7364)),
+            ),
+        ]);
+        fill_exchange_rates(&providers, &mut dates)?;
+        assert_eq!(
+            dates,
+            HashMap::from([
+                (
+                    Exchange::USD("03/01/21".to_owned()),
+                    Some(("2021-03-01".to_owned(), 3.7247))
+                ),
+                (
+                    Exchange::EUR("03/01/21".to_owned()),
+                    Some(("N/A".to_owned(), 1.0))
+                ),
+                (
+                    Exchange::USD("03/02/21".to_owned()),
+                    Some(("2021-03-01".to_owned(), 3.7364))
+                ),
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_exchange_rates_missing() {
+        let providers: Vec<Box<dyn ExchangeRateProvider>> = vec![Box::new(MockProvider {
+            rates: HashMap::new(),
+        })];
+        let mut dates: ExchangeRates =
+            HashMap::from([(Exchange::USD("03/01/21".to_owned()), None)]);
+        assert_eq!(
+            fill_exchange_rates(&providers, &mut dates),
+            Err("Error: exchange rates not found for: USD(\"03/01/21\")".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_file_rate_provider() -> Result<(), String> {
+        let rates = crate::rates::read_exchange_rates_file("data/exchange_rates.csv")?;
+        let mut dates: ExchangeRates = HashMap::from([
+            (Exchange::USD("03/02/00".to_owned()), None),
+            (Exchange::EUR("03/03/00".to_owned()), None),
+        ]);
+        FileRateProvider::with_rates(rates, previous_day).fill_exchange_rates(&mut dates)?;
+        assert_eq!(
+            dates[&Exchange::USD("03/02/00".to_owned())],
+            Some(("2000-03-01".to_owned(), 3.8855))
+        );
+        assert_eq!(dates[&Exchange::EUR("03/03/00".to_owned())], None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_exchange_rates_org() -> Result<(), String> {
+        let body = "<span>1 USD</span> = <span>0.9368</span> EUR ... USD to EUR on 2023-2-20</td>";
+        assert_eq!(
+            parse_exchange_rates_org(body, "USD", "EUR")?,
+            (0.9368, "2023-02-20".to_owned())
+        );
+        assert!(parse_exchange_rates_org(body, "EUR", "USD").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ecb_rate_provider_fake_server() -> Result<(), String> {
+        let server = crate::fake_http_server::FakeHttpServer::start(vec![
+            crate::fake_http_server::FakeResponse {
+                path_prefix: "/service/data/EXR/D.USD.EUR.SP00.A".to_owned(),
+                status: 200,
+                content_type: "application/vnd.sdmx.genericdata+xml;version=2.1".to_owned(),
+                body: include_str!("../data/ecb_example_response.xml").to_owned(),
+                ..Default::default()
+            },
+        ]);
+        let providers: Vec<Box<dyn ExchangeRateProvider>> =
+            vec![Box::new(EcbRateProvider::with_base_url(
+                &format!("{}/service/data/EXR/D.USD.EUR.SP00.A", server.url),
+                previous_business_day,
+            ))];
+        let mut dates: ExchangeRates =
+            HashMap::from([(Exchange::USD("07/14/23".to_owned()), None)]);
+        fill_exchange_rates(&providers, &mut dates)?;
+        assert_eq!(
+            dates[&Exchange::USD("07/14/23".to_owned())],
+            Some(("2023-07-13".to_owned(), 0.89429444))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ecb_rate_provider_holiday() -> Result<(), String> {
+        let server = crate::fake_http_server::FakeHttpServer::start(vec![
+            crate::fake_http_server::FakeResponse {
+                path_prefix: "/service/data/EXR/D.USD.EUR.SP00.A".to_owned(),
+                status: 200,
+                content_type: "application/vnd.sdmx.genericdata+xml;version=2.1".to_owned(),
+                body: include_str!("../data/ecb_example_range_response.xml").to_owned(),
+                ..Default::default()
+            },
+        ]);
+        let provider = EcbRateProvider::with_base_url(
+            &format!("{}/service/data/EXR/D.USD.EUR.SP00.A", server.url),
+            previous_business_day,
+        );
+        // Business day preceding settlement is 2023-12-26, a TARGET holiday as is the day
+        // before it, so rate of Friday (2023-12-22) is used
+        let mut dates: ExchangeRates =
+            HashMap::from([(Exchange::USD("12/27/23".to_owned()), None)]);
+        provider.fill_exchange_rates(&mut dates)?;
+        assert_eq!(
+            dates[&Exchange::USD("12/27/23".to_owned())],
+            Some(("2023-12-22".to_owned(), 1.0 / 1.1023))
+        );
+        assert_eq!(
+            server.requests(),
+            vec!["/service/data/EXR/D.USD.EUR.SP00.A?startPeriod=2023-12-19&endPeriod=2023-12-26"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_exchange_rates_org_provider_fake_server() -> Result<(), String> {
+        let server = crate::fake_http_server::FakeHttpServer::start(vec![
+            crate::fake_http_server::FakeResponse {
+                path_prefix: "/Rate/usd/eur/".to_owned(),
+                status: 200,
+                content_type: "text/html".to_owned(),
+                body:
+                    "<span>1 USD</span> = <span>0.9368</span> EUR ... USD to EUR on 2023-2-20</td>"
+                        .to_owned(),
+                ..Default::default()
+            },
+        ]);
+        let provider = ExchangeRatesOrgProvider::with_base_url(
+            "EUR",
+            &format!("{}/Rate/", server.url),
+            previous_business_day,
+        );
+        let mut dates: ExchangeRates =
+            HashMap::from([(Exchange::USD("02/21/23".to_owned()), None)]);
+        provider.fill_exchange_rates(&mut dates)?;
+        assert_eq!(
+            dates[&Exchange::USD("02/21/23".to_owned())],
+            Some(("2023-02-20".to_owned(), 0.9368))
+        );
+        assert_eq!(
+            server.requests(),
+            vec!["/Rate/usd/eur/02-20-2023/?format=json"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_date_policies() -> Result<(), String> {
+        let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        // Monday
+        assert_eq!(same_day(&d("2023-02-20"))?, d("2023-02-20"));
+        assert_eq!(previous_day(&d("2023-02-20"))?, d("2023-02-19"));
+        assert_eq!(previous_business_day(&d("2023-02-20"))?, d("2023-02-17"));
+        assert_eq!(previous_business_day(&d("2023-02-21"))?, d("2023-02-20"));
+        assert_eq!(previous_business_day(&d("2023-02-19"))?, d("2023-02-17"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_date_rule_proceeds_date() {
+        let rule = RateDateRule {
+            sale_date: SaleDate::Trade,
+            rate_date: previous_day,
+        };
+        assert_eq!(rule.proceeds_date("03/01/21", "03/03/21"), "03/01/21");
+        let rule = RateDateRule {
+            sale_date: SaleDate::Settlement,
+            ..rule
+        };
+        assert_eq!(rule.proceeds_date("03/01/21", "03/03/21"), "03/03/21");
+    }
+}
@@ -0,0 +1,349 @@
+// SPDX-FileCopyrightText: 2025 RustInFinance
+// SPDX-License-Identifier: BSD-3-Clause
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use crate::Exchange;
+
+// When enabled no exchange rate is to be requested over the network. Rates are taken
+// only from bundled data and from user supplied rate files
+static OFFLINE_MODE: AtomicBool = AtomicBool::new(false);
+
+// Exchange rates loaded from user supplied files.
+// Key: Exchange::<currency>(rate date in "%Y-%m-%d" format)
+static USER_EXCHANGE_RATES: OnceLock<Mutex<HashMap<Exchange, f32>>> = OnceLock::new();
+
+// Default endpoints of exchange rates services. Can be overridden with environment
+// variables (e.g. to use local mirror or stand-in)
+pub const NBP_API_URL_VAR: &str = "NBP_API_URL";
+pub const ECB_API_URL_VAR: &str = "ECB_API_URL";
+pub const EXCHANGE_RATES_ORG_URL_VAR: &str = "EXCHANGE_RATES_ORG_URL";
+const NBP_API_URL: &str = "https://api.nbp.pl/api/exchangerates/rates/a/";
+const EXCHANGE_RATES_ORG_URL: &str = "https://www.exchange-rates.org/Rate/";
+
+// NBP (table A) exchange rates bundled with the program (output of gen_exchange_rates).
+// Embedded data can be replaced at runtime with a newer file
+const EMBEDDED_NBP_EXCHANGE_RATES: &str = include_str!("nbp_exchange_rates.csv");
+static NBP_EXCHANGE_RATES: OnceLock<HashMap<Exchange, f32>> = OnceLock::new();
+// Days on which NBP published exchange rates (derived from NBP exchange rates data)
+static NBP_PUBLICATION_DAYS: OnceLock<BTreeSet<chrono::NaiveDate>> = OnceLock::new();
+
+fn user_exchange_rates() -> &'static Mutex<HashMap<Exchange, f32>> {
+    USER_EXCHANGE_RATES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub fn set_offline_mode(offline: bool) {
+    log::info!("Offline mode: {offline}");
+    OFFLINE_MODE.store(offline, Ordering::Relaxed);
+}
+
+pub fn is_offline_mode() -> bool {
+    OFFLINE_MODE.load(Ordering::Relaxed)
+}
+
+fn url_from_env(var: &str, default: &str) -> String {
+    match std::env::var(var) {
+        Ok(url) if !url.is_empty() => {
+            log::info!("Using {var}: {url}");
+            url
+        }
+        _ => default.to_owned(),
+    }
+}
+
+/// NBP exchange rates (table A) API URL ($NBP_API_URL or https://api.nbp.pl/api/exchangerates/rates/a/)
+pub fn nbp_api_url() -> String {
+    url_from_env(NBP_API_URL_VAR, NBP_API_URL)
+}
+
+/// ECB USD/EUR exchange rates API URL ($ECB_API_URL or ECB data API)
+pub fn ecb_api_url() -> String {
+    url_from_env(ECB_API_URL_VAR, crate::ecb::ECB_URL)
+}
+
+/// exchange-rates.org URL ($EXCHANGE_RATES_ORG_URL or https://www.exchange-rates.org/Rate/)
+pub fn exchange_rates_org_url() -> String {
+    url_from_env(EXCHANGE_RATES_ORG_URL_VAR, EXCHANGE_RATES_ORG_URL)
+}
+
+/// Exchange with the same currency as given one, but with other date
+pub fn exchange_with_date(exchange: &Exchange, date: String) -> Exchange {
+    match exchange {
+        Exchange::USD(_) => Exchange::USD(date),
+        Exchange::EUR(_) => Exchange::EUR(date),
+        Exchange::PLN(_) => Exchange::PLN(date),
+    }
+}
+
+pub fn currency_code(exchange: &Exchange) -> &'static str {
+    match exchange {
+        Exchange::USD(_) => "USD",
+        Exchange::EUR(_) => "EUR",
+        Exchange::PLN(_) => "PLN",
+    }
+}
+
+/// Read exchange rates from CSV file in a form:
+/// currency,date,rate
+/// USD,2024-03-01,3.9877
+/// where date is a day the rate was published (%Y-%m-%d) and rate is a value of one unit
+/// of currency expressed in currency of residency (e.g. PLN for PL)
+pub fn read_exchange_rates_file(path: &str) -> Result<HashMap<Exchange, f32>, String> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| format!("Error: opening exchange rates file {path}: {e}"))?;
+    Ok(parse_exchange_rates_csv(file, path)?.into_iter().collect())
+}
+
+/// Load exchange rates file (see read_exchange_rates_file) to be used by FileRateProvider.
+/// Returns number of loaded rates
+pub fn load_exchange_rates_file(path: &str) -> Result<usize, String> {
+    let rates = read_exchange_rates_file(path)?;
+
+    let count = rates.len();
+    log::info!("Loaded {count} exchange rates from: {path}");
+    user_exchange_rates()
+        .lock()
+        .map_err(|_| "Error: unable to access user exchange rates")?
+        .extend(rates);
+    Ok(count)
+}
+
+fn parse_exchange_rates_csv<R: std::io::Read>(
+    reader: R,
+    origin: &str,
+) -> Result<Vec<(Exchange, f32)>, String> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .comment(Some(b'#'))
+        .trim(csv::Trim::All)
+        .from_reader(reader);
+
+    let mut rates: Vec<(Exchange, f32)> = vec![];
+    for record in rdr.records() {
+        let record =
+            record.map_err(|e| format!("Error: reading exchange rates file {origin}: {e}"))?;
+        let (currency, date, rate) = match (record.get(0), record.get(1), record.get(2)) {
+            (Some(c), Some(d), Some(r)) => (c, d, r),
+            _ => {
+                return Err(format!(
+                    "Error: ill formed exchange rate entry: {:?}",
+                    record
+                ))
+            }
+        };
+        let date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("Error: unable to parse exchange rate date: {date}"))?
+            .format("%Y-%m-%d")
+            .to_string();
+        let rate = rate
+            .parse::<f32>()
+            .map_err(|_| format!("Error: unable to parse exchange rate: {rate}"))?;
+        let exchange = match currency.to_uppercase().as_str() {
+            "USD" => Exchange::USD(date),
+            "EUR" => Exchange::EUR(date),
+            "PLN" => Exchange::PLN(date),
+            _ => return Err(format!("Error: unsupported currency: {currency}")),
+        };
+        rates.push((exchange, rate));
+    }
+    Ok(rates)
+}
+
+/// Use given file (same format as exchange rates file) instead of NBP exchange rates
+/// embedded in the program. Has to be called before first use of NBP exchange rates.
+/// File is parsed right away, so that ill formed file is reported before taxes are computed
+pub fn set_nbp_exchange_rates_file(path: &str) -> Result<(), String> {
+    if NBP_EXCHANGE_RATES.get().is_some() {
+        return Err("Error: NBP exchange rates are already loaded".to_owned());
+    }
+    let file = std::fs::File::open(path)
+        .map_err(|e| format!("Error: opening NBP exchange rates file {path}: {e}"))?;
+    let rates = parse_exchange_rates_csv(file, path)?;
+    if rates.is_empty() {
+        return Err(format!(
+            "Error: no exchange rates in NBP exchange rates file: {path}"
+        ));
+    }
+    log::info!("Loaded {} NBP exchange rates from: {path}", rates.len());
+    NBP_EXCHANGE_RATES
+        .set(rates.into_iter().collect())
+        .map_err(|_| "Error: NBP exchange rates are already loaded".to_owned())
+}
+
+fn load_nbp_exchange_rates() -> HashMap<Exchange, f32> {
+    let rates = parse_exchange_rates_csv(EMBEDDED_NBP_EXCHANGE_RATES.as_bytes(), "embedded");
+    match rates {
+        Ok(rates) => {
+            log::info!("Loaded {} NBP exchange rates", rates.len());
+            rates.into_iter().collect()
+        }
+        Err(e) => {
+            log::error!("NBP exchange rates could not be loaded: {e}");
+            HashMap::new()
+        }
+    }
+}
+
+/// NBP exchange rate (for a given currency and rate date) bundled with the program
+/// (or loaded from file set with set_nbp_exchange_rates_file)
+pub fn get_nbp_exchange_rate(exchange: &Exchange) -> Option<f32> {
+    NBP_EXCHANGE_RATES
+        .get_or_init(load_nbp_exchange_rates)
+        .get(exchange)
+        .copied()
+}
+
+fn nbp_publication_days() -> BTreeSet<chrono::NaiveDate> {
+    let days: BTreeSet<chrono::NaiveDate> = NBP_EXCHANGE_RATES
+        .get_or_init(load_nbp_exchange_rates)
+        .keys()
+        .filter_map(|exchange| match exchange {
+            Exchange::USD(date) | Exchange::EUR(date) | Exchange::PLN(date) => {
+                chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+            }
+        })
+        .collect();
+    log::info!("NBP publication calendar: {} days", days.len());
+    days
+}
+
+// Whether day is one of publication days. None if day is out of range of the calendar
+fn is_publication_day(
+    days: &BTreeSet<chrono::NaiveDate>,
+    date: &chrono::NaiveDate,
+) -> Option<bool> {
+    match (days.first(), days.last()) {
+        (Some(first), Some(last)) if first <= date && date <= last => Some(days.contains(date)),
+        _ => None,
+    }
+}
+
+/// Whether NBP published exchange rates (table A) on a given day according to NBP exchange
+/// rates data (bundled with the program or set with set_nbp_exchange_rates_file).
+/// None if day is not covered by the data
+pub fn is_nbp_publication_day(date: &chrono::NaiveDate) -> Option<bool> {
+    is_publication_day(NBP_PUBLICATION_DAYS.get_or_init(nbp_publication_days), date)
+}
+
+/// Exchange rate (for a given currency and rate date) loaded from user supplied files
+pub fn get_user_exchange_rate(exchange: &Exchange) -> Option<f32> {
+    user_exchange_rates()
+        .lock()
+        .ok()
+        .and_then(|rates| rates.get(exchange).copied())
+}
+
+/// Error message listing exchange rates (currency, rate date) that could not be found
+/// without going online
+pub fn format_missing_exchange_rates(missing: &[(String, String)]) -> String {
+    let mut missing = missing.to_vec();
+    missing.sort();
+    missing.dedup();
+    let list = missing
+        .iter()
+        .map(|(currency, date)| format!("\t{currency} {date}"))
+        .collect::<Vec<String>>()
+        .join("\n");
+    format!("Offline mode is enabled and following exchange rates (currency, date) are missing:\n{list}\nPlease provide them in exchange rates file (columns: currency,date,rate)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_publication_day() {
+        let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        // No publication on ad hoc day off (2018-11-12)
+        let days = BTreeSet::from([d("2018-11-08"), d("2018-11-09"), d("2018-11-13")]);
+        assert_eq!(is_publication_day(&days, &d("2018-11-09")), Some(true));
+        assert_eq!(is_publication_day(&days, &d("2018-11-12")), Some(false));
+        assert_eq!(is_publication_day(&days, &d("2018-11-07")), None);
+        assert_eq!(is_publication_day(&days, &d("2018-11-14")), None);
+        assert_eq!(is_publication_day(&BTreeSet::new(), &d("2018-11-09")), None);
+    }
+
+    #[test]
+    fn test_is_nbp_publication_day() {
+        let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        assert_eq!(is_nbp_publication_day(&d("2024-02-23")), Some(true));
+        // Saturday
+        assert_eq!(is_nbp_publication_day(&d("2024-02-24")), Some(false));
+        // Before bundled data
+        assert_eq!(is_nbp_publication_day(&d("2005-05-04")), None);
+    }
+
+    #[test]
+    fn test_read_exchange_rates_file() -> Result<(), String> {
+        let rates = read_exchange_rates_file("data/exchange_rates.csv")?;
+        assert_eq!(rates.len(), 3);
+        assert_eq!(
+            rates.get(&Exchange::USD("2000-03-01".to_owned())),
+            Some(&3.8855)
+        );
+        assert_eq!(
+            rates.get(&Exchange::EUR("2000-03-01".to_owned())),
+            Some(&4.2246)
+        );
+        assert_eq!(rates.get(&Exchange::USD("2000-03-02".to_owned())), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_embedded_nbp_exchange_rates() -> Result<(), String> {
+        let rates = parse_exchange_rates_csv(EMBEDDED_NBP_EXCHANGE_RATES.as_bytes(), "embedded")?;
+        assert!(rates.len() > 4000);
+        assert_eq!(
+            get_nbp_exchange_rate(&Exchange::USD("2021-02-26".to_owned())),
+            Some(3.7247)
+        );
+        assert_eq!(
+            get_nbp_exchange_rate(&Exchange::EUR("2023-09-22".to_owned())),
+            Some(4.6069)
+        );
+        assert_eq!(
+            get_nbp_exchange_rate(&Exchange::USD("2000-02-25".to_owned())),
+            None
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_api_urls() {
+        assert_eq!(
+            url_from_env("ETRADE_TAX_RETURN_HELPER_TEST_NO_SUCH_URL", NBP_API_URL),
+            NBP_API_URL
+        );
+        if std::env::var(NBP_API_URL_VAR).is_err() {
+            assert_eq!(nbp_api_url(), NBP_API_URL);
+        }
+    }
+
+    #[test]
+    fn test_load_exchange_rates_file_fail() {
+        assert!(read_exchange_rates_file("data/no_such_file.csv").is_err());
+        assert!(load_exchange_rates_file("data/no_such_file.csv").is_err());
+    }
+
+    #[test]
+    fn test_set_nbp_exchange_rates_file_fail() {
+        assert!(set_nbp_exchange_rates_file("data/no_such_file.csv").is_err());
+        // Not a CSV file of exchange rates
+        assert!(set_nbp_exchange_rates_file("data/ecb_example_response.xml").is_err());
+    }
+
+    #[test]
+    fn test_format_missing_exchange_rates() {
+        let missing = vec![
+            ("USD".to_owned(), "2024-03-01".to_owned()),
+            ("EUR".to_owned(), "2024-02-29".to_owned()),
+            ("USD".to_owned(), "2024-03-01".to_owned()),
+        ];
+        assert_eq!(
+            format_missing_exchange_rates(&missing),
+            "Offline mode is enabled and following exchange rates (currency, date) are missing:\n\tEUR 2024-02-29\n\tUSD 2024-03-01\nPlease provide them in exchange rates file (columns: currency,date,rate)"
+        );
+    }
+}
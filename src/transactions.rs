@@ -7,13 +7,164 @@ use polars::prelude::*;
 use std::collections::HashMap;
 
 pub use crate::logging::ResultExt;
-use crate::{SoldTransaction, Transaction};
+use crate::{
+    Account, EsppCostBasis, EsppPolicy, EsppPurchase, Form1042S, Form1099BLot, Holding, LotDetails,
+    PlanType, SoldTransaction, TaxWithholdingAdjustment, TaxYearRule, TradeAction,
+    TradeConfirmation, Transaction, VestEvent,
+};
+
+// Sold shares (from PDF): trade_date, settlement_date, quantity, price, amount_sold, company
+type PdfSoldTransaction = (String, String, f32, f32, f32, Option<String>);
+// Sold shares with acquisition: trade_date, settlement_date, acquisition_date, income,
+// cost basis, company, lot details
+type DetailedSoldTransaction = (String, String, String, f32, f32, Option<String>, LotDetails);
+// Totals of account: number of transactions, gross, tax paid, cost and dates of transactions
+type AccountTotals = (u32, f32, f32, f32, Vec<chrono::NaiveDate>);
+// Interests: transaction date, gross_us, tax_us, account they were paid to
+type InterestsTransaction = (String, f32, f32, Option<Account>);
+// Sold shares of account statement: account holding them and sold shares
+pub(crate) type StatementSoldTransaction = (Account, PdfSoldTransaction);
+// Holdings of the same security on the same account: (account, company) -> dates of
+// statements and holdings
+type Positions<'a> = std::collections::BTreeMap<
+    (Option<&'a Account>, &'a str),
+    Vec<(chrono::NaiveDate, &'a Holding)>,
+>;
+
+/// Number of days shares were held: from acquisition date up to trade (sell) date
+fn compute_holding_days(acquisition_date: &str, trade_date: &str) -> i64 {
+    let acquired = chrono::NaiveDate::parse_from_str(acquisition_date, "%m/%d/%y").expect_and_log(
+        &format!("Unable to parse acquisition date: {acquisition_date}"),
+    );
+    let traded = chrono::NaiveDate::parse_from_str(trade_date, "%m/%d/%y")
+        .expect_and_log(&format!("Unable to parse trade date: {trade_date}"));
+    (traded - acquired).num_days()
+}
+
+/// Adjustments of withheld tax are applied to dividends they correct: dividend of the same
+/// security paid on pay date referred by adjustment or (if not referred) the latest one paid
+/// before adjustment. Refund can not exceed tax withheld. Returned are report of adjusted
+/// dividends and adjustments that could not be matched (they are not taken into account)
+pub fn apply_tax_withholding_adjustments(
+    div_transactions: &mut [(String, f32, f32, Option<String>)],
+    adjustments: &[TaxWithholdingAdjustment],
+) -> (Vec<String>, Vec<String>) {
+    const TOLERANCE: f32 = 0.001;
+    let parse_date = |date: &str| chrono::NaiveDate::parse_from_str(date, "%m/%d/%y").ok();
+    let mut adjusted = vec![];
+    let mut unmatched = vec![];
+    for adjustment in adjustments {
+        let adjustment_date = parse_date(&adjustment.date);
+        let pay_date = adjustment.pay_date.as_deref().and_then(parse_date);
+        let dividend = div_transactions
+            .iter_mut()
+            .filter_map(|dividend| parse_date(&dividend.0).map(|date| (date, dividend)))
+            .filter(|(date, (_, _, tax_us, company))| {
+                let paid = match pay_date {
+                    Some(pay_date) => *date == pay_date,
+                    None => adjustment_date.is_some_and(|adjusted| *date <= adjusted),
+                };
+                paid && *company == adjustment.company && *tax_us + TOLERANCE >= adjustment.amount
+            })
+            .max_by_key(|(date, _)| *date)
+            .map(|(_, dividend)| dividend);
+        match dividend {
+            Some((transaction_date, gross_us, tax_us, company)) => {
+                adjusted.push(format!(
+                    "TAX ADJUSTMENT of {} (${}) applied to dividend of {} paid on {transaction_date} (gross: ${gross_us}): tax withheld ${} -> ${}",
+                    adjustment.date,
+                    adjustment.amount,
+                    company.as_deref().unwrap_or("N/A"),
+                    tax_us,
+                    *tax_us - adjustment.amount,
+                ));
+                *tax_us -= adjustment.amount;
+            }
+            None => unmatched.push(format!(
+                "Tax withholding adjustment of {} (company: {}, pay date: {}, amount: ${}) has no matching dividend and it is not taken into account",
+                adjustment.date,
+                adjustment.company.as_deref().unwrap_or("N/A"),
+                adjustment.pay_date.as_deref().unwrap_or("N/A"),
+                adjustment.amount,
+            )),
+        }
+    }
+    (adjusted, unmatched)
+}
+
+/// Interests reportable for the prior year (paid in January) are taken out of interests of
+/// the year they were received in when residency attributes income to the year it is
+/// reportable for. Returned are interests (transaction date, gross_us, tax_us) of the year
+/// they were received in and interests reportable for the prior year (dated last day of
+/// prior year), both paid to account of statement
+pub fn attribute_interests_to_tax_year(
+    interests: Vec<(String, f32, f32, bool)>,
+    rule: TaxYearRule,
+    account: &Account,
+) -> (Vec<InterestsTransaction>, Vec<InterestsTransaction>) {
+    let mut prior_year_interests = vec![];
+    let mut received_interests = vec![];
+    interests
+        .into_iter()
+        .for_each(|(transaction_date, gross_us, tax_us, prior_year)| {
+            let reportable_date = chrono::NaiveDate::parse_from_str(&transaction_date, "%m/%d/%y")
+                .ok()
+                .and_then(|d| chrono::NaiveDate::from_ymd_opt(d.year() - 1, 12, 31));
+            match (prior_year, rule, reportable_date) {
+                (true, TaxYearRule::ReportableYear, Some(reportable_date)) => {
+                    log::info!("Interests of {transaction_date} are reportable for the prior year");
+                    prior_year_interests.push((
+                        reportable_date.format("%m/%d/%y").to_string(),
+                        gross_us,
+                        tax_us,
+                        Some(account.clone()),
+                    ))
+                }
+                (true, _, _) => {
+                    log::info!("Interests of {transaction_date} reportable for the prior year are taxed in year they were received");
+                    received_interests.push((transaction_date, gross_us, tax_us, Some(account.clone())))
+                }
+                (false, _, _) => received_interests.push((
+                    transaction_date,
+                    gross_us,
+                    tax_us,
+                    Some(account.clone()),
+                )),
+            }
+        });
+    (received_interests, prior_year_interests)
+}
+
+/// Interests reportable for the prior year are taxed with interests of tax year, unless they
+/// are reportable for the year before it (paid in January of tax year). Tax year is the latest
+/// year interests are reportable for. Returned are interests reportable for the prior year
+/// that belong to tax year and those that belong to the year before it
+pub fn split_prior_year_interests(
+    interests: &[InterestsTransaction],
+    prior_year_interests: Vec<InterestsTransaction>,
+) -> (Vec<InterestsTransaction>, Vec<InterestsTransaction>) {
+    let year = |date: &str| {
+        chrono::NaiveDate::parse_from_str(date, "%m/%d/%y")
+            .ok()
+            .map(|d| d.year())
+    };
+    let tax_year = interests
+        .iter()
+        .chain(prior_year_interests.iter())
+        .filter_map(|(date, _, _, _)| year(date))
+        .max();
+    prior_year_interests
+        .into_iter()
+        .partition(|(date, _, _, _)| year(date) == tax_year)
+}
 
 /// Check if all interests rate transactions come from the same year
-pub fn verify_interests_transactions<T>(transactions: &[(String, T, T)]) -> Result<(), String> {
+pub fn verify_interests_transactions<T>(
+    transactions: &[(String, T, T, Option<Account>)],
+) -> Result<(), String> {
     let mut trans = transactions.iter();
     let transaction_date = match trans.next() {
-        Some((x, _, _)) => x,
+        Some((x, _, _, _)) => x,
         None => {
             log::info!("No interests transactions");
             return Ok(());
@@ -24,7 +175,7 @@ pub fn verify_interests_transactions<T>(transactions: &[(String, T, T)]) -> Resu
         .map_err(|_| format!("Unable to parse transaction date: \"{transaction_date}\""))?
         .year();
     let mut verification: Result<(), String> = Ok(());
-    trans.try_for_each(|(tr_date, _, _)| {
+    trans.try_for_each(|(tr_date, _, _, _)| {
         let tr_year = chrono::NaiveDate::parse_from_str(tr_date, "%m/%d/%y")
             .map_err(|_| format!("Unable to parse transaction date: \"{tr_date}\""))?
             .year();
@@ -68,11 +219,11 @@ pub fn verify_dividends_transactions<T>(
 }
 
 pub fn verify_transactions<T>(
-    transactions: &[(String, String, T, T, Option<String>)],
+    transactions: &[(String, String, T, T, Option<String>, LotDetails)],
 ) -> Result<(), String> {
     let mut trans = transactions.iter();
     let transaction_date = match trans.next() {
-        Some((_, x, _, _, _)) => x,
+        Some((_, x, _, _, _, _)) => x,
         None => {
             log::info!("No revolut sold transactions");
             return Ok(());
@@ -83,7 +234,7 @@ pub fn verify_transactions<T>(
         .map_err(|_| format!("Unable to parse transaction date: \"{transaction_date}\""))?
         .year();
     let mut verification: Result<(), String> = Ok(());
-    trans.try_for_each(|(_, tr_date, _, _, _)| {
+    trans.try_for_each(|(_, tr_date, _, _, _, _)| {
         let tr_year = chrono::NaiveDate::parse_from_str(tr_date, "%m/%d/%y")
             .map_err(|_| format!("Unable to parse transaction date: \"{tr_date}\""))?
             .year();
@@ -108,8 +259,11 @@ pub fn verify_transactions<T>(
 
 pub fn reconstruct_sold_transactions(
     sold_transactions: &Vec<(String, String, f32, f32, f32, Option<String>)>,
-    gains_and_losses: &Vec<(String, String, f32, f32, f32)>,
-) -> Result<Vec<(String, String, String, f32, f32, Option<String>)>, String> {
+    gains_and_losses: &Vec<(String, String, f32, f32, f32, LotDetails)>,
+    vest_events: &[VestEvent],
+    espp_purchases: &[EsppPurchase],
+    espp_policy: EsppPolicy,
+) -> Result<Vec<DetailedSoldTransaction>, String> {
     // Ok What do I need.
     // 1. trade date
     // 2. settlement date
@@ -117,16 +271,27 @@ pub fn reconstruct_sold_transactions(
     // 4. gross income
     // 5. cost cost basis
     // 6. company symbol (ticker)
-    let mut detailed_sold_transactions: Vec<(String, String, String, f32, f32, Option<String>)> =
-        vec![];
-
-    if sold_transactions.len() > 0 && gains_and_losses.is_empty() {
+    // 7. details of sold lot (quantity, plan type, grant date...)
+    let mut detailed_sold_transactions: Vec<DetailedSoldTransaction> = vec![];
+
+    if !sold_transactions.is_empty()
+        && gains_and_losses.is_empty()
+        && vest_events.is_empty()
+        && espp_purchases.is_empty()
+    {
         return Err("\n\nERROR: Sold transaction detected, but corressponding Gain&Losses document is missing. Please download Gain&Losses  XLSX document at:\n
             https://us.etrade.com/etx/sp/stockplan#/myAccount/gainsLosses\n\n".to_string());
     }
 
     // iterate through all sold transactions and update it with needed info
-    for (acquisition_date, tr_date, cost_basis, _, inc) in gains_and_losses {
+    for (acquisition_date, tr_date, acquisition_cost, adjusted_cost_basis, inc, lot) in
+        gains_and_losses
+    {
+        // Cost basis of ESPP shares is adjusted by discount when it is taxed as income
+        let cost_basis = match (&lot.plan_type, espp_policy.cost_basis) {
+            (Some(PlanType::ESPP), EsppCostBasis::MarketValue) => adjusted_cost_basis,
+            _ => acquisition_cost,
+        };
         // match trade date and gross with principal and trade date of  trade confirmation
 
         log::info!("Reconstructing G&L sold transaction: trade date: {tr_date}, acquisition date: {acquisition_date}, cost basis: {cost_basis}, income: {inc}");
@@ -155,12 +320,426 @@ pub fn reconstruct_sold_transactions(
             *inc,
             *cost_basis,
             symbol.clone(),
+            LotDetails {
+                grant_date: lot.grant_date.as_ref().map(|grant_date| {
+                    chrono::NaiveDate::parse_from_str(grant_date, "%m/%d/%Y")
+                        .map(|d| d.format("%m/%d/%y").to_string())
+                        .unwrap_or(grant_date.clone())
+                }),
+                ..lot.clone()
+            },
         ));
     }
 
+    // Sold transactions without G&L rows get cost basis from RSU release and ESPP purchase
+    // confirmations
+    if !vest_events.is_empty() || !espp_purchases.is_empty() {
+        let not_in_gains_and_losses: Vec<&PdfSoldTransaction> = sold_transactions
+            .iter()
+            .filter(|(trade_dt, _, _, _, _, _)| {
+                !gains_and_losses.iter().any(|(_, tr_date, _, _, _, _)| {
+                    chrono::NaiveDate::parse_from_str(tr_date, "%m/%d/%Y").ok()
+                        == chrono::NaiveDate::parse_from_str(trade_dt, "%m/%d/%y").ok()
+                })
+            })
+            .collect();
+        let mut lots = vest_events
+            .iter()
+            .map(|v| AcquiredLot {
+                acquisition_date: v.vest_date.clone(),
+                company: v.company.clone(),
+                symbol: v.symbol.clone(),
+                available: v.shares_issued(),
+                cost_basis_per_share: v.fmv,
+                plan_type: PlanType::RS,
+            })
+            .chain(espp_purchases.iter().map(|p| AcquiredLot {
+                acquisition_date: p.purchase_date.clone(),
+                company: p.company.clone(),
+                symbol: p.symbol.clone(),
+                available: p.shares_purchased,
+                cost_basis_per_share: p.cost_basis_per_share(espp_policy),
+                plan_type: PlanType::ESPP,
+            }))
+            .collect::<Vec<AcquiredLot>>();
+        detailed_sold_transactions.append(&mut reconstruct_sold_transactions_from_lots(
+            &not_in_gains_and_losses,
+            &mut lots,
+        )?);
+    }
+
+    Ok(detailed_sold_transactions)
+}
+
+// Shares acquired from RSU release or ESPP purchase
+struct AcquiredLot {
+    acquisition_date: String,
+    company: Option<String>,
+    symbol: Option<String>,
+    // Shares not sold yet
+    available: f32,
+    cost_basis_per_share: f32,
+    plan_type: PlanType,
+}
+
+impl AcquiredLot {
+    fn is_of_company(&self, company: &Option<String>) -> bool {
+        match company {
+            Some(c) => self.company.as_ref() == Some(c) || self.symbol.as_ref() == Some(c),
+            None => true,
+        }
+    }
+}
+
+/// Cost basis of sold RSU shares is their fair market value at release, of ESPP shares it
+/// depends on residency. Acquired shares (of the same company) are sold first-in first-out
+fn reconstruct_sold_transactions_from_lots(
+    sold_transactions: &[&PdfSoldTransaction],
+    lots: &mut [AcquiredLot],
+) -> Result<Vec<DetailedSoldTransaction>, String> {
+    let parse_date = |date: &str| {
+        chrono::NaiveDate::parse_from_str(date, "%m/%d/%y")
+            .map_err(|_| format!("Unable to parse date: {date}"))
+    };
+    lots.iter()
+        .try_for_each(|l| parse_date(&l.acquisition_date).map(|_| ()))?;
+    lots.sort_by_key(|l| parse_date(&l.acquisition_date).ok());
+    let mut sales = sold_transactions.to_vec();
+    sales.sort_by_key(|(trade_date, _, _, _, _, _)| parse_date(trade_date).ok());
+
+    let mut detailed_sold_transactions = vec![];
+    for (trade_date, settlement_date, quantity, price, amount_sold, symbol) in sales {
+        let sold_on = parse_date(trade_date)?;
+        let mut remaining = *quantity;
+        for lot in lots.iter_mut() {
+            if remaining <= 0.0 {
+                break;
+            }
+            if parse_date(&lot.acquisition_date)? > sold_on
+                || lot.available <= 0.0
+                || !lot.is_of_company(symbol)
+            {
+                continue;
+            }
+            let sold_quantity = remaining.min(lot.available);
+            lot.available -= sold_quantity;
+            remaining -= sold_quantity;
+            log::info!(
+                "Sold transaction: trade date: {trade_date}, quantity: {sold_quantity} matched with {} shares acquired: {}",
+                lot.plan_type,
+                lot.acquisition_date
+            );
+            detailed_sold_transactions.push((
+                trade_date.clone(),
+                settlement_date.clone(),
+                lot.acquisition_date.clone(),
+                amount_sold * sold_quantity / quantity,
+                sold_quantity * lot.cost_basis_per_share,
+                symbol.clone(),
+                LotDetails {
+                    quantity: Some(sold_quantity),
+                    plan_type: Some(lot.plan_type.clone()),
+                    cost_basis_per_share: Some(lot.cost_basis_per_share),
+                    proceeds_per_share: Some(*price),
+                    ..Default::default()
+                },
+            ));
+        }
+        if remaining > 0.001 {
+            return Err(format!("\n\nERROR: Sold transaction (trade_date: {trade_date}, quantity: {quantity}, company: {symbol:?}) is not covered by Gain&Losses document nor by release/purchase confirmations. Please download Gain&Losses  XLSX document at:\n
+            https://us.etrade.com/etx/sp/stockplan#/myAccount/gainsLosses\n\n"));
+        }
+    }
     Ok(detailed_sold_transactions)
 }
 
+/// Sold lots of Form 1099-B come with acquisition date and cost basis, so they are used
+/// instead of account statements and G&L. 1099-B does not report settlement date, so date
+/// sold is used as settlement date
+pub fn reconstruct_sold_transactions_from_1099b(
+    lots: &[Form1099BLot],
+) -> Result<Vec<DetailedSoldTransaction>, String> {
+    lots.iter()
+        .map(|lot| {
+            let acquisition_date = lot.date_acquired.clone().ok_or(format!(
+                "\n\nERROR: Lot of Form 1099-B (date sold: {}, quantity: {}, company: {:?}) has various acquisition dates. Please provide account statements and Gain&Losses document instead\n\n",
+                lot.date_sold, lot.quantity, lot.company
+            ))?;
+            log::info!("Reconstructing 1099-B sold transaction: trade date: {}, acquisition date: {acquisition_date}, cost basis: {}, income: {}", lot.date_sold, lot.cost_basis, lot.proceeds);
+            Ok((
+                lot.date_sold.clone(),
+                lot.date_sold.clone(),
+                acquisition_date,
+                lot.proceeds,
+                lot.cost_basis,
+                lot.company.clone().or(lot.symbol.clone()),
+                LotDetails {
+                    quantity: Some(lot.quantity),
+                    cost_basis_per_share: Some(lot.cost_basis / lot.quantity),
+                    proceeds_per_share: Some(lot.proceeds / lot.quantity),
+                    ..Default::default()
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Cross-check sell trade confirmations with SOLD entries of account statements and
+/// G&L rows of the same trade date. Returns description of found discrepancies
+pub fn verify_trade_confirmations(
+    trade_confirmations: &[TradeConfirmation],
+    sold_transactions: &[PdfSoldTransaction],
+    gains_and_losses: &[(String, String, f32, f32, f32, LotDetails)],
+) -> Vec<String> {
+    const TOLERANCE: f32 = 0.01;
+    let mut discrepancies = vec![];
+    for trade in trade_confirmations
+        .iter()
+        .filter(|t| t.action == TradeAction::Sell)
+    {
+        let trade_date = chrono::NaiveDate::parse_from_str(&trade.trade_date, "%m/%d/%y").ok();
+        let sold: Vec<&PdfSoldTransaction> = sold_transactions
+            .iter()
+            .filter(|(trade_dt, _, _, _, _, symbol)| {
+                *trade_dt == trade.trade_date && trade.is_of_company(symbol)
+            })
+            .collect();
+        if sold.is_empty() {
+            discrepancies.push(format!(
+                "Trade confirmation of {} has no corresponding SOLD entry in account statements",
+                trade.trade_date
+            ));
+        } else {
+            let quantity: f32 = sold.iter().map(|(_, _, q, _, _, _)| q).sum();
+            let amount: f32 = sold.iter().map(|(_, _, _, _, a, _)| a).sum();
+            if (quantity - trade.quantity).abs() > TOLERANCE
+                || (amount - trade.net_amount).abs() > TOLERANCE
+            {
+                discrepancies.push(format!(
+                    "Trade confirmation of {} (quantity: {}, net amount: {}) does not match SOLD entries of account statements (quantity: {quantity}, amount: {amount})",
+                    trade.trade_date, trade.quantity, trade.net_amount
+                ));
+            }
+        }
+        let rows: Vec<&(String, String, f32, f32, f32, LotDetails)> = gains_and_losses
+            .iter()
+            .filter(|(_, tr_date, _, _, _, _)| {
+                chrono::NaiveDate::parse_from_str(tr_date, "%m/%d/%Y").ok() == trade_date
+            })
+            .collect();
+        if !rows.is_empty() {
+            // G&L may report proceeds with or without commission and fees
+            let proceeds: f32 = rows.iter().map(|(_, _, _, _, p, _)| p).sum();
+            if (proceeds - trade.net_amount).abs() > TOLERANCE
+                && (proceeds - trade.principal).abs() > TOLERANCE
+            {
+                discrepancies.push(format!(
+                    "Trade confirmation of {} (principal: {}, net amount: {}) does not match Gain&Losses proceeds: {proceeds}",
+                    trade.trade_date, trade.principal, trade.net_amount
+                ));
+            }
+        }
+    }
+    discrepancies
+}
+
+/// Shares leave account only when they are sold, so drop of holdings between consecutive
+/// statements is expected to be covered by shares sold in that period. Sold shares are taken
+/// from Gain&Losses when provided and single security is held (G&L does not name securities),
+/// otherwise from SOLD entries of account statements (of the same account as holdings).
+/// Positions are tracked for each account separately
+pub fn reconcile_holdings(
+    holdings: &[Holding],
+    sold_transactions: &[StatementSoldTransaction],
+    gains_and_losses: &[(String, String, f32, f32, f32, LotDetails)],
+) -> Vec<String> {
+    const TOLERANCE: f32 = 0.001;
+    let mut positions: Positions = std::collections::BTreeMap::new();
+    holdings.iter().for_each(|holding| {
+        if let Ok(date) = chrono::NaiveDate::parse_from_str(&holding.date, "%m/%d/%y") {
+            positions
+                .entry((holding.account.as_ref(), holding.company.as_str()))
+                .or_default()
+                .push((date, holding));
+        }
+    });
+    let use_gains_and_losses = !gains_and_losses.is_empty() && positions.len() == 1;
+    let mut discrepancies = vec![];
+    for ((account, company), mut snapshots) in positions {
+        snapshots.sort_by_key(|(date, _)| *date);
+        // The same statement may be given more than once
+        snapshots.dedup_by_key(|(date, _)| *date);
+        for pair in snapshots.windows(2) {
+            let ((start, previous), (end, current)) = (pair[0], pair[1]);
+            let drop = previous.quantity - current.quantity;
+            if drop <= TOLERANCE {
+                continue;
+            }
+            let in_period = |date: Option<chrono::NaiveDate>| {
+                date.is_some_and(|date| date > start && date <= end)
+            };
+            let sold: f32 = if use_gains_and_losses {
+                gains_and_losses
+                    .iter()
+                    .filter(|(_, tr_date, _, _, _, _)| {
+                        in_period(chrono::NaiveDate::parse_from_str(tr_date, "%m/%d/%Y").ok())
+                    })
+                    .filter_map(|(_, _, _, _, _, lot)| lot.quantity)
+                    .sum()
+            } else {
+                sold_transactions
+                    .iter()
+                    .filter(|(sold_account, (trade_date, _, _, _, _, symbol))| {
+                        in_period(chrono::NaiveDate::parse_from_str(trade_date, "%m/%d/%y").ok())
+                            && current.is_of_company(symbol)
+                            && account.is_none_or(|account| account == sold_account)
+                    })
+                    // Sum of no quantities would be -0
+                    .fold(0.0, |total, (_, (_, _, quantity, _, _, _))| {
+                        total + quantity
+                    })
+            };
+            if sold + TOLERANCE < drop {
+                discrepancies.push(format!(
+                    "Holdings of {company} ({}) dropped by {drop} shares between {start} and {end}, but {sold} shares were sold in that period",
+                    account
+                        .map(|a| a.to_string())
+                        .unwrap_or("N/A".to_owned())
+                ));
+            }
+        }
+    }
+    discrepancies
+}
+
+/// Commission and fees of sale are deductible costs. Sold lots matching trade confirmations
+/// get (proportionally to their income) principal as income and commission and fees added
+/// to cost basis
+pub fn apply_trade_confirmations(
+    detailed_sold_transactions: &mut [DetailedSoldTransaction],
+    trade_confirmations: &[TradeConfirmation],
+) {
+    let sells: Vec<&TradeConfirmation> = trade_confirmations
+        .iter()
+        .filter(|t| t.action == TradeAction::Sell)
+        .collect();
+    // Lots sold by the same trades (several orders may be executed the same day)
+    let mut lots_of_trades: HashMap<Vec<usize>, Vec<usize>> = HashMap::new();
+    detailed_sold_transactions.iter().enumerate().for_each(
+        |(i, (trade_date, _, _, _, _, symbol, _))| {
+            let trades: Vec<usize> = sells
+                .iter()
+                .enumerate()
+                .filter(|(_, t)| t.trade_date == *trade_date && t.is_of_company(symbol))
+                .map(|(j, _)| j)
+                .collect();
+            if !trades.is_empty() {
+                lots_of_trades.entry(trades).or_default().push(i);
+            }
+        },
+    );
+    for (trades, lots) in lots_of_trades {
+        let principal: f32 = trades.iter().map(|&j| sells[j].principal).sum();
+        let costs: f32 = trades
+            .iter()
+            .map(|&j| sells[j].commission + sells[j].fees)
+            .sum();
+        let income: f32 = lots.iter().map(|&i| detailed_sold_transactions[i].3).sum();
+        if income <= 0.0 {
+            continue;
+        }
+        for i in lots {
+            let (trade_date, _, _, lot_income, cost_basis, _, _) =
+                &mut detailed_sold_transactions[i];
+            let share = *lot_income / income;
+            log::info!("Trade confirmation of {trade_date}: income: {lot_income} -> {}, cost basis: {cost_basis} -> {}", principal * share, *cost_basis + costs * share);
+            *lot_income = principal * share;
+            *cost_basis += costs * share;
+        }
+    }
+}
+
+/// Sold lots are held in account of statement reporting their sale (same trade date and
+/// company)
+pub fn apply_statement_accounts(
+    detailed_sold_transactions: &mut [DetailedSoldTransaction],
+    sold_transactions: &[StatementSoldTransaction],
+) {
+    detailed_sold_transactions
+        .iter_mut()
+        .for_each(|(trade_date, _, _, _, _, company, lot)| {
+            let account = sold_transactions
+                .iter()
+                .find(|(_, (trade_dt, _, _, _, _, symbol))| {
+                    trade_dt == trade_date && symbol == company
+                })
+                .map(|(account, _)| account.clone());
+            if account.is_some() {
+                log::info!("Sold transaction of {trade_date} held in account: {account:?}");
+                lot.account = account;
+            }
+        });
+}
+
+/// Compare totals of 1042-S forms with dividends (income code 06) and interests (income
+/// code 01) found in account statements. Returns report and found discrepancies
+pub fn reconcile_form_1042s(
+    forms: &[Form1042S],
+    interests: &[InterestsTransaction],
+    dividends: &[(String, f32, f32, Option<String>)],
+) -> (Vec<String>, Vec<String>) {
+    // 1042-S reports amounts in whole dollars
+    const TOLERANCE: f32 = 1.0;
+    let statements = [
+        (
+            "06",
+            "dividends",
+            dividends.iter().map(|(_, g, _, _)| g).sum::<f32>(),
+            dividends.iter().map(|(_, _, t, _)| t).sum::<f32>(),
+        ),
+        (
+            "01",
+            "interests",
+            interests.iter().map(|(_, g, _, _)| g).sum::<f32>(),
+            interests.iter().map(|(_, _, t, _)| t).sum::<f32>(),
+        ),
+    ];
+    let mut report = vec![];
+    let mut discrepancies = vec![];
+    for (income_code, name, gross, tax) in statements {
+        let reported: Vec<&Form1042S> = forms
+            .iter()
+            .filter(|f| {
+                f.income_code.trim_start_matches('0') == income_code.trim_start_matches('0')
+            })
+            .collect();
+        let form_gross: f32 = reported.iter().map(|f| f.gross_income).sum();
+        let form_tax: f32 = reported.iter().map(|f| f.tax_withheld).sum();
+        report.push(format!(
+            "1042-S income code {income_code} ({name}): gross income: ${form_gross:.2}, tax withheld: ${form_tax:.2}; account statements: gross income: ${gross:.2}, tax withheld: ${tax:.2}"
+        ));
+        if (form_gross - gross).abs() > TOLERANCE || (form_tax - tax).abs() > TOLERANCE {
+            discrepancies.push(format!(
+                "1042-S {name} (income code {income_code}) do not match account statements: gross income: ${form_gross:.2} vs ${gross:.2}, tax withheld: ${form_tax:.2} vs ${tax:.2}"
+            ));
+        }
+    }
+    forms
+        .iter()
+        .filter(|f| {
+            !statements
+                .iter()
+                .any(|(code, _, _, _)| f.income_code.trim_start_matches('0') == code.trim_start_matches('0'))
+        })
+        .for_each(|f| {
+            report.push(format!(
+                "1042-S income code {} (not reconciled): gross income: ${:.2}, tax withheld: ${:.2}",
+                f.income_code, f.gross_income, f.tax_withheld
+            ))
+        });
+    (report, discrepancies)
+}
+
 pub fn create_detailed_revolut_transactions(
     transactions: Vec<(String, crate::Currency, crate::Currency, Option<String>)>,
     dates: &std::collections::HashMap<crate::Exchange, Option<(String, f32)>>,
@@ -182,6 +761,7 @@ pub fn create_detailed_revolut_transactions(
                 exchange_rate_date,
                 exchange_rate,
                 company: company.clone(),
+                account: None,
             };
 
             let msg = transaction.format_to_print("REVOLUT")?;
@@ -195,13 +775,13 @@ pub fn create_detailed_revolut_transactions(
 }
 
 pub fn create_detailed_interests_transactions(
-    transactions: Vec<(String, f32, f32)>,
+    transactions: Vec<InterestsTransaction>,
     dates: &std::collections::HashMap<crate::Exchange, Option<(String, f32)>>,
 ) -> Result<Vec<Transaction>, &str> {
     let mut detailed_transactions: Vec<Transaction> = Vec::new();
     transactions
         .iter()
-        .try_for_each(|(transaction_date, gross_us, tax_us)| {
+        .try_for_each(|(transaction_date, gross_us, tax_us, account)| {
             let (exchange_rate_date, exchange_rate) = dates
                 [&crate::Exchange::USD(transaction_date.clone())]
                 .clone()
@@ -214,6 +794,7 @@ pub fn create_detailed_interests_transactions(
                 exchange_rate_date,
                 exchange_rate,
                 company: None, // No company info when interests are paid on money
+                account: account.clone(),
             };
 
             let msg = transaction.format_to_print("INTERESTS")?;
@@ -246,6 +827,7 @@ pub fn create_detailed_div_transactions(
                 exchange_rate_date,
                 exchange_rate,
                 company: company.clone(),
+                account: None,
             };
 
             let msg = transaction.format_to_print("DIV")?;
@@ -258,6 +840,80 @@ pub fn create_detailed_div_transactions(
     Ok(detailed_transactions)
 }
 
+/// Income from RSU releases (employment income). Value of shares withheld to cover taxes
+/// is reported as tax paid
+pub fn create_detailed_vest_transactions(
+    vest_events: &[VestEvent],
+    dates: &std::collections::HashMap<crate::Exchange, Option<(String, f32)>>,
+) -> Result<Vec<Transaction>, &'static str> {
+    vest_events
+        .iter()
+        .map(|vest| {
+            let (exchange_rate_date, exchange_rate) = dates
+                [&crate::Exchange::USD(vest.vest_date.clone())]
+                .clone()
+                .unwrap();
+
+            let transaction = Transaction {
+                transaction_date: vest.vest_date.clone(),
+                gross: crate::Currency::USD(vest.income() as f64),
+                tax_paid: crate::Currency::USD(vest.tax_withheld() as f64),
+                exchange_rate_date,
+                exchange_rate,
+                company: vest.company.clone(),
+                account: vest.account.clone(),
+            };
+
+            let msg = format!(
+                "{}\n{}",
+                vest.format_to_print(),
+                transaction.format_to_print("VEST")?
+            );
+            println!("{}", msg);
+            log::info!("{}", msg);
+            Ok(transaction)
+        })
+        .collect()
+}
+
+/// ESPP discount is reported as employment income in residencies taxing it at purchase.
+/// Otherwise purchases are only printed
+pub fn create_detailed_espp_transactions(
+    espp_purchases: &[EsppPurchase],
+    espp_policy: EsppPolicy,
+    dates: &std::collections::HashMap<crate::Exchange, Option<(String, f32)>>,
+) -> Result<Vec<Transaction>, &'static str> {
+    let mut detailed_transactions: Vec<Transaction> = Vec::new();
+    for purchase in espp_purchases {
+        let msg = purchase.format_to_print();
+        println!("{}", msg);
+        log::info!("{}", msg);
+        if !espp_policy.discount_is_income {
+            continue;
+        }
+        let (exchange_rate_date, exchange_rate) = dates
+            [&crate::Exchange::USD(purchase.purchase_date.clone())]
+            .clone()
+            .unwrap();
+
+        let transaction = Transaction {
+            transaction_date: purchase.purchase_date.clone(),
+            gross: crate::Currency::USD(purchase.discount() as f64),
+            tax_paid: crate::Currency::USD(0.0),
+            exchange_rate_date,
+            exchange_rate,
+            company: purchase.company.clone(),
+            account: purchase.account.clone(),
+        };
+
+        let msg = transaction.format_to_print("ESPP DISCOUNT")?;
+        println!("{}", msg);
+        log::info!("{}", msg);
+        detailed_transactions.push(transaction);
+    }
+    Ok(detailed_transactions)
+}
+
 //    pub trade_date: String,
 //    pub settlement_date: String,
 //    pub acquisition_date: String,
@@ -268,14 +924,17 @@ pub fn create_detailed_div_transactions(
 //    pub exchange_rate_acquisition_date: String,
 //    pub exchange_rate_acquisition: f32,
 pub fn create_detailed_sold_transactions(
-    transactions: Vec<(String, String, String, f32, f32, Option<String>)>,
+    transactions: Vec<DetailedSoldTransaction>,
     dates: &std::collections::HashMap<crate::Exchange, Option<(String, f32)>>,
+    rate_date_rule: crate::RateDateRule,
 ) -> Result<Vec<SoldTransaction>, &str> {
     let mut detailed_transactions: Vec<SoldTransaction> = Vec::new();
     transactions.iter().for_each(
-        |(trade_date, settlement_date, acquisition_date, income, cost_basis, symbol)| {
+        |(trade_date, settlement_date, acquisition_date, income, cost_basis, symbol, lot)| {
+            // Proceeds are converted with rate of trade or settlement date (residency rule)
+            let proceeds_date = rate_date_rule.proceeds_date(trade_date, settlement_date);
             let (exchange_rate_settlement_date, exchange_rate_settlement) = dates
-                [&crate::Exchange::USD(settlement_date.clone())]
+                [&crate::Exchange::USD(proceeds_date.to_owned())]
                 .clone()
                 .unwrap();
             let (exchange_rate_acquisition_date, exchange_rate_acquisition) = dates
@@ -294,6 +953,8 @@ pub fn create_detailed_sold_transactions(
                 exchange_rate_acquisition_date,
                 exchange_rate_acquisition,
                 company: symbol.clone(),
+                lot: lot.clone(),
+                holding_days: compute_holding_days(acquisition_date, trade_date),
             };
 
             let msg = transaction.format_to_print("");
@@ -314,12 +975,13 @@ pub fn create_detailed_revolut_sold_transactions(
         crate::Currency,
         crate::Currency,
         Option<String>,
+        LotDetails,
     )>,
     dates: &std::collections::HashMap<crate::Exchange, Option<(String, f32)>>,
 ) -> Result<Vec<SoldTransaction>, &str> {
     let mut detailed_transactions: Vec<SoldTransaction> = Vec::new();
     transactions.iter().for_each(
-        |(acquired_date, sold_date, cost_basis, gross_income, symbol)| {
+        |(acquired_date, sold_date, cost_basis, gross_income, symbol, lot)| {
             let (exchange_rate_settlement_date, exchange_rate_settlement) = dates
                 [&gross_income.derive_exchange(sold_date.clone())] // TODO: settlement date???
                 .clone()
@@ -340,6 +1002,8 @@ pub fn create_detailed_revolut_sold_transactions(
                 exchange_rate_acquisition_date,
                 exchange_rate_acquisition,
                 company: symbol.clone(),
+                lot: lot.clone(),
+                holding_days: compute_holding_days(acquired_date, sold_date),
             };
 
             let msg = transaction.format_to_print("REVOLUT ");
@@ -424,12 +1088,108 @@ pub(crate) fn create_per_company_report(
         .map_err(|_| "Unable to sort per company report dataframe")
 }
 
+// Make a dataframe with totals of each account, so that documents missing or provided twice
+// are noticeable
+pub(crate) fn create_per_account_report(
+    interests: &[Transaction],
+    dividends: &[Transaction],
+    sold_transactions: &[SoldTransaction],
+    revolut_dividends_transactions: &[Transaction],
+    revolut_sold_transactions: &[SoldTransaction],
+) -> Result<DataFrame, &'static str> {
+    // Key: Account , Value : (transactions, gross_pl, tax_paid_in_us_pl, cost_pl, dates)
+    let mut per_account_data: HashMap<Option<Account>, AccountTotals> = HashMap::new();
+
+    interests
+        .iter()
+        .chain(dividends.iter())
+        .chain(revolut_dividends_transactions.iter())
+        .try_for_each(|x| {
+            let entry =
+                per_account_data
+                    .entry(x.account.clone())
+                    .or_insert((0, 0.0, 0.0, 0.0, vec![]));
+            entry.0 += 1;
+            entry.1 += x.exchange_rate * x.gross.value() as f32;
+            entry.2 += x.exchange_rate * x.tax_paid.value() as f32;
+            entry.4.push(
+                chrono::NaiveDate::parse_from_str(&x.transaction_date, "%m/%d/%y")
+                    .map_err(|_| "Unable to parse transaction date")?,
+            );
+            Ok::<(), &str>(())
+        })?;
+
+    sold_transactions
+        .iter()
+        .chain(revolut_sold_transactions.iter())
+        .try_for_each(|x| {
+            let entry =
+                per_account_data
+                    .entry(x.lot.account.clone())
+                    .or_insert((0, 0.0, 0.0, 0.0, vec![]));
+            entry.0 += 1;
+            entry.1 += x.income_us * x.exchange_rate_settlement;
+            entry.3 += x.cost_basis * x.exchange_rate_acquisition;
+            entry.4.push(
+                chrono::NaiveDate::parse_from_str(&x.trade_date, "%m/%d/%y")
+                    .map_err(|_| "Unable to parse trade date")?,
+            );
+            Ok::<(), &str>(())
+        })?;
+
+    let mut accounts: Vec<Option<String>> = Vec::new();
+    let mut counts: Vec<u32> = Vec::new();
+    let mut first_dates: Vec<Option<String>> = Vec::new();
+    let mut last_dates: Vec<Option<String>> = Vec::new();
+    let mut gross: Vec<f32> = Vec::new();
+    let mut tax: Vec<f32> = Vec::new();
+    let mut cost: Vec<f32> = Vec::new();
+    per_account_data.iter().for_each(
+        |(account, (count, gross_pl, tax_paid_in_us_pl, cost_pl, dates))| {
+            log::info!(
+                "Account: {:?}, Transactions: {}, Gross PLN: {:.2}, Tax Paid in USD PLN: {:.2}, Cost PLN: {:.2}",
+                account,
+                count,
+                gross_pl,
+                tax_paid_in_us_pl,
+                cost_pl
+            );
+            let format = |d: &chrono::NaiveDate| d.format("%Y-%m-%d").to_string();
+            accounts.push(account.as_ref().map(|a| a.to_string()));
+            counts.push(*count);
+            first_dates.push(dates.iter().min().map(format));
+            last_dates.push(dates.iter().max().map(format));
+            gross.push(*gross_pl);
+            tax.push(*tax_paid_in_us_pl);
+            cost.push(*cost_pl);
+        },
+    );
+    let series = vec![
+        Series::new("Account", accounts),
+        Series::new("Transactions", counts),
+        Series::new("First Date", first_dates),
+        Series::new("Last Date", last_dates),
+        Series::new("Gross[PLN]", gross),
+        Series::new("Cost[PLN]", cost),
+        Series::new("Tax Paid in USD[PLN]", tax),
+    ];
+    DataFrame::new(series)
+        .map_err(|_| "Unable to create per account report dataframe")?
+        .sort(["Account"], false, true)
+        .map_err(|_| "Unable to sort per account report dataframe")
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
     use crate::Currency;
 
+    const ESPP_POLICY: EsppPolicy = EsppPolicy {
+        discount_is_income: false,
+        cost_basis: EsppCostBasis::PurchasePrice,
+    };
+
     fn round4(val: f64) -> f64 {
         (val * 10_000.0).round() / 10_000.0
     }
@@ -444,6 +1204,7 @@ mod tests {
                 exchange_rate_date: "02/28/21".to_string(),
                 exchange_rate: 2.0,
                 company: None,
+                account: None,
             },
             Transaction {
                 transaction_date: "04/11/21".to_string(),
@@ -452,6 +1213,7 @@ mod tests {
                 exchange_rate_date: "04/10/21".to_string(),
                 exchange_rate: 3.0,
                 company: None,
+                account: None,
             },
         ];
         let df = create_per_company_report(&input, &[], &[], &[], &[])
@@ -486,6 +1248,7 @@ mod tests {
                 exchange_rate_date: "04/10/21".to_string(),
                 exchange_rate: 3.0,
                 company: Some("INTEL CORP".to_owned()),
+                account: None,
             },
             Transaction {
                 transaction_date: "03/01/21".to_string(),
@@ -494,6 +1257,7 @@ mod tests {
                 exchange_rate_date: "02/28/21".to_string(),
                 exchange_rate: 2.0,
                 company: Some("INTEL CORP".to_owned()),
+                account: None,
             },
             Transaction {
                 transaction_date: "03/11/21".to_string(),
@@ -502,6 +1266,7 @@ mod tests {
                 exchange_rate_date: "02/28/21".to_string(),
                 exchange_rate: 10.0,
                 company: Some("ABEV".to_owned()),
+                account: None,
             },
         ];
         let df = create_per_company_report(&[], &input, &[], &[], &[])
@@ -543,6 +1308,78 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_create_per_account_report() -> Result<(), String> {
+        let etrade = Account::new("E*TRADE", Some("XXXX-1234"));
+        let dividend = |transaction_date: &str, account: Option<Account>| Transaction {
+            transaction_date: transaction_date.to_string(),
+            gross: crate::Currency::USD(100.0),
+            tax_paid: crate::Currency::USD(15.0),
+            exchange_rate_date: "N/A".to_string(),
+            exchange_rate: 4.0,
+            company: Some("INTEL CORP".to_owned()),
+            account,
+        };
+        let dividends = vec![
+            dividend("06/01/21", Some(etrade.clone())),
+            dividend("03/01/21", Some(etrade.clone())),
+        ];
+        let vest = vec![dividend("05/01/21", None)];
+        let sold = vec![SoldTransaction {
+            trade_date: "09/01/21".to_string(),
+            settlement_date: "09/03/21".to_string(),
+            acquisition_date: "01/01/21".to_string(),
+            income_us: 20.0,
+            cost_basis: 10.0,
+            exchange_rate_settlement_date: "09/02/21".to_string(),
+            exchange_rate_settlement: 2.5,
+            exchange_rate_acquisition_date: "12/31/20".to_string(),
+            exchange_rate_acquisition: 5.0,
+            company: Some("INTEL CORP".to_owned()),
+            lot: LotDetails {
+                account: Some(etrade),
+                ..Default::default()
+            },
+            holding_days: 243,
+        }];
+        let df = create_per_account_report(&vest, &dividends, &sold, &[], &[])
+            .map_err(|e| format!("Error creating per account report: {}", e))?;
+
+        // Transactions without account are folded to one row
+        assert_eq!(df.height(), 2);
+        assert_eq!(df.width(), 7);
+
+        let account_col = df.column("Account").unwrap().utf8().unwrap();
+        let index = match account_col.get(0) {
+            Some("E*TRADE XXXX-1234") => 0,
+            _ => 1,
+        };
+        assert_eq!(account_col.get(index), Some("E*TRADE XXXX-1234"));
+        let count_col = df.column("Transactions").unwrap();
+        assert_eq!(count_col.get(index).unwrap().extract::<u32>(), Some(3));
+        assert_eq!(count_col.get(1 - index).unwrap().extract::<u32>(), Some(1));
+        let first_col = df.column("First Date").unwrap().utf8().unwrap();
+        assert_eq!(first_col.get(index), Some("2021-03-01"));
+        let last_col = df.column("Last Date").unwrap().utf8().unwrap();
+        assert_eq!(last_col.get(index), Some("2021-09-01"));
+        let gross_col = df.column("Gross[PLN]").unwrap();
+        assert_eq!(
+            round4(gross_col.get(index).unwrap().extract::<f64>().unwrap()),
+            round4(2.0 * 100.0 * 4.0 + 20.0 * 2.5)
+        );
+        let cost_col = df.column("Cost[PLN]").unwrap();
+        assert_eq!(
+            round4(cost_col.get(index).unwrap().extract::<f64>().unwrap()),
+            round4(10.0 * 5.0)
+        );
+        let tax_col = df.column("Tax Paid in USD[PLN]").unwrap();
+        assert_eq!(
+            round4(tax_col.get(index).unwrap().extract::<f64>().unwrap()),
+            round4(2.0 * 15.0 * 4.0)
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_create_per_company_report_sells() -> Result<(), String> {
         let input = vec![
@@ -557,6 +1394,8 @@ mod tests {
                 exchange_rate_acquisition_date: "02/28/21".to_string(),
                 exchange_rate_acquisition: 5.0,
                 company: Some("INTEL CORP".to_owned()),
+                lot: LotDetails::default(),
+                holding_days: 59,
             },
             SoldTransaction {
                 trade_date: "06/01/21".to_string(),
@@ -569,6 +1408,8 @@ mod tests {
                 exchange_rate_acquisition_date: "12/30/18".to_string(),
                 exchange_rate_acquisition: 6.0,
                 company: Some("INTEL CORP".to_owned()),
+                lot: LotDetails::default(),
+                holding_days: 882,
             },
             SoldTransaction {
                 trade_date: "06/01/21".to_string(),
@@ -581,6 +1422,8 @@ mod tests {
                 exchange_rate_acquisition_date: "12/30/18".to_string(),
                 exchange_rate_acquisition: 6.0,
                 company: Some("PXD".to_owned()),
+                lot: LotDetails::default(),
+                holding_days: 882,
             },
         ];
         let df = create_per_company_report(&[], &[], &input, &[], &[])
@@ -622,24 +1465,131 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_apply_tax_withholding_adjustments() {
+        let mut dividends = vec![
+            (
+                "3/1/24".to_owned(),
+                380.25,
+                57.04,
+                Some("INTEL CORP".to_owned()),
+            ),
+            (
+                "6/3/24".to_owned(),
+                57.25,
+                8.59,
+                Some("INTEL CORP".to_owned()),
+            ),
+        ];
+        let adjustment = |date: &str, company: &str, pay_date: Option<&str>, amount: f32| {
+            TaxWithholdingAdjustment {
+                date: date.to_owned(),
+                company: Some(company.to_owned()),
+                pay_date: pay_date.map(|d| d.to_owned()),
+                amount,
+            }
+        };
+        let adjustments = vec![
+            // Latest dividend paid before adjustment
+            adjustment("6/20/24", "INTEL CORP", None, 2.0),
+            // Dividend of pay date, additional tax withheld
+            adjustment("6/20/24", "INTEL CORP", Some("03/01/24"), -1.0),
+            // Refund exceeding tax withheld
+            adjustment("6/21/24", "INTEL CORP", Some("06/03/24"), 10.0),
+            // No dividend of company
+            adjustment("6/20/24", "NVIDIA CORP", None, 1.0),
+        ];
+        let (adjusted, unmatched) = apply_tax_withholding_adjustments(&mut dividends, &adjustments);
+        let taxes: Vec<f32> = dividends.iter().map(|(_, _, tax_us, _)| *tax_us).collect();
+        assert_eq!(taxes, vec![58.04, 6.59]);
+        assert_eq!(adjusted.len(), 2);
+        assert_eq!(
+            unmatched,
+            vec![
+                "Tax withholding adjustment of 6/21/24 (company: INTEL CORP, pay date: 06/03/24, amount: $10) has no matching dividend and it is not taken into account".to_owned(),
+                "Tax withholding adjustment of 6/20/24 (company: NVIDIA CORP, pay date: N/A, amount: $1) has no matching dividend and it is not taken into account".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attribute_interests_to_tax_year() {
+        let interests = vec![
+            ("1/2/24".to_owned(), 0.92, 0.22, true),
+            ("2/1/24".to_owned(), 1.05, 0.0, false),
+        ];
+        let account = Account::new("Morgan Stanley", Some("123-456789-012"));
+        let (received, prior_year) =
+            attribute_interests_to_tax_year(interests.clone(), TaxYearRule::CashReceived, &account);
+        assert_eq!(
+            received,
+            vec![
+                ("1/2/24".to_owned(), 0.92, 0.22, Some(account.clone())),
+                ("2/1/24".to_owned(), 1.05, 0.0, Some(account.clone()))
+            ]
+        );
+        assert!(prior_year.is_empty());
+
+        let (received, prior_year) =
+            attribute_interests_to_tax_year(interests, TaxYearRule::ReportableYear, &account);
+        assert_eq!(
+            received,
+            vec![("2/1/24".to_owned(), 1.05, 0.0, Some(account.clone()))]
+        );
+        assert_eq!(
+            prior_year,
+            vec![("12/31/23".to_owned(), 0.92, 0.22, Some(account))]
+        );
+    }
+
+    #[test]
+    fn test_split_prior_year_interests() {
+        // Statements of December and January: interests of January are reportable for tax year
+        let interests = vec![("12/1/23".to_owned(), 0.85, 0.0, None)];
+        let prior_year = vec![("12/31/23".to_owned(), 0.92, 0.22, None)];
+        assert_eq!(
+            split_prior_year_interests(&interests, prior_year.clone()),
+            (prior_year.clone(), vec![])
+        );
+        // Statement of January only: interests of January are reportable for the year before
+        let interests = vec![("2/1/24".to_owned(), 1.05, 0.0, None)];
+        assert_eq!(
+            split_prior_year_interests(&interests, prior_year.clone()),
+            (vec![], prior_year.clone())
+        );
+        // No other interests: tax year is the year interests are reportable for
+        assert_eq!(
+            split_prior_year_interests(&[], prior_year.clone()),
+            (prior_year, vec![])
+        );
+    }
+
     #[test]
     fn test_interests_verification_ok() -> Result<(), String> {
-        let transactions: Vec<(String, f32, f32)> = vec![
-            ("06/01/21".to_string(), 100.0, 0.00),
-            ("03/01/21".to_string(), 126.0, 0.00),
+        let transactions: Vec<(String, f32, f32, Option<Account>)> = vec![
+            ("06/01/21".to_string(), 100.0, 0.00, None),
+            ("03/01/21".to_string(), 126.0, 0.00, None),
         ];
         verify_interests_transactions(&transactions)
     }
 
     #[test]
     fn test_revolut_sold_verification_false() -> Result<(), String> {
-        let transactions: Vec<(String, String, Currency, Currency, Option<String>)> = vec![
+        let transactions: Vec<(
+            String,
+            String,
+            Currency,
+            Currency,
+            Option<String>,
+            LotDetails,
+        )> = vec![
             (
                 "06/01/21".to_string(),
                 "06/01/22".to_string(),
                 Currency::PLN(10.0),
                 Currency::PLN(2.0),
                 Some("INTEL CORP".to_owned()),
+                LotDetails::default(),
             ),
             (
                 "06/01/21".to_string(),
@@ -647,6 +1597,7 @@ mod tests {
                 Currency::PLN(10.0),
                 Currency::PLN(2.0),
                 Some("INTEL CORP".to_owned()),
+                LotDetails::default(),
             ),
         ];
         assert_eq!(
@@ -739,6 +1690,7 @@ mod tests {
                     exchange_rate_date: "02/28/21".to_string(),
                     exchange_rate: 2.0,
                     company: None,
+                    account: None,
                 },
                 Transaction {
                     transaction_date: "04/11/21".to_string(),
@@ -747,6 +1699,7 @@ mod tests {
                     exchange_rate_date: "04/10/21".to_string(),
                     exchange_rate: 3.0,
                     company: None,
+                    account: None,
                 },
             ])
         );
@@ -794,6 +1747,7 @@ mod tests {
                     exchange_rate_date: "N/A".to_string(),
                     exchange_rate: 1.0,
                     company: None,
+                    account: None,
                 },
                 Transaction {
                     transaction_date: "04/11/21".to_string(),
@@ -802,6 +1756,7 @@ mod tests {
                     exchange_rate_date: "N/A".to_string(),
                     exchange_rate: 1.0,
                     company: None,
+                    account: None,
                 },
             ])
         );
@@ -810,9 +1765,10 @@ mod tests {
 
     #[test]
     fn test_create_detailed_interests_transactions() -> Result<(), String> {
-        let parsed_transactions: Vec<(String, f32, f32)> = vec![
-            ("04/11/21".to_string(), 100.0, 0.00),
-            ("03/01/21".to_string(), 126.0, 0.00),
+        let account = Account::new("Morgan Stanley", Some("123-456789-012"));
+        let parsed_transactions: Vec<(String, f32, f32, Option<Account>)> = vec![
+            ("04/11/21".to_string(), 100.0, 0.00, Some(account.clone())),
+            ("03/01/21".to_string(), 126.0, 0.00, None),
         ];
 
         let mut dates: std::collections::HashMap<crate::Exchange, Option<(String, f32)>> =
@@ -839,6 +1795,7 @@ mod tests {
                     exchange_rate_date: "04/10/21".to_string(),
                     exchange_rate: 3.0,
                     company: None,
+                    account: Some(account),
                 },
                 Transaction {
                     transaction_date: "03/01/21".to_string(),
@@ -847,6 +1804,7 @@ mod tests {
                     exchange_rate_date: "02/28/21".to_string(),
                     exchange_rate: 2.0,
                     company: None,
+                    account: None,
                 },
             ])
         );
@@ -893,7 +1851,8 @@ mod tests {
                     tax_paid: crate::Currency::USD(25.0),
                     exchange_rate_date: "04/10/21".to_string(),
                     exchange_rate: 3.0,
-                    company: Some("INTEL CORP".to_owned())
+                    company: Some("INTEL CORP".to_owned()),
+                    account: None,
                 },
                 Transaction {
                     transaction_date: "03/01/21".to_string(),
@@ -901,7 +1860,8 @@ mod tests {
                     tax_paid: crate::Currency::USD(10.0),
                     exchange_rate_date: "02/28/21".to_string(),
                     exchange_rate: 2.0,
-                    company: Some("INTEL CORP".to_owned())
+                    company: Some("INTEL CORP".to_owned()),
+                    account: None,
                 },
             ])
         );
@@ -910,14 +1870,21 @@ mod tests {
 
     #[test]
     fn test_create_detailed_revolut_sold_transactions() -> Result<(), String> {
-        let parsed_transactions: Vec<(String, String, Currency, Currency, Option<String>)> =
-            vec![(
-                "11/20/23".to_string(),
-                "12/08/24".to_string(),
-                Currency::USD(5000.0),
-                Currency::USD(5804.62),
-                Some("INTEL CORP".to_owned()),
-            )];
+        let parsed_transactions: Vec<(
+            String,
+            String,
+            Currency,
+            Currency,
+            Option<String>,
+            LotDetails,
+        )> = vec![(
+            "11/20/23".to_string(),
+            "12/08/24".to_string(),
+            Currency::USD(5000.0),
+            Currency::USD(5804.62),
+            Some("INTEL CORP".to_owned()),
+            LotDetails::default(),
+        )];
 
         let mut dates: std::collections::HashMap<crate::Exchange, Option<(String, f32)>> =
             std::collections::HashMap::new();
@@ -946,6 +1913,8 @@ mod tests {
                 exchange_rate_acquisition_date: "11/19/23".to_string(),
                 exchange_rate_acquisition: 2.0,
                 company: Some("INTEL CORP".to_owned()),
+                lot: LotDetails::default(),
+                holding_days: 384,
             },])
         );
         Ok(())
@@ -953,7 +1922,15 @@ mod tests {
 
     #[test]
     fn test_create_detailed_sold_transactions() -> Result<(), String> {
-        let parsed_transactions: Vec<(String, String, String, f32, f32, Option<String>)> = vec![
+        let parsed_transactions: Vec<(
+            String,
+            String,
+            String,
+            f32,
+            f32,
+            Option<String>,
+            LotDetails,
+        )> = vec![
             (
                 "03/01/21".to_string(),
                 "03/03/21".to_string(),
@@ -961,6 +1938,7 @@ mod tests {
                 20.0,
                 20.0,
                 Some("INTEL CORP".to_owned()),
+                LotDetails::default(),
             ),
             (
                 "06/01/21".to_string(),
@@ -969,6 +1947,7 @@ mod tests {
                 25.0,
                 10.0,
                 Some("INTEL CORP".to_owned()),
+                LotDetails::default(),
             ),
         ];
 
@@ -1008,7 +1987,12 @@ mod tests {
             Some(("04/10/21".to_owned(), 7.0)),
         );
 
-        let transactions = create_detailed_sold_transactions(parsed_transactions, &dates);
+        let mut rule = crate::RateDateRule {
+            sale_date: crate::SaleDate::Settlement,
+            rate_date: crate::previous_day,
+        };
+        let transactions =
+            create_detailed_sold_transactions(parsed_transactions.clone(), &dates, rule);
 
         assert_eq!(
             transactions,
@@ -1024,6 +2008,8 @@ mod tests {
                     exchange_rate_acquisition_date: "02/28/21".to_string(),
                     exchange_rate_acquisition: 5.0,
                     company: Some("INTEL CORP".to_owned()),
+                    lot: LotDetails::default(),
+                    holding_days: 59,
                 },
                 SoldTransaction {
                     trade_date: "06/01/21".to_string(),
@@ -1036,9 +2022,24 @@ mod tests {
                     exchange_rate_acquisition_date: "12/30/18".to_string(),
                     exchange_rate_acquisition: 6.0,
                     company: Some("INTEL CORP".to_owned()),
+                    lot: LotDetails::default(),
+                    holding_days: 882,
                 },
             ])
         );
+        // Residency converting proceeds with rate of trade date
+        rule.sale_date = crate::SaleDate::Trade;
+        let transactions = create_detailed_sold_transactions(parsed_transactions, &dates, rule)?;
+        assert_eq!(
+            transactions
+                .iter()
+                .map(|t| (
+                    t.exchange_rate_settlement_date.as_str(),
+                    t.exchange_rate_settlement
+                ))
+                .collect::<Vec<_>>(),
+            vec![("02/28/21", 2.0), ("06/03/21", 3.0)]
+        );
         Ok(())
     }
 
@@ -1072,10 +2073,15 @@ mod tests {
     fn test_sold_transaction_reconstruction_dividiends_only() -> Result<(), String> {
         let parsed_sold_transactions: Vec<(String, String, f32, f32, f32, Option<String>)> = vec![];
 
-        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32)> = vec![];
+        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![];
 
-        let detailed_sold_transactions =
-            reconstruct_sold_transactions(&parsed_sold_transactions, &parsed_gains_and_losses)?;
+        let detailed_sold_transactions = reconstruct_sold_transactions(
+            &parsed_sold_transactions,
+            &parsed_gains_and_losses,
+            &[],
+            &[],
+            ESPP_POLICY,
+        )?;
         // 1. trade date
         // 2. settlement date
         // 3. date of purchase
@@ -1106,13 +2112,14 @@ mod tests {
             ),
         ];
 
-        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32)> = vec![
+        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![
             (
                 "01/01/2019".to_string(),
                 "06/01/2021".to_string(),
                 10.0,
                 10.0,
                 24.8,
+                LotDetails::default(),
             ),
             (
                 "01/01/2021".to_string(),
@@ -1120,11 +2127,17 @@ mod tests {
                 20.0,
                 20.0,
                 19.8,
+                LotDetails::default(),
             ),
         ];
 
-        let detailed_sold_transactions =
-            reconstruct_sold_transactions(&parsed_sold_transactions, &parsed_gains_and_losses)?;
+        let detailed_sold_transactions = reconstruct_sold_transactions(
+            &parsed_sold_transactions,
+            &parsed_gains_and_losses,
+            &[],
+            &[],
+            ESPP_POLICY,
+        )?;
 
         // 1. trade date
         // 2. settlement date
@@ -1140,7 +2153,8 @@ mod tests {
                     "01/01/19".to_string(),
                     24.8,
                     10.0,
-                    Some("INTEL CORP".to_owned())
+                    Some("INTEL CORP".to_owned()),
+                    LotDetails::default()
                 ),
                 (
                     "03/01/21".to_string(),
@@ -1148,7 +2162,8 @@ mod tests {
                     "01/01/21".to_string(),
                     19.8,
                     20.0,
-                    Some("INTEL CORP".to_owned())
+                    Some("INTEL CORP".to_owned()),
+                    LotDetails::default()
                 ),
             ]
         );
@@ -1176,13 +2191,14 @@ mod tests {
             ),
         ];
 
-        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32)> = vec![
+        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![
             (
                 "01/01/2019".to_string(),
                 "06/01/2021".to_string(),
                 10.0,
                 10.0,
                 24.8,
+                LotDetails::default(),
             ),
             (
                 "01/01/2021".to_string(),
@@ -1190,11 +2206,17 @@ mod tests {
                 20.0,
                 20.0,
                 19.8,
+                LotDetails::default(),
             ),
         ];
 
-        let detailed_sold_transactions =
-            reconstruct_sold_transactions(&parsed_sold_transactions, &parsed_gains_and_losses)?;
+        let detailed_sold_transactions = reconstruct_sold_transactions(
+            &parsed_sold_transactions,
+            &parsed_gains_and_losses,
+            &[],
+            &[],
+            ESPP_POLICY,
+        )?;
 
         // 1. trade date
         // 2. settlement date
@@ -1210,7 +2232,8 @@ mod tests {
                     "01/01/19".to_string(),
                     24.8,
                     10.0,
-                    Some("INTEL CORP".to_owned())
+                    Some("INTEL CORP".to_owned()),
+                    LotDetails::default()
                 ),
                 (
                     "03/01/21".to_string(),
@@ -1218,7 +2241,8 @@ mod tests {
                     "01/01/21".to_string(),
                     19.8,
                     20.0,
-                    Some("INTEL CORP".to_owned())
+                    Some("INTEL CORP".to_owned()),
+                    LotDetails::default()
                 ),
             ]
         );
@@ -1237,13 +2261,14 @@ mod tests {
                 Some("INTEL CORP".to_owned()), // company symbol (ticker)
             )];
 
-        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32)> = vec![
+        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![
             (
                 "05/02/22".to_string(), // date when sold stock was acquired (date_acquired)
                 "07/19/22".to_string(), // date when stock was sold (date_sold)
                 0.0,                    // aqusition cost of sold stock (aquisition_cost)
                 1593.0,                 // adjusted aquisition cost of sold stock (cost_basis)
                 1415.480004,            // income from sold stock (total_proceeds)
+                LotDetails::default(),
             ),
             (
                 "02/18/22".to_string(),
@@ -1251,6 +2276,7 @@ mod tests {
                 4241.16,
                 4989.6,
                 4325.10001,
+                LotDetails::default(),
             ),
             (
                 "08/19/22".to_string(),
@@ -1258,12 +2284,19 @@ mod tests {
                 5236.0872,
                 6160.0975,
                 4877.355438,
+                LotDetails::default(),
             ),
         ];
 
         assert_eq!(
-            reconstruct_sold_transactions(&parsed_sold_transactions, &parsed_gains_and_losses)
-                .is_ok(),
+            reconstruct_sold_transactions(
+                &parsed_sold_transactions,
+                &parsed_gains_and_losses,
+                &[],
+                &[],
+                ESPP_POLICY
+            )
+            .is_ok(),
             false
         );
     }
@@ -1289,13 +2322,14 @@ mod tests {
             ),
         ];
 
-        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32)> = vec![
+        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![
             (
                 "08/19/2021".to_string(),
                 "12/19/2022".to_string(),
                 4336.4874,
                 4758.6971,
                 2711.0954,
+                LotDetails::default(),
             ),
             (
                 "05/03/2021".to_string(),
@@ -1303,6 +2337,7 @@ mod tests {
                 0.0,
                 3876.918,
                 2046.61285,
+                LotDetails::default(),
             ),
             (
                 "08/19/2022".to_string(),
@@ -1310,6 +2345,7 @@ mod tests {
                 5045.6257,
                 5936.0274,
                 3986.9048,
+                LotDetails::default(),
             ),
             (
                 "05/02/2022".to_string(),
@@ -1317,11 +2353,17 @@ mod tests {
                 0.0,
                 4013.65,
                 2285.82733,
+                LotDetails::default(),
             ),
         ];
 
-        let detailed_sold_transactions =
-            reconstruct_sold_transactions(&parsed_sold_transactions, &parsed_gains_and_losses)?;
+        let detailed_sold_transactions = reconstruct_sold_transactions(
+            &parsed_sold_transactions,
+            &parsed_gains_and_losses,
+            &[],
+            &[],
+            ESPP_POLICY,
+        )?;
 
         assert_eq!(
             detailed_sold_transactions,
@@ -1332,7 +2374,8 @@ mod tests {
                     "08/19/21".to_string(),
                     2711.0954,
                     4336.4874,
-                    Some("INTEL CORP".to_owned())
+                    Some("INTEL CORP".to_owned()),
+                    LotDetails::default()
                 ),
                 (
                     "12/21/22".to_string(),
@@ -1340,7 +2383,8 @@ mod tests {
                     "05/03/21".to_string(),
                     2046.61285,
                     0.0,
-                    Some("INTEL CORP".to_owned())
+                    Some("INTEL CORP".to_owned()),
+                    LotDetails::default()
                 ),
                 (
                     "12/19/22".to_string(),
@@ -1348,7 +2392,8 @@ mod tests {
                     "08/19/22".to_string(),
                     3986.9048,
                     5045.6257,
-                    Some("INTEL CORP".to_owned())
+                    Some("INTEL CORP".to_owned()),
+                    LotDetails::default()
                 ),
                 (
                     "12/21/22".to_string(),
@@ -1356,7 +2401,8 @@ mod tests {
                     "05/02/22".to_string(),
                     2285.82733,
                     0.0,
-                    Some("INTEL CORP".to_owned())
+                    Some("INTEL CORP".to_owned()),
+                    LotDetails::default()
                 ),
             ]
         );
@@ -1384,11 +2430,469 @@ mod tests {
             ),
         ];
 
-        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32)> = vec![];
+        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![];
 
-        let result =
-            reconstruct_sold_transactions(&parsed_sold_transactions, &parsed_gains_and_losses);
+        let result = reconstruct_sold_transactions(
+            &parsed_sold_transactions,
+            &parsed_gains_and_losses,
+            &[],
+            &[],
+            ESPP_POLICY,
+        );
         assert_eq!( result , Err("\n\nERROR: Sold transaction detected, but corressponding Gain&Losses document is missing. Please download Gain&Losses  XLSX document at:\n
             https://us.etrade.com/etx/sp/stockplan#/myAccount/gainsLosses\n\n".to_string()));
     }
+
+    #[test]
+    fn test_sold_transaction_reconstruction_from_vests() -> Result<(), String> {
+        let vest = |vest_date: &str, released: f32, withheld: f32, fmv: f32| VestEvent {
+            vest_date: vest_date.to_owned(),
+            company: Some("INTEL CORP".to_owned()),
+            symbol: Some("INTC".to_owned()),
+            shares_released: released,
+            shares_withheld: withheld,
+            fmv,
+            ..Default::default()
+        };
+        let vests = vec![
+            vest("05/15/23", 20.0, 7.0, 29.25),
+            vest("02/15/23", 40.0, 14.0, 28.5),
+        ];
+        let parsed_sold_transactions: Vec<(String, String, f32, f32, f32, Option<String>)> =
+            vec![(
+                "06/01/23".to_string(),
+                "06/05/23".to_string(),
+                30.0,
+                30.0,
+                900.0,
+                Some("INTC".to_owned()),
+            )];
+        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![];
+
+        // Shares of earliest release are sold first
+        let lot = |quantity: f32, fmv: f32| LotDetails {
+            quantity: Some(quantity),
+            plan_type: Some(PlanType::RS),
+            cost_basis_per_share: Some(fmv),
+            proceeds_per_share: Some(30.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            reconstruct_sold_transactions(
+                &parsed_sold_transactions,
+                &parsed_gains_and_losses,
+                &vests,
+                &[],
+                ESPP_POLICY
+            )?,
+            vec![
+                (
+                    "06/01/23".to_string(),
+                    "06/05/23".to_string(),
+                    "02/15/23".to_string(),
+                    780.0,
+                    741.0,
+                    Some("INTC".to_owned()),
+                    lot(26.0, 28.5)
+                ),
+                (
+                    "06/01/23".to_string(),
+                    "06/05/23".to_string(),
+                    "05/15/23".to_string(),
+                    120.0,
+                    117.0,
+                    Some("INTC".to_owned()),
+                    lot(4.0, 29.25)
+                ),
+            ]
+        );
+
+        // Shares released after sale can not cover it
+        assert!(reconstruct_sold_transactions(
+            &parsed_sold_transactions,
+            &parsed_gains_and_losses,
+            &vests[..1],
+            &[],
+            ESPP_POLICY
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sold_transaction_reconstruction_espp_policy() -> Result<(), String> {
+        let parsed_sold_transactions: Vec<(String, String, f32, f32, f32, Option<String>)> = vec![
+            (
+                "07/03/23".to_string(),
+                "07/05/23".to_string(),
+                10.0,
+                35.0,
+                350.0,
+                Some("INTEL CORP".to_owned()),
+            ),
+            (
+                "08/01/23".to_string(),
+                "08/03/23".to_string(),
+                10.0,
+                36.0,
+                360.0,
+                Some("INTC".to_owned()),
+            ),
+        ];
+        let espp_lot = LotDetails {
+            quantity: Some(10.0),
+            plan_type: Some(PlanType::ESPP),
+            ..Default::default()
+        };
+        // Only first sale is in G&L: acquisition cost and adjusted cost basis
+        let parsed_gains_and_losses: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![(
+            "06/30/2023".to_string(),
+            "07/03/2023".to_string(),
+            229.5,
+            334.4,
+            350.0,
+            espp_lot,
+        )];
+        let purchases = vec![EsppPurchase {
+            purchase_date: "06/30/23".to_owned(),
+            company: Some("INTEL CORP".to_owned()),
+            symbol: Some("INTC".to_owned()),
+            shares_purchased: 50.0,
+            purchase_fmv: 33.44,
+            purchase_price: 22.95,
+            ..Default::default()
+        }];
+
+        let cost_basis = |policy: EsppPolicy| -> Result<Vec<f32>, String> {
+            Ok(reconstruct_sold_transactions(
+                &parsed_sold_transactions,
+                &parsed_gains_and_losses,
+                &[],
+                &purchases,
+                policy,
+            )?
+            .iter()
+            .map(|(_, _, _, _, cost_basis, _, _)| *cost_basis)
+            .collect())
+        };
+        assert_eq!(cost_basis(ESPP_POLICY)?, vec![229.5, 229.5]);
+        assert_eq!(
+            cost_basis(EsppPolicy {
+                discount_is_income: true,
+                cost_basis: EsppCostBasis::MarketValue,
+            })?,
+            vec![334.4, 334.4]
+        );
+        Ok(())
+    }
+
+    fn sell_confirmation() -> TradeConfirmation {
+        TradeConfirmation {
+            trade_date: "04/20/22".to_owned(),
+            settlement_date: "04/22/22".to_owned(),
+            company: Some("INTEL CORP".to_owned()),
+            symbol: Some("INTC".to_owned()),
+            action: TradeAction::Sell,
+            quantity: 10.0,
+            price: 45.0,
+            principal: 450.0,
+            commission: 0.0,
+            fees: 0.03,
+            net_amount: 449.97,
+        }
+    }
+
+    #[test]
+    fn test_verify_trade_confirmations() {
+        let sold = vec![(
+            "04/20/22".to_owned(),
+            "04/22/22".to_owned(),
+            10.0,
+            45.0,
+            449.97,
+            Some("INTEL CORP".to_owned()),
+        )];
+        let gains_and_losses = vec![(
+            "01/03/2022".to_owned(),
+            "04/20/2022".to_owned(),
+            400.0,
+            400.0,
+            449.97,
+            LotDetails::default(),
+        )];
+        let trades = vec![sell_confirmation()];
+        assert!(verify_trade_confirmations(&trades, &sold, &gains_and_losses).is_empty());
+
+        // Different quantity sold and missing sale
+        let mut other_day = sell_confirmation();
+        other_day.trade_date = "04/21/22".to_owned();
+        let trades = vec![
+            TradeConfirmation {
+                quantity: 11.0,
+                ..sell_confirmation()
+            },
+            other_day,
+        ];
+        assert_eq!(
+            verify_trade_confirmations(&trades, &sold, &gains_and_losses).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_reconcile_holdings() {
+        let account = Account::new("Morgan Stanley", Some("123-456789-012"));
+        let other_account = Account::new("Morgan Stanley", Some("123-456789-345"));
+        let holding = |date: &str, quantity: f32, account: &Account| Holding {
+            date: date.to_owned(),
+            company: "INTEL CORP".to_owned(),
+            symbol: "INTC".to_owned(),
+            quantity,
+            price: 45.0,
+            market_value: quantity * 45.0,
+            account: Some(account.clone()),
+        };
+        let sold = |account: &Account| {
+            vec![(
+                account.clone(),
+                (
+                    "04/20/22".to_owned(),
+                    "04/22/22".to_owned(),
+                    10.0,
+                    45.0,
+                    449.97,
+                    Some("INTC".to_owned()),
+                ),
+            )]
+        };
+        // Shares vested in May do not need to be explained
+        let holdings = vec![
+            holding("05/31/22", 95.0, &account),
+            holding("03/31/22", 100.0, &account),
+            holding("04/30/22", 90.0, &account),
+        ];
+        assert!(reconcile_holdings(&holdings, &sold(&account), &[]).is_empty());
+        // Sale of other account does not explain drop of holdings
+        assert_eq!(
+            reconcile_holdings(&holdings, &sold(&other_account), &[]),
+            vec![
+                "Holdings of INTEL CORP (Morgan Stanley XXX-XXXXX9-012) dropped by 10 shares between 2022-03-31 and 2022-04-30, but 0 shares were sold in that period"
+                    .to_owned()
+            ]
+        );
+        // Positions of the same company and date on other account are not duplicates
+        let mut both_accounts = holdings.clone();
+        both_accounts.push(holding("03/31/22", 50.0, &other_account));
+        both_accounts.push(holding("04/30/22", 40.0, &other_account));
+        assert_eq!(
+            reconcile_holdings(&both_accounts, &sold(&account), &[]),
+            vec![
+                "Holdings of INTEL CORP (Morgan Stanley XXX-XXXXX9-345) dropped by 10 shares between 2022-03-31 and 2022-04-30, but 0 shares were sold in that period"
+                    .to_owned()
+            ]
+        );
+
+        // G&L lists fewer shares sold than holdings dropped
+        let gains_and_losses = vec![(
+            "01/03/2022".to_owned(),
+            "04/20/2022".to_owned(),
+            400.0,
+            400.0,
+            449.97,
+            LotDetails {
+                quantity: Some(8.0),
+                ..Default::default()
+            },
+        )];
+        assert_eq!(
+            reconcile_holdings(&holdings, &sold(&account), &gains_and_losses),
+            vec![
+                "Holdings of INTEL CORP (Morgan Stanley XXX-XXXXX9-012) dropped by 10 shares between 2022-03-31 and 2022-04-30, but 8 shares were sold in that period"
+                    .to_owned()
+            ]
+        );
+        // No sale at all
+        assert_eq!(reconcile_holdings(&holdings, &[], &[]).len(), 1);
+    }
+
+    #[test]
+    fn test_apply_statement_accounts() {
+        let lot = |trade_date: &str, company: &str| {
+            (
+                trade_date.to_owned(),
+                "04/22/22".to_owned(),
+                "01/03/22".to_owned(),
+                449.97,
+                400.0,
+                Some(company.to_owned()),
+                LotDetails {
+                    account: Some(Account::new("E*TRADE", None)),
+                    ..Default::default()
+                },
+            )
+        };
+        let sold = vec![
+            (
+                Account::new("Morgan Stanley", Some("123-456789-012")),
+                (
+                    "04/20/22".to_owned(),
+                    "04/22/22".to_owned(),
+                    10.0,
+                    45.0,
+                    449.97,
+                    Some("INTC".to_owned()),
+                ),
+            ),
+            (
+                Account::new("E*TRADE", Some("XXXX-1234")),
+                (
+                    "05/20/22".to_owned(),
+                    "05/24/22".to_owned(),
+                    10.0,
+                    45.0,
+                    449.97,
+                    Some("INTC".to_owned()),
+                ),
+            ),
+        ];
+        // Lot of G&L (without account number) sold on day not reported by any statement
+        let mut detailed = vec![
+            lot("04/20/22", "INTC"),
+            lot("05/20/22", "INTC"),
+            lot("06/20/22", "INTC"),
+        ];
+        apply_statement_accounts(&mut detailed, &sold);
+        let accounts: Vec<Option<String>> = detailed
+            .iter()
+            .map(|(_, _, _, _, _, _, lot)| lot.account.as_ref().map(|a| a.to_string()))
+            .collect();
+        assert_eq!(
+            accounts,
+            vec![
+                Some("Morgan Stanley XXX-XXXXX9-012".to_owned()),
+                Some("E*TRADE XXXX-1234".to_owned()),
+                Some("E*TRADE".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_trade_confirmations() {
+        let lot = |acquisition_date: &str, income: f32, cost_basis: f32| {
+            (
+                "04/20/22".to_owned(),
+                "04/22/22".to_owned(),
+                acquisition_date.to_owned(),
+                income,
+                cost_basis,
+                Some("INTEL CORP".to_owned()),
+                LotDetails::default(),
+            )
+        };
+        // Lot sold another day is not affected
+        let mut other_day = lot("02/03/22", 100.0, 90.0);
+        other_day.0 = "04/21/22".to_owned();
+        let mut detailed_sold_transactions = vec![
+            lot("01/03/22", 179.988, 160.0),
+            lot("02/03/22", 269.982, 240.0),
+            other_day,
+        ];
+        apply_trade_confirmations(&mut detailed_sold_transactions, &[sell_confirmation()]);
+        let amounts: Vec<(f32, f32)> = detailed_sold_transactions
+            .iter()
+            .map(|(_, _, _, income, cost_basis, _, _)| {
+                (
+                    (income * 100.0).round() / 100.0,
+                    (cost_basis * 1000.0).round() / 1000.0,
+                )
+            })
+            .collect();
+        // Principal is income, fees are split among lots proportionally
+        assert_eq!(
+            amounts,
+            vec![(180.0, 160.012), (270.0, 240.018), (100.0, 90.0)]
+        );
+    }
+
+    #[test]
+    fn test_sold_transactions_from_1099b() -> Result<(), String> {
+        let lot = crate::Form1099BLot {
+            date_sold: "03/01/23".to_owned(),
+            date_acquired: Some("09/15/22".to_owned()),
+            company: Some("INTEL CORP".to_owned()),
+            symbol: Some("INTC".to_owned()),
+            quantity: 10.0,
+            proceeds: 250.0,
+            cost_basis: 230.0,
+        };
+        assert_eq!(
+            reconstruct_sold_transactions_from_1099b(std::slice::from_ref(&lot))?,
+            vec![(
+                "03/01/23".to_owned(),
+                "03/01/23".to_owned(),
+                "09/15/22".to_owned(),
+                250.0,
+                230.0,
+                Some("INTEL CORP".to_owned()),
+                LotDetails {
+                    quantity: Some(10.0),
+                    cost_basis_per_share: Some(23.0),
+                    proceeds_per_share: Some(25.0),
+                    ..Default::default()
+                },
+            )]
+        );
+        // Lot of various acquisition dates can not be taxed
+        assert!(
+            reconstruct_sold_transactions_from_1099b(&[crate::Form1099BLot {
+                date_acquired: None,
+                ..lot
+            }])
+            .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_reconcile_form_1042s() {
+        let forms = vec![
+            Form1042S {
+                income_code: "06".to_owned(),
+                gross_income: 723.0,
+                tax_withheld: 108.0,
+            },
+            Form1042S {
+                income_code: "01".to_owned(),
+                gross_income: 12.0,
+                tax_withheld: 0.0,
+            },
+            Form1042S {
+                income_code: "16".to_owned(),
+                gross_income: 5.0,
+                tax_withheld: 0.0,
+            },
+        ];
+        let interests = vec![("03/01/23".to_owned(), 12.4, 0.0, None)];
+        let dividends = vec![
+            (
+                "03/01/23".to_owned(),
+                698.25,
+                104.74,
+                Some("INTEL CORP".to_owned()),
+            ),
+            (
+                "06/01/23".to_owned(),
+                24.0,
+                3.6,
+                Some("INTEL CORP".to_owned()),
+            ),
+        ];
+        let (report, discrepancies) = reconcile_form_1042s(&forms, &interests, &dividends);
+        assert_eq!(report.len(), 3);
+        assert!(discrepancies.is_empty());
+
+        // Statement with one of dividends is missing
+        let (_, discrepancies) = reconcile_form_1042s(&forms, &interests, &dividends[..1]);
+        assert_eq!(discrepancies.len(), 1);
+    }
 }
@@ -1,19 +1,63 @@
 // SPDX-FileCopyrightText: 2022-2025 RustInFinance
 // SPDX-License-Identifier: BSD-3-Clause
 
-pub struct US {}
+pub struct US {
+    // Sources of exchange rates given by user (e.g. corporate rates), asked before fixed rate of USD
+    custom_providers: Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>>,
+}
+
+impl US {
+    pub(crate) fn with_exchange_rate_providers(
+        providers: Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>>,
+    ) -> Self {
+        US {
+            custom_providers: providers,
+        }
+    }
+}
+
+impl Default for US {
+    fn default() -> Self {
+        Self::with_exchange_rate_providers(vec![])
+    }
+}
 impl etradeTaxReturnHelper::Residency for US {
-    fn get_exchange_rates(
+    fn currency(&self) -> &'static str {
+        "USD"
+    }
+
+    // Amounts are already in USD so no exchange rate is really chosen
+    fn rate_date_rule(&self) -> etradeTaxReturnHelper::RateDateRule {
+        etradeTaxReturnHelper::RateDateRule {
+            sale_date: etradeTaxReturnHelper::SaleDate::Trade,
+            rate_date: etradeTaxReturnHelper::same_day,
+        }
+    }
+
+    // Discount is ordinary income (reported on W-2) and cost basis is adjusted by it
+    fn espp_policy(&self) -> etradeTaxReturnHelper::EsppPolicy {
+        etradeTaxReturnHelper::EsppPolicy {
+            discount_is_income: true,
+            cost_basis: etradeTaxReturnHelper::EsppCostBasis::MarketValue,
+        }
+    }
+
+    // Income is reported for the year of Form 1099 it is listed in
+    fn tax_year_rule(&self) -> etradeTaxReturnHelper::TaxYearRule {
+        etradeTaxReturnHelper::TaxYearRule::ReportableYear
+    }
+
+    fn custom_exchange_rate_providers(
         &self,
-        dates: &mut std::collections::HashMap<
-            etradeTaxReturnHelper::Exchange,
-            Option<(String, f32)>,
-        >,
-    ) -> Result<(), String> {
-        dates.iter_mut().for_each(|(_date, val)| {
-            *val = Some(("N/A".to_owned(), 1.0));
-        });
-        Ok(())
+    ) -> &[Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>] {
+        &self.custom_providers
+    }
+
+    // Amounts are already in USD
+    fn exchange_rate_providers(&self) -> Vec<Box<dyn etradeTaxReturnHelper::ExchangeRateProvider>> {
+        vec![Box::new(etradeTaxReturnHelper::FixedRateProvider::new(
+            "N/A", 1.0,
+        ))]
     }
 
     fn present_result(
@@ -33,14 +77,46 @@ impl etradeTaxReturnHelper::Residency for US {
         ));
         (presentation, None)
     }
+
+    // Shares held for more than one year (sold after anniversary of acquisition) are long
+    // term capital gain. Number of days is not used as year may have leap day
+    fn holding_period_term(
+        &self,
+        acquisition_date: &chrono::NaiveDate,
+        trade_date: &chrono::NaiveDate,
+    ) -> Option<&'static str> {
+        match acquisition_date.checked_add_months(chrono::Months::new(12)) {
+            Some(anniversary) if *trade_date > anniversary => Some("LONG TERM"),
+            _ => Some("SHORT TERM"),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use etradeTaxReturnHelper::Residency;
+
+    #[test]
+    fn test_holding_period_term_us() {
+        let rd = US::default();
+        let term = |acquisition_date: &str, trade_date: &str| {
+            let d = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+            rd.holding_period_term(&d(acquisition_date), &d(trade_date))
+        };
+        assert_eq!(term("2022-01-10", "2022-02-09"), Some("SHORT TERM"));
+        assert_eq!(term("2022-01-10", "2023-01-10"), Some("SHORT TERM"));
+        assert_eq!(term("2022-01-10", "2023-01-11"), Some("LONG TERM"));
+        // Year across leap day has 366 days
+        assert_eq!(term("2023-03-01", "2024-03-01"), Some("SHORT TERM"));
+        assert_eq!(term("2023-03-01", "2024-03-02"), Some("LONG TERM"));
+        // Anniversary of shares acquired on leap day is the last day of February
+        assert_eq!(term("2024-02-29", "2025-02-28"), Some("SHORT TERM"));
+        assert_eq!(term("2024-02-29", "2025-03-01"), Some("LONG TERM"));
+    }
     #[test]
     fn test_present_result_us() -> Result<(), String> {
-        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(US {});
+        let rd: Box<dyn etradeTaxReturnHelper::Residency> = Box::new(US::default());
 
         let gross_div = 100.0f32;
         let tax_div = 15.0f32;
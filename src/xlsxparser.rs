@@ -1,9 +1,33 @@
 // SPDX-FileCopyrightText: 2022-2025 RustInFinance
 // SPDX-License-Identifier: BSD-3-Clause
 
-use calamine::{open_workbook, Reader, Xlsx};
+use calamine::{open_workbook, DataType, Reader, Xlsx};
 
 pub use crate::logging::ResultExt;
+use crate::{Account, LotDetails, PlanType};
+
+// G&L documents are downloaded from E*TRADE stock plan account. They do not report account
+// number
+const BROKER: &str = "E*TRADE";
+
+fn get_optional_string(cell: Option<&DataType>) -> Option<String> {
+    cell.and_then(|c| c.get_string())
+        .map(|s| s.trim().to_owned())
+        .filter(|s| !s.is_empty())
+}
+
+fn get_optional_float(cell: Option<&DataType>) -> Option<f32> {
+    cell.and_then(|c| c.get_float().or(c.get_int().map(|v| v as f64)))
+        .map(|v| v as f32)
+}
+
+fn parse_plan_type(plan_type: &str) -> PlanType {
+    match plan_type {
+        "RS" | "RSU" => PlanType::RS,
+        "ESPP" => PlanType::ESPP,
+        _ => PlanType::Other(plan_type.to_owned()),
+    }
+}
 
 /// This function parses G&L Collappsed and Expanded for needed transaction details
 /// and it returns found sold transactions in a form:
@@ -12,9 +36,10 @@ pub use crate::logging::ResultExt;
 /// aqusition cost of sold stock (aquisition_cost)
 /// adjusted aquisition cost of sold stock (cost_basis)
 /// income from sold stock (total_proceeds)
+/// details of sold lot e.g. quantity, plan type, grant date, account (lot details)
 pub fn parse_gains_and_losses(
     xlsxtoparse: &str,
-) -> Result<Vec<(String, String, f32, f32, f32)>, &str> {
+) -> Result<Vec<(String, String, f32, f32, f32, LotDetails)>, &str> {
     let mut excel: Xlsx<_> =
         open_workbook(xlsxtoparse).map_err(|_| "Error opening XLSX file: {}")?;
     let name = excel
@@ -23,7 +48,7 @@ pub fn parse_gains_and_losses(
         .expect_and_log("No worksheet found")
         .clone();
     log::info!("name: {}", name);
-    let mut transactions: Vec<(String, String, f32, f32, f32)> = vec![];
+    let mut transactions: Vec<(String, String, f32, f32, f32, LotDetails)> = vec![];
     if let Some(Ok(r)) = excel.worksheet_range(&name) {
         let mut rows = r.rows();
         let categories = rows
@@ -34,6 +59,13 @@ pub fn parse_gains_and_losses(
         let mut cost_basis_idx = 0;
         let mut acquistion_cost_idx = 0;
         let mut total_proceeds_idx = 0;
+        // Columns describing sold lot are not present in all variants of G&L
+        let mut quantity_idx: Option<usize> = None;
+        let mut plan_type_idx: Option<usize> = None;
+        let mut grant_date_idx: Option<usize> = None;
+        let mut order_type_idx: Option<usize> = None;
+        let mut cost_basis_per_share_idx: Option<usize> = None;
+        let mut proceeds_per_share_idx: Option<usize> = None;
 
         let mut idx = 0;
         for c in categories {
@@ -45,6 +77,17 @@ pub fn parse_gains_and_losses(
                     "Acquisition Cost" | "Koszt zakupu" => acquistion_cost_idx = idx,
                     "Adjusted Cost Basis" | "Skorygowana podstawa kosztów" => cost_basis_idx = idx,
                     "Total Proceeds" | "Łączne wpływy" => total_proceeds_idx = idx,
+                    "Qty." | "Liczba" => quantity_idx = Some(idx),
+                    "Plan Type" | "Rodzaj programu" => plan_type_idx = Some(idx),
+                    "Grant Date" | "Data przyznania" => grant_date_idx = Some(idx),
+                    "Order Type" | "Rodzaj zlecenia" => order_type_idx = Some(idx),
+                    "Adjusted Cost Basis Per Share"
+                    | "Skorygowana podstawa kalkulacji kosztów na akcję" => {
+                        cost_basis_per_share_idx = Some(idx)
+                    }
+                    "Proceeds Per Share" | "Dochód na akcję" => {
+                        proceeds_per_share_idx = Some(idx)
+                    }
                     _ => (),
                 }
             }
@@ -79,6 +122,22 @@ pub fn parse_gains_and_losses(
                 break;
             }
 
+            let lot = LotDetails {
+                quantity: get_optional_float(quantity_idx.map(|i| &transakcja[i])),
+                plan_type: get_optional_string(plan_type_idx.map(|i| &transakcja[i]))
+                    .map(|p| parse_plan_type(&p)),
+                grant_date: get_optional_string(grant_date_idx.map(|i| &transakcja[i])),
+                order_type: get_optional_string(order_type_idx.map(|i| &transakcja[i])),
+                cost_basis_per_share: get_optional_float(
+                    cost_basis_per_share_idx.map(|i| &transakcja[i]),
+                ),
+                proceeds_per_share: get_optional_float(
+                    proceeds_per_share_idx.map(|i| &transakcja[i]),
+                ),
+                account: Some(Account::new(BROKER, None)),
+            };
+            log::info!("G&L LOT DETAILS: {}", lot.format_to_print());
+
             //println!("transakcja: {:?}", transakcja);
             transactions.push((
                 transakcja[date_acquired_idx]
@@ -89,6 +148,7 @@ pub fn parse_gains_and_losses(
                 transakcja[acquistion_cost_idx].get_float().unwrap() as f32,
                 transakcja[cost_basis_idx].get_float().unwrap() as f32,
                 transakcja[total_proceeds_idx].get_float().unwrap() as f32,
+                lot,
             ));
         }
     }
@@ -100,10 +160,23 @@ pub fn parse_gains_and_losses(
 mod tests {
     use super::*;
 
+    fn without_lot(
+        transactions: Vec<(String, String, f32, f32, f32, LotDetails)>,
+    ) -> Vec<(String, String, f32, f32, f32)> {
+        transactions
+            .into_iter()
+            .map(
+                |(acquired, sold, acquisition_cost, cost_basis, proceeds, _)| {
+                    (acquired, sold, acquisition_cost, cost_basis, proceeds)
+                },
+            )
+            .collect()
+    }
+
     #[test]
     fn test_parse_gain_and_losses() -> Result<(), String> {
         assert_eq!(
-            parse_gains_and_losses("data/G&L_Collapsed.xlsx"),
+            parse_gains_and_losses("data/G&L_Collapsed.xlsx").map(without_lot),
             Ok(vec![
                 (
                     "04/24/2013".to_owned(),
@@ -122,7 +195,7 @@ mod tests {
             ])
         );
         assert_eq!(
-            parse_gains_and_losses("data/G&L_Expanded.xlsx"),
+            parse_gains_and_losses("data/G&L_Expanded.xlsx").map(without_lot),
             Ok(vec![
                 (
                     "04/24/2013".to_owned(),
@@ -147,7 +220,7 @@ mod tests {
     #[test]
     fn test_parse_gain_and_losses_pl() -> Result<(), String> {
         assert_eq!(
-            parse_gains_and_losses("data/G&L_Expanded_polish.xlsx"),
+            parse_gains_and_losses("data/G&L_Expanded_polish.xlsx").map(without_lot),
             Ok(vec![
                 (
                     "02/17/2023".to_owned(),
@@ -223,4 +296,95 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_parse_gain_and_losses_lot_details() -> Result<(), String> {
+        let lots: Vec<LotDetails> = parse_gains_and_losses("data/G&L_Expanded.xlsx")?
+            .into_iter()
+            .map(|(_, _, _, _, _, lot)| lot)
+            .collect();
+        assert_eq!(
+            lots,
+            vec![
+                LotDetails {
+                    quantity: Some(1.0),
+                    plan_type: Some(PlanType::RS),
+                    grant_date: Some("04/24/2012".to_owned()),
+                    order_type: Some("Sell Restricted Stock".to_owned()),
+                    cost_basis_per_share: Some(23.5175),
+                    proceeds_per_share: Some(46.9),
+                    account: Some(Account::new(BROKER, None)),
+                },
+                LotDetails {
+                    quantity: Some(1.0),
+                    plan_type: Some(PlanType::ESPP),
+                    grant_date: Some("01/30/2015".to_owned()),
+                    order_type: Some("Sell ESPP".to_owned()),
+                    cost_basis_per_share: Some(29.28195),
+                    proceeds_per_share: Some(43.67),
+                    account: Some(Account::new(BROKER, None)),
+                },
+            ]
+        );
+
+        // Collapsed document is missing grant date and order type
+        let lots: Vec<LotDetails> = parse_gains_and_losses("data/G&L_Collapsed.xlsx")?
+            .into_iter()
+            .map(|(_, _, _, _, _, lot)| lot)
+            .collect();
+        assert_eq!(
+            lots,
+            vec![
+                LotDetails {
+                    quantity: Some(1.0),
+                    plan_type: Some(PlanType::RS),
+                    grant_date: None,
+                    order_type: None,
+                    cost_basis_per_share: Some(23.5175),
+                    proceeds_per_share: Some(46.9),
+                    account: Some(Account::new(BROKER, None)),
+                },
+                LotDetails {
+                    quantity: Some(1.0),
+                    plan_type: Some(PlanType::ESPP),
+                    grant_date: None,
+                    order_type: None,
+                    cost_basis_per_share: Some(29.28195),
+                    proceeds_per_share: Some(43.67),
+                    account: Some(Account::new(BROKER, None)),
+                },
+            ]
+        );
+
+        let lots: Vec<LotDetails> = parse_gains_and_losses("data/G&L_Expanded_polish.xlsx")?
+            .into_iter()
+            .map(|(_, _, _, _, _, lot)| lot)
+            .collect();
+        assert_eq!(lots.len(), 10);
+        assert_eq!(
+            lots[0],
+            LotDetails {
+                quantity: Some(76.0),
+                plan_type: Some(PlanType::ESPP),
+                grant_date: Some("07/29/2022".to_owned()),
+                order_type: Some("Sell ESPP".to_owned()),
+                cost_basis_per_share: Some(27.725),
+                proceeds_per_share: Some(26.557297),
+                account: Some(Account::new(BROKER, None)),
+            }
+        );
+        assert_eq!(
+            lots[1],
+            LotDetails {
+                quantity: Some(7.0),
+                plan_type: Some(PlanType::RS),
+                grant_date: Some("04/30/2022".to_owned()),
+                order_type: Some("Sell Restricted Stock".to_owned()),
+                cost_basis_per_share: Some(36.87),
+                proceeds_per_share: Some(31.289642),
+                account: Some(Account::new(BROKER, None)),
+            }
+        );
+        Ok(())
+    }
 }